@@ -1,13 +1,18 @@
-#ifdef __LIBBPF_SYS_NOVENDOR
+#if defined(__LIBBPF_SYS_EBPF_FOR_WINDOWS)
+#include <bpf/bpf.h>
+#include <bpf/libbpf.h>
+#elif defined(__LIBBPF_SYS_NOVENDOR)
 #include <linux/if_link.h>
 #include <linux/perf_event.h>
 #include <bpf/bpf.h>
 #include <bpf/btf.h>
 #include <bpf/libbpf.h>
+#include <linux/pkt_cls.h>
 #else
 #include "libbpf/include/uapi/linux/if_link.h"
 #include "libbpf/include/uapi/linux/perf_event.h"
 #include "libbpf/src/bpf.h"
 #include "libbpf/src/btf.h"
 #include "libbpf/src/libbpf.h"
-#endif /* __LIBBPF_SYS_NOVENDOR */
+#include "libbpf/include/uapi/linux/pkt_cls.h"
+#endif
@@ -0,0 +1,5 @@
+// btf-only/include/asm/posix_types.h
+
+#if __has_include_next(<asm/posix_types.h>)
+#include_next <asm/posix_types.h>
+#endif
@@ -0,0 +1,17 @@
+// btf-only/include/asm/types.h
+
+#if __has_include_next(<asm/types.h>)
+#include_next <asm/types.h>
+#elif !defined(LIBBPF_SYS_ASM_TYPES_H)
+#define LIBBPF_SYS_ASM_TYPES_H
+
+typedef signed char __s8;
+typedef unsigned char __u8;
+typedef signed short __s16;
+typedef unsigned short __u16;
+typedef signed int __s32;
+typedef unsigned int __u32;
+typedef signed long long __s64;
+typedef unsigned long long __u64;
+
+#endif
@@ -0,0 +1,12 @@
+// btf-only/include/byteswap.h
+
+#if __has_include_next(<byteswap.h>)
+#include_next <byteswap.h>
+#elif !defined(LIBBPF_SYS_BYTESWAP_H)
+#define LIBBPF_SYS_BYTESWAP_H
+
+#define bswap_16(x) __builtin_bswap16(x)
+#define bswap_32(x) __builtin_bswap32(x)
+#define bswap_64(x) __builtin_bswap64(x)
+
+#endif
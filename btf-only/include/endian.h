@@ -0,0 +1,7 @@
+// btf-only/include/endian.h
+
+/* libbpf goes by the compiler's `__BYTE_ORDER__` instead. */
+
+#if __has_include_next(<endian.h>)
+#include_next <endian.h>
+#endif
@@ -0,0 +1,22 @@
+// btf-only/include/fcntl.h
+
+#include_next <fcntl.h>
+
+#if defined(_WIN32) && !defined(LIBBPF_SYS_FCNTL_H)
+#define LIBBPF_SYS_FCNTL_H
+
+#include <errno.h>
+
+#ifndef O_CLOEXEC
+#define O_CLOEXEC 0
+#endif
+#ifndef F_DUPFD_CLOEXEC
+#define F_DUPFD_CLOEXEC 1030
+#endif
+
+static inline int fcntl(int fd, int cmd, ...)
+{
+	errno = ENOSYS;
+	return -1;
+}
+#endif
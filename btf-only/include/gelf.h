@@ -0,0 +1,24 @@
+// btf-only/include/gelf.h
+
+/*
+ * The subset of libelf's class independent API libbpf's BTF code uses,
+ * implemented by `shim.c`.
+ */
+
+#ifndef LIBBPF_SYS_GELF_H
+#define LIBBPF_SYS_GELF_H
+
+#include <libelf.h>
+
+typedef Elf64_Ehdr GElf_Ehdr;
+typedef Elf64_Shdr GElf_Shdr;
+
+#define gelf_getclass libbpf_sys_gelf_getclass
+#define gelf_getehdr libbpf_sys_gelf_getehdr
+#define gelf_getshdr libbpf_sys_gelf_getshdr
+
+int gelf_getclass(Elf *elf);
+GElf_Ehdr *gelf_getehdr(Elf *elf, GElf_Ehdr *dst);
+GElf_Shdr *gelf_getshdr(Elf_Scn *scn, GElf_Shdr *dst);
+
+#endif /* LIBBPF_SYS_GELF_H */
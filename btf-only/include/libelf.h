@@ -0,0 +1,107 @@
+// btf-only/include/libelf.h
+
+/*
+ * The subset of libelf's API libbpf's BTF code uses, implemented by
+ * `shim.c`. The functions get renamed, so that they don't clash with a
+ * real libelf linked into the same binary.
+ */
+
+#ifndef LIBBPF_SYS_LIBELF_H
+#define LIBBPF_SYS_LIBELF_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#if __has_include(<elf.h>)
+#include <elf.h>
+#else
+#define EI_NIDENT 16
+#define ELFCLASSNONE 0
+#define ELFCLASS32 1
+#define ELFCLASS64 2
+#define EV_NONE 0
+#define EV_CURRENT 1
+#define SHT_NULL 0
+#define SHT_PROGBITS 1
+#define SHT_SYMTAB 2
+#define SHT_STRTAB 3
+#define SHT_REL 9
+
+typedef struct {
+	unsigned char e_ident[EI_NIDENT];
+	uint16_t e_type;
+	uint16_t e_machine;
+	uint32_t e_version;
+	uint64_t e_entry;
+	uint64_t e_phoff;
+	uint64_t e_shoff;
+	uint32_t e_flags;
+	uint16_t e_ehsize;
+	uint16_t e_phentsize;
+	uint16_t e_phnum;
+	uint16_t e_shentsize;
+	uint16_t e_shnum;
+	uint16_t e_shstrndx;
+} Elf64_Ehdr;
+
+typedef struct {
+	uint32_t sh_name;
+	uint32_t sh_type;
+	uint64_t sh_flags;
+	uint64_t sh_addr;
+	uint64_t sh_offset;
+	uint64_t sh_size;
+	uint32_t sh_link;
+	uint32_t sh_info;
+	uint64_t sh_addralign;
+	uint64_t sh_entsize;
+} Elf64_Shdr;
+#endif
+
+typedef struct Elf Elf;
+typedef struct Elf_Scn Elf_Scn;
+
+typedef enum {
+	ELF_C_NULL,
+	ELF_C_READ,
+	ELF_C_RDWR,
+	ELF_C_WRITE,
+	ELF_C_CLR,
+	ELF_C_SET,
+	ELF_C_FDDONE,
+	ELF_C_FDREAD,
+	ELF_C_READ_MMAP,
+} Elf_Cmd;
+
+typedef struct {
+	void *d_buf;
+	int d_type;
+	unsigned int d_version;
+	size_t d_size;
+	int64_t d_off;
+	size_t d_align;
+} Elf_Data;
+
+#define elf_version libbpf_sys_elf_version
+#define elf_begin libbpf_sys_elf_begin
+#define elf_end libbpf_sys_elf_end
+#define elf_errmsg libbpf_sys_elf_errmsg
+#define elf_getshdrstrndx libbpf_sys_elf_getshdrstrndx
+#define elf_getscn libbpf_sys_elf_getscn
+#define elf_nextscn libbpf_sys_elf_nextscn
+#define elf_getdata libbpf_sys_elf_getdata
+#define elf_rawdata libbpf_sys_elf_rawdata
+#define elf_strptr libbpf_sys_elf_strptr
+
+unsigned int elf_version(unsigned int version);
+Elf *elf_begin(int fd, Elf_Cmd cmd, Elf *ref);
+int elf_end(Elf *elf);
+const char *elf_errmsg(int error);
+int elf_getshdrstrndx(Elf *elf, size_t *dst);
+Elf_Scn *elf_getscn(Elf *elf, size_t index);
+Elf_Scn *elf_nextscn(Elf *elf, Elf_Scn *scn);
+Elf_Data *elf_getdata(Elf_Scn *scn, Elf_Data *data);
+Elf_Data *elf_rawdata(Elf_Scn *scn, Elf_Data *data);
+char *elf_strptr(Elf *elf, size_t index, size_t offset);
+
+#endif /* LIBBPF_SYS_LIBELF_H */
@@ -0,0 +1,13 @@
+// btf-only/include/string.h
+
+#include_next <string.h>
+
+#if defined(_WIN32) && !defined(LIBBPF_SYS_STRING_H)
+#define LIBBPF_SYS_STRING_H
+
+/* The XSI `strerror_r`, which `libbpf_strerror` uses. */
+static inline int strerror_r(int errnum, char *buf, size_t buflen)
+{
+	return strerror_s(buf, buflen, errnum);
+}
+#endif
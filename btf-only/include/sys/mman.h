@@ -0,0 +1,5 @@
+// btf-only/include/sys/mman.h
+
+#if __has_include_next(<sys/mman.h>)
+#include_next <sys/mman.h>
+#endif
@@ -0,0 +1,24 @@
+// btf-only/include/sys/syscall.h
+
+/*
+ * libbpf's internal header wraps a few Linux system calls, which the
+ * BTF code never makes. Elsewhere, they merely have to compile.
+ */
+
+#if __has_include_next(<sys/syscall.h>)
+#include_next <sys/syscall.h>
+#elif !defined(LIBBPF_SYS_SYS_SYSCALL_H)
+#define LIBBPF_SYS_SYS_SYSCALL_H
+
+#include <errno.h>
+
+static inline long syscall(long number, ...)
+{
+	errno = ENOSYS;
+	return -1;
+}
+#endif
+
+#ifndef __NR_dup3
+#define __NR_dup3 -1
+#endif
@@ -0,0 +1,24 @@
+// btf-only/include/sys/utsname.h
+
+#if __has_include_next(<sys/utsname.h>)
+#include_next <sys/utsname.h>
+#elif !defined(LIBBPF_SYS_SYS_UTSNAME_H)
+#define LIBBPF_SYS_SYS_UTSNAME_H
+
+#include <errno.h>
+
+/* Only used to locate the kernel's BTF, which fails without `uname`. */
+struct utsname {
+	char sysname[65];
+	char nodename[65];
+	char release[65];
+	char version[65];
+	char machine[65];
+};
+
+static inline int uname(struct utsname *buf)
+{
+	errno = ENOSYS;
+	return -1;
+}
+#endif
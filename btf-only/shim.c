@@ -0,0 +1,159 @@
+// btf-only/shim.c
+
+/*
+ * Stand-ins for the parts of libbpf and libelf the BTF code calls into,
+ * for the `btf-only` feature. Everything talking to the kernel fails
+ * with -EOPNOTSUPP, and so does parsing BTF from ELF files, with libelf
+ * refusing to initialize.
+ */
+
+#include <errno.h>
+#include <stdarg.h>
+#include <stdio.h>
+
+#include "bpf.h"
+#include "btf.h"
+#include "libbpf.h"
+#include "libbpf_internal.h"
+#include "libbpf_version.h"
+
+static int base_print(enum libbpf_print_level level, const char *format, va_list args)
+{
+	if (level == LIBBPF_DEBUG)
+		return 0;
+	return vfprintf(stderr, format, args);
+}
+
+static libbpf_print_fn_t print_fn = base_print;
+
+libbpf_print_fn_t libbpf_set_print(libbpf_print_fn_t fn)
+{
+	libbpf_print_fn_t old_print_fn = print_fn;
+
+	print_fn = fn;
+	return old_print_fn;
+}
+
+void libbpf_print(enum libbpf_print_level level, const char *format, ...)
+{
+	int old_errno = errno;
+	va_list args;
+
+	if (print_fn) {
+		va_start(args, format);
+		print_fn(level, format, args);
+		va_end(args);
+	}
+	errno = old_errno;
+}
+
+__u32 libbpf_major_version(void)
+{
+	return LIBBPF_MAJOR_VERSION;
+}
+
+__u32 libbpf_minor_version(void)
+{
+	return LIBBPF_MINOR_VERSION;
+}
+
+#define STR(x) #x
+#define VERSION_STRING(major, minor) "v" STR(major) "." STR(minor)
+
+const char *libbpf_version_string(void)
+{
+	return VERSION_STRING(LIBBPF_MAJOR_VERSION, LIBBPF_MINOR_VERSION);
+}
+
+int bpf_btf_load(const void *btf_data, size_t btf_size, struct bpf_btf_load_opts *opts)
+{
+	return libbpf_err(-EOPNOTSUPP);
+}
+
+int bpf_btf_get_fd_by_id(__u32 id)
+{
+	return libbpf_err(-EOPNOTSUPP);
+}
+
+int bpf_btf_get_fd_by_id_opts(__u32 id, const struct bpf_get_fd_by_id_opts *opts)
+{
+	return libbpf_err(-EOPNOTSUPP);
+}
+
+int bpf_obj_get_info_by_fd(int bpf_fd, void *info, __u32 *info_len)
+{
+	return libbpf_err(-EOPNOTSUPP);
+}
+
+int bpf_btf_get_info_by_fd(int btf_fd, struct bpf_btf_info *info, __u32 *info_len)
+{
+	return libbpf_err(-EOPNOTSUPP);
+}
+
+/* `include/libelf.h` and `include/gelf.h` rename these, so that they
+ * don't clash with a real libelf linked into the same binary. */
+
+unsigned int elf_version(unsigned int version)
+{
+	return EV_NONE;
+}
+
+Elf *elf_begin(int fd, Elf_Cmd cmd, Elf *ref)
+{
+	return NULL;
+}
+
+int elf_end(Elf *elf)
+{
+	return 0;
+}
+
+const char *elf_errmsg(int error)
+{
+	return "ELF files are not supported with the btf-only feature of libbpf-sys";
+}
+
+int elf_getshdrstrndx(Elf *elf, size_t *dst)
+{
+	return -1;
+}
+
+Elf_Scn *elf_getscn(Elf *elf, size_t index)
+{
+	return NULL;
+}
+
+Elf_Scn *elf_nextscn(Elf *elf, Elf_Scn *scn)
+{
+	return NULL;
+}
+
+Elf_Data *elf_getdata(Elf_Scn *scn, Elf_Data *data)
+{
+	return NULL;
+}
+
+Elf_Data *elf_rawdata(Elf_Scn *scn, Elf_Data *data)
+{
+	return NULL;
+}
+
+char *elf_strptr(Elf *elf, size_t index, size_t offset)
+{
+	return NULL;
+}
+
+int gelf_getclass(Elf *elf)
+{
+	return ELFCLASSNONE;
+}
+
+GElf_Ehdr *gelf_getehdr(Elf *elf, GElf_Ehdr *dst)
+{
+	return NULL;
+}
+
+GElf_Shdr *gelf_getshdr(Elf_Scn *scn, GElf_Shdr *dst)
+{
+	return NULL;
+}
@@ -1,17 +1,16 @@
 // build.rs
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::env;
 use std::ffi;
 use std::fs;
 use std::fs::read_dir;
-use std::fs::File;
 use std::io;
 use std::path;
 use std::path::Path;
 use std::process;
 
-use nix::fcntl;
-
 fn emit_rerun_directives_for_contents(dir: &Path) {
     for result in read_dir(dir).unwrap() {
         let file = result.unwrap();
@@ -19,223 +18,3104 @@ fn emit_rerun_directives_for_contents(dir: &Path) {
     }
 }
 
-#[cfg(feature = "bindgen")]
-fn generate_bindings(src_dir: path::PathBuf) {
-    use std::collections::HashSet;
+#[cfg(feature = "bindgen")]
+fn generate_bindings(src_dir: path::PathBuf) {
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct Callbacks {
+        ignored_macros: HashSet<&'static str>,
+        serde_types: HashSet<&'static str>,
+        pod_types: HashSet<&'static str>,
+        src_dir: path::PathBuf,
+        /// The kernel headers included from outside of the source tree.
+        system_uapi_headers: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl bindgen::callbacks::ParseCallbacks for Callbacks {
+        fn will_parse_macro(&self, name: &str) -> bindgen::callbacks::MacroParsingBehavior {
+            if self.ignored_macros.contains(name) {
+                bindgen::callbacks::MacroParsingBehavior::Ignore
+            } else {
+                bindgen::callbacks::MacroParsingBehavior::Default
+            }
+        }
+
+        fn add_attributes(&self, info: &bindgen::callbacks::AttributeInfo<'_>) -> Vec<String> {
+            let mut attrs = Vec::new();
+            if self.serde_types.contains(info.name) {
+                attrs.push(
+                    r#"#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]"#
+                        .to_string(),
+                );
+            }
+            if self.pod_types.contains(info.name) {
+                attrs.push(
+                    r#"#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]"#
+                        .to_string(),
+                );
+            }
+            // bindgen only carries over alignment attributes exceeding
+            // the natural alignment of the target it generates for.
+            if UAPI_ALIGNED_TYPES.contains(&info.name) {
+                attrs.push("#[repr(align(8))]".to_string());
+            }
+            attrs
+        }
+
+        fn include_file(&self, filename: &str) {
+            let path = Path::new(filename);
+            let uapi = path.components().any(|component| {
+                matches!(
+                    component.as_os_str().to_str(),
+                    Some("linux" | "asm" | "asm-generic")
+                )
+            });
+            if uapi && path.is_absolute() && !path.starts_with(&self.src_dir) {
+                self.system_uapi_headers
+                    .borrow_mut()
+                    .push(filename.to_string());
+            }
+        }
+    }
+
+    /// Check whether the function `name` hands out a resource, i.e., a
+    /// file descriptor or an object, that the caller has to release.
+    fn returns_resource(name: &str) -> bool {
+        const FUNCTIONS: &[&str] = &[
+            "bpf_btf_get_fd_by_id",
+            "bpf_btf_get_fd_by_id_opts",
+            "bpf_btf_load",
+            "bpf_enable_stats",
+            "bpf_iter_create",
+            "bpf_link__open",
+            "bpf_link_create",
+            "bpf_link_get_fd_by_id",
+            "bpf_link_get_fd_by_id_opts",
+            "bpf_linker__new",
+            "bpf_map__attach_struct_ops",
+            "bpf_map_create",
+            "bpf_map_get_fd_by_id",
+            "bpf_map_get_fd_by_id_opts",
+            "bpf_obj_get",
+            "bpf_obj_get_opts",
+            "bpf_prog_get_fd_by_id",
+            "bpf_prog_get_fd_by_id_opts",
+            "bpf_prog_linfo__new",
+            "bpf_prog_load",
+            "bpf_raw_tracepoint_open",
+            "bpf_raw_tracepoint_open_opts",
+            "bpf_token_create",
+            "btf__load_from_kernel_by_id",
+            "btf__load_from_kernel_by_id_split",
+            "btf__load_module_btf",
+            "btf__load_vmlinux_btf",
+            "btf_dump__new",
+            "btf_ext__new",
+            "libbpf_find_kernel_btf",
+            "perf_buffer__new",
+            "perf_buffer__new_raw",
+            "ring_buffer__new",
+            "user_ring_buffer__new",
+            "user_ring_buffer__reserve",
+            "user_ring_buffer__reserve_blocking",
+        ];
+        const PREFIXES: &[&str] = &[
+            "bpf_object__open",
+            "bpf_program__attach",
+            "btf__new",
+            "btf__parse",
+        ];
+
+        FUNCTIONS.contains(&name) || PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+    }
+
+    /// Mark functions returning resources as `#[must_use]`, so that
+    /// leaking them is caught at compile time.
+    ///
+    /// bindgen's parse callbacks can't add attributes to functions,
+    /// hence this operates on the generated source.
+    fn add_must_use(bindings: &str) -> String {
+        let mut out = String::with_capacity(bindings.len());
+        for line in bindings.lines() {
+            let name = line
+                .strip_prefix("    pub fn ")
+                .and_then(|rest| rest.split('(').next());
+            if name.is_some_and(returns_resource) {
+                out.push_str("    #[must_use]\n");
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+        out
+    }
+
+    let system_uapi_headers = Rc::new(RefCell::new(Vec::new()));
+    let callbacks = Callbacks {
+        src_dir: src_dir.clone(),
+        system_uapi_headers: system_uapi_headers.clone(),
+        ignored_macros: vec![
+            "BTF_KIND_FUNC",
+            "BTF_KIND_FUNC_PROTO",
+            "BTF_KIND_VAR",
+            "BTF_KIND_DATASEC",
+            "BTF_KIND_FLOAT",
+            "BTF_KIND_DECL_TAG",
+            "BTF_KIND_TYPE_TAG",
+            "BTF_KIND_ENUM64",
+        ]
+        .into_iter()
+        .collect(),
+        // Info structs consisting of plain integers only. Those with
+        // bitfields or unions are taken care of in `src/serialize.rs`.
+        serde_types: vec!["bpf_btf_info", "bpf_map_info"].into_iter().collect(),
+        // Types commonly found in byte buffers, such as BTF and .BTF.ext
+        // sections, that consist of integers only and have no implicit
+        // padding. `Pod` cannot be derived for unions, so those are
+        // taken care of in `src/pod.rs`.
+        pod_types: vec![
+            "bpf_btf_info",
+            "bpf_core_relo",
+            "bpf_func_info",
+            "bpf_insn",
+            "bpf_line_info",
+            "bpf_map_info",
+            "bpf_prog_info",
+            "btf_array",
+            "btf_decl_tag",
+            "btf_enum",
+            "btf_enum64",
+            "btf_header",
+            "btf_member",
+            "btf_param",
+            "btf_type",
+            "btf_var",
+            "btf_var_secinfo",
+            "perf_event_header",
+        ]
+        .into_iter()
+        .collect(),
+    };
+
+    #[cfg(feature = "bindgen-source")]
+    check_shipped_bindings_target();
+    #[cfg(feature = "bindgen-source")]
+    let out_dir = &src_dir.join("src");
+    #[cfg(not(feature = "bindgen-source"))]
+    let out_dir =
+        &path::PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR should always be set"));
+
+    let mut builder = bindgen::Builder::default()
+        .derive_default(true)
+        .use_core()
+        .ctypes_prefix("::core::ffi")
+        .explicit_padding(true)
+        .default_enum_style(bindgen::EnumVariation::Consts)
+        .size_t_is_usize(false)
+        .prepend_enum_name(false)
+        .layout_tests(false)
+        .generate_comments(false)
+        // `Default` for opts structs is implemented manually, so that
+        // `sz` gets filled in.
+        .no_default(".+_opts")
+        .no_default("bpf_tc_hook")
+        // Types that are only ever handled through pointers are defined
+        // in `src/opaque.rs`.
+        .blocklist_type("bpf_link")
+        .blocklist_type("bpf_linker")
+        .blocklist_type("bpf_map")
+        .blocklist_type("bpf_object")
+        .blocklist_type("bpf_prog_linfo")
+        .blocklist_type("bpf_program")
+        .blocklist_type("btf")
+        .blocklist_type("btf_dump")
+        .blocklist_type("btf_ext")
+        .blocklist_type("perf_buffer")
+        .blocklist_type("ring")
+        .blocklist_type("ring_buffer")
+        .blocklist_type("user_ring_buffer")
+        .allowlist_function("vdprintf")
+        .allowlist_type("bpf_.+")
+        .allowlist_type("btf_.+")
+        .allowlist_type("xdp_.+")
+        .allowlist_type("perf_.+")
+        .allowlist_var("BPF_.+")
+        .allowlist_var("BTF_.+")
+        .allowlist_var("XDP_.+")
+        .allowlist_var("PERF_.+")
+        // The netlink attributes for attaching XDP programs. The former
+        // pulls in the `IFLA_*` enum as a whole.
+        .allowlist_var("IFLA_XDP")
+        .allowlist_var("IFLA_XDP_.+")
+        // The TC uapi bits needed to make sense of `bpf_tc_*` hooks and
+        // of the return values of TC programs.
+        .allowlist_var("TC_H_.+")
+        .allowlist_var("TC_ACT_.+")
+        .allowlist_var("TCA_BPF_.+")
+        .parse_callbacks(Box::new(callbacks))
+        .header("bindings.h");
+
+    if ebpf_for_windows() {
+        builder = builder
+            .clang_arg("-D__LIBBPF_SYS_EBPF_FOR_WINDOWS")
+            .clang_arg(format!(
+                "-I{}",
+                ebpf_for_windows_dir().join("include").display()
+            ));
+    } else if cfg!(feature = "bindgen-system-headers") {
+        builder = builder.clang_arg("-D__LIBBPF_SYS_NOVENDOR");
+        for dir in system_include_paths() {
+            builder = builder.clang_arg(format!("-I{}", dir.display()));
+        }
+    }
+
+    if ebpf_for_windows() || cfg!(feature = "bindgen-system-headers") {
+        // There is no linker version script to go by, so allowlist
+        // functions by name.
+        for pattern in [
+            "bpf_.+",
+            "btf_.+",
+            "libbpf_.+",
+            "perf_.+",
+            "ring_buffer_.+",
+            "user_ring_buffer_.+",
+        ] {
+            builder = builder.allowlist_function(pattern);
+        }
+    } else {
+        // The kernel headers libbpf does not come with are vendored in
+        // `uapi`, so that the bindings don't depend on the ones of the
+        // system.
+        println!("cargo:rerun-if-changed=uapi");
+        builder = builder
+            .clang_arg(format!("-I{}", src_dir.join("libbpf/include").display()))
+            .clang_arg(format!(
+                "-I{}",
+                src_dir.join("libbpf/include/uapi").display()
+            ))
+            .clang_arg(format!("-I{}", src_dir.join("uapi").display()));
+        // Functions are allowlisted based on what libbpf exports, so
+        // that newly added ones can't be missed. The types they use are
+        // pulled in automatically.
+        for symbol in libbpf_exported_symbols(&src_dir) {
+            builder = builder.allowlist_function(symbol);
+        }
+    }
+
+    // Typedefs of the system, e.g., of its libc, may leak in through
+    // transitive includes and conflict with each other.
+    println!("cargo:rerun-if-env-changed=LIBBPF_SYS_BINDGEN_BLOCKLIST");
+    if let Ok(blocklist) = env::var("LIBBPF_SYS_BINDGEN_BLOCKLIST") {
+        for pattern in blocklist.split_whitespace() {
+            println!("Blocklisting {}", pattern);
+            builder = builder.blocklist_item(pattern);
+        }
+    }
+
+    // Bindings in the source directory always cover everything and
+    // target the latest Rust; they get trimmed and downgraded
+    // afterwards as necessary.
+    if cfg!(not(feature = "bindgen-source")) {
+        for prefix in trimmed_prefixes() {
+            builder = builder.blocklist_item(format!("{prefix}.*"));
+        }
+        if cfg!(feature = "msrv-bindings") {
+            // Keep in sync with `downgrade_bindings`.
+            let target = bindgen::RustTarget::stable(70, 0)
+                .unwrap_or_else(|_| panic!("Rust 1.70 should be a supported bindgen target"));
+            builder = builder.rust_target(target);
+        }
+        // Builtins the bindings use, i.e., `__builtin_va_list`, come
+        // along anyway. The remaining ones are unused, and vary with the
+        // version of libclang.
+        if cfg!(feature = "bindgen-builtins") {
+            builder = builder.emit_builtins();
+        }
+    }
+
+    let bindings = builder
+        .generate()
+        .expect("Unable to generate bindings")
+        .to_string();
+
+    let system_uapi_headers = system_uapi_headers.borrow();
+    if !ebpf_for_windows()
+        && !cfg!(feature = "bindgen-system-headers")
+        && !system_uapi_headers.is_empty()
+    {
+        panic!(
+            "generating the bindings pulled in kernel headers of the system: {}; vendor them \
+             in `uapi` instead",
+            system_uapi_headers.join(", ")
+        );
+    }
+
+    let mut bindings = add_deprecated(&add_must_use(&bindings), &deprecated_functions(&src_dir));
+    // The padding fields are generated regardless and left out here, so
+    // that the structs that have padding are known.
+    if cfg!(all(
+        feature = "no-explicit-padding",
+        not(feature = "bindgen-source")
+    )) {
+        bindings = strip_explicit_padding(&bindings);
+    }
+    fs::write(out_dir.join("bindings.rs"), bindings).expect("Couldn't write bindings");
+}
+
+/// Find the functions libbpf's headers mark with `LIBBPF_DEPRECATED` or
+/// `LIBBPF_DEPRECATED_SINCE`, along with the reason, as the compiler
+/// would report it.
+#[cfg(feature = "bindgen")]
+fn deprecated_functions(src_dir: &Path) -> Vec<(String, String)> {
+    let dirs = if cfg!(feature = "bindgen-system-headers") {
+        system_include_paths()
+            .into_iter()
+            .chain([path::PathBuf::from("/usr/include")])
+            .map(|dir| dir.join("bpf"))
+            .collect()
+    } else {
+        vec![src_dir.join("libbpf/src")]
+    };
+    let version = bindings_libbpf_version();
+
+    let mut deprecated = Vec::new();
+    for header in ["bpf.h", "btf.h", "libbpf.h", "libbpf_legacy.h"] {
+        let Some(text) = dirs
+            .iter()
+            .find_map(|dir| fs::read_to_string(dir.join(header)).ok())
+        else {
+            continue;
+        };
+
+        // Leave out preprocessor directives, e.g., the macros' own
+        // definitions, including their continuation lines.
+        let mut code = String::new();
+        let mut directive = false;
+        for line in text.lines() {
+            directive = directive || line.trim_start().starts_with('#');
+            if !directive {
+                code.push_str(line);
+                code.push('\n');
+            }
+            directive = directive && line.ends_with('\\');
+        }
+
+        let mut rest = code.as_str();
+        while let Some(start) = rest.find("LIBBPF_DEPRECATED") {
+            rest = &rest[start + "LIBBPF_DEPRECATED".len()..];
+            // The declaration ends with the first semicolon, and the
+            // arguments of the macro with the parenthesis closing the
+            // string literal(s).
+            let Some(end) = rest.find(';') else {
+                break;
+            };
+            let decl = &rest[..end];
+            let Some(args_end) = decl
+                .rfind('"')
+                .and_then(|i| decl[i..].find(')').map(|j| i + j))
+            else {
+                continue;
+            };
+
+            let args = &decl[..args_end];
+            let mut note = args.split('"').skip(1).step_by(2).collect::<String>();
+            if let Some(since) = args.strip_prefix("_SINCE(") {
+                let mut numbers = since.split(',').map(|n| n.trim().parse::<u32>().ok());
+                let (Some(Some(major)), Some(Some(minor))) = (numbers.next(), numbers.next())
+                else {
+                    continue;
+                };
+                // Such functions only get marked once libbpf reaches the
+                // version.
+                if version.is_some_and(|version| version < (major, minor)) {
+                    continue;
+                }
+                note = format!("libbpf v{}.{}+: {}", major, minor, note);
+            }
+
+            let signature = &decl[args_end + 1..];
+            let name = signature.split('(').next().and_then(|head| {
+                head.split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .rfind(|s| !s.is_empty())
+            });
+            if let Some(name) = name {
+                deprecated.push((name.to_string(), note));
+            }
+        }
+    }
+    deprecated
+}
+
+/// Mark the functions in `deprecated` as `#[deprecated]` with their
+/// notes, so that Rust users learn about them before they are gone.
+///
+/// No parse callback of bindgen adds attributes to functions, with
+/// `add_attributes` only covering types, hence this operates on the
+/// generated source, like `add_must_use`. Functions the headers
+/// deprecate but the bindings leave out are skipped; any declared in a
+/// way that isn't recognized, e.g., after a change in the formatting of
+/// the generated source, fail the build instead of going unmarked.
+#[cfg(feature = "bindgen")]
+fn add_deprecated(bindings: &str, deprecated: &[(String, String)]) -> String {
+    let mut marked = BTreeSet::new();
+    let mut out = String::with_capacity(bindings.len());
+    for line in bindings.lines() {
+        let name = line
+            .strip_prefix("    pub fn ")
+            .and_then(|rest| rest.split('(').next());
+        if let Some((name, note)) = name.and_then(|name| deprecated.iter().find(|(n, _)| n == name))
+        {
+            out.push_str(&format!("    #[deprecated(note = {:?})]\n", note));
+            marked.insert(name.as_str());
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    let unmarked = deprecated
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .filter(|name| !marked.contains(name))
+        .filter(|name| bindings.contains(&format!("fn {}(", name)))
+        .collect::<Vec<_>>();
+    assert!(
+        unmarked.is_empty(),
+        "failed to find the declarations of deprecated functions {} in the bindings",
+        unmarked.join(", ")
+    );
+    out
+}
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_: path::PathBuf) {}
+
+/// Check whether to build against eBPF-for-Windows instead of libbpf,
+/// which is the case for Windows targets with the `ebpf-for-windows`
+/// feature enabled.
+fn ebpf_for_windows() -> bool {
+    cfg!(feature = "ebpf-for-windows")
+        && env::var("CARGO_CFG_TARGET_OS").is_ok_and(|os| os == "windows")
+}
+
+/// The architectures `src/arch.rs` provides register layouts for, which
+/// libbpf-sys supports, in the spelling of `target_arch`, along with
+/// whether they are 64 bit architectures.
+const SUPPORTED_ARCHS: &[(&str, bool)] = &[
+    ("x86_64", true),
+    ("aarch64", true),
+    ("riscv64", true),
+    ("s390x", true),
+    ("powerpc64", true),
+    ("loongarch64", true),
+    ("x86", false),
+    ("arm", false),
+];
+
+/// Check that the target is one libbpf-sys supports, returning a
+/// description of the supported ones otherwise.
+fn unsupported_target() -> Option<String> {
+    let target = env::var("TARGET").unwrap_or_default();
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+
+    let reason = match SUPPORTED_ARCHS.iter().find(|(name, _)| *name == arch) {
+        None => Some(format!("the {arch} architecture is not supported")),
+        // ABIs like x32 use 32 bit pointers and `long`s on 64 bit
+        // architectures, which the register layouts don't account for.
+        Some((_, true)) if width != "64" => Some(format!(
+            "the {} ABI with {width} bit pointers on {arch} is not supported",
+            env::var("CARGO_CFG_TARGET_ABI").unwrap_or_default()
+        )),
+        // The stubs don't need a kernel to talk to, and neither does the
+        // BTF code.
+        Some(_)
+            if !matches!(os.as_str(), "linux" | "android")
+                && !cfg!(any(feature = "stubs", feature = "btf-only")) =>
+        {
+            Some(format!("the {os} operating system is not supported"))
+        },
+        Some(_) => None,
+    };
+
+    reason.map(|reason| {
+        let archs = SUPPORTED_ARCHS
+            .iter()
+            .map(|(name, is_64bit)| {
+                if *is_64bit {
+                    name.to_string()
+                } else {
+                    format!("{name} (with the `bindgen` feature)")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "unsupported target {}: {}; libbpf-sys supports Linux and Android on {}, as well as \
+             Windows with the `ebpf-for-windows` feature and any operating system with the \
+             `btf-only` feature",
+            target, reason, archs
+        )
+    })
+}
+
+/// Determine why the bindings in `src/bindings.rs` don't fit the target,
+/// if they don't.
+///
+/// These bindings are generated for x86_64 and spell out the padding of
+/// structs, which is only valid for targets with the same data model: 64
+/// bit pointers, `long`s, and `size_t`s. Others, such as 32 bit ARM or
+/// Windows, need bindings of their own.
+fn shipped_bindings_mismatch() -> Option<String> {
+    let target = env::var("TARGET").unwrap_or_default();
+    let width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    if width != "64" {
+        return Some(format!("{target} is a {width} bit target"));
+    }
+    if env::var("CARGO_CFG_TARGET_OS").is_ok_and(|os| os == "windows") {
+        return Some(format!("{target} is a Windows target, with 32 bit `long`s"));
+    }
+    None
+}
+
+/// Check that the shipped bindings in `src/bindings.rs` can be generated
+/// for the target, for the `bindgen-source` feature.
+#[cfg(feature = "bindgen-source")]
+fn check_shipped_bindings_target() {
+    if let Some(reason) = shipped_bindings_mismatch() {
+        panic!(
+            "the shipped bindings are only valid for 64 bit Linux targets, but {}; generate \
+             them on one of those",
+            reason
+        );
+    }
+}
+
+/// Select the shipped bindings for the target: the ones in
+/// `src/bindings.rs`, or, for targets they don't fit, the ones in
+/// `src/bindings/<arch>.rs` that `regenerate-bindings` generates for 32
+/// bit architectures.
+///
+/// Without fitting bindings, the build fails, as the 64 bit ones have
+/// the wrong `long`s and padding.
+fn shipped_bindings(src_dir: &Path) -> (path::PathBuf, bool) {
+    let Some(reason) = shipped_bindings_mismatch() else {
+        return (src_dir.join("src/bindings.rs"), false);
+    };
+
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let path = src_dir.join("src/bindings").join(format!("{arch}.rs"));
+    if path.exists() && env::var("CARGO_CFG_TARGET_OS").is_ok_and(|os| os != "windows") {
+        println!("cargo:rerun-if-changed={}", path.display());
+        return (path, true);
+    }
+
+    panic!(
+        "the shipped bindings are only valid for 64 bit Linux targets, but {}, and there are \
+         no bindings for it in src/bindings/{}.rs; enable the `bindgen` feature to generate \
+         bindings for it",
+        reason, arch
+    );
+}
+
+/// Determine the GNU host triple of the target, as used by `configure`
+/// scripts.
+fn configure_host() -> String {
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    let arch = match arch.as_str() {
+        "riscv64gc" => "riscv64",
+        "riscv32gc" => "riscv32",
+        other => other,
+    };
+    let vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
+    // The ABI is part of the environment in GNU triples, e.g., for
+    // `arm-unknown-linux-gnueabihf`.
+    let abi = env::var("CARGO_CFG_TARGET_ABI").unwrap_or_default();
+    format!("{arch}-{vendor}-{os}-{env}{abi}")
+}
+
+/// Retrieve the eBPF-for-Windows installation directory, containing the
+/// headers in `include` and the import library in `lib`.
+fn ebpf_for_windows_dir() -> path::PathBuf {
+    println!("cargo:rerun-if-env-changed=EBPF_FOR_WINDOWS_DIR");
+    env::var_os("EBPF_FOR_WINDOWS_DIR")
+        .map(path::PathBuf::from)
+        .expect("EBPF_FOR_WINDOWS_DIR has to point to an eBPF-for-Windows installation")
+}
+
+/// Retrieve the include paths of the system's libbpf, as reported by
+/// pkg-config.
+fn system_include_paths() -> Vec<path::PathBuf> {
+    pkg_config::Config::new()
+        .cargo_metadata(false)
+        .env_metadata(false)
+        .probe("libbpf")
+        .map(|lib| lib.include_paths)
+        .unwrap_or_default()
+}
+
+/// Determine the version of the system's libbpf headers.
+fn system_header_version() -> Option<(u32, u32)> {
+    let header = system_include_paths()
+        .into_iter()
+        .chain([path::PathBuf::from("/usr/include")])
+        .find_map(|dir| fs::read_to_string(dir.join("bpf/libbpf_version.h")).ok())?;
+    let define = |name: &str| {
+        header.lines().find_map(|line| {
+            let value = line.strip_prefix("#define ")?.strip_prefix(name)?;
+            value.trim().parse::<u32>().ok()
+        })
+    };
+    Some((
+        define("LIBBPF_MAJOR_VERSION")?,
+        define("LIBBPF_MINOR_VERSION")?,
+    ))
+}
+
+/// Retrieve the symbols exported by libbpf, as listed in its linker
+/// version script.
+fn libbpf_exported_symbols(src_dir: &Path) -> Vec<String> {
+    let path = src_dir.join("libbpf/src/libbpf.map");
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let map = fs::read_to_string(&path).expect("Couldn't read libbpf.map");
+    map.lines()
+        .filter_map(|line| {
+            // Symbols are listed one per line, terminated by a
+            // semicolon. Everything else is version node syntax or the
+            // `*` wildcard of the `local` section.
+            let symbol = line.trim().strip_suffix(';')?;
+            let valid = !symbol.is_empty()
+                && symbol
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            valid.then(|| symbol.to_string())
+        })
+        .collect()
+}
+
+/// Make the shipped bindings compatible with Rust 1.70, the oldest
+/// release supported with the `msrv-bindings` feature, mirroring what
+/// bindgen emits for that target.
+///
+/// The only construct in the shipped bindings that requires a newer
+/// release are `unsafe extern` blocks, which were stabilized in 1.82.
+fn downgrade_bindings(bindings: &str) -> String {
+    bindings.replace("unsafe extern \"C\" {", "extern \"C\" {")
+}
+
+/// Remove the padding fields bindgen's `explicit_padding` adds from
+/// `bindings`, for the `no-explicit-padding` feature, so that struct
+/// literals need not spell them out. The compiler inserts the same
+/// padding implicitly, leaving the layout as is.
+///
+/// `Pod` does not allow for implicit padding, so the structs losing
+/// their padding fields only derive `Zeroable`.
+fn strip_explicit_padding(bindings: &str) -> String {
+    const POD: &str = "derive(bytemuck::Pod, bytemuck::Zeroable)";
+
+    let mut lines: Vec<String> = Vec::new();
+    // The line deriving `Pod` for the current item, if any.
+    let mut pod: Option<usize> = None;
+    for line in bindings.lines() {
+        if line.trim_start().starts_with("pub __bindgen_padding_") {
+            if let Some(i) = pod.take() {
+                lines[i] = lines[i].replace(POD, "derive(bytemuck::Zeroable)");
+            }
+            continue;
+        }
+        if line.contains(POD) {
+            pod = Some(lines.len());
+        } else if line == "}" {
+            pod = None;
+        }
+        lines.push(line.to_string());
+    }
+    lines.join("\n") + "\n"
+}
+
+/// The types of the C library that may appear in the bindings, along
+/// with their names in the `libc` crate, for the `libc` feature.
+/// Typedefs glibc uses internally map to the public ones.
+const LIBC_TYPES: &[(&str, &str)] = &[
+    ("FILE", "FILE"),
+    ("__gid_t", "gid_t"),
+    ("__off_t", "off_t"),
+    ("__pid_t", "pid_t"),
+    ("__uid_t", "uid_t"),
+    ("gid_t", "gid_t"),
+    ("in6_addr", "in6_addr"),
+    ("in_addr", "in_addr"),
+    ("iovec", "iovec"),
+    ("off_t", "off_t"),
+    ("pid_t", "pid_t"),
+    ("sa_family_t", "sa_family_t"),
+    ("size_t", "size_t"),
+    ("sockaddr", "sockaddr"),
+    ("sockaddr_in", "sockaddr_in"),
+    ("sockaddr_in6", "sockaddr_in6"),
+    ("sockaddr_storage", "sockaddr_storage"),
+    ("socklen_t", "socklen_t"),
+    ("ssize_t", "ssize_t"),
+    ("timespec", "timespec"),
+    ("uid_t", "uid_t"),
+];
+
+/// Replace the definitions of the C library's types in `bindings` with
+/// aliases of the `libc` crate's, for the `libc` feature.
+fn libc_bindings(bindings: &str) -> String {
+    let mut aliases = bindings
+        .lines()
+        .filter_map(|line| {
+            ["pub type ", "pub struct ", "pub union "]
+                .iter()
+                .find_map(|prefix| line.strip_prefix(prefix))
+        })
+        .filter_map(|decl| {
+            let name = ident(decl);
+            LIBC_TYPES.iter().find(|(ty, _)| *ty == name)
+        })
+        .collect::<Vec<_>>();
+    aliases.sort_unstable();
+    aliases.dedup();
+
+    let mut mapped = trim_bindings(bindings, |name, item| {
+        // Functions may share their names with types.
+        let function = item.lines().any(|line| {
+            line.trim_start_matches("unsafe ")
+                .starts_with("extern \"C\" {")
+        });
+        !function && aliases.iter().any(|(ty, _)| *ty == name)
+    });
+    for (name, libc) in aliases {
+        mapped.push_str(&format!("pub type {name} = ::libc::{libc};\n"));
+    }
+    mapped
+}
+
+/// The prefixes of the items excluded from the bindings by the
+/// various `no-*` features.
+fn trimmed_prefixes() -> Vec<&'static str> {
+    let mut prefixes = Vec::new();
+    if cfg!(feature = "no-perf") {
+        prefixes.extend(["perf_", "PERF_"]);
+    }
+    if cfg!(feature = "no-btf-dump") {
+        prefixes.push("btf_dump");
+    }
+    if cfg!(feature = "no-linker") {
+        prefixes.push("bpf_linker");
+    }
+    prefixes
+}
+
+/// Retrieve the identifier at the start of `s`.
+fn ident(s: &str) -> &str {
+    let end = s
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Remove all items for whose names and text `trim` returns `true` from
+/// `bindings`, along with their `impl` blocks.
+fn trim_bindings(bindings: &str, trim: impl Fn(&str, &str) -> bool) -> String {
+    fn item_name(item: &str) -> Option<&str> {
+        item.lines().find_map(|line| {
+            let line = line.trim_start();
+            if let Some(decl) = line.strip_prefix("impl ") {
+                let decl = decl.rsplit_once(" for ").map_or(decl, |(_, ty)| ty);
+                return Some(ident(decl));
+            }
+            [
+                "pub const ",
+                "pub static ",
+                "pub type ",
+                "pub struct ",
+                "pub union ",
+                "pub fn ",
+            ]
+            .iter()
+            .find_map(|prefix| line.strip_prefix(prefix))
+            .map(ident)
+        })
+    }
+
+    let mut trimmed = String::with_capacity(bindings.len());
+    let mut item = String::new();
+    for line in bindings.lines() {
+        item.push_str(line);
+        item.push('\n');
+
+        // Top-level items end with an unindented closing brace or
+        // semicolon.
+        let unindented = line.starts_with(|c: char| !c.is_whitespace());
+        if unindented && (line.ends_with('}') || line.ends_with(';')) {
+            let name = item_name(&item).unwrap_or_default();
+            if !trim(name, &item) {
+                trimmed.push_str(&item);
+            }
+            item.clear();
+        }
+    }
+    trimmed.push_str(&item);
+    trimmed
+}
+
+/// The libbpf sources the `btf-only` feature builds.
+const BTF_ONLY_SOURCES: &[&str] = &[
+    "btf.c",
+    "btf_dump.c",
+    "btf_iter.c",
+    "btf_relocate.c",
+    "hashmap.c",
+    "strset.c",
+    "libbpf_errno.c",
+];
+
+/// Check whether `name` is one of the functions the `btf-only` feature
+/// provides, from `BTF_ONLY_SOURCES` and `btf-only/shim.c`.
+fn btf_only_function(name: &str) -> bool {
+    ["btf__", "btf_ext__", "btf_dump__"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+        || matches!(
+            name,
+            "libbpf_find_kernel_btf"
+                | "libbpf_strerror"
+                | "libbpf_set_print"
+                | "libbpf_major_version"
+                | "libbpf_minor_version"
+                | "libbpf_version_string"
+        )
+}
+
+/// Remove all functions the `btf-only` feature does not provide from
+/// `bindings`, keeping the types, some of which share their names with
+/// functions.
+fn btf_only_bindings(bindings: &str) -> String {
+    trim_bindings(bindings, |name, item| {
+        // Downgraded bindings lack the `unsafe`.
+        let function = item.lines().any(|line| {
+            line.trim_start_matches("unsafe ")
+                .starts_with("extern \"C\" {")
+        });
+        function && !btf_only_function(name)
+    })
+}
+
+/// The functions returning a pointer that return `NULL` for an absent
+/// value, without setting `errno`.
+const NULLABLE_FUNCTIONS: &[&str] = &[
+    "bpf_link__pin_path",
+    "bpf_map__get_pin_path",
+    "bpf_map__initial_value",
+    "bpf_map__pin_path",
+    "bpf_object__btf",
+    "bpf_program__log_buf",
+    "btf__base_btf",
+    "libbpf_bpf_attach_type_str",
+    "libbpf_bpf_link_type_str",
+    "libbpf_bpf_map_type_str",
+    "libbpf_bpf_prog_type_str",
+];
+
+/// Classify the error convention of a function returning `ret`, which
+/// is the return type as it appears in the bindings.
+///
+/// Since libbpf 1.0, functions returning an integer report errors as
+/// negative `errno` values, while functions returning a pointer return
+/// `NULL` and set `errno`. The exceptions are iterators, for which
+/// `NULL` marks the end of iteration, and the getters in
+/// `NULLABLE_FUNCTIONS`.
+fn error_convention(name: &str, ret: Option<&str>) -> &'static str {
+    match ret {
+        _ if name.contains("__next_") || name.contains("__prev_") => "None",
+        _ if NULLABLE_FUNCTIONS.contains(&name) => "Nullable",
+        Some(ret) if ret.starts_with('*') => "NullErrno",
+        Some(
+            "::core::ffi::c_int"
+            | "::core::ffi::c_long"
+            | "::core::ffi::c_longlong"
+            | "__s32"
+            | "__s64"
+            | "ssize_t",
+        ) => "NegErrno",
+        _ => "None",
+    }
+}
+
+/// Retrieve the names, parameter lists, and return types of all
+/// functions declared in `bindings`.
+fn bound_functions(bindings: &str) -> Vec<(String, String, Option<String>)> {
+    let mut functions = Vec::new();
+    let mut rest = bindings;
+    while let Some(idx) = rest.find("extern \"C\" {\n") {
+        rest = &rest[idx + "extern \"C\" {\n".len()..];
+        let end = rest.find("\n}\n").expect("Malformed bindings");
+        let block = &rest[..end];
+        rest = &rest[end..];
+
+        for decl in block.split_terminator(';') {
+            let decl = decl.split_whitespace().collect::<Vec<_>>().join(" ");
+            let Some(decl) = decl.split_once("pub fn ").map(|(_, decl)| decl) else {
+                continue;
+            };
+            let (name, rest) = decl.split_once('(').expect("Malformed bindings");
+            let (params, ret) = rest.split_once(')').expect("Malformed bindings");
+            let ret = ret.strip_prefix(" -> ").map(str::to_string);
+            functions.push((name.to_string(), params.to_string(), ret));
+        }
+    }
+    functions
+}
+
+/// Retrieve the names of all public items in `bindings`.
+fn bound_items(bindings: &str) -> Vec<String> {
+    let mut items = bound_functions(bindings)
+        .into_iter()
+        .map(|(name, _, _)| name)
+        .collect::<Vec<_>>();
+    for line in bindings.lines() {
+        let decl = [
+            "pub const ",
+            "pub static ",
+            "pub type ",
+            "pub struct ",
+            "pub union ",
+        ]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix));
+        if let Some(decl) = decl {
+            items.push(ident(decl).to_string());
+        }
+    }
+    items
+}
+
+/// Generate a table of the error conventions of all bound functions,
+/// sorted by name.
+fn generate_error_conventions(bindings: &str, out_dir: &Path) -> path::PathBuf {
+    let mut functions = bound_functions(bindings)
+        .into_iter()
+        .map(|(name, _, ret)| {
+            let convention = error_convention(&name, ret.as_deref());
+            (name, convention)
+        })
+        .collect::<Vec<_>>();
+    functions.sort_unstable();
+
+    let mut table = String::from("&[\n");
+    for (name, convention) in functions {
+        table.push_str(&format!(
+            "    (\"{name}\", ErrorConvention::{convention}),\n"
+        ));
+    }
+    table.push_str("]\n");
+
+    let path = out_dir.join("error_conventions.rs");
+    fs::write(&path, table).expect("Couldn't write error conventions");
+    path
+}
+
+/// Generate the `BTF_KIND_*` constants, along with a variant name for
+/// each, as an invocation of the `btf_kind_table!` macro for
+/// `src/btf_kind.rs`.
+fn generate_btf_kind_table(bindings: &str, out_dir: &Path) {
+    let mut table = String::from("btf_kind_table! {\n");
+    for line in bindings.lines() {
+        let Some(rest) = line.trim().strip_prefix("pub const BTF_KIND_") else {
+            continue;
+        };
+        let Some((kind, _)) = rest.split_once(':') else {
+            continue;
+        };
+        // `BTF_KIND_MAX` aliases the last kind.
+        if kind == "MAX" {
+            continue;
+        }
+        let variant = kind
+            .split('_')
+            .map(|word| {
+                let (first, rest) = word.split_at(1);
+                format!("{first}{}", rest.to_ascii_lowercase())
+            })
+            .collect::<String>();
+        table.push_str(&format!("    {variant} = BTF_KIND_{kind},\n"));
+    }
+    table.push_str("}\n");
+
+    fs::write(out_dir.join("btf_kind.rs"), table).expect("Couldn't write BTF kind table");
+}
+
+/// The sizes and alignments of heavily used types, as defined by the
+/// kernel's ABI, which the bindings are checked against at compile
+/// time.
+const LAYOUTS: &[(&str, usize, usize)] = &[
+    ("bpf_insn", 8, 4),
+    ("bpf_map_info", 88, 8),
+    ("btf_header", 24, 4),
+    ("btf_type", 12, 4),
+    ("perf_event_header", 8, 4),
+];
+
+/// The types the kernel's UAPI declares with `__attribute__((aligned(8)))`,
+/// so that their layout is the same on 32 and 64 bit architectures.
+const UAPI_ALIGNED_TYPES: &[&str] = &[
+    "bpf_attr",
+    "bpf_btf_info",
+    "bpf_dynptr",
+    "bpf_iter_num",
+    "bpf_link_info",
+    "bpf_list_head",
+    "bpf_list_node",
+    "bpf_map_info",
+    "bpf_prog_info",
+    "bpf_rb_node",
+    "bpf_rb_root",
+    "bpf_timer",
+    "bpf_wq",
+];
+
+/// Generate compile-time assertions on the layouts of `LAYOUTS` and on
+/// the alignment of `UAPI_ALIGNED_TYPES` for `src/layout.rs`.
+///
+/// Types missing from the bindings, e.g., trimmed ones or ones system
+/// headers predate, are skipped, as is the size of `bpf_map_info` for
+/// system headers, which may predate some of its members.
+fn generate_layout_asserts(bindings: &str, out_dir: &Path) {
+    let bound = |ty: &str| {
+        bindings.contains(&format!("pub struct {ty} {{"))
+            || bindings.contains(&format!("pub union {ty} {{"))
+    };
+    let mut asserts = String::new();
+    for (ty, size, align) in LAYOUTS {
+        if !bound(ty) {
+            continue;
+        }
+        if !(*ty == "bpf_map_info" && cfg!(feature = "bindgen-system-headers")) {
+            asserts.push_str(&format!(
+                "const _: () = assert!(size_of::<{ty}>() == {size}, \"unexpected size of `{ty}`\");\n"
+            ));
+        }
+        asserts.push_str(&format!(
+            "const _: () = assert!(align_of::<{ty}>() == {align}, \"unexpected alignment of `{ty}`\");\n"
+        ));
+    }
+    for ty in UAPI_ALIGNED_TYPES {
+        if bound(ty) && !LAYOUTS.iter().any(|(name, _, _)| name == ty) {
+            asserts.push_str(&format!(
+                "const _: () = assert!(align_of::<{ty}>() == 8, \"unexpected alignment of `{ty}`\");\n"
+            ));
+        }
+    }
+
+    fs::write(out_dir.join("layout_asserts.rs"), asserts).expect("Couldn't write layout asserts");
+}
+
+/// Rust keywords, which bindgen appends an underscore to when used as
+/// names.
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match",
+    "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static", "struct",
+    "super", "trait", "true", "try", "type", "unsafe", "use", "where", "while", "yield",
+];
+
+/// Generate the structs and unions of `bindings`, along with their
+/// members, and the integer constants, as an invocation of the
+/// `layout_table!` macro for `tests/layout.rs`.
+///
+/// Types and members bindgen made up, i.e., anonymous ones, bitfield
+/// storage, and padding, are left out, as they have no counterpart in
+/// C.
+fn generate_layout_table(bindings: &str, out_dir: &Path) {
+    let mut table = String::from("layout_table! {\n    types {\n");
+    let mut lines = bindings.lines();
+    while let Some(line) = lines.next() {
+        let Some((kind, rest)) = line
+            .strip_prefix("pub struct ")
+            .map(|rest| ("struct", rest))
+            .or_else(|| line.strip_prefix("pub union ").map(|rest| ("union", rest)))
+        else {
+            continue;
+        };
+        // Skip unit and tuple structs, which don't span multiple lines.
+        if !rest.ends_with('{') {
+            continue;
+        }
+        let name = ident(rest);
+        let skip = name.starts_with('_') || name.contains("__bindgen");
+
+        let mut fields = Vec::new();
+        for line in lines.by_ref() {
+            if line == "}" {
+                break;
+            }
+            let Some(field) = line.trim().strip_prefix("pub ").map(ident) else {
+                continue;
+            };
+            if field.starts_with('_')
+                && (field.starts_with("__bindgen") || field.starts_with("_bitfield"))
+            {
+                continue;
+            }
+            if field == "_address" {
+                continue;
+            }
+            let c_field = field
+                .strip_suffix('_')
+                .filter(|base| RUST_KEYWORDS.contains(base))
+                .unwrap_or(field);
+            fields.push(format!("{field} => \"{c_field}\""));
+        }
+
+        if !skip {
+            // Members of unions all reside at offset zero.
+            if kind == "union" {
+                fields.clear();
+            }
+            table.push_str(&format!(
+                "        {kind} {name} {{{}}}\n",
+                fields.join(", ")
+            ));
+        }
+    }
+
+    table.push_str("    }\n    consts {\n");
+    for line in bindings.lines() {
+        let Some(rest) = line.strip_prefix("pub const ") else {
+            continue;
+        };
+        let Some((_, value)) = rest.split_once(" = ") else {
+            continue;
+        };
+        // Skip string constants.
+        if !value.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+            continue;
+        }
+        table.push_str(&format!("        {},\n", ident(rest)));
+    }
+    table.push_str("    }\n}\n");
+
+    fs::write(out_dir.join("layout_table.rs"), table).expect("Couldn't write layout table");
+}
+
+/// Retrieve the structs of `bindings` libbpf extends across releases
+/// and tells apart by their leading `sz` member, i.e., the opts structs,
+/// along with whether bindgen provides `Default` for them.
+fn sized_structs(bindings: &str) -> Vec<(&str, bool)> {
+    let mut structs = Vec::new();
+    let mut derive = "";
+    let mut lines = bindings.lines();
+    while let Some(line) = lines.next() {
+        if line.starts_with("#[derive(") {
+            derive = line;
+            continue;
+        }
+        let Some(rest) = line.strip_prefix("pub struct ") else {
+            continue;
+        };
+        if !rest.ends_with('{') {
+            continue;
+        }
+        let sized = lines
+            .next()
+            .is_some_and(|line| matches!(line.trim(), "pub sz: size_t," | "pub sz: usize,"));
+        if sized {
+            let name = ident(rest);
+            let implemented = bindings.contains(&format!("impl Default for {name} {{"));
+            structs.push((name, derive.contains("Default") || implemented));
+        }
+    }
+    structs
+}
+
+/// Generate the opts structs of `bindings`, along with the libbpf
+/// version the bindings correspond to, as an invocation of the
+/// `opts_sizes!` macro for `tests/opts.rs`.
+fn generate_opts_sizes(bindings: &str, out_dir: &Path) {
+    let version = match bindings_libbpf_version() {
+        Some((major, minor)) => format!("{major}.{minor}"),
+        None => String::new(),
+    };
+    let mut table = format!("opts_sizes! {{\n    \"{version}\" {{\n");
+    for (name, _) in sized_structs(bindings) {
+        if name.ends_with("_opts") {
+            table.push_str(&format!("        {name},\n"));
+        }
+    }
+    table.push_str("    }\n}\n");
+
+    fs::write(out_dir.join("opts_sizes.rs"), table).expect("Couldn't write opts sizes");
+}
+
+/// Generate the opts structs of `bindings` bindgen provides no
+/// `Default` for, as an invocation of the `impl_default_opts!` macro for
+/// `src/opts.rs`.
+fn generate_default_opts(bindings: &str, out_dir: &Path) {
+    let mut table = String::from("impl_default_opts!(\n");
+    for (name, derives_default) in sized_structs(bindings) {
+        if !derives_default {
+            table.push_str(&format!("    {name},\n"));
+        }
+    }
+    table.push_str(");\n");
+
+    fs::write(out_dir.join("default_opts.rs"), table).expect("Couldn't write default opts");
+}
+
+/// The BTF structs `src/btf_wire.rs` reads in either byte order, bar
+/// `btf_type`, which contains a union.
+const BTF_WIRE_STRUCTS: &[&str] = &[
+    "btf_header",
+    "btf_array",
+    "btf_member",
+    "btf_param",
+    "btf_enum",
+    "btf_enum64",
+    "btf_var",
+    "btf_var_secinfo",
+    "btf_decl_tag",
+];
+
+/// Retrieve the members of struct `name` in `bindings`, along with their
+/// types.
+fn struct_fields<'b>(bindings: &'b str, name: &str) -> Vec<(&'b str, &'b str)> {
+    let header = format!("pub struct {name} {{");
+    let mut lines = bindings.lines().skip_while(|line| *line != header);
+    assert!(lines.next().is_some(), "struct `{}` is not bound", name);
+    lines
+        .take_while(|line| *line != "}")
+        .filter_map(|line| line.trim().strip_prefix("pub "))
+        .map(|field| {
+            field
+                .trim_end_matches(',')
+                .split_once(": ")
+                .expect("Malformed bindings")
+        })
+        .collect()
+}
+
+/// Generate the members of the BTF structs in [`BTF_WIRE_STRUCTS`] as an
+/// invocation of the `impl_read!` macro for `src/btf_wire.rs`.
+fn generate_btf_wire(bindings: &str, out_dir: &Path) {
+    let mut table = String::from("impl_read! {\n");
+    for name in BTF_WIRE_STRUCTS {
+        let fields = struct_fields(bindings, name)
+            .into_iter()
+            .map(|(field, ty)| format!("{field}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        table.push_str(&format!("    {name} {{ {fields} }}\n"));
+    }
+    table.push_str("}\n");
+
+    fs::write(out_dir.join("btf_wire.rs"), table).expect("Couldn't write BTF wire structs");
+}
+
+/// The enum types libbpf provides `libbpf_*_str` functions for, along
+/// with the prefix their constants' names have over the names libbpf
+/// reports.
+const NAMED_TYPES: &[(&str, &str)] = &[
+    ("bpf_attach_type", "BPF_"),
+    ("bpf_link_type", "BPF_LINK_TYPE_"),
+    ("bpf_map_type", "BPF_MAP_TYPE_"),
+    ("bpf_prog_type", "BPF_PROG_TYPE_"),
+];
+
+/// Generate the names of the constants of `NAMED_TYPES`, as reported by
+/// libbpf, as an invocation of the `type_names!` macro for
+/// `src/names.rs`.
+fn generate_type_names(bindings: &str, out_dir: &Path) {
+    let mut table = String::from("type_names! {\n");
+    for (ty, prefix) in NAMED_TYPES {
+        table.push_str(&format!("    fn {ty}_from_str, {ty}_name -> {ty} {{\n"));
+        for line in bindings.lines() {
+            let Some(rest) = line.trim().strip_prefix("pub const ") else {
+                continue;
+            };
+            let Some((constant, rest)) = rest.split_once(": ") else {
+                continue;
+            };
+            if !rest.starts_with(&format!("{ty} =")) || constant.ends_with("_DEPRECATED") {
+                continue;
+            }
+            // Skips the `__MAX_*` sentinels as well.
+            let Some(name) = constant.strip_prefix(prefix) else {
+                continue;
+            };
+            let name = name.to_ascii_lowercase();
+            table.push_str(&format!("        \"{name}\" => {constant},\n"));
+        }
+        table.push_str("    }\n");
+    }
+    table.push_str("}\n");
+
+    fs::write(out_dir.join("type_names.rs"), table).expect("Couldn't write type names");
+}
+
+/// Generate a table of libbpf's `SEC()` definitions, as found in the
+/// `section_defs` array of the vendored `libbpf.c`.
+///
+/// The table is empty if the libbpf sources are not available.
+fn generate_section_defs(src_dir: &Path, out_dir: &Path) {
+    let path = src_dir.join("libbpf/src/libbpf.c");
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let source = fs::read_to_string(&path).unwrap_or_default();
+    let defs = source
+        .split_once("section_defs[] = {")
+        .and_then(|(_, rest)| rest.split_once("\n};"))
+        .map(|(defs, _)| defs)
+        .unwrap_or_default();
+
+    let mut table = String::from("&[\n");
+    for def in defs.split("SEC_DEF(").skip(1) {
+        let Some((args, _)) = def.split_once(')') else {
+            continue;
+        };
+        let args = args.split(',').map(str::trim).collect::<Vec<_>>();
+        let [name, prog_type, attach_type, flags, ..] = args.as_slice() else {
+            continue;
+        };
+        let flags = flags.split('|').map(str::trim).collect::<Vec<_>>();
+        table.push_str(&format!(
+            "    SectionDef {{ name: {name}, prog_type: BPF_PROG_TYPE_{prog_type}, \
+             expected_attach_type: {attach_type}, attach_btf: {}, sleepable: {} }},\n",
+            flags.contains(&"SEC_ATTACH_BTF"),
+            flags.contains(&"SEC_SLEEPABLE"),
+        ));
+    }
+    table.push_str("]\n");
+
+    fs::write(out_dir.join("section_defs.rs"), table).expect("Couldn't write section definitions");
+}
+
+/// The prefixes of the constants the bindings cover, mirroring the
+/// variables allowlisted in `generate_bindings`.
+const BOUND_CONSTANT_PREFIXES: &[&str] = &[
+    "BPF_", "BTF_", "XDP_", "PERF_", "IFLA_XDP", "TC_H_", "TC_ACT_", "TCA_BPF_",
+];
+
+/// Retrieve the names of the constants, i.e., object-like macros and
+/// enumerators, defined by the C `header`.
+///
+/// The `BPF_FUNC_*` enumerators are generated from the helper list in
+/// `__BPF_FUNC_MAPPER`, whose entries are recognized separately.
+fn header_constants(header: &str) -> Vec<String> {
+    let mut constants = Vec::new();
+    let mut in_enum = false;
+    for line in header.lines() {
+        let line = line.trim();
+        if let Some(define) = line.strip_prefix("#define ") {
+            let define = define.trim_start();
+            let name = ident(define);
+            // Function-like macros are no constants.
+            if !define[name.len()..].starts_with('(') {
+                constants.push(name.to_string());
+            }
+        } else if let Some(helper) = line.strip_prefix("FN(") {
+            constants.push(format!("BPF_FUNC_{}", ident(helper)));
+        } else if line.starts_with("enum ") && line.ends_with('{') {
+            in_enum = true;
+        } else if line.starts_with('}') {
+            in_enum = false;
+        } else if in_enum {
+            let name = ident(line);
+            let rest = line[name.len()..].trim_start();
+            if !name.is_empty() && (rest.is_empty() || rest.starts_with([',', '=', '/'])) {
+                constants.push(name.to_string());
+            }
+        }
+    }
+    constants
+}
+
+/// Write a report of what the bindings lack or have in excess compared
+/// to the functions libbpf exports and the constants the headers
+/// define, to make the effect of libbpf updates auditable.
+///
+/// With `LIBBPF_SYS_COMPLETENESS_REPORT` set, the report is printed as
+/// build warnings as well.
+fn generate_completeness_report(bindings: &str, src_dir: &Path, out_dir: &Path) {
+    // The report compares against the vendored sources, which may not
+    // be available.
+    if cfg!(feature = "bindgen-system-headers")
+        || ebpf_for_windows()
+        || !src_dir.join("libbpf/src/libbpf.map").exists()
+    {
+        return;
+    }
+
+    let trimmed = trimmed_prefixes();
+    let is_trimmed = |name: &str| trimmed.iter().any(|prefix| name.starts_with(prefix));
+
+    let exported = libbpf_exported_symbols(src_dir)
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+    let functions = bound_functions(bindings)
+        .into_iter()
+        .map(|(name, _, _)| name)
+        .collect::<BTreeSet<_>>();
+
+    let headers = [
+        "include/uapi/linux/bpf.h",
+        "include/uapi/linux/btf.h",
+        "include/uapi/linux/if_link.h",
+        "include/uapi/linux/perf_event.h",
+        "include/uapi/linux/pkt_cls.h",
+        "src/bpf.h",
+        "src/btf.h",
+        "src/libbpf.h",
+    ]
+    .iter()
+    .map(|header| {
+        let path = src_dir.join("libbpf").join(header);
+        println!("cargo:rerun-if-changed={}", path.display());
+        (*header, fs::read_to_string(&path).unwrap_or_default())
+    })
+    .collect::<Vec<_>>();
+    let mut defined = BTreeSet::new();
+    let mut uapi = BTreeSet::new();
+    for (header, contents) in &headers {
+        for name in header_constants(contents) {
+            if header.starts_with("include/uapi/")
+                && BOUND_CONSTANT_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+            {
+                uapi.insert(name.clone());
+            }
+            defined.insert(name);
+        }
+    }
+    let constants = bindings
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub const "))
+        .map(|decl| ident(decl).to_string())
+        .collect::<BTreeSet<_>>();
+
+    let sections = [
+        (
+            "Functions exported by libbpf.map but not bound",
+            exported
+                .difference(&functions)
+                .filter(|name| !is_trimmed(name))
+                .collect::<Vec<_>>(),
+        ),
+        (
+            "Functions bound but not exported by libbpf.map",
+            functions.difference(&exported).collect(),
+        ),
+        (
+            "Constants defined by the uapi headers but not bound",
+            uapi.difference(&constants)
+                .filter(|name| !is_trimmed(name))
+                .collect(),
+        ),
+        (
+            "Constants bound but not defined by the headers",
+            constants.difference(&defined).collect(),
+        ),
+    ];
+
+    let mut report = String::new();
+    for (title, names) in &sections {
+        report.push_str(&format!("{title} ({}):\n", names.len()));
+        for name in names {
+            report.push_str(&format!("    {name}\n"));
+        }
+        report.push('\n');
+    }
+    fs::write(out_dir.join("completeness.txt"), &report)
+        .expect("Couldn't write completeness report");
+
+    println!("cargo:rerun-if-env-changed=LIBBPF_SYS_COMPLETENESS_REPORT");
+    if env::var_os("LIBBPF_SYS_COMPLETENESS_REPORT").is_some() {
+        for line in report.lines().filter(|line| !line.is_empty()) {
+            println!("cargo:warning={line}");
+        }
+    }
+}
+
+/// Generate the table of the libbpf releases introducing the functions
+/// libbpf exports, from the version nodes of `libbpf.map`.
+fn generate_libbpf_versions(src_dir: &Path, out_dir: &Path) {
+    let path = src_dir.join("libbpf/src/libbpf.map");
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let map = fs::read_to_string(&path).unwrap_or_default();
+    let mut versions = BTreeMap::new();
+    let mut version = None;
+    for line in map.lines() {
+        let line = line.trim();
+        // Version nodes start with, e.g., `LIBBPF_0.7.0 {`.
+        if let Some(node) = line
+            .strip_prefix("LIBBPF_")
+            .and_then(|node| node.strip_suffix(" {"))
+        {
+            let mut parts = node.split('.').map(str::parse::<u32>);
+            version = match (parts.next(), parts.next()) {
+                (Some(Ok(major)), Some(Ok(minor))) => Some((major, minor)),
+                _ => None,
+            };
+            continue;
+        }
+        let (Some(version), Some(symbol)) = (version, line.strip_suffix(';')) else {
+            continue;
+        };
+        if !symbol.is_empty()
+            && symbol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            versions.entry(symbol.to_string()).or_insert(version);
+        }
+    }
+
+    let mut table = String::from("&[\n");
+    for (symbol, (major, minor)) in versions {
+        table.push_str(&format!("    (\"{symbol}\", ({major}, {minor})),\n"));
+    }
+    table.push_str("]\n");
+
+    fs::write(out_dir.join("libbpf_versions.rs"), table).expect("Couldn't write libbpf versions");
+}
+
+/// Split a C parameter declaration, e.g., `const void *key`, into its
+/// type and name.
+fn split_c_param(param: &str) -> (&str, &str) {
+    let param = param.trim();
+    let start = param
+        .rfind(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map_or(0, |idx| idx + 1);
+    let (ty, name) = param.split_at(start);
+    if ty.trim().is_empty() {
+        // A parameter without name, i.e., just a type.
+        (param, "")
+    } else {
+        (ty.trim_end(), name)
+    }
+}
+
+/// Generate a table of the BPF helper prototypes declared in the
+/// vendored `bpf_helper_defs.h`.
+///
+/// The table is empty if the libbpf sources are not available.
+fn generate_helper_protos(src_dir: &Path, out_dir: &Path) {
+    let path = src_dir.join("libbpf/src/bpf_helper_defs.h");
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let source = fs::read_to_string(&path).unwrap_or_default();
+    let mut protos = Vec::new();
+    for line in source.lines() {
+        // Helpers are declared as function pointers with the helper's
+        // ID as value, e.g.:
+        // static long (* const bpf_map_update_elem)(void *map, ...) = (void *) 2;
+        let Some(decl) = line.strip_prefix("static ") else {
+            continue;
+        };
+        let Some((ret, rest)) = decl.split_once("(*") else {
+            continue;
+        };
+        let rest = rest.trim_start().trim_start_matches("const ");
+        let Some((name, rest)) = rest.split_once(")(") else {
+            continue;
+        };
+        let Some((params, id)) = rest.rsplit_once(") = (void *) ") else {
+            continue;
+        };
+        let Some(id) = id.strip_suffix(';').and_then(|id| id.parse::<u32>().ok()) else {
+            continue;
+        };
+
+        let args = params
+            .split(',')
+            .map(|param| split_c_param(param).0)
+            .filter(|ty| *ty != "void")
+            .map(|ty| format!("{ty:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let proto = format!(
+            "    HelperProto {{ name: \"{}\", id: {id}, ret: {:?}, args: &[{args}] }},\n",
+            name.trim(),
+            ret.trim(),
+        );
+        protos.push((id, proto));
+    }
+    protos.sort_unstable();
+
+    let mut table = String::from("&[\n");
+    for (_, proto) in protos {
+        table.push_str(&proto);
+    }
+    table.push_str("]\n");
+
+    fs::write(out_dir.join("helper_protos.rs"), table).expect("Couldn't write helper prototypes");
+}
+
+/// Functions that bindgen picks up from system headers but that are not
+/// part of libbpf.
+const FOREIGN_FUNCTIONS: &[&str] = &["vdprintf"];
+
+/// Generate the declarations of all bound libbpf functions as an
+/// invocation of the `{name}_table!` macro, for `src/{name}.rs` to turn
+/// into whatever it needs.
+fn generate_function_table(bindings: &str, out_dir: &Path, name: &str) {
+    let mut table = format!("{name}_table! {{\n");
+    for (func, params, ret) in bound_functions(bindings) {
+        if FOREIGN_FUNCTIONS.contains(&func.as_str()) {
+            continue;
+        }
+        let ret = ret.map(|ret| format!(" -> {ret}")).unwrap_or_default();
+        table.push_str(&format!("    fn {func}({params}){ret};\n"));
+    }
+    table.push_str("}\n");
+
+    fs::write(out_dir.join(format!("{name}.rs")), table).expect("Couldn't write function table");
+}
+
+/// Map the Rust type `ty` of `bindings` to the C type of the stubs, with
+/// all pointers, including function pointers, becoming `void *`. Type
+/// aliases are resolved through their definitions in `bindings`.
+fn stub_c_type(bindings: &str, ty: &str) -> String {
+    let ty = ty.trim();
+    if ty.starts_with('*') || ty.starts_with("::core::option::Option<") {
+        return "void *".to_string();
+    }
+    let name = ty.rsplit("::").next().unwrap_or(ty);
+    let c_ty = match name {
+        "c_char" => "char",
+        "c_schar" => "signed char",
+        "c_uchar" => "unsigned char",
+        "c_short" => "short",
+        "c_ushort" => "unsigned short",
+        "c_int" => "int",
+        "c_uint" => "unsigned int",
+        "c_long" => "long",
+        "c_ulong" => "unsigned long",
+        "c_longlong" => "long long",
+        "c_ulonglong" => "unsigned long long",
+        "c_float" | "f32" => "float",
+        "c_double" | "f64" => "double",
+        "bool" => "bool",
+        "i8" => "int8_t",
+        "i16" => "int16_t",
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "u8" => "uint8_t",
+        "u16" => "uint16_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "isize" => "intptr_t",
+        "usize" => "uintptr_t",
+        _ if ty.starts_with("::libc::") => name,
+        _ => {
+            let prefix = format!("pub type {ty} = ");
+            let alias = bindings
+                .lines()
+                .find_map(|line| line.strip_prefix(&prefix))
+                .unwrap_or_else(|| panic!("cannot stub type `{}`", ty));
+            return stub_c_type(bindings, alias.trim_end_matches(';'));
+        },
+    };
+    c_ty.to_string()
+}
+
+/// Generate weak C definitions of all bound libbpf functions, for the
+/// `stubs` module, instantiating the macros of `stubs/stubs.h`.
+fn generate_stubs(bindings: &str, out_dir: &Path) {
+    let mut stubs = String::from("#include \"stubs.h\"\n\n");
+    for (func, params, ret) in bound_functions(bindings) {
+        if FOREIGN_FUNCTIONS.contains(&func.as_str()) {
+            continue;
+        }
+        let params = params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+            .map(|param| {
+                let (name, ty) = param.split_once(": ").expect("Malformed bindings");
+                let ty = stub_c_type(bindings, ty);
+                let sep = if ty.ends_with('*') { "" } else { " " };
+                format!("{ty}{sep}{name}")
+            })
+            .collect::<Vec<_>>();
+        let params = if params.is_empty() {
+            "(void)".to_string()
+        } else {
+            format!("({})", params.join(", "))
+        };
+        let stub = match ret.map(|ret| stub_c_type(bindings, &ret)) {
+            None => format!("STUB_VOID({func}, {params})\n"),
+            Some(ret) if ret == "void *" => format!("STUB_POINTER({func}, {params})\n"),
+            Some(ret)
+                if ret.starts_with("unsigned")
+                    || ret.starts_with("uint")
+                    || ret == "bool"
+                    || ret == "size_t" =>
+            {
+                format!("STUB_UNSIGNED({ret}, {func}, {params})\n")
+            },
+            Some(ret) => format!("STUB_SIGNED({ret}, {func}, {params})\n"),
+        };
+        stubs.push_str(&stub);
+    }
+
+    fs::write(out_dir.join("stubs.c"), stubs).expect("Couldn't write stubs");
+}
+
+/// Build the stubs generated by [`generate_stubs`].
+fn make_stubs(src_dir: &Path, out_dir: &Path) {
+    let stubs_dir = src_dir.join("stubs");
+    cc::Build::new()
+        .file(out_dir.join("stubs.c"))
+        .include(&stubs_dir)
+        // The stubs ignore their parameters.
+        .flag_if_supported("-Wno-unused-parameter")
+        .compile("bpf_stubs");
+    emit_rerun_directives_for_contents(&stubs_dir);
+}
+
+/// Classify parameter `param` of function `func` for the `io_safety`
+/// module: `borrowed` for file descriptors the function merely uses,
+/// `owned` for ones it takes ownership of, and `raw` for anything else.
+///
+/// File descriptors are the `int` parameters named `fd` or `*_fd`, bar
+/// the ones that aren't always file descriptors, e.g., the `target_fd`
+/// of `bpf_link_create`, which is an interface index for XDP, or the
+/// `prog_fd` of `bpf_xdp_attach`, which is -1 for detaching.
+fn fd_param_kind(func: &str, param: &str) -> &'static str {
+    let (name, ty) = param.split_once(": ").expect("Malformed bindings");
+    match (func, name) {
+        ("btf__set_fd", "fd") => "owned",
+        ("bpf_link_create" | "bpf_prog_query", "target_fd") => "raw",
+        ("bpf_program__set_attach_target", "attach_prog_fd") => "raw",
+        // -1 detaches the attached program.
+        ("bpf_xdp_attach", "prog_fd") => "raw",
+        // 0 leaves the program to detach unspecified.
+        ("bpf_prog_detach2" | "bpf_prog_detach_opts", "prog_fd") => "raw",
+        // 0 uses the target set with `bpf_program__set_attach_target`.
+        ("bpf_program__attach_freplace", "target_fd") => "raw",
+        _ if ty == "::core::ffi::c_int" && (name == "fd" || name.ends_with("_fd")) => "borrowed",
+        _ => "raw",
+    }
+}
+
+/// Classify the return value of function `func` for the `io_safety`
+/// module: `owned` for newly created file descriptors, `borrowed` for
+/// ones belonging to the object passed in, and `raw` for anything else.
+fn fd_return_kind(func: &str) -> &'static str {
+    match func {
+        "bpf_map_create"
+        | "bpf_prog_load"
+        | "bpf_btf_load"
+        | "bpf_obj_get"
+        | "bpf_obj_get_opts"
+        | "bpf_link_create"
+        | "bpf_iter_create"
+        | "bpf_raw_tracepoint_open"
+        | "bpf_raw_tracepoint_open_opts"
+        | "bpf_enable_stats"
+        | "bpf_token_create" => "owned",
+        _ if func.ends_with("_get_fd_by_id") || func.ends_with("_get_fd_by_id_opts") => "owned",
+        "btf__fd"
+        | "bpf_program__fd"
+        | "bpf_map__fd"
+        | "bpf_link__fd"
+        | "bpf_object__find_map_fd_by_name"
+        | "ring_buffer__epoll_fd"
+        | "perf_buffer__epoll_fd"
+        | "perf_buffer__buffer_fd" => "borrowed",
+        _ => "raw",
+    }
+}
+
+/// Generate the bound functions taking or returning file descriptors as
+/// an invocation of the `io_safety_table!` macro, for `src/io_safety.rs`
+/// to wrap. Each parameter and the return type are prefixed with their
+/// kind, as classified by [`fd_param_kind`] and [`fd_return_kind`].
+fn generate_io_safety_table(bindings: &str, out_dir: &Path) {
+    let mut table = String::from("io_safety_table! {\n");
+    for (func, params, ret) in bound_functions(bindings) {
+        if FOREIGN_FUNCTIONS.contains(&func.as_str()) {
+            continue;
+        }
+        let params = params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+            .map(|param| (fd_param_kind(&func, param), param))
+            .collect::<Vec<_>>();
+        let ret_kind = fd_return_kind(&func);
+        if ret_kind == "raw" && params.iter().all(|(kind, _)| *kind == "raw") {
+            continue;
+        }
+        let params = params
+            .iter()
+            .map(|(kind, param)| format!("[{kind}] {param}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ret = ret.unwrap_or_else(|| "()".to_string());
+        table.push_str(&format!("    fn {func}({params}) -> [{ret_kind}] {ret};\n"));
+    }
+    table.push_str("}\n");
+
+    fs::write(out_dir.join("io_safety.rs"), table).expect("Couldn't write I/O safety table");
+}
+
+/// Generate the printf-style callback types, i.e., the function pointer
+/// types taking a format string along with a `va_list` as their last
+/// parameters, as an invocation of the `printf_callbacks_table!` macro,
+/// for `src/printf.rs` to wrap. The table starts with the type the
+/// `va_list` is passed as, which depends on the architecture, e.g.,
+/// `*mut __va_list_tag` on x86_64 and `va_list` on aarch64. Each entry
+/// lists the parameters preceding the format string and the return type.
+fn generate_printf_callbacks(bindings: &str, out_dir: &Path) {
+    let mut va_list = None;
+    let mut entries = String::new();
+    let mut lines = bindings.lines();
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("pub type ")
+            .and_then(|rest| rest.strip_suffix(" = ::core::option::Option<"))
+        else {
+            continue;
+        };
+        let mut decl = String::new();
+        for line in lines.by_ref() {
+            if line == ">;" {
+                break;
+            }
+            decl.push_str(line.trim());
+            decl.push(' ');
+        }
+
+        let Some(decl) = decl.strip_prefix("unsafe extern \"C\" fn(") else {
+            continue;
+        };
+        let Some((params, ret)) = decl.rsplit_once(')') else {
+            continue;
+        };
+        let ret = ret.trim().trim_end_matches(',');
+        let ret = ret.strip_prefix("-> ").unwrap_or("()");
+        let mut params = params
+            .split(',')
+            .map(str::trim)
+            .filter(|param| !param.is_empty())
+            .collect::<Vec<_>>();
+        if params.len() < 2 || !params[params.len() - 2].ends_with(": *const ::core::ffi::c_char") {
+            continue;
+        }
+        let Some((_, ty)) = params[params.len() - 1].split_once(": ") else {
+            continue;
+        };
+        let ty = match ty {
+            "*mut __va_list_tag" => "*mut __va_list_tag",
+            "va_list" => "va_list",
+            _ => continue,
+        };
+        assert_eq!(
+            *va_list.get_or_insert(ty),
+            ty,
+            "printf-style callbacks take `va_list` as different types"
+        );
+        params.truncate(params.len() - 2);
+        entries.push_str(&format!(
+            "    {}({}) -> {};\n",
+            name,
+            params.join(", "),
+            ret
+        ));
+    }
+    let va_list = va_list.expect("No printf-style callbacks in the bindings");
+    let table = format!("printf_callbacks_table! {{\n    va_list = {va_list};\n{entries}}}\n");
+
+    fs::write(out_dir.join("printf_callbacks.rs"), table).expect("Couldn't write printf callbacks");
+}
+
+/// Build the C shim formatting the messages of printf-style callbacks
+/// for the `printf` module.
+fn make_printf_shim(src_dir: &Path) {
+    let shim_dir = src_dir.join("printf");
+    cc::Build::new()
+        .file(shim_dir.join("shim.c"))
+        .compile("bpf_printf");
+    emit_rerun_directives_for_contents(&shim_dir);
+}
+
+/// The subsystems items are grouped into, by name prefix. The first
+/// matching prefix wins.
+const SUBSYSTEMS: &[(&str, &[&str])] = &[
+    ("linker", &["bpf_linker"]),
+    (
+        "ringbuf",
+        &["bpf_ringbuf", "ring_buffer", "ring__", "user_ring_buffer"],
+    ),
+    ("perf", &["perf_"]),
+    ("xdp", &["bpf_xdp_", "ifla_xdp", "xdp_"]),
+    ("btf", &["btf"]),
+    ("bpf", &["bpf_", "libbpf_"]),
+];
+
+/// Generate, for each subsystem, a `use` declaration re-exporting the
+/// items belonging to it.
+fn generate_subsystem_modules(bindings: &str, out_dir: &Path) {
+    let mut modules = SUBSYSTEMS
+        .iter()
+        .map(|(subsys, _)| (*subsys, Vec::new()))
+        .collect::<Vec<_>>();
+
+    for item in bound_items(bindings) {
+        let lower = item.to_ascii_lowercase();
+        let subsys = SUBSYSTEMS
+            .iter()
+            .position(|(_, prefixes)| prefixes.iter().any(|prefix| lower.starts_with(prefix)));
+        if let Some(idx) = subsys {
+            modules[idx].1.push(item);
+        }
+    }
+
+    for (subsys, mut items) in modules {
+        items.sort_unstable();
+        items.dedup();
+
+        let mut module = String::from("pub use crate::bindings::{\n");
+        for item in items {
+            module.push_str(&format!("    {item},\n"));
+        }
+        module.push_str("};\n");
+
+        fs::write(out_dir.join(format!("subsys_{subsys}.rs")), module)
+            .expect("Couldn't write subsystem module");
+    }
+}
+
+fn pkg_check(pkg: &str) {
+    if process::Command::new(pkg)
+        .stdout(process::Stdio::null())
+        .stderr(process::Stdio::null())
+        .status()
+        .is_err()
+    {
+        panic!(
+            "{} is required to compile libbpf-sys with the selected set of features",
+            pkg
+        );
+    }
+}
+
+fn main() {
+    let src_dir = path::PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+
+    // Parts of the crate building on Linux specifics are unavailable
+    // with eBPF-for-Windows.
+    println!("cargo:rustc-check-cfg=cfg(libbpf_sys_windows)");
+    println!("cargo:rustc-check-cfg=cfg(libbpf_sys_arch_bindings)");
+    // Type checking, e.g., by rust-analyzer on a macOS host, works for
+    // any target, so an unsupported one only fails building libbpf.
+    let unsupported = if ebpf_for_windows() {
+        println!("cargo:rustc-cfg=libbpf_sys_windows");
+        None
+    } else {
+        unsupported_target()
+    };
+    if let Some(unsupported) = &unsupported {
+        println!("cargo:warning={}", unsupported);
+    }
+
+    if cfg!(feature = "asan") {
+        check_asan();
+    }
+    if cfg!(feature = "btf-only") {
+        check_btf_only();
+    }
+    check_link_features();
+
+    // The tests comparing the bindings to libbpf's linker version script
+    // get ignored without it, e.g., with the submodule not checked out,
+    // and `provenance::LIBBPF_VERSIONS`, which is generated from it, is
+    // empty.
+    println!("cargo:rustc-check-cfg=cfg(libbpf_sys_no_libbpf_map)");
+    if !src_dir.join("libbpf/src/libbpf.map").exists() {
+        println!(
+            "cargo:warning=libbpf/src/libbpf.map not found; the libbpf versions of \
+             `provenance::LIBBPF_VERSIONS` are unavailable; check out the libbpf submodule"
+        );
+        println!("cargo:rustc-cfg=libbpf_sys_no_libbpf_map");
+    }
+
+    // The virtual machine test builds the root tests with the same
+    // features it was built with itself.
+    if cfg!(feature = "vmtest") {
+        let mut features = env::vars()
+            .filter_map(|(var, _)| {
+                var.strip_prefix("CARGO_FEATURE_")
+                    .map(|feature| feature.to_ascii_lowercase().replace('_', "-"))
+            })
+            .collect::<Vec<_>>();
+        features.sort_unstable();
+        println!("cargo:rustc-env=LIBBPF_SYS_FEATURES={}", features.join(","));
+    }
+
+    generate_bindings(src_dir.clone());
+
+    let out_dir = path::PathBuf::from(env::var_os("OUT_DIR").unwrap());
+
+    let bindings = if cfg!(feature = "bindgen") && !cfg!(feature = "bindgen-source") {
+        fs::read_to_string(out_dir.join("bindings.rs")).expect("Couldn't read bindings")
+    } else {
+        let (path, per_arch) = shipped_bindings(&src_dir);
+        println!("cargo:rerun-if-changed=src/bindings.rs");
+        let bindings = fs::read_to_string(path).expect("Couldn't read bindings");
+        // `src/lib.rs` only includes `src/bindings.rs` directly.
+        if per_arch {
+            println!("cargo:rustc-cfg=libbpf_sys_arch_bindings");
+        }
+        let prefixes = trimmed_prefixes();
+        let trimmed = if prefixes.is_empty() {
+            None
+        } else {
+            Some(trim_bindings(&bindings, |name, _| {
+                prefixes.iter().any(|prefix| name.starts_with(prefix))
+            }))
+        };
+        let downgraded = if cfg!(feature = "msrv-bindings") {
+            Some(downgrade_bindings(trimmed.as_deref().unwrap_or(&bindings)))
+        } else {
+            None
+        };
+        let unpadded = if cfg!(feature = "no-explicit-padding") {
+            let modified = downgraded.as_deref().or(trimmed.as_deref());
+            Some(strip_explicit_padding(modified.unwrap_or(&bindings)))
+        } else {
+            None
+        };
+        match unpadded.or(downgraded).or(trimmed) {
+            Some(modified) => {
+                fs::write(out_dir.join("bindings.rs"), &modified).expect("Couldn't write bindings");
+                modified
+            },
+            None if per_arch => {
+                fs::write(out_dir.join("bindings.rs"), &bindings).expect("Couldn't write bindings");
+                bindings
+            },
+            None => bindings,
+        }
+    };
+    let bindings = if cfg!(feature = "btf-only") {
+        let trimmed = btf_only_bindings(&bindings);
+        fs::write(out_dir.join("bindings.rs"), &trimmed).expect("Couldn't write bindings");
+        trimmed
+    } else {
+        bindings
+    };
+    let bindings = if cfg!(feature = "libc") {
+        let mapped = libc_bindings(&bindings);
+        fs::write(out_dir.join("bindings.rs"), &mapped).expect("Couldn't write bindings");
+        mapped
+    } else {
+        bindings
+    };
+    let error_conventions = generate_error_conventions(&bindings, &out_dir);
+    // Make the table available to build scripts of dependent crates as
+    // `DEP_BPF_ERROR_CONVENTIONS`.
+    println!(
+        "cargo:error_conventions={}",
+        error_conventions.to_string_lossy()
+    );
+    generate_subsystem_modules(&bindings, &out_dir);
+    generate_btf_kind_table(&bindings, &out_dir);
+    // The BTF structs are not part of eBPF for Windows' headers.
+    if !ebpf_for_windows() {
+        generate_btf_wire(&bindings, &out_dir);
+    }
+    generate_layout_asserts(&bindings, &out_dir);
+    generate_layout_table(&bindings, &out_dir);
+    generate_opts_sizes(&bindings, &out_dir);
+    generate_default_opts(&bindings, &out_dir);
+    generate_type_names(&bindings, &out_dir);
+    generate_section_defs(&src_dir, &out_dir);
+    generate_helper_protos(&src_dir, &out_dir);
+    generate_libbpf_versions(&src_dir, &out_dir);
+    generate_completeness_report(&bindings, &src_dir, &out_dir);
+    if cfg!(feature = "dlopen") {
+        generate_function_table(&bindings, &out_dir, "dlopen");
+    }
+    if cfg!(feature = "stubs") {
+        generate_stubs(&bindings, &out_dir);
+    }
+    generate_printf_callbacks(&bindings, &out_dir);
+    if cfg!(feature = "io-safety") {
+        generate_io_safety_table(&bindings, &out_dir);
+    }
+    // The shim only depends on the C library, and gets built however
+    // libbpf is provided.
+    if cfg!(feature = "printf") && !ebpf_for_windows() {
+        make_printf_shim(&src_dir);
+    }
+
+    let vendored_libbpf = cfg!(feature = "vendored-libbpf");
+    let vendored_libelf = cfg!(feature = "vendored-libelf");
+    // zlib's stand-in replaces any zlib with `no-zlib`.
+    let no_zlib = cfg!(feature = "no-zlib");
+    let vendored_zlib = cfg!(feature = "vendored-zlib") && !no_zlib;
+    let vendored_zstd = cfg!(feature = "vendored-zstd");
+    let libelf_cc = cfg!(feature = "libelf-cc");
+    let libbpf_make = cfg!(feature = "libbpf-make");
+    println!("Using feature no-zlib={}", no_zlib);
+    println!("Using feature vendored-libbpf={}", vendored_libbpf);
+    println!("Using feature vendored-libelf={}", vendored_libelf);
+    println!("Using feature vendored-zlib={}", vendored_zlib);
+    println!("Using feature vendored-zstd={}", vendored_zstd);
+    println!("Using feature libelf-cc={}", libelf_cc);
+    println!("Using feature libbpf-make={}", libbpf_make);
+
+    let static_libbpf = cfg!(feature = "static-libbpf");
+    let static_libelf = cfg!(feature = "static-libelf");
+    let static_zlib = cfg!(feature = "static-zlib") && !no_zlib;
+    println!("Using feature static-libbpf={}", static_libbpf);
+    println!("Using feature static-libelf={}", static_libelf);
+    println!("Using feature static-zlib={}", static_zlib);
+
+    if cfg!(feature = "dlopen") {
+        // libbpf is loaded at runtime, so there is nothing to link.
+        return;
+    }
+
+    if cfg!(feature = "stubs") {
+        // The stubs stand in for libbpf.
+        make_stubs(&src_dir, &out_dir);
+        return;
+    }
+
+    // Type checking, e.g., by `cargo check` or rust-analyzer, only needs
+    // the bindings, which are in place by now, while building the
+    // vendored libraries takes minutes.
+    println!("cargo:rerun-if-env-changed=LIBBPF_SYS_SKIP_NATIVE_BUILD");
+    if env::var_os("LIBBPF_SYS_SKIP_NATIVE_BUILD").is_some_and(|skip| skip == "1") {
+        println!(
+            "cargo:warning=LIBBPF_SYS_SKIP_NATIVE_BUILD is set; not building or linking libbpf"
+        );
+        return;
+    }
+
+    if let Some(unsupported) = unsupported {
+        panic!("{}", unsupported);
+    }
+
+    if cfg!(feature = "btf-only") {
+        make_libbpf_btf(&src_dir);
+        return;
+    }
+
+    if ebpf_for_windows() {
+        let lib_dir = ebpf_for_windows_dir().join("lib");
+        println!("cargo:rustc-link-search=native={}", lib_dir.display());
+        println!("cargo:rustc-link-lib=ebpfapi");
+        return;
+    }
+
+    if cfg!(feature = "novendor") {
+        println!("cargo:warning=the `novendor` feature of `libbpf-sys` is deprecated; build without features instead");
+        println!(
+            "cargo:rustc-link-lib={}bpf",
+            if static_libbpf { "static=" } else { "" }
+        );
+        return;
+    }
+
+    if vendored_libbpf {
+        check_mixed_libbpf();
+    }
+
+    // check for all necessary compilation tools
+    if vendored_libelf && !libelf_cc {
+        pkg_check("autoreconf");
+        pkg_check("autopoint");
+        pkg_check("flex");
+        pkg_check("bison");
+        pkg_check("gawk");
+    }
+
+    let (compiler, mut cflags) = if vendored_libbpf || vendored_libelf || vendored_zlib {
+        // Only libbpf and libelf can be built without make.
+        if (vendored_libbpf && libbpf_make) || (vendored_libelf && !libelf_cc) || vendored_zlib {
+            pkg_check("make");
+            pkg_check("pkg-config");
+        }
+
+        let compiler = cc::Build::new().try_get_compiler().expect(
+            "a C compiler is required to compile libbpf-sys using the vendored copy of libbpf",
+        );
+        if cfg!(feature = "linker-plugin-lto") {
+            check_lto(&compiler);
+        }
+        if cfg!(feature = "branch-protection") {
+            check_branch_protection();
+        }
+        if cfg!(feature = "cf-protection") {
+            check_cf_protection();
+        }
+        let mut cflags = compiler.cflags_env();
+        println!("cargo:rerun-if-env-changed=LIBBPF_SYS_EXTRA_CFLAGS");
+        if let Some(extra_cflags) = env::var_os("LIBBPF_SYS_EXTRA_CFLAGS") {
+            cflags.push(" ");
+            cflags.push(extra_cflags);
+        }
+        cflags.push(instrumentation_cflags(&compiler));
+        (Some(compiler), cflags)
+    } else {
+        (None, ffi::OsString::new())
+    };
+
+    // The libraries the system provides and that get linked dynamically
+    // are located with pkg-config, and their headers are where the
+    // vendored libbpf finds them.
+    let lib_dirs = library_path_dirs();
+    let mut system_libs = Vec::new();
+    let mut zlib_version = None;
+    for (system, library, name) in [
+        (!vendored_libbpf && !static_libbpf, "libbpf", "bpf"),
+        (!vendored_libelf && !static_libelf, "libelf", "elf"),
+        (!vendored_zlib && !static_zlib && !no_zlib, "zlib", "z"),
+    ] {
+        if !system {
+            continue;
+        }
+        if let Some(lib) = probe_system_library(library, name, &lib_dirs) {
+            for path in &lib.include_paths {
+                cflags.push(format!(" -I{}", path.display()));
+            }
+            if name == "z" {
+                zlib_version = Some(lib.version.clone());
+            }
+            system_libs.push(lib);
+        }
+    }
+
+    if vendored_zlib {
+        make_zlib(compiler.as_ref().unwrap(), &src_dir, &out_dir);
+        cflags.push(format!(" -I{}/zlib/", out_dir.display()));
+        zlib_version = zlib_header_version(&src_dir);
+    }
+
+    if no_zlib {
+        make_zlib_shim(&src_dir);
+        cflags.push(format!(" -I{}/no-zlib/include/", src_dir.display()));
+    }
+
+    if vendored_zstd {
+        make_zstd(compiler.as_ref().unwrap(), &src_dir, &out_dir);
+    }
+
+    if vendored_libelf && libelf_cc {
+        make_libelf_cc(&cflags, &src_dir, &out_dir);
+        cflags.push(format!(" -I{}/elfutils/libelf/", src_dir.display()));
+    } else if vendored_libelf {
+        make_elfutils(compiler.as_ref().unwrap(), &src_dir, &out_dir);
+        cflags.push(format!(" -I{}/elfutils/libelf/", out_dir.display()));
+    }
+
+    if vendored_libbpf && libbpf_make {
+        make_libbpf(compiler.as_ref().unwrap(), &cflags, &src_dir, &out_dir);
+    } else if vendored_libbpf {
+        make_libbpf_cc(&cflags, &src_dir, &out_dir);
+    }
+
+    for (vendored, archive) in [
+        (vendored_libbpf, "libbpf.a"),
+        (vendored_libelf, "libelf.a"),
+        (vendored_zlib, "libz.a"),
+        (vendored_zstd, "libzstd.a"),
+    ] {
+        if !vendored {
+            continue;
+        }
+        let path = out_dir.join(archive);
+        if small_profile() {
+            strip_archive(&path);
+        }
+        if let Ok(metadata) = fs::metadata(&path) {
+            println!("Archive size {}={} bytes", archive, metadata.len());
+        }
+    }
+
+    println!(
+        "cargo:rustc-link-search=native={}",
+        out_dir.to_string_lossy()
+    );
+    println!(
+        "cargo:rustc-link-lib={}elf",
+        if static_libelf { "static=" } else { "" }
+    );
+    if vendored_zstd {
+        println!("cargo:rustc-link-lib=static=zstd");
+    }
+    if !no_zlib {
+        println!(
+            "cargo:rustc-link-lib={}z",
+            if static_zlib { "static=" } else { "" }
+        );
+    }
+    println!(
+        "cargo:rustc-link-lib={}bpf",
+        if static_libbpf { "static=" } else { "" }
+    );
+    if no_zlib {
+        // After libbpf and libelf, which refer to it.
+        println!("cargo:rustc-link-lib=static=bpf_no_zlib");
+    }
+    if let Some(compiler) = &compiler {
+        if cfg!(feature = "coverage") {
+            link_coverage_runtime(compiler);
+        }
+    }
+    println!("cargo:include={}/include", out_dir.to_string_lossy());
+    // Dependents linking zlib themselves, e.g., through libz-sys, can
+    // check that they end up with the same one.
+    if let Some(version) = zlib_version {
+        println!("cargo:zlib_version={}", version);
+    }
+
+    for dir in &lib_dirs {
+        println!("cargo:rustc-link-search=native={}", dir.display());
+    }
+    // After the directories given explicitly, which take precedence.
+    for path in system_libs.iter().flat_map(|lib| &lib.link_paths) {
+        println!("cargo:rustc-link-search=native={}", path.display());
+    }
+
+    for (vendored, linked_statically, library, name) in [
+        (vendored_libbpf, static_libbpf, "libbpf", "bpf"),
+        (vendored_libelf, static_libelf, "libelf", "elf"),
+        (vendored_zlib, static_zlib, "zlib", "z"),
+    ] {
+        if linked_statically && !vendored {
+            link_system_static(library, name, lib_dirs.clone());
+        }
+    }
+
+    if !static_libbpf {
+        check_shared_libbpf(lib_dirs);
+    }
+}
+
+/// Retrieve the directories in `LIBBPF_SYS_LIBRARY_PATH`, which get
+/// searched for the libraries before any other location.
+fn library_path_dirs() -> Vec<path::PathBuf> {
+    println!("cargo:rerun-if-env-changed=LIBBPF_SYS_LIBRARY_PATH");
+    env::var("LIBBPF_SYS_LIBRARY_PATH")
+        .map(|lib_path| {
+            lib_path
+                .split(':')
+                .filter(|path| !path.is_empty())
+                .map(path::PathBuf::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Locate the shared `library`, i.e., libbpf, libelf, or zlib, linked
+/// as `name`, that the system provides, with pkg-config.
+///
+/// Without its `.pc` file, e.g., when the development package is
+/// missing or pkg-config is not set up for the target when cross
+/// compiling, the library is left to the linker's default search paths,
+/// with a warning naming the file, unless it is in `lib_dirs`.
+fn probe_system_library(
+    library: &str,
+    name: &str,
+    lib_dirs: &[path::PathBuf],
+) -> Option<pkg_config::Library> {
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_LIBDIR");
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_SYSROOT_DIR");
+    match pkg_config::Config::new()
+        .cargo_metadata(false)
+        .env_metadata(false)
+        .probe(library)
+    {
+        Ok(lib) => {
+            println!("Using system {} {}", library, lib.version);
+            Some(lib)
+        },
+        Err(err) => {
+            let file = format!("lib{}.so", name);
+            if !lib_dirs.iter().any(|dir| dir.join(&file).exists()) {
+                // Cargo only shows the first line of a warning.
+                let err = err
+                    .to_string()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                println!(
+                    "cargo:warning=pkg-config failed to find {library} ({err}); linking {file} \
+                     from the linker's default search paths. Install the development files of \
+                     {library}, or set PKG_CONFIG_PATH to the directory containing \
+                     {library}.pc or LIBBPF_SYS_LIBRARY_PATH to the one containing {file}",
+                );
+            }
+            None
+        },
+    }
+}
+
+/// Locate the file of `library`, e.g., `libbpf.so` of libbpf, the
+/// linker is going to pick up, searching `lib_dirs` before the usual
+/// system locations.
+fn find_library(
+    mut lib_dirs: Vec<path::PathBuf>,
+    library: &str,
+    file: &str,
+) -> Option<path::PathBuf> {
+    if let Ok(libdir) = pkg_config::get_variable(library, "libdir") {
+        lib_dirs.push(libdir.into());
+    }
+    lib_dirs.extend(
+        [
+            "/usr/local/lib64",
+            "/usr/local/lib",
+            "/usr/lib64",
+            "/usr/lib",
+            "/lib64",
+            "/lib",
+        ]
+        .iter()
+        .map(path::PathBuf::from),
+    );
+    if let Ok(target) = env::var("TARGET") {
+        let multiarch = target.replace("-unknown-", "-");
+        lib_dirs.push(Path::new("/usr/lib").join(multiarch));
+    }
+
+    lib_dirs
+        .into_iter()
+        .map(|dir| dir.join(file))
+        .find(|lib| lib.exists())
+}
+
+/// Link the static `library`, i.e., libbpf, libelf, or zlib, linked as
+/// `name`, that the system provides, e.g., as part of a distribution's
+/// libbpf-static package, with `static-<library>` but without
+/// `vendored-<library>`.
+///
+/// rustc only looks for static libraries in the directories it gets
+/// told about, so the one containing the archive has to be found here.
+/// pkg-config's static flags guide the search and name any further
+/// libraries it depends on, e.g., libzstd for libelf.
+fn link_system_static(library: &str, name: &str, mut lib_dirs: Vec<path::PathBuf>) {
+    let probed = pkg_config::Config::new()
+        .statik(true)
+        .cargo_metadata(false)
+        .env_metadata(false)
+        .probe(library);
+    if let Ok(probed) = probed {
+        lib_dirs.extend(probed.link_paths);
+        for lib in probed
+            .libs
+            .iter()
+            .filter(|lib| !["bpf", "elf", "z"].contains(&lib.as_str()))
+        {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
+    }
+
+    let file = format!("lib{}.a", name);
+    let Some(lib) = find_library(lib_dirs, library, &file) else {
+        panic!(
+            "the `static-{library}` feature requires either the `vendored-{library}` \
+             feature or a {file} provided by the system, but none was found in \
+             LIBBPF_SYS_LIBRARY_PATH, pkg-config's libdir, or the usual system locations",
+            library = library,
+            file = file,
+        );
+    };
+    println!("Using static {} {}", library, lib.display());
+    println!(
+        "cargo:rustc-link-search=native={}",
+        lib.parent().unwrap().display()
+    );
+}
+
+/// Determine the major and minor version of libbpf the bindings
+/// correspond to.
+fn bindings_libbpf_version() -> Option<(u32, u32)> {
+    // Unless generated from the system headers, the libbpf version the
+    // bindings correspond to is part of the crate version, e.g.,
+    // `1.5.1+v1.5.1`.
+    if cfg!(feature = "bindgen-system-headers") {
+        system_header_version()
+    } else {
+        let version = env::var("CARGO_PKG_VERSION").unwrap();
+        version
+            .split_once("+v")
+            .and_then(|(_, libbpf)| libbpf.split_once('.'))
+            .and_then(|(major, rest)| {
+                Some((major.parse::<u32>().ok()?, ident(rest).parse::<u32>().ok()?))
+            })
+    }
+}
+
+/// Check that the shared libbpf that is going to be linked provides
+/// the symbol versions the bindings were generated for and warn if it
+/// does not, as any function introduced later would fail to resolve.
+fn check_shared_libbpf(lib_dirs: Vec<path::PathBuf>) {
+    let Some((major, minor)) = bindings_libbpf_version() else {
+        return;
+    };
+
+    let Some(lib) = find_library(lib_dirs, "libbpf", "libbpf.so") else {
+        return;
+    };
+    let output = process::Command::new("readelf")
+        .arg("--version-info")
+        .arg("--wide")
+        .arg(&lib)
+        .output();
+    let output = match output {
+        Ok(output) if output.status.success() => output.stdout,
+        _ => {
+            println!(
+                "cargo:warning=failed to run `readelf` on {}; unable to verify libbpf symbol versions",
+                lib.display()
+            );
+            return;
+        },
+    };
+
+    let newest = String::from_utf8_lossy(&output)
+        .lines()
+        .filter_map(|line| line.split_once("Name: LIBBPF_"))
+        .filter_map(|(_, version)| {
+            let mut parts = version.trim().split('.').map(|part| part.parse::<u32>());
+            Some((parts.next()?.ok()?, parts.next()?.ok()?))
+        })
+        .max();
+
+    match newest {
+        Some(newest) if newest >= (major, minor) => (),
+        Some((newest_major, newest_minor)) => println!(
+            "cargo:warning={} only provides symbols up to version LIBBPF_{newest_major}.{newest_minor}.0, \
+             but the bindings are for LIBBPF_{major}.{minor}.0; \
+             functions introduced since will fail to link",
+            lib.display(),
+        ),
+        None => println!(
+            "cargo:warning={} does not define any LIBBPF_* symbol versions",
+            lib.display()
+        ),
+    }
+}
+
+/// Copy the source tree at `src` to `dst`, to build it there.
+///
+/// zlib and elfutils only build in their source tree. Building them in
+/// a copy in `OUT_DIR`, which Cargo keeps apart per target, profile,
+/// and set of features, lets concurrent builds, e.g., for different
+/// targets, or of crates depending on libbpf-sys with different
+/// features, proceed without interfering, and leaves the source tree,
+/// which may be read-only, untouched.
+fn copy_source_tree(src: &Path, dst: &Path) -> io::Result<()> {
+    // Start out clean, as left overs of a previous build would confuse
+    // configure.
+    match fs::remove_dir_all(dst) {
+        Ok(()) => (),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+        Err(err) => return Err(err),
+    }
+    fs::create_dir_all(dst)?;
+
+    for entry in read_dir(src)? {
+        let entry = entry?;
+        // Submodules contain a `.git` file referring to the repository.
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        let path = entry.path();
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_source_tree(&path, &target)?;
+        } else {
+            fs::copy(&path, &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// The flags instrumenting the vendored C code for coverage with the
+/// `coverage` feature: LLVM's source based coverage, as used by
+/// `-C instrument-coverage`, for clang and gcov for GCC.
+fn coverage_cflags(compiler: &cc::Tool) -> Option<&'static str> {
+    if !cfg!(feature = "coverage") {
+        None
+    } else if compiler.is_like_clang() {
+        Some(" -fprofile-instr-generate -fcoverage-mapping")
+    } else if compiler.is_like_gnu() {
+        Some(" --coverage")
+    } else {
+        panic!("the `coverage` feature requires clang or GCC")
+    }
+}
+
+/// The flags making the vendored C code easier to follow for valgrind
+/// with the `valgrind` feature: no optimizations, which make memcheck
+/// report spurious uses of uninitialized values, no inlining, and
+/// frame pointers for complete stack traces. Fortification has to go as
+/// well, as it requires optimizations.
+fn valgrind_cflags() -> Option<&'static str> {
+    if cfg!(feature = "valgrind") {
+        Some(" -O0 -g -fno-inline -fno-omit-frame-pointer -U_FORTIFY_SOURCE")
+    } else {
+        None
+    }
+}
+
+/// The flags instrumenting the vendored C code with AddressSanitizer
+/// with the `asan` feature. Frame pointers make for cheap and complete
+/// stack traces in reports.
+fn asan_cflags() -> Option<&'static str> {
+    if cfg!(feature = "asan") {
+        Some(" -fsanitize=address -fno-omit-frame-pointer")
+    } else {
+        None
+    }
+}
+
+/// Check whether the Rust code gets built with a flag ending in `suffix`
+/// in `RUSTFLAGS`, which matches both `-Zsanitizer=address` and the
+/// argument following a separate `-Z`.
+fn has_rustflag(suffix: &str) -> bool {
+    has_rustflag_matching(|flag| flag.ends_with(suffix))
+}
+
+/// Check whether the Rust code gets built with a flag in `RUSTFLAGS`
+/// that `matches` accepts.
+fn has_rustflag_matching(matches: impl Fn(&str) -> bool) -> bool {
+    env::var("CARGO_ENCODED_RUSTFLAGS")
+        .unwrap_or_default()
+        .split('\x1f')
+        .any(matches)
+}
+
+/// The flags compiling the vendored C code to LLVM bitcode with the
+/// `linker-plugin-lto` feature, in the flavor of LTO the Rust code uses:
+/// full with `-Clto=fat`, and thin otherwise.
+fn lto_cflags() -> Option<&'static str> {
+    if !cfg!(feature = "linker-plugin-lto") {
+        None
+    } else if has_rustflag("lto=fat") {
+        Some(" -flto=full")
+    } else {
+        Some(" -flto=thin")
+    }
+}
+
+/// Check whether the target architecture is `arch`, which the build
+/// script may not run on when cross compiling.
+fn target_arch_is(arch: &str) -> bool {
+    env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|target| target == arch)
+}
+
+/// The flags enabling pointer authentication (PAC) and branch target
+/// identification (BTI) for the vendored C code with the
+/// `branch-protection` feature, which only exist on AArch64.
+fn branch_protection_cflags() -> Option<&'static str> {
+    if cfg!(feature = "branch-protection") && target_arch_is("aarch64") {
+        Some(" -mbranch-protection=standard")
+    } else {
+        None
+    }
+}
+
+/// The flags enabling Intel CET, i.e., shadow stacks and indirect branch
+/// tracking, for the vendored C code with the `cf-protection` feature,
+/// which only exist on x86_64.
+fn cf_protection_cflags() -> Option<&'static str> {
+    if cfg!(feature = "cf-protection") && target_arch_is("x86_64") {
+        Some(" -fcf-protection=full")
+    } else {
+        None
+    }
+}
+
+/// Check that branch protection is not enabled for the C code only. The
+/// linker marks the binary as BTI compatible only if all objects are,
+/// so the Rust code has to be built with `-Zbranch-protection` as well.
+fn check_branch_protection() {
+    if !target_arch_is("aarch64") {
+        println!("Using feature branch-protection=false (target is not aarch64)");
+        return;
+    }
+
+    let bti = has_rustflag_matching(|flag| {
+        flag.split_once("branch-protection=")
+            .is_some_and(|(_, value)| value.split(',').any(|value| value == "bti"))
+    });
+    if !bti {
+        println!(
+            "cargo:warning=the `branch-protection` feature only protects the C code; \
+             build with `RUSTFLAGS=-Zbranch-protection=bti,pac-ret` to protect the whole binary"
+        );
+    }
+}
+
+/// Check that CET is not enabled for the C code only. The linker marks
+/// the binary as supporting shadow stacks and IBT only if all objects
+/// do, so the Rust code has to be built with `-Zcf-protection=full` as
+/// well.
+fn check_cf_protection() {
+    if !target_arch_is("x86_64") {
+        println!("Using feature cf-protection=false (target is not x86_64)");
+        return;
+    }
+
+    if !has_rustflag("cf-protection=full") {
+        println!(
+            "cargo:warning=the `cf-protection` feature only protects the C code; \
+             build with `RUSTFLAGS=-Zcf-protection=full` to protect the whole binary"
+        );
+    }
+}
+
+/// Determine the major LLVM version from the output of `command`, on
+/// the line containing `prefix`.
+fn llvm_major_version(command: &mut process::Command, prefix: &str) -> Option<u32> {
+    let output = command.output().ok()?;
+    let output = String::from_utf8(output.stdout).ok()?;
+    let version = output.lines().find_map(|line| {
+        let (_, version) = line.split_once(prefix)?;
+        Some(version.trim_start())
+    })?;
+    version.split('.').next()?.parse().ok()
+}
 
-    #[derive(Debug)]
-    struct IgnoreMacros(HashSet<&'static str>);
+/// Check that cross-language LTO can work: the Rust code has to be
+/// built with `-Clinker-plugin-lto`, and the C code with a clang whose
+/// bitcode the linker plugin understands.
+fn check_lto(compiler: &cc::Tool) {
+    assert!(
+        has_rustflag("linker-plugin-lto"),
+        "the `linker-plugin-lto` feature requires building with \
+         `RUSTFLAGS=-Clinker-plugin-lto`"
+    );
+    assert!(
+        compiler.is_like_clang(),
+        "the `linker-plugin-lto` feature requires clang"
+    );
 
-    impl bindgen::callbacks::ParseCallbacks for IgnoreMacros {
-        fn will_parse_macro(&self, name: &str) -> bindgen::callbacks::MacroParsingBehavior {
-            if self.0.contains(name) {
-                bindgen::callbacks::MacroParsingBehavior::Ignore
-            } else {
-                bindgen::callbacks::MacroParsingBehavior::Default
-            }
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let rustc_llvm = llvm_major_version(
+        process::Command::new(rustc)
+            .arg("--version")
+            .arg("--verbose"),
+        "LLVM version:",
+    );
+    let clang_llvm = llvm_major_version(
+        process::Command::new(compiler.path()).arg("--version"),
+        "clang version",
+    );
+    if let (Some(rustc_llvm), Some(clang_llvm)) = (rustc_llvm, clang_llvm) {
+        if rustc_llvm != clang_llvm {
+            println!(
+                "cargo:warning=rustc uses LLVM {}, but {} LLVM {}; \
+                 cross-language LTO may fail to link",
+                rustc_llvm,
+                compiler.path().display(),
+                clang_llvm
+            );
         }
     }
 
-    let ignored_macros = IgnoreMacros(
-        vec![
-            "BTF_KIND_FUNC",
-            "BTF_KIND_FUNC_PROTO",
-            "BTF_KIND_VAR",
-            "BTF_KIND_DATASEC",
-            "BTF_KIND_FLOAT",
-            "BTF_KIND_DECL_TAG",
-            "BTF_KIND_TYPE_TAG",
-            "BTF_KIND_ENUM64",
-        ]
-        .into_iter()
-        .collect(),
-    );
-
-    #[cfg(feature = "bindgen-source")]
-    let out_dir = &src_dir.join("src");
-    #[cfg(not(feature = "bindgen-source"))]
-    let out_dir =
-        &path::PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR should always be set"));
-
-    bindgen::Builder::default()
-        .derive_default(true)
-        .explicit_padding(true)
-        .default_enum_style(bindgen::EnumVariation::Consts)
-        .size_t_is_usize(false)
-        .prepend_enum_name(false)
-        .layout_tests(false)
-        .generate_comments(false)
-        .emit_builtins()
-        .allowlist_function("bpf_.+")
-        .allowlist_function("btf_.+")
-        .allowlist_function("libbpf_.+")
-        .allowlist_function("perf_.+")
-        .allowlist_function("ring_buffer_.+")
-        .allowlist_function("user_ring_buffer_.+")
-        .allowlist_function("vdprintf")
-        .allowlist_type("bpf_.+")
-        .allowlist_type("btf_.+")
-        .allowlist_type("xdp_.+")
-        .allowlist_type("perf_.+")
-        .allowlist_var("BPF_.+")
-        .allowlist_var("BTF_.+")
-        .allowlist_var("XDP_.+")
-        .allowlist_var("PERF_.+")
-        .parse_callbacks(Box::new(ignored_macros))
-        .header("bindings.h")
-        .clang_arg(format!("-I{}", src_dir.join("libbpf/include").display()))
-        .clang_arg(format!(
-            "-I{}",
-            src_dir.join("libbpf/include/uapi").display()
-        ))
-        .generate()
-        .expect("Unable to generate bindings")
-        .write_to_file(out_dir.join("bindings.rs"))
-        .expect("Couldn't write bindings");
+    // Archives of bitcode need an archiver understanding it, to index
+    // their symbols.
+    println!("cargo:rerun-if-env-changed=AR");
+    if env::var_os("AR").is_none() {
+        env::set_var("AR", "llvm-ar");
+    }
 }
 
-#[cfg(not(feature = "bindgen"))]
-fn generate_bindings(_: path::PathBuf) {}
-
-fn pkg_check(pkg: &str) {
-    if process::Command::new(pkg)
-        .stdout(process::Stdio::null())
-        .stderr(process::Stdio::null())
-        .status()
-        .is_err()
-    {
+/// Check that `btf-only` is not combined with features providing libbpf
+/// in other ways, or relying on more of it.
+fn check_btf_only() {
+    if cfg!(any(
+        feature = "dlopen",
+        feature = "stubs",
+        feature = "ebpf-for-windows",
+        feature = "compat-0x",
+    )) {
         panic!(
-            "{} is required to compile libbpf-sys with the selected set of features",
-            pkg
+            "the `btf-only` feature cannot be combined with `dlopen`, `stubs`, \
+             `ebpf-for-windows`, or `compat-0x`"
         );
     }
 }
 
-fn main() {
-    let src_dir = path::PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+/// Check that libelf and zlib only get vendored or linked statically
+/// if libbpf-sys links them in the first place, which it does not if
+/// libbpf gets loaded at runtime, stubbed out, or comes from the system
+/// by way of `novendor`, and with `btf-only`.
+fn check_link_features() {
+    let mode = if cfg!(feature = "dlopen") {
+        "dlopen"
+    } else if cfg!(feature = "stubs") {
+        "stubs"
+    } else if cfg!(feature = "btf-only") {
+        "btf-only"
+    } else if cfg!(feature = "novendor") {
+        "novendor"
+    } else {
+        return;
+    };
 
-    generate_bindings(src_dir.clone());
+    for (enabled, feature) in [
+        (cfg!(feature = "vendored-libelf"), "vendored-libelf"),
+        (cfg!(feature = "vendored-zlib"), "vendored-zlib"),
+        (cfg!(feature = "vendored-zstd"), "vendored-zstd"),
+        (cfg!(feature = "libelf-cc"), "libelf-cc"),
+        (cfg!(feature = "static-libelf"), "static-libelf"),
+        (cfg!(feature = "static-zlib"), "static-zlib"),
+    ] {
+        if enabled {
+            panic!(
+                "the `{}` feature has no effect with `{}`, which does not link libelf \
+                 and zlib; drop it",
+                feature, mode
+            );
+        }
+    }
+}
 
-    let vendored_libbpf = cfg!(feature = "vendored-libbpf");
-    let vendored_libelf = cfg!(feature = "vendored-libelf");
-    let vendored_zlib = cfg!(feature = "vendored-zlib");
-    println!("Using feature vendored-libbpf={}", vendored_libbpf);
-    println!("Using feature vendored-libelf={}", vendored_libelf);
-    println!("Using feature vendored-zlib={}", vendored_zlib);
+/// Check that the Rust code gets built with AddressSanitizer along with
+/// the vendored C code, as the former brings along the runtime the
+/// latter requires.
+fn check_asan() {
+    if cfg!(feature = "valgrind") {
+        panic!("the `asan` and `valgrind` features are mutually exclusive");
+    }
+    assert!(
+        has_rustflag("sanitizer=address"),
+        "the `asan` feature requires building with `RUSTFLAGS=-Zsanitizer=address` \
+         and an explicit `--target`"
+    );
+}
 
-    let static_libbpf = cfg!(feature = "static-libbpf");
-    let static_libelf = cfg!(feature = "static-libelf");
-    let static_zlib = cfg!(feature = "static-zlib");
-    println!("Using feature static-libbpf={}", static_libbpf);
-    println!("Using feature static-libelf={}", static_libelf);
-    println!("Using feature static-zlib={}", static_zlib);
+/// Check whether `arg`, an argument to the linker, links libbpf, e.g.,
+/// `-lbpf` or `/usr/lib64/libbpf.so.1`.
+fn links_libbpf(arg: &str) -> bool {
+    let file = arg.rsplit(['/', '\\']).next().unwrap_or(arg);
+    arg == "-lbpf" || file == "libbpf.a" || file.starts_with("libbpf.so")
+}
 
-    if cfg!(feature = "novendor") {
-        println!("cargo:warning=the `novendor` feature of `libbpf-sys` is deprecated; build without features instead");
-        println!(
-            "cargo:rustc-link-lib={}bpf",
-            if static_libbpf { "static=" } else { "" }
+/// Find the flag in `RUSTFLAGS` linking libbpf besides libbpf-sys, if
+/// any, i.e., `-l bpf`, `-l dylib=bpf`, or `-C link-arg=-lbpf`, split
+/// into two arguments or not.
+fn rustflag_linking_libbpf() -> Option<String> {
+    let rustflags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    let flags = rustflags.split('\x1f').collect::<Vec<_>>();
+    for (i, flag) in flags.iter().enumerate() {
+        let (option, value) = match *flag {
+            "-l" | "-C" => (&flag[..], flags.get(i + 1).copied().unwrap_or_default()),
+            _ if flag.starts_with("-l") || flag.starts_with("-C") => flag.split_at(2),
+            _ => continue,
+        };
+        let links = match option {
+            // `-l [KIND[:MODIFIERS]=]NAME[:RENAME]`
+            "-l" => {
+                let name = value.rsplit('=').next().unwrap_or(value);
+                name.split(':').next() == Some("bpf")
+            },
+            _ => value
+                .strip_prefix("link-arg=")
+                .or_else(|| value.strip_prefix("link-args="))
+                .is_some_and(|args| args.split_whitespace().any(links_libbpf)),
+        };
+        if links {
+            return Some(format!("{} {}", option, value));
+        }
+    }
+    None
+}
+
+/// Check that the vendored libbpf, which gets linked statically, does
+/// not end up alongside another libbpf.
+///
+/// The linker resolves the calls from Rust against the vendored copy,
+/// but any other libbpf linked in or loaded gets used by whatever else
+/// refers to it, e.g., C code linked with `-lbpf`, without sharing any
+/// state, such as the print callback or the results of feature probes,
+/// with the vendored copy. Linking another libbpf explicitly is hence an
+/// error, while finding one in `LD_PRELOAD` or `LD_LIBRARY_PATH`, which
+/// only affect programs run from within the build, e.g., tests, merits a
+/// warning.
+fn check_mixed_libbpf() {
+    if let Some(flag) = rustflag_linking_libbpf() {
+        panic!(
+            "`RUSTFLAGS` link libbpf (`{}`), but the `vendored-libbpf` feature \
+             links the vendored copy statically already; calls from Rust would use \
+             the vendored copy and anything else the other one, without sharing any \
+             state. Drop the flag, or build libbpf-sys without `vendored-libbpf` to \
+             use the system libbpf only",
+            flag
         );
-        return;
     }
 
-    let out_dir = path::PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    println!("cargo:rerun-if-env-changed=LD_PRELOAD");
+    if let Some(preload) = env::var("LD_PRELOAD")
+        .unwrap_or_default()
+        .split([':', ' '])
+        .find(|lib| links_libbpf(lib))
+    {
+        println!(
+            "cargo:warning=LD_PRELOAD injects {}, but libbpf-sys links the vendored libbpf \
+             statically, which calls from Rust keep using; only other code ends up with the \
+             preloaded libbpf",
+            preload
+        );
+    }
 
-    // check for all necessary compilation tools
-    if vendored_libelf {
-        pkg_check("autoreconf");
-        pkg_check("autopoint");
-        pkg_check("flex");
-        pkg_check("bison");
-        pkg_check("gawk");
+    println!("cargo:rerun-if-env-changed=LD_LIBRARY_PATH");
+    let shared = env::var_os("LD_LIBRARY_PATH")
+        .map(|paths| env::split_paths(&paths).collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|dir| read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("libbpf.so"))
+        });
+    if let Some(shared) = shared {
+        println!(
+            "cargo:warning=LD_LIBRARY_PATH provides {}, but libbpf-sys links the vendored \
+             libbpf statically, which calls from Rust keep using; dependencies linking \
+             libbpf dynamically would load the former, mixing the two",
+            shared.display()
+        );
     }
+}
 
-    let (compiler, mut cflags) = if vendored_libbpf || vendored_libelf || vendored_zlib {
-        pkg_check("make");
-        pkg_check("pkg-config");
+/// The build profiles selectable with `LIBBPF_SYS_PROFILE`, along with
+/// their flags.
+const PROFILES: &[(&str, &str)] = &[
+    ("release", " -O2 -g0 -DNDEBUG"),
+    ("debug", " -O0 -g3 -fno-omit-frame-pointer"),
+    (
+        "hardened",
+        " -O2 -U_FORTIFY_SOURCE -D_FORTIFY_SOURCE=2 -fstack-protector-strong \
+         -fstack-clash-protection",
+    ),
+    // Besides, the `small` profile builds libelf without optional
+    // features and strips the archives.
+    (
+        "small",
+        " -Os -g0 -DNDEBUG -ffunction-sections -fdata-sections \
+         -fno-asynchronous-unwind-tables -fno-unwind-tables",
+    ),
+];
 
-        let compiler = cc::Build::new().try_get_compiler().expect(
-            "a C compiler is required to compile libbpf-sys using the vendored copy of libbpf",
-        );
-        let mut cflags = compiler.cflags_env();
-        println!("cargo:rerun-if-env-changed=LIBBPF_SYS_EXTRA_CFLAGS");
-        if let Some(extra_cflags) = env::var_os("LIBBPF_SYS_EXTRA_CFLAGS") {
-            cflags.push(" ");
-            cflags.push(extra_cflags);
-        }
-        (Some(compiler), cflags)
-    } else {
-        (None, ffi::OsString::new())
-    };
+/// The build profile selected with `LIBBPF_SYS_PROFILE`, if any.
+fn profile() -> Option<String> {
+    println!("cargo:rerun-if-env-changed=LIBBPF_SYS_PROFILE");
+    env::var("LIBBPF_SYS_PROFILE").ok()
+}
 
-    if vendored_zlib {
-        make_zlib(compiler.as_ref().unwrap(), &src_dir, &out_dir);
-        cflags.push(format!(" -I{}/zlib/", src_dir.display()));
+/// Check whether the `small` profile is selected.
+fn small_profile() -> bool {
+    profile().is_some_and(|profile| profile == "small")
+}
+
+/// The flags of the build profile selected with `LIBBPF_SYS_PROFILE`,
+/// if any.
+fn profile_cflags() -> Option<&'static str> {
+    let profile = profile()?;
+    match PROFILES.iter().find(|(name, _)| *name == profile) {
+        Some((_, cflags)) => Some(cflags),
+        None => panic!(
+            "unknown LIBBPF_SYS_PROFILE `{}`; expected one of {}",
+            profile,
+            PROFILES
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
     }
+}
 
-    if vendored_libelf {
-        make_elfutils(compiler.as_ref().unwrap(), &src_dir, &out_dir);
-        cflags.push(format!(" -I{}/elfutils/libelf/", src_dir.display()));
+/// The flags to add to those of the compiler for building the vendored
+/// C code, depending on the selected profile and the enabled features.
+/// The latter come last, so that, e.g., `valgrind` disables
+/// optimizations regardless of the profile.
+fn instrumentation_cflags(compiler: &cc::Tool) -> String {
+    [
+        profile_cflags(),
+        coverage_cflags(compiler),
+        valgrind_cflags(),
+        asan_cflags(),
+        lto_cflags(),
+        branch_protection_cflags(),
+        cf_protection_cflags(),
+    ]
+    .iter()
+    .flatten()
+    .copied()
+    .collect()
+}
+
+/// Link the runtime the coverage instrumentation of the vendored C code
+/// depends on.
+fn link_coverage_runtime(compiler: &cc::Tool) {
+    if !compiler.is_like_clang() {
+        println!("cargo:rustc-link-lib=gcov");
+        return;
     }
 
-    if vendored_libbpf {
-        make_libbpf(compiler.as_ref().unwrap(), &cflags, &src_dir, &out_dir);
+    // Rust code built with `-C instrument-coverage` brings along the
+    // profile runtime already, and linking it twice fails.
+    let rustflags = env::var("CARGO_ENCODED_RUSTFLAGS").unwrap_or_default();
+    if rustflags
+        .split('\x1f')
+        .any(|flag| flag.contains("instrument-coverage"))
+    {
+        return;
     }
 
+    // The profile runtime lives next to the compiler-rt builtins.
+    let output = process::Command::new(compiler.path())
+        .arg("--rtlib=compiler-rt")
+        .arg("-print-libgcc-file-name")
+        .output()
+        .expect("could not execute the C compiler");
+    assert!(output.status.success(), "failed to locate compiler-rt");
+    let builtins = path::PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    let profile = builtins
+        .file_name()
+        .and_then(|name| name.to_str())
+        .expect("failed to locate compiler-rt")
+        .replace("builtins", "profile");
     println!(
         "cargo:rustc-link-search=native={}",
-        out_dir.to_string_lossy()
-    );
-    println!(
-        "cargo:rustc-link-lib={}elf",
-        if static_libelf { "static=" } else { "" }
+        builtins.parent().unwrap().display()
     );
-    println!(
-        "cargo:rustc-link-lib={}z",
-        if static_zlib { "static=" } else { "" }
-    );
-    println!(
-        "cargo:rustc-link-lib={}bpf",
-        if static_libbpf { "static=" } else { "" }
-    );
-    println!("cargo:include={}/include", out_dir.to_string_lossy());
-
-    println!("cargo:rerun-if-env-changed=LIBBPF_SYS_LIBRARY_PATH");
-    if let Ok(lib_path) = env::var("LIBBPF_SYS_LIBRARY_PATH") {
-        for path in lib_path.split(':') {
-            if !path.is_empty() {
-                println!("cargo:rustc-link-search=native={}", path);
-            }
-        }
-    }
-}
-
-fn open_lockable(path: &Path) -> io::Result<File> {
-    let result = File::options()
-        .read(true)
-        // Open with write permissions because flock(2) may require them
-        // on some platforms.
-        .write(true)
-        .open(path);
-    match result {
-        Ok(file) => Ok(file),
-        Err(err) if err.kind() == io::ErrorKind::PermissionDenied => {
-            // On a read-only file system we may not be able to open
-            // with write permissions. So just open for reading and hope
-            // for the best.
-            File::open(path)
-        },
-        e @ Err(..) => e,
-    }
+    println!("cargo:rustc-link-lib=static:+verbatim={}", profile);
 }
 
 fn make_zlib(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path) {
     let src_dir = src_dir.join("zlib");
-    // lock README such that if two crates are trying to compile
-    // this at the same time (eg libbpf-rs libbpf-cargo)
-    // they wont trample each other
-    let file = open_lockable(&src_dir.join("README")).unwrap();
-    let _lock = fcntl::Flock::lock(file, fcntl::FlockArg::LockExclusive).unwrap();
+    let build_dir = out_dir.join("zlib");
+    copy_source_tree(&src_dir, &build_dir).expect("failed to copy zlib sources");
+
+    let mut cflags = compiler.cflags_env();
+    cflags.push(instrumentation_cflags(compiler));
 
     let status = process::Command::new("./configure")
         .arg("--static")
@@ -244,8 +3124,8 @@ fn make_zlib(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path) {
         .arg("--libdir")
         .arg(out_dir)
         .env("CC", compiler.path())
-        .env("CFLAGS", compiler.cflags_env())
-        .current_dir(&src_dir)
+        .env("CFLAGS", cflags)
+        .current_dir(&build_dir)
         .status()
         .expect("could not execute make");
 
@@ -255,28 +3135,71 @@ fn make_zlib(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path) {
         .arg("install")
         .arg("-j")
         .arg(format!("{}", num_cpus()))
-        .current_dir(&src_dir)
+        .current_dir(&build_dir)
         .status()
         .expect("could not execute make");
 
     assert!(status.success(), "make failed");
+    emit_rerun_directives_for_contents(&src_dir);
+}
+
+/// Retrieve the version of the vendored zlib from its header.
+fn zlib_header_version(src_dir: &path::Path) -> Option<String> {
+    let header = fs::read_to_string(src_dir.join("zlib/zlib.h")).ok()?;
+    header.lines().find_map(|line| {
+        let version = line.strip_prefix("#define ZLIB_VERSION ")?;
+        Some(version.trim().trim_matches('"').to_string())
+    })
+}
 
+/// Build the stand-in for zlib of the `no-zlib` feature, which gets
+/// linked after libbpf and libelf.
+fn make_zlib_shim(src_dir: &path::Path) {
+    let shim_dir = src_dir.join("no-zlib");
+    cc::Build::new()
+        .file(shim_dir.join("shim.c"))
+        .include(shim_dir.join("include"))
+        .cargo_metadata(false)
+        .compile("bpf_no_zlib");
+    emit_rerun_directives_for_contents(&shim_dir);
+    emit_rerun_directives_for_contents(&shim_dir.join("include"));
+}
+
+fn make_zstd(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path) {
+    let src_dir = src_dir.join("zstd");
+    let build_dir = out_dir.join("zstd");
+    copy_source_tree(&src_dir, &build_dir).expect("failed to copy zstd sources");
+
+    let mut cflags = compiler.cflags_env();
+    cflags.push(instrumentation_cflags(compiler));
+
+    // The static library gets built single-threaded, which is all
+    // libelf needs. It never comes across the legacy formats either.
     let status = process::Command::new("make")
-        .arg("distclean")
-        .current_dir(&src_dir)
+        .arg("libzstd.a")
+        .arg("-j")
+        .arg(format!("{}", num_cpus()))
+        .arg("ZSTD_LEGACY_SUPPORT=0")
+        .env("CC", compiler.path())
+        .env("CFLAGS", cflags)
+        .current_dir(build_dir.join("lib"))
         .status()
         .expect("could not execute make");
 
     assert!(status.success(), "make failed");
-    emit_rerun_directives_for_contents(&src_dir);
+
+    fs::copy(
+        build_dir.join("lib").join("libzstd.a"),
+        out_dir.join("libzstd.a"),
+    )
+    .expect("failed to copy libzstd.a");
+    emit_rerun_directives_for_contents(&src_dir.join("lib"));
 }
 
 fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path) {
-    // lock README such that if two crates are trying to compile
-    // this at the same time (eg libbpf-rs libbpf-cargo)
-    // they wont trample each other
-    let file = open_lockable(&src_dir.join("elfutils/README")).unwrap();
-    let _lock = fcntl::Flock::lock(file, fcntl::FlockArg::LockExclusive).unwrap();
+    let build_dir = out_dir.join("elfutils");
+    copy_source_tree(&src_dir.join("elfutils"), &build_dir)
+        .expect("failed to copy elfutils sources");
 
     let flags = compiler
         .cflags_env()
@@ -294,14 +3217,26 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
         })
         .collect();
 
-    #[cfg(target_arch = "aarch64")]
-    cflags.push_str(" -Wno-error=stringop-overflow");
-    cflags.push_str(&format!(" -I{}/zlib/", src_dir.display()));
+    // GCC reports false positives for aarch64, regardless of the libc.
+    // This depends on the target, not the architecture the build script
+    // runs on, which differs when cross compiling.
+    if compiler.is_like_gnu() && target_arch_is("aarch64") {
+        cflags.push_str(" -Wno-error=stringop-overflow");
+    }
+    if cfg!(feature = "no-zlib") {
+        cflags.push_str(&format!(" -I{}/no-zlib/include/", src_dir.display()));
+    } else {
+        cflags.push_str(&format!(" -I{}/zlib/", out_dir.display()));
+    }
+    if cfg!(feature = "vendored-zstd") {
+        cflags.push_str(&format!(" -I{}/zstd/lib/", out_dir.display()));
+    }
+    cflags.push_str(&instrumentation_cflags(compiler));
 
     let status = process::Command::new("autoreconf")
         .arg("--install")
         .arg("--force")
-        .current_dir(src_dir.join("elfutils"))
+        .current_dir(&build_dir)
         .status()
         .expect("could not execute make");
 
@@ -309,27 +3244,50 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
 
     // location of libz.a
     let out_lib = format!("-L{}", out_dir.display());
-    let status = process::Command::new("./configure")
+    let mut configure = process::Command::new("./configure");
+    if env::var("CARGO_CFG_TARGET_ENV").is_ok_and(|env| env == "musl") {
+        // musl lacks argp, fts, and obstack, which configure insists on
+        // but only the tools and libdw use. Tell it they are built in,
+        // as only libelf gets built.
+        for func in ["argp_parse", "fts_close", "_obstack_free"] {
+            configure.env(format!("ac_cv_search_{func}"), "none required");
+        }
+    }
+    if cfg!(feature = "no-zlib") {
+        // zlib is required, and its stand-in only gets linked in the
+        // end, with its functions renamed.
+        configure.env("ac_cv_search_gzdirect", "none required");
+    }
+    if small_profile() {
+        // Translations and the compression formats besides zlib only
+        // take up space.
+        configure
+            .arg("--disable-nls")
+            .arg("--without-bzlib")
+            .arg("--without-lzma");
+    }
+    if cfg!(feature = "vendored-zstd") {
+        // Compression support is detected with pkg-config, which doesn't
+        // know about the vendored libzstd; the variables take precedence.
+        configure
+            .arg("--with-zstd")
+            .env(
+                "ZSTD_COMPRESS_CFLAGS",
+                format!("-I{}/zstd/lib/", out_dir.display()),
+            )
+            .env("ZSTD_COMPRESS_LIBS", format!("{out_lib} -lzstd"));
+    } else {
+        configure.arg("--without-zstd");
+    }
+    let status = configure
         .arg("--enable-maintainer-mode")
         .arg("--disable-debuginfod")
         .arg("--disable-libdebuginfod")
         .arg("--disable-demangler")
-        .arg("--without-zstd")
         .arg("--prefix")
-        .arg(src_dir.join("elfutils/prefix_dir"))
+        .arg(build_dir.join("prefix_dir"))
         .arg("--host")
-        .arg({
-            let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
-            let arch = match arch.as_str() {
-                "riscv64gc" => "riscv64",
-                "riscv32gc" => "riscv32",
-                other => other,
-            };
-            let vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
-            let env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
-            let os = env::var("CARGO_CFG_TARGET_OS").unwrap();
-            format!("{arch}-{vendor}-{os}-{env}")
-        })
+        .arg(configure_host())
         .arg("--libdir")
         .arg(out_dir)
         .env("CC", compiler.path())
@@ -337,7 +3295,7 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
         .env("CFLAGS", &cflags)
         .env("CXXFLAGS", &cflags)
         .env("LDFLAGS", &out_lib)
-        .current_dir(src_dir.join("elfutils"))
+        .current_dir(&build_dir)
         .status()
         .expect("could not execute make");
 
@@ -348,7 +3306,7 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
         .arg("-j")
         .arg(format!("{}", num_cpus()))
         .arg("BUILD_STATIC_ONLY=y")
-        .current_dir(src_dir.join("elfutils/lib"))
+        .current_dir(build_dir.join("lib"))
         .status()
         .expect("could not execute make");
 
@@ -360,20 +3318,198 @@ fn make_elfutils(compiler: &cc::Tool, src_dir: &path::Path, out_dir: &path::Path
         .arg("-j")
         .arg(format!("{}", num_cpus()))
         .arg("BUILD_STATIC_ONLY=y")
-        .current_dir(src_dir.join("elfutils/libelf"))
+        .current_dir(build_dir.join("libelf"))
         .status()
         .expect("could not execute make");
 
     assert!(status.success(), "make failed");
+    emit_rerun_directives_for_contents(&src_dir.join("elfutils").join("src"));
+}
 
-    let status = process::Command::new("make")
-        .arg("distclean")
-        .current_dir(src_dir.join("elfutils"))
-        .status()
-        .expect("could not execute make");
+/// Retrieve the value of the `variable` of a `Makefile` or automake
+/// `Makefile.am`, with line continuations joined and any `+=` appended.
+fn makefile_variable(makefile: &str, variable: &str) -> Vec<String> {
+    let joined = makefile.replace("\\\n", " ");
+    joined
+        .lines()
+        .filter_map(|line| {
+            let (name, value) = line.split_once('=')?;
+            let name = name.trim_end_matches(['+', ':', ' ', '\t']).trim();
+            (name == variable).then_some(value)
+        })
+        .flat_map(str::split_whitespace)
+        .map(str::to_string)
+        .collect()
+}
 
-    assert!(status.success(), "make failed");
-    emit_rerun_directives_for_contents(&src_dir.join("elfutils").join("src"));
+/// Retrieve the version of elfutils from the `AC_INIT` of its
+/// `configure.ac`.
+fn elfutils_version(configure_ac: &str) -> Option<&str> {
+    let args = configure_ac.split_once("AC_INIT(")?.1;
+    let version = args.split(',').nth(1)?;
+    Some(version.trim().trim_start_matches('[').trim_end_matches(']'))
+}
+
+/// Check whether `code` compiles for the target, the way a configure
+/// script would.
+fn try_compile(out_dir: &path::Path, name: &str, code: &str) -> bool {
+    let probe_dir = out_dir.join("libelf-probes");
+    fs::create_dir_all(&probe_dir).expect("failed to create probe directory");
+    let file = probe_dir.join(format!("{name}.c"));
+    fs::write(&file, code).expect("failed to write probe");
+    cc::Build::new()
+        .file(&file)
+        .define("_GNU_SOURCE", None)
+        .flag_if_supported("-Werror")
+        .warnings(false)
+        .cargo_metadata(false)
+        .cargo_warnings(false)
+        .out_dir(&probe_dir)
+        .try_compile_intermediates()
+        .is_ok()
+}
+
+/// Generate the `config.h` elfutils' configure script would, for
+/// building libelf, in `config_dir`.
+fn generate_libelf_config(
+    elfutils_dir: &path::Path,
+    out_dir: &path::Path,
+    config_dir: &path::Path,
+) {
+    let configure_ac = fs::read_to_string(elfutils_dir.join("configure.ac"))
+        .expect("failed to read elfutils/configure.ac");
+    let version = elfutils_version(&configure_ac).expect("no version in elfutils/configure.ac");
+
+    let mut config = String::from("/* Generated by libbpf-sys' build script. */\n");
+    config.push_str("#define _GNU_SOURCE 1\n");
+    config.push_str("#define _FILE_OFFSET_BITS 64\n");
+    for (name, value) in [
+        ("PACKAGE", "elfutils"),
+        ("PACKAGE_NAME", "elfutils"),
+        ("PACKAGE_TARNAME", "elfutils"),
+        ("PACKAGE_URL", "http://elfutils.org/"),
+        ("PACKAGE_BUGREPORT", "https://sourceware.org/bugzilla"),
+        ("PACKAGE_VERSION", version),
+        ("VERSION", version),
+        ("PACKAGE_STRING", &format!("elfutils {version}")),
+    ] {
+        config.push_str(&format!("#define {name} \"{value}\"\n"));
+    }
+
+    // The declarations the C library may lack, with fallbacks in
+    // `lib/system.h`.
+    for (func, header, call) in [
+        ("mempcpy", "string.h", "mempcpy(d, s, 1)"),
+        ("memrchr", "string.h", "memrchr(s, 0, 1)"),
+        ("rawmemchr", "string.h", "rawmemchr(s, 0)"),
+        ("powerof2", "sys/param.h", "powerof2(4)"),
+        ("reallocarray", "stdlib.h", "reallocarray(d, 1, 1)"),
+        ("strerror_r", "string.h", "strerror_r(1, d, 1)"),
+    ] {
+        let code = format!(
+            "#include <{header}>\n\
+             int probe(char *d, const char *s) {{ (void) d; (void) s; (void) ({call}); return 0; }}\n"
+        );
+        let have = try_compile(out_dir, func, &code);
+        config.push_str(&format!(
+            "#define HAVE_DECL_{} {}\n",
+            func.to_uppercase(),
+            u8::from(have)
+        ));
+    }
+    for (name, header) in [("HAVE_ERROR_H", "error.h"), ("HAVE_ERR_H", "err.h")] {
+        if try_compile(
+            out_dir,
+            &name.to_lowercase(),
+            &format!("#include <{header}>\n"),
+        ) {
+            config.push_str(&format!("#define {name} 1\n"));
+        }
+    }
+    for (name, code) in [
+        ("HAVE_VISIBILITY", "__attribute__ ((visibility (\"hidden\"))) int probe;\n"),
+        ("HAVE_GCC_STRUCT", "struct __attribute__ ((gcc_struct)) probe { int i; };\n"),
+        (
+            "HAVE_FALLTHROUGH",
+            "int probe(int i) { switch (i) { case 0: i++; __attribute__ ((fallthrough)); default: return i; } }\n",
+        ),
+    ] {
+        if try_compile(out_dir, &name.to_lowercase(), code) {
+            config.push_str(&format!("#define {name} 1\n"));
+        }
+    }
+    if cfg!(feature = "vendored-zstd") {
+        config.push_str("#define USE_ZSTD 1\n");
+        config.push_str("#define USE_ZSTD_COMPRESS 1\n");
+    }
+    // Like the `AH_BOTTOM` of configure.ac.
+    config.push_str("#include <eu-config.h>\n");
+
+    fs::create_dir_all(config_dir).expect("failed to create config directory");
+    fs::write(config_dir.join("config.h"), config).expect("failed to write config.h");
+}
+
+/// Build the vendored libelf with the `cc` crate, in place of its
+/// autotools based build system, for the `libelf-cc` feature.
+///
+/// The sources are the ones `libelf/Makefile.am` lists, and the
+/// `config.h` the configure script would generate gets probed for and
+/// written here. Neither the rest of elfutils nor the headers get
+/// installed, as libbpf only needs `libelf.a` and the headers in
+/// `libelf/`.
+fn make_libelf_cc(cflags: &ffi::OsStr, src_dir: &path::Path, out_dir: &path::Path) {
+    let elfutils_dir = src_dir.join("elfutils");
+    let libelf_dir = elfutils_dir.join("libelf");
+    let lib_dir = elfutils_dir.join("lib");
+    let config_dir = out_dir.join("libelf-config");
+    generate_libelf_config(&elfutils_dir, out_dir, &config_dir);
+
+    let makefile = fs::read_to_string(libelf_dir.join("Makefile.am"))
+        .expect("failed to read elfutils/libelf/Makefile.am");
+    let sources = makefile_variable(&makefile, "libelf_a_SOURCES");
+    assert!(
+        !sources.is_empty(),
+        "no sources in elfutils/libelf/Makefile.am"
+    );
+
+    let mut build = cc::Build::new();
+    build
+        .files(sources.iter().map(|file| libelf_dir.join(file)))
+        .include(&config_dir)
+        .include(&libelf_dir)
+        .include(&lib_dir)
+        .include(&elfutils_dir)
+        .define("HAVE_CONFIG_H", None)
+        .flag("-std=gnu11");
+    // libelf's search trees live in libeu, which does not get built.
+    let eu_search = lib_dir.join("eu-search.c");
+    if eu_search.exists() {
+        build.file(eu_search);
+    }
+    if cfg!(feature = "no-zlib") {
+        build.include(src_dir.join("no-zlib/include"));
+    } else {
+        build.include(out_dir.join("zlib"));
+    }
+    if cfg!(feature = "vendored-zstd") {
+        build.include(out_dir.join("zstd/lib"));
+    }
+    // The flags for the make based build, e.g., LIBBPF_SYS_EXTRA_CFLAGS
+    // and the instrumentation ones.
+    for flag in cflags
+        .to_str()
+        .expect("failed to get cflags")
+        .split_whitespace()
+    {
+        build.flag(flag);
+    }
+    build
+        .warnings(false)
+        .cargo_metadata(false)
+        .out_dir(out_dir)
+        .compile("elf");
+    emit_rerun_directives_for_contents(&libelf_dir);
+    emit_rerun_directives_for_contents(&lib_dir);
 }
 
 fn make_libbpf(
@@ -387,8 +3523,11 @@ fn make_libbpf(
     let obj_dir = path::PathBuf::from(&out_dir.join("obj").into_os_string());
     let _ = fs::create_dir(&obj_dir);
 
+    // Export the UAPI headers along with libbpf's own ones, as the
+    // latter include them.
     let status = process::Command::new("make")
         .arg("install")
+        .arg("install_uapi_headers")
         .arg("-j")
         .arg(format!("{}", num_cpus()))
         .env("BUILD_STATIC_ONLY", "y")
@@ -414,6 +3553,117 @@ fn make_libbpf(
     emit_rerun_directives_for_contents(&src_dir);
 }
 
+/// The UAPI headers `make install_uapi_headers` installs along with
+/// libbpf's own ones, which include them.
+const LIBBPF_UAPI_HEADERS: &[&str] = &["bpf.h", "bpf_common.h", "btf.h"];
+
+/// Build the vendored libbpf with the `cc` crate, from the objects its
+/// `Makefile` lists, and install its headers and the UAPI headers into
+/// `out_dir/include`, like `make install install_uapi_headers` does.
+fn make_libbpf_cc(cflags: &ffi::OsStr, src_dir: &path::Path, out_dir: &path::Path) {
+    let libbpf_dir = src_dir.join("libbpf");
+    let libbpf_src_dir = libbpf_dir.join("src");
+    let makefile = fs::read_to_string(libbpf_src_dir.join("Makefile"))
+        .expect("failed to read libbpf/src/Makefile");
+    let sources: Vec<_> = makefile_variable(&makefile, "OBJS")
+        .iter()
+        .filter_map(|obj| obj.strip_suffix(".o"))
+        .map(|obj| libbpf_src_dir.join(format!("{obj}.c")))
+        .collect();
+    assert!(!sources.is_empty(), "no objects in libbpf/src/Makefile");
+
+    let mut build = cc::Build::new();
+    build
+        .files(sources)
+        .include(&libbpf_src_dir)
+        .include(libbpf_dir.join("include"))
+        .include(libbpf_dir.join("include/uapi"))
+        // Mirror libbpf's Makefile.
+        .define("_LARGEFILE64_SOURCE", None)
+        .define("_FILE_OFFSET_BITS", "64")
+        .flag("-fvisibility=hidden");
+    // The flags for the make based build, e.g., the include directories
+    // of the vendored libelf and zlib.
+    for flag in cflags
+        .to_str()
+        .expect("failed to get cflags")
+        .split_whitespace()
+    {
+        build.flag(flag);
+    }
+    build
+        .warnings(false)
+        .cargo_metadata(false)
+        .out_dir(out_dir)
+        .compile("bpf");
+
+    let include_dir = out_dir.join("include");
+    for (dir, headers, dst) in [
+        (
+            &libbpf_src_dir,
+            makefile_variable(&makefile, "HEADERS"),
+            include_dir.join("bpf"),
+        ),
+        (
+            &libbpf_dir.join("include/uapi/linux"),
+            LIBBPF_UAPI_HEADERS.iter().map(|h| h.to_string()).collect(),
+            include_dir.join("linux"),
+        ),
+    ] {
+        fs::create_dir_all(&dst).expect("failed to create include directory");
+        for header in headers {
+            fs::copy(dir.join(&header), dst.join(&header))
+                .unwrap_or_else(|err| panic!("failed to install {}: {}", header, err));
+        }
+    }
+    emit_rerun_directives_for_contents(&libbpf_src_dir);
+}
+
+/// Build the BTF code of the vendored libbpf for the `btf-only`
+/// feature, i.e., parsing, building, deduplicating, and dumping BTF,
+/// which does not need a kernel.
+///
+/// `btf-only/shim.c` stands in for the parts of libbpf and libelf this
+/// code calls into, and the headers in `btf-only/include` for the Linux
+/// specific system headers it includes, where the host lacks them.
+fn make_libbpf_btf(src_dir: &path::Path) {
+    let libbpf_dir = src_dir.join("libbpf/src");
+    let btf_only_dir = src_dir.join("btf-only");
+    cc::Build::new()
+        .files(BTF_ONLY_SOURCES.iter().map(|file| libbpf_dir.join(file)))
+        .file(btf_only_dir.join("shim.c"))
+        .include(btf_only_dir.join("include"))
+        .include(src_dir.join("libbpf/include"))
+        .include(src_dir.join("libbpf/include/uapi"))
+        .include(&libbpf_dir)
+        // Mirror libbpf's Makefile.
+        .define("_LARGEFILE64_SOURCE", None)
+        .define("_FILE_OFFSET_BITS", "64")
+        .warnings(false)
+        .compile("bpf_btf");
+    emit_rerun_directives_for_contents(&libbpf_dir);
+    emit_rerun_directives_for_contents(&btf_only_dir);
+}
+
+/// Strip debug information and compiler identification off `archive`,
+/// with the `strip` in `STRIP` or the one in `PATH`.
+fn strip_archive(archive: &path::Path) {
+    println!("cargo:rerun-if-env-changed=STRIP");
+    let strip = env::var_os("STRIP").unwrap_or_else(|| "strip".into());
+    let status = process::Command::new(&strip)
+        .arg("--strip-debug")
+        .arg("--remove-section=.comment")
+        .arg(archive)
+        .status();
+    if !status.is_ok_and(|status| status.success()) {
+        // A `strip` for the host may not support the target.
+        println!(
+            "cargo:warning=failed to strip {}; set STRIP to a strip for the target",
+            archive.display()
+        );
+    }
+}
+
 fn num_cpus() -> usize {
     std::thread::available_parallelism().map_or(1, |count| count.get())
 }
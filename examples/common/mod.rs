@@ -0,0 +1,16 @@
+// examples/common/mod.rs
+
+//! Helpers shared by the examples.
+
+use std::io;
+use std::os::raw::c_int;
+
+/// Turn a libbpf return value into a `Result`, mentioning `what` failed.
+pub fn check(ret: c_int, what: &str) -> io::Result<c_int> {
+    if ret < 0 {
+        let err = io::Error::from_raw_os_error(-ret);
+        Err(io::Error::new(err.kind(), format!("{}: {}", what, err)))
+    } else {
+        Ok(ret)
+    }
+}
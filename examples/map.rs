@@ -0,0 +1,87 @@
+// examples/map.rs
+
+//! Create a hash map, then update, look up, iterate over, and delete
+//! its elements, using the low-level map API.
+//!
+//! Creating maps requires `CAP_BPF` or `CAP_SYS_ADMIN`.
+
+#[cfg(not(feature = "dlopen"))]
+mod common;
+
+#[cfg(not(feature = "dlopen"))]
+fn main() -> std::io::Result<()> {
+    use std::mem::size_of;
+    use std::os::fd::AsRawFd;
+    use std::os::fd::FromRawFd;
+    use std::os::fd::OwnedFd;
+    use std::os::raw::c_int;
+    use std::ptr;
+
+    use libbpf_sys::*;
+
+    use common::check;
+
+    const ENOENT: c_int = 2;
+
+    let map_fd = unsafe {
+        bpf_map_create(
+            BPF_MAP_TYPE_HASH,
+            b"squares\0".as_ptr().cast(),
+            size_of::<u32>() as _,
+            size_of::<u64>() as _,
+            16,
+            ptr::null(),
+        )
+    };
+    let map = unsafe { OwnedFd::from_raw_fd(check(map_fd, "bpf_map_create")?) };
+    let map_fd = map.as_raw_fd();
+
+    for key in 1u32..=4 {
+        let value = u64::from(key * key);
+        let ret = unsafe {
+            bpf_map_update_elem(
+                map_fd,
+                (&key as *const u32).cast(),
+                (&value as *const u64).cast(),
+                BPF_NOEXIST as _,
+            )
+        };
+        check(ret, "bpf_map_update_elem")?;
+    }
+
+    let key = 2u32;
+    let ret = unsafe { bpf_map_delete_elem(map_fd, (&key as *const u32).cast()) };
+    check(ret, "bpf_map_delete_elem")?;
+
+    // Starting with a null key yields the first key.
+    let mut prev: Option<u32> = None;
+    let mut key = 0u32;
+    loop {
+        let prev_ptr = prev
+            .as_ref()
+            .map_or(ptr::null(), |prev| (prev as *const u32).cast());
+        let ret = unsafe { bpf_map_get_next_key(map_fd, prev_ptr, (&mut key as *mut u32).cast()) };
+        if ret == -ENOENT {
+            break;
+        }
+        check(ret, "bpf_map_get_next_key")?;
+
+        let mut value = 0u64;
+        let ret = unsafe {
+            bpf_map_lookup_elem(
+                map_fd,
+                (&key as *const u32).cast(),
+                (&mut value as *mut u64).cast(),
+            )
+        };
+        check(ret, "bpf_map_lookup_elem")?;
+        println!("{} -> {}", key, value);
+        prev = Some(key);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "dlopen")]
+fn main() {
+    eprintln!("this example calls libbpf directly and does not support the `dlopen` feature");
+}
@@ -0,0 +1,110 @@
+// examples/perf_buffer.rs
+
+//! Consume the samples a program outputs to a perf event array, using
+//! `perf_buffer__new` and `perf_buffer__poll`.
+//!
+//! The program, a classifier that outputs a constant, is run through
+//! `BPF_PROG_TEST_RUN` instead of being attached anywhere. Loading it
+//! requires `CAP_BPF` or `CAP_SYS_ADMIN`.
+
+#[cfg(not(any(feature = "dlopen", feature = "no-perf")))]
+mod common;
+
+#[cfg(not(any(feature = "dlopen", feature = "no-perf")))]
+fn main() -> std::io::Result<()> {
+    use std::io;
+    use std::os::fd::AsRawFd;
+    use std::os::fd::FromRawFd;
+    use std::os::fd::OwnedFd;
+    use std::ptr;
+
+    use libbpf_sys::callback::perf_buffer_callbacks;
+    use libbpf_sys::insn::*;
+    use libbpf_sys::*;
+
+    use common::check;
+
+    // One entry per CPU, which perf_buffer__new populates.
+    let map_fd = unsafe {
+        bpf_map_create(
+            BPF_MAP_TYPE_PERF_EVENT_ARRAY,
+            b"samples\0".as_ptr().cast(),
+            4,
+            4,
+            libbpf_num_possible_cpus() as _,
+            ptr::null(),
+        )
+    };
+    let map = unsafe { OwnedFd::from_raw_fd(check(map_fd, "bpf_map_create")?) };
+
+    // bpf_perf_event_output(ctx, &map, BPF_F_CURRENT_CPU, &42, sizeof(u64))
+    let [ld_map_lo, ld_map_hi] = ld_map_fd(BPF_REG_2, map.as_raw_fd());
+    let [ld_flags_lo, ld_flags_hi] = ld_imm64(BPF_REG_3, BPF_F_CURRENT_CPU as _);
+    let insns = [
+        st_mem(BPF_DW, BPF_REG_10, -8, 42),
+        ld_map_lo,
+        ld_map_hi,
+        ld_flags_lo,
+        ld_flags_hi,
+        mov64_reg(BPF_REG_4, BPF_REG_10),
+        alu64_imm(BPF_ADD, BPF_REG_4, -8),
+        mov64_imm(BPF_REG_5, 8),
+        emit_call(BPF_FUNC_perf_event_output),
+        mov64_imm(BPF_REG_0, 0),
+        exit_insn(),
+    ];
+    let prog_fd = unsafe {
+        bpf_prog_load(
+            BPF_PROG_TYPE_SCHED_CLS,
+            b"output\0".as_ptr().cast(),
+            b"GPL\0".as_ptr().cast(),
+            insns.as_ptr(),
+            insns.len() as _,
+            ptr::null_mut(),
+        )
+    };
+    let prog = unsafe { OwnedFd::from_raw_fd(check(prog_fd, "bpf_prog_load")?) };
+
+    let callbacks = perf_buffer_callbacks(
+        |cpu, data| println!("sample on CPU {}: {:?}", cpu, data),
+        |cpu, cnt| println!("lost {} samples on CPU {}", cnt, cpu),
+    );
+    let (sample_cb, lost_cb) = callbacks.callback();
+    let pb = unsafe {
+        perf_buffer__new(
+            map.as_raw_fd(),
+            8,
+            sample_cb,
+            lost_cb,
+            callbacks.ctx(),
+            ptr::null(),
+        )
+    };
+    if pb.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    // Classifiers need at least an Ethernet header to run on.
+    let mut packet = [0u8; 64];
+    let mut opts = bpf_test_run_opts {
+        data_in: packet.as_mut_ptr().cast(),
+        data_size_in: packet.len() as _,
+        repeat: 3,
+        ..Default::default()
+    };
+    let result = check(
+        unsafe { bpf_prog_test_run_opts(prog.as_raw_fd(), &mut opts) },
+        "bpf_prog_test_run_opts",
+    )
+    .and_then(|_| check(unsafe { perf_buffer__poll(pb, 100) }, "perf_buffer__poll"));
+    // The callbacks have to outlive the perf buffer.
+    unsafe { perf_buffer__free(pb) };
+    drop(callbacks);
+
+    result.map(|_| ())
+}
+
+#[cfg(any(feature = "dlopen", feature = "no-perf"))]
+fn main() {
+    eprintln!("this example requires the perf buffer API, linked against libbpf");
+}
@@ -0,0 +1,105 @@
+// examples/ring_buffer.rs
+
+//! Consume the samples a program submits to a BPF ring buffer, using
+//! `ring_buffer__new` and `ring_buffer__poll`.
+//!
+//! The program, a classifier that outputs a constant, is run through
+//! `BPF_PROG_TEST_RUN` instead of being attached anywhere. Loading it
+//! requires `CAP_BPF` or `CAP_SYS_ADMIN`.
+
+#[cfg(not(feature = "dlopen"))]
+mod common;
+
+#[cfg(not(feature = "dlopen"))]
+fn main() -> std::io::Result<()> {
+    use std::io;
+    use std::os::fd::AsRawFd;
+    use std::os::fd::FromRawFd;
+    use std::os::fd::OwnedFd;
+    use std::ptr;
+
+    use libbpf_sys::callback::ring_buffer_callback;
+    use libbpf_sys::insn::*;
+    use libbpf_sys::*;
+
+    use common::check;
+
+    let map_fd = unsafe {
+        bpf_map_create(
+            BPF_MAP_TYPE_RINGBUF,
+            b"samples\0".as_ptr().cast(),
+            0,
+            0,
+            4096,
+            ptr::null(),
+        )
+    };
+    let map = unsafe { OwnedFd::from_raw_fd(check(map_fd, "bpf_map_create")?) };
+
+    // bpf_ringbuf_output(&map, &42, sizeof(u64), 0)
+    let [ld_map_lo, ld_map_hi] = ld_map_fd(BPF_REG_1, map.as_raw_fd());
+    let insns = [
+        st_mem(BPF_DW, BPF_REG_10, -8, 42),
+        ld_map_lo,
+        ld_map_hi,
+        mov64_reg(BPF_REG_2, BPF_REG_10),
+        alu64_imm(BPF_ADD, BPF_REG_2, -8),
+        mov64_imm(BPF_REG_3, 8),
+        mov64_imm(BPF_REG_4, 0),
+        emit_call(BPF_FUNC_ringbuf_output),
+        mov64_imm(BPF_REG_0, 0),
+        exit_insn(),
+    ];
+    let prog_fd = unsafe {
+        bpf_prog_load(
+            BPF_PROG_TYPE_SCHED_CLS,
+            b"submit\0".as_ptr().cast(),
+            b"GPL\0".as_ptr().cast(),
+            insns.as_ptr(),
+            insns.len() as _,
+            ptr::null_mut(),
+        )
+    };
+    let prog = unsafe { OwnedFd::from_raw_fd(check(prog_fd, "bpf_prog_load")?) };
+
+    let callback = ring_buffer_callback(|data| {
+        println!("sample: {:?}", data);
+        0
+    });
+    let rb = unsafe {
+        ring_buffer__new(
+            map.as_raw_fd(),
+            callback.callback(),
+            callback.ctx(),
+            ptr::null(),
+        )
+    };
+    if rb.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    // Classifiers need at least an Ethernet header to run on.
+    let mut packet = [0u8; 64];
+    let mut opts = bpf_test_run_opts {
+        data_in: packet.as_mut_ptr().cast(),
+        data_size_in: packet.len() as _,
+        repeat: 3,
+        ..Default::default()
+    };
+    let result = check(
+        unsafe { bpf_prog_test_run_opts(prog.as_raw_fd(), &mut opts) },
+        "bpf_prog_test_run_opts",
+    )
+    .and_then(|_| check(unsafe { ring_buffer__poll(rb, 100) }, "ring_buffer__poll"));
+    // The callback has to outlive the ring buffer.
+    unsafe { ring_buffer__free(rb) };
+    drop(callback);
+
+    println!("consumed {} samples", result?);
+    Ok(())
+}
+
+#[cfg(feature = "dlopen")]
+fn main() {
+    eprintln!("this example calls libbpf directly and does not support the `dlopen` feature");
+}
@@ -0,0 +1,52 @@
+// fuzz/fuzz_targets/btf_new.rs
+
+//! Parse arbitrary bytes as raw BTF using `btf__new` and walk the
+//! result, cross-checking it against the crate's own BTF helpers.
+
+#![no_main]
+
+use std::ffi::CStr;
+use std::slice;
+
+use libbpf_sys::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    unsafe { libbpf_set_print(None) };
+
+    let btf = unsafe { btf__new(data.as_ptr().cast(), data.len() as _) };
+    if btf.is_null() {
+        return;
+    }
+
+    // libbpf only accepts BTF with a valid magic.
+    assert!(btf_header::endianness(data).is_some());
+
+    let cnt = unsafe { btf__type_cnt(btf) };
+    for id in 1..cnt {
+        let ty = unsafe { btf__type_by_id(btf, id) };
+        assert!(!ty.is_null(), "type {} of {} missing", id, cnt);
+        let ty = unsafe { &*ty };
+        // libbpf rejects unknown kinds while parsing.
+        ty.kind().unwrap();
+        let name = unsafe { btf__name_by_offset(btf, ty.name_off) };
+        assert!(!name.is_null(), "name of type {} missing", id);
+        let _ = unsafe { CStr::from_ptr(name) };
+    }
+    assert!(unsafe { btf__type_by_id(btf, cnt) }.is_null());
+
+    // The raw data libbpf hands back has to parse again, to the same
+    // number of types.
+    let mut size = 0;
+    let raw = unsafe { btf__raw_data(btf, &mut size) };
+    assert!(!raw.is_null());
+    let raw = unsafe { slice::from_raw_parts(raw.cast::<u8>(), size as usize) }.to_vec();
+    let copy = unsafe { btf__new(raw.as_ptr().cast(), raw.len() as _) };
+    assert!(!copy.is_null());
+    assert_eq!(unsafe { btf__type_cnt(copy) }, cnt);
+
+    unsafe {
+        btf__free(copy);
+        btf__free(btf);
+    }
+});
@@ -0,0 +1,68 @@
+// fuzz/fuzz_targets/object_open_mem.rs
+
+//! Open arbitrary bytes as a BPF object file using
+//! `bpf_object__open_mem` and walk its programs, maps, and BTF.
+
+#![no_main]
+
+use std::ffi::CStr;
+use std::hint::black_box;
+use std::mem::size_of;
+use std::ptr;
+use std::slice;
+
+use libbpf_sys::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    unsafe { libbpf_set_print(None) };
+
+    let opts = bpf_object_open_opts {
+        sz: size_of::<bpf_object_open_opts>() as _,
+        object_name: b"fuzz\0".as_ptr().cast(),
+        ..Default::default()
+    };
+    let obj = unsafe { bpf_object__open_mem(data.as_ptr().cast(), data.len() as _, &opts) };
+    if obj.is_null() {
+        return;
+    }
+
+    let mut prog = ptr::null_mut();
+    loop {
+        prog = unsafe { bpf_object__next_program(obj, prog) };
+        if prog.is_null() {
+            break;
+        }
+        let _ = unsafe { CStr::from_ptr(bpf_program__name(prog)) };
+        let _ = unsafe { CStr::from_ptr(bpf_program__section_name(prog)) };
+        let cnt = unsafe { bpf_program__insn_cnt(prog) } as usize;
+        let insns = unsafe { bpf_program__insns(prog) };
+        if cnt > 0 {
+            // Touch every instruction to catch length mistakes.
+            let insns = unsafe { slice::from_raw_parts(insns, cnt) };
+            for insn in insns {
+                black_box(insn.imm);
+            }
+        }
+    }
+
+    let mut map = ptr::null_mut();
+    loop {
+        map = unsafe { bpf_object__next_map(obj, map) };
+        if map.is_null() {
+            break;
+        }
+        let _ = unsafe { CStr::from_ptr(bpf_map__name(map)) };
+        let _ = unsafe { bpf_map__value_size(map) };
+    }
+
+    let btf = unsafe { bpf_object__btf(obj) };
+    if !btf.is_null() {
+        let cnt = unsafe { btf__type_cnt(btf) };
+        for id in 1..cnt {
+            assert!(!unsafe { btf__type_by_id(btf, id) }.is_null());
+        }
+    }
+
+    unsafe { bpf_object__close(obj) };
+});
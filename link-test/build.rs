@@ -0,0 +1,33 @@
+// link-test/build.rs
+
+use std::env;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(shim)");
+    println!("cargo:rustc-check-cfg=cfg(zlib_version)");
+    println!("cargo:rerun-if-changed=src/shim.c");
+
+    // The version of the zlib libbpf-sys links, if it links any and
+    // knows its version.
+    if let Ok(version) = env::var("DEP_BPF_ZLIB_VERSION") {
+        println!("cargo:rustc-env=LIBBPF_SYS_ZLIB_VERSION={}", version);
+        println!("cargo:rustc-cfg=zlib_version");
+    }
+
+    // The directory libbpf-sys exports as `cargo:include`, which only
+    // contains headers if it built libbpf. Without linking libbpf, e.g.,
+    // with the `stubs` feature, there is none at all.
+    let Some(include) = env::var_os("DEP_BPF_INCLUDE").map(PathBuf::from) else {
+        return;
+    };
+    if !include.join("bpf/libbpf.h").exists() {
+        return;
+    }
+
+    cc::Build::new()
+        .file("src/shim.c")
+        .include(&include)
+        .compile("shim");
+    println!("cargo:rustc-cfg=shim");
+}
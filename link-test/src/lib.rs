@@ -0,0 +1,44 @@
+// link-test/src/lib.rs
+
+//! A crate linking libbpf-sys along with other users of the native
+//! libraries it brings along: libz-sys for zlib, and itself for libelf.
+//!
+//! The libraries must end up in the final binary once, with libbpf and
+//! the other users agreeing on the implementation. The checks live in
+//! `tests/link.rs`.
+//!
+//! With the vendored libbpf, the build script also compiles a C shim
+//! against the headers libbpf-sys exports, declared here as well.
+
+#[cfg(shim)]
+use std::os::raw::c_char;
+use std::os::raw::c_int;
+#[cfg(shim)]
+use std::os::raw::c_long;
+use std::os::raw::c_uint;
+use std::os::raw::c_void;
+
+/// The `Elf_Cmd` to read a file.
+pub const ELF_C_READ: c_int = 1;
+/// The `Elf_Kind` of ELF objects.
+pub const ELF_K_ELF: c_int = 3;
+
+// libelf is linked through libbpf-sys, so neither a library name nor a
+// link kind is given, which could pull in a second copy.
+extern "C" {
+    pub fn elf_version(version: c_uint) -> c_uint;
+    pub fn elf_begin(fd: c_int, cmd: c_int, elf: *mut c_void) -> *mut c_void;
+    pub fn elf_kind(elf: *mut c_void) -> c_int;
+    pub fn elf_end(elf: *mut c_void) -> c_int;
+}
+
+// The functions of `src/shim.c`.
+#[cfg(shim)]
+extern "C" {
+    pub fn shim_libbpf_major_version() -> c_uint;
+    pub fn shim_libbpf_minor_version() -> c_uint;
+    pub fn shim_bpf_object_open_opts_size() -> usize;
+    pub fn shim_map_lookup_elem_id() -> c_int;
+    pub fn shim_map_lookup_elem_helper() -> c_long;
+    pub fn shim_libbpf_version_string() -> *const c_char;
+}
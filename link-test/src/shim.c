@@ -0,0 +1,49 @@
+// link-test/src/shim.c
+
+/*
+ * A C shim built against the headers libbpf-sys exports, the way a
+ * downstream build script would. The functions report what the headers
+ * say, for `tests/link.rs` to compare with the bindings.
+ */
+
+#include <stddef.h>
+
+#include <linux/bpf.h>
+#include <bpf/bpf.h>
+#include <bpf/btf.h>
+#include <bpf/libbpf.h>
+#include <bpf/libbpf_version.h>
+#include <bpf/bpf_helper_defs.h>
+
+unsigned int shim_libbpf_major_version(void)
+{
+	return LIBBPF_MAJOR_VERSION;
+}
+
+unsigned int shim_libbpf_minor_version(void)
+{
+	return LIBBPF_MINOR_VERSION;
+}
+
+size_t shim_bpf_object_open_opts_size(void)
+{
+	return sizeof(struct bpf_object_open_opts);
+}
+
+/* The helper ID according to the UAPI header. */
+int shim_map_lookup_elem_id(void)
+{
+	return BPF_FUNC_map_lookup_elem;
+}
+
+/* The helper ID according to bpf_helper_defs.h, which encodes it as the
+ * address of the helper. */
+long shim_map_lookup_elem_helper(void)
+{
+	return (long)bpf_map_lookup_elem;
+}
+
+const char *shim_libbpf_version_string(void)
+{
+	return libbpf_version_string();
+}
@@ -0,0 +1,103 @@
+// link-test/tests/link.rs
+
+use std::ffi::CString;
+use std::fs::File;
+use std::os::fd::AsRawFd;
+use std::os::raw::c_ulong;
+use std::ptr;
+
+use libbpf_sys::*;
+use libbpf_sys_link_test::*;
+
+/// Open the running executable as a BPF object, which libbpf rejects
+/// after reading it using libelf.
+#[test]
+fn shared_libelf() {
+    let exe = std::env::current_exe().unwrap();
+    let path = CString::new(exe.to_str().unwrap()).unwrap();
+
+    // libbpf initializes its libelf with `elf_version`; this crate
+    // deliberately does not, so using libelf below only works if both
+    // share a single implementation.
+    let obj = unsafe { bpf_object__open(path.as_ptr()) };
+    assert!(obj.is_null());
+
+    let file = File::open(&exe).unwrap();
+    let elf = unsafe { elf_begin(file.as_raw_fd(), ELF_C_READ, ptr::null_mut()) };
+    assert!(
+        !elf.is_null(),
+        "libelf not initialized: a second copy is linked"
+    );
+    assert_eq!(unsafe { elf_kind(elf) }, ELF_K_ELF);
+    assert_eq!(unsafe { elf_end(elf) }, 0);
+}
+
+/// Round trip data through libz-sys' zlib, linked alongside the one
+/// libbpf uses, and check that it is the one libbpf-sys links.
+#[test]
+fn zlib() {
+    use libz_sys::*;
+
+    #[cfg(zlib_version)]
+    {
+        let version = unsafe { std::ffi::CStr::from_ptr(zlibVersion()) };
+        assert_eq!(
+            version.to_str().unwrap(),
+            env!("LIBBPF_SYS_ZLIB_VERSION"),
+            "libz-sys uses a different zlib than libbpf-sys links"
+        );
+    }
+
+    let data = b"libbpf-sys libbpf-sys libbpf-sys libbpf-sys";
+    let mut compressed = vec![0u8; unsafe { compressBound(data.len() as _) } as usize];
+    let mut len = compressed.len() as c_ulong;
+    let ret = unsafe {
+        compress(
+            compressed.as_mut_ptr(),
+            &mut len,
+            data.as_ptr(),
+            data.len() as _,
+        )
+    };
+    assert_eq!(ret, Z_OK);
+
+    let mut decompressed = vec![0u8; data.len()];
+    let mut decompressed_len = decompressed.len() as c_ulong;
+    let ret = unsafe {
+        uncompress(
+            decompressed.as_mut_ptr(),
+            &mut decompressed_len,
+            compressed.as_ptr(),
+            len,
+        )
+    };
+    assert_eq!(ret, Z_OK);
+    assert_eq!(&decompressed[..decompressed_len as usize], data);
+}
+
+/// Compare what the exported headers say, according to the C shim, with
+/// the bindings.
+#[cfg(shim)]
+#[test]
+fn exported_headers() {
+    use std::ffi::CStr;
+    use std::mem::size_of;
+
+    unsafe {
+        assert_eq!(shim_libbpf_major_version(), libbpf_major_version());
+        assert_eq!(shim_libbpf_minor_version(), libbpf_minor_version());
+        assert_eq!(
+            shim_bpf_object_open_opts_size(),
+            size_of::<bpf_object_open_opts>()
+        );
+        assert_eq!(
+            CStr::from_ptr(shim_libbpf_version_string()),
+            CStr::from_ptr(libbpf_version_string())
+        );
+    }
+
+    // The UAPI header and `bpf_helper_defs.h` have to agree.
+    let id = unsafe { shim_map_lookup_elem_id() };
+    assert_eq!(id, BPF_FUNC_map_lookup_elem as i32);
+    assert_eq!(unsafe { shim_map_lookup_elem_helper() }, id.into());
+}
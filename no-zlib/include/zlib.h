@@ -0,0 +1,88 @@
+// no-zlib/include/zlib.h
+
+/*
+ * The subset of zlib's API libbpf and libelf use, implemented by
+ * `shim.c` for the `no-zlib` feature. The functions get renamed, so
+ * that they don't clash with a real zlib linked into the same binary.
+ */
+
+#ifndef LIBBPF_SYS_ZLIB_H
+#define LIBBPF_SYS_ZLIB_H
+
+#define ZLIB_VERSION "libbpf-sys-no-zlib"
+
+#define Z_NO_FLUSH 0
+#define Z_SYNC_FLUSH 2
+#define Z_FULL_FLUSH 3
+#define Z_FINISH 4
+
+#define Z_OK 0
+#define Z_STREAM_END 1
+#define Z_NEED_DICT 2
+#define Z_ERRNO (-1)
+#define Z_STREAM_ERROR (-2)
+#define Z_DATA_ERROR (-3)
+#define Z_MEM_ERROR (-4)
+#define Z_BUF_ERROR (-5)
+#define Z_VERSION_ERROR (-6)
+
+#define Z_DEFAULT_COMPRESSION (-1)
+#define Z_BEST_COMPRESSION 9
+
+#define Z_NULL 0
+
+typedef unsigned char Bytef;
+typedef unsigned int uInt;
+typedef unsigned long uLong;
+typedef void *voidpf;
+
+typedef voidpf (*alloc_func)(voidpf opaque, uInt items, uInt size);
+typedef void (*free_func)(voidpf opaque, voidpf address);
+
+typedef struct z_stream_s {
+	const Bytef *next_in;
+	uInt avail_in;
+	uLong total_in;
+	Bytef *next_out;
+	uInt avail_out;
+	uLong total_out;
+	const char *msg;
+	struct internal_state *state;
+	alloc_func zalloc;
+	free_func zfree;
+	voidpf opaque;
+	int data_type;
+	uLong adler;
+	uLong reserved;
+} z_stream;
+
+typedef z_stream *z_streamp;
+
+typedef struct gzFile_s *gzFile;
+
+#define deflateInit(strm, level) libbpf_sys_deflateInit((strm), (level))
+#define deflate libbpf_sys_deflate
+#define deflateEnd libbpf_sys_deflateEnd
+#define inflateInit(strm) libbpf_sys_inflateInit((strm))
+#define inflate libbpf_sys_inflate
+#define inflateEnd libbpf_sys_inflateEnd
+#define inflateReset libbpf_sys_inflateReset
+#define gzopen libbpf_sys_gzopen
+#define gzgets libbpf_sys_gzgets
+#define gzclose libbpf_sys_gzclose
+#define gzdirect libbpf_sys_gzdirect
+
+int libbpf_sys_deflateInit(z_streamp strm, int level);
+int deflate(z_streamp strm, int flush);
+int deflateEnd(z_streamp strm);
+int libbpf_sys_inflateInit(z_streamp strm);
+int inflate(z_streamp strm, int flush);
+int inflateEnd(z_streamp strm);
+int inflateReset(z_streamp strm);
+
+gzFile gzopen(const char *path, const char *mode);
+char *gzgets(gzFile file, char *buf, int len);
+int gzclose(gzFile file);
+int gzdirect(gzFile file);
+
+#endif /* LIBBPF_SYS_ZLIB_H */
@@ -0,0 +1,88 @@
+// no-zlib/shim.c
+
+/*
+ * Stand-ins for zlib, for the `no-zlib` feature. Compressing and
+ * decompressing ELF sections with libelf fails, as does reading the
+ * gzip-compressed /proc/config.gz with libbpf, which falls back to the
+ * uncompressed /boot/config-$(uname -r) first anyway.
+ */
+
+#include <errno.h>
+#include <stddef.h>
+
+#include <zlib.h>
+
+static const char unsupported[] = "zlib support disabled by libbpf-sys' no-zlib feature";
+
+static int fail(z_streamp strm)
+{
+	if (strm)
+		strm->msg = unsupported;
+	return Z_STREAM_ERROR;
+}
+
+int libbpf_sys_deflateInit(z_streamp strm, int level)
+{
+	(void)level;
+	return fail(strm);
+}
+
+int deflate(z_streamp strm, int flush)
+{
+	(void)flush;
+	return fail(strm);
+}
+
+int deflateEnd(z_streamp strm)
+{
+	return fail(strm);
+}
+
+int libbpf_sys_inflateInit(z_streamp strm)
+{
+	return fail(strm);
+}
+
+int inflate(z_streamp strm, int flush)
+{
+	(void)flush;
+	return fail(strm);
+}
+
+int inflateEnd(z_streamp strm)
+{
+	return fail(strm);
+}
+
+int inflateReset(z_streamp strm)
+{
+	return fail(strm);
+}
+
+gzFile gzopen(const char *path, const char *mode)
+{
+	(void)path;
+	(void)mode;
+	errno = ENOTSUP;
+	return NULL;
+}
+
+char *gzgets(gzFile file, char *buf, int len)
+{
+	(void)file;
+	(void)buf;
+	(void)len;
+	return NULL;
+}
+
+int gzclose(gzFile file)
+{
+	(void)file;
+	return Z_STREAM_ERROR;
+}
+
+int gzdirect(gzFile file)
+{
+	(void)file;
+	return 0;
+}
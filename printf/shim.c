@@ -0,0 +1,27 @@
+// printf/shim.c
+
+/*
+ * Formatting of the format string and `va_list` libbpf passes to its
+ * printf-style callbacks, for the `printf` module. Rust can't consume a
+ * `va_list` on stable, but it can pass one on to C.
+ */
+
+#include <stdarg.h>
+#include <stddef.h>
+#include <stdio.h>
+
+/*
+ * Format `fmt` with `args` into `buf` of `size` bytes, returning the
+ * length of the complete message, like vsnprintf. `args` is left
+ * untouched, so that formatting can be retried with a larger buffer.
+ */
+int libbpf_sys_vformat(char *buf, size_t size, const char *fmt, va_list args)
+{
+	va_list copy;
+	int len;
+
+	va_copy(copy, args);
+	len = vsnprintf(buf, size, fmt, copy);
+	va_end(copy);
+	return len;
+}
@@ -0,0 +1,360 @@
+// src/arch.rs
+
+//! Architecture specific register layouts.
+//!
+//! `pt_regs` and friends live in the architecture's `asm/ptrace.h`, so
+//! bindgen output for them would only be valid for the architecture the
+//! bindings were generated on. Instead, they are defined by hand here,
+//! mirroring the UAPI headers, for the architectures libbpf's
+//! `bpf_tracing.h` supports. `bpf_user_pt_regs_t` is what the kernel
+//! hands to perf event programs as part of `bpf_perf_event_data`.
+
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct pt_regs {
+    pub r15: ::core::ffi::c_ulong,
+    pub r14: ::core::ffi::c_ulong,
+    pub r13: ::core::ffi::c_ulong,
+    pub r12: ::core::ffi::c_ulong,
+    pub rbp: ::core::ffi::c_ulong,
+    pub rbx: ::core::ffi::c_ulong,
+    pub r11: ::core::ffi::c_ulong,
+    pub r10: ::core::ffi::c_ulong,
+    pub r9: ::core::ffi::c_ulong,
+    pub r8: ::core::ffi::c_ulong,
+    pub rax: ::core::ffi::c_ulong,
+    pub rcx: ::core::ffi::c_ulong,
+    pub rdx: ::core::ffi::c_ulong,
+    pub rsi: ::core::ffi::c_ulong,
+    pub rdi: ::core::ffi::c_ulong,
+    pub orig_rax: ::core::ffi::c_ulong,
+    pub rip: ::core::ffi::c_ulong,
+    pub cs: ::core::ffi::c_ulong,
+    pub eflags: ::core::ffi::c_ulong,
+    pub rsp: ::core::ffi::c_ulong,
+    pub ss: ::core::ffi::c_ulong,
+}
+
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct pt_regs {
+    pub ebx: ::core::ffi::c_long,
+    pub ecx: ::core::ffi::c_long,
+    pub edx: ::core::ffi::c_long,
+    pub esi: ::core::ffi::c_long,
+    pub edi: ::core::ffi::c_long,
+    pub ebp: ::core::ffi::c_long,
+    pub eax: ::core::ffi::c_long,
+    pub xds: ::core::ffi::c_int,
+    pub xes: ::core::ffi::c_int,
+    pub xfs: ::core::ffi::c_int,
+    pub xgs: ::core::ffi::c_int,
+    pub orig_eax: ::core::ffi::c_long,
+    pub eip: ::core::ffi::c_long,
+    pub xcs: ::core::ffi::c_int,
+    pub eflags: ::core::ffi::c_long,
+    pub esp: ::core::ffi::c_long,
+    pub xss: ::core::ffi::c_int,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub type bpf_user_pt_regs_t = pt_regs;
+
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct user_pt_regs {
+    pub regs: [crate::bindings::__u64; 31],
+    pub sp: crate::bindings::__u64,
+    pub pc: crate::bindings::__u64,
+    pub pstate: crate::bindings::__u64,
+}
+
+#[cfg(target_arch = "aarch64")]
+pub type bpf_user_pt_regs_t = user_pt_regs;
+
+#[cfg(target_arch = "arm")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct pt_regs {
+    pub uregs: [::core::ffi::c_long; 18],
+}
+
+#[cfg(target_arch = "arm")]
+pub type bpf_user_pt_regs_t = pt_regs;
+
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct user_regs_struct {
+    pub pc: ::core::ffi::c_ulong,
+    pub ra: ::core::ffi::c_ulong,
+    pub sp: ::core::ffi::c_ulong,
+    pub gp: ::core::ffi::c_ulong,
+    pub tp: ::core::ffi::c_ulong,
+    pub t0: ::core::ffi::c_ulong,
+    pub t1: ::core::ffi::c_ulong,
+    pub t2: ::core::ffi::c_ulong,
+    pub s0: ::core::ffi::c_ulong,
+    pub s1: ::core::ffi::c_ulong,
+    pub a0: ::core::ffi::c_ulong,
+    pub a1: ::core::ffi::c_ulong,
+    pub a2: ::core::ffi::c_ulong,
+    pub a3: ::core::ffi::c_ulong,
+    pub a4: ::core::ffi::c_ulong,
+    pub a5: ::core::ffi::c_ulong,
+    pub a6: ::core::ffi::c_ulong,
+    pub a7: ::core::ffi::c_ulong,
+    pub s2: ::core::ffi::c_ulong,
+    pub s3: ::core::ffi::c_ulong,
+    pub s4: ::core::ffi::c_ulong,
+    pub s5: ::core::ffi::c_ulong,
+    pub s6: ::core::ffi::c_ulong,
+    pub s7: ::core::ffi::c_ulong,
+    pub s8: ::core::ffi::c_ulong,
+    pub s9: ::core::ffi::c_ulong,
+    pub s10: ::core::ffi::c_ulong,
+    pub s11: ::core::ffi::c_ulong,
+    pub t3: ::core::ffi::c_ulong,
+    pub t4: ::core::ffi::c_ulong,
+    pub t5: ::core::ffi::c_ulong,
+    pub t6: ::core::ffi::c_ulong,
+}
+
+#[cfg(target_arch = "riscv64")]
+pub type bpf_user_pt_regs_t = user_regs_struct;
+
+#[cfg(target_arch = "s390x")]
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct psw_t {
+    pub mask: ::core::ffi::c_ulong,
+    pub addr: ::core::ffi::c_ulong,
+}
+
+#[cfg(target_arch = "s390x")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct user_pt_regs {
+    pub args: [::core::ffi::c_ulong; 1],
+    pub psw: psw_t,
+    pub gprs: [::core::ffi::c_ulong; 16],
+}
+
+#[cfg(target_arch = "powerpc64")]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct user_pt_regs {
+    pub gpr: [::core::ffi::c_ulong; 32],
+    pub nip: ::core::ffi::c_ulong,
+    pub msr: ::core::ffi::c_ulong,
+    pub orig_gpr3: ::core::ffi::c_ulong,
+    pub ctr: ::core::ffi::c_ulong,
+    pub link: ::core::ffi::c_ulong,
+    pub xer: ::core::ffi::c_ulong,
+    pub ccr: ::core::ffi::c_ulong,
+    pub softe: ::core::ffi::c_ulong,
+    pub trap: ::core::ffi::c_ulong,
+    pub dar: ::core::ffi::c_ulong,
+    pub dsisr: ::core::ffi::c_ulong,
+    pub result: ::core::ffi::c_ulong,
+}
+
+#[cfg(target_arch = "loongarch64")]
+#[repr(C)]
+#[repr(align(8))]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct user_pt_regs {
+    pub regs: [::core::ffi::c_ulong; 32],
+    pub orig_a0: ::core::ffi::c_ulong,
+    pub csr_era: ::core::ffi::c_ulong,
+    pub csr_badv: ::core::ffi::c_ulong,
+    pub reserved: [::core::ffi::c_ulong; 10],
+}
+
+#[cfg(any(
+    target_arch = "s390x",
+    target_arch = "powerpc64",
+    target_arch = "loongarch64"
+))]
+pub type bpf_user_pt_regs_t = user_pt_regs;
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "s390x",
+    target_arch = "powerpc64",
+    target_arch = "loongarch64"
+))]
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct bpf_perf_event_data {
+    pub regs: bpf_user_pt_regs_t,
+    pub sample_period: crate::bindings::__u64,
+    pub addr: crate::bindings::__u64,
+}
+
+/// Implement the accessors of the `PT_REGS_*` macros of `bpf_tracing.h`
+/// in terms of the register expressions given for `$regs`.
+macro_rules! pt_regs_accessors {
+    (
+        $regs:ident =>
+        parm: [$($parm:expr),* $(,)?],
+        ret: $ret:expr,
+        fp: $fp:expr,
+        rc: $rc:expr,
+        sp: $sp:expr,
+        ip: $ip:expr $(,)?
+    ) => {
+        impl bpf_user_pt_regs_t {
+            /// The number of arguments passed in registers, i.e., the
+            /// highest `n` for which [`parm`][Self::parm] succeeds.
+            pub const PARM_COUNT: usize = [$(stringify!($parm)),*].len();
+
+            /// Retrieve the `n`th (starting at 1) argument of the called
+            /// function (`PT_REGS_PARMn`), if it is passed in a register.
+            pub fn parm(&self, n: usize) -> Option<u64> {
+                let $regs = self;
+                let parms = [$($parm as ::core::ffi::c_ulong as u64),*];
+                parms.get(n.checked_sub(1)?).copied()
+            }
+
+            /// Retrieve the register holding the return address
+            /// (`PT_REGS_RET`).
+            pub fn ret(&self) -> u64 {
+                let $regs = self;
+                $ret as ::core::ffi::c_ulong as u64
+            }
+
+            /// Retrieve the frame pointer (`PT_REGS_FP`), if the
+            /// architecture has a dedicated one.
+            pub fn fp(&self) -> Option<u64> {
+                let $regs = self;
+                $fp.map(|fp| fp as ::core::ffi::c_ulong as u64)
+            }
+
+            /// Retrieve the return value (`PT_REGS_RC`).
+            pub fn rc(&self) -> u64 {
+                let $regs = self;
+                $rc as ::core::ffi::c_ulong as u64
+            }
+
+            /// Retrieve the stack pointer (`PT_REGS_SP`).
+            pub fn sp(&self) -> u64 {
+                let $regs = self;
+                $sp as ::core::ffi::c_ulong as u64
+            }
+
+            /// Retrieve the instruction pointer (`PT_REGS_IP`).
+            pub fn ip(&self) -> u64 {
+                let $regs = self;
+                $ip as ::core::ffi::c_ulong as u64
+            }
+        }
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+pt_regs_accessors! {
+    regs =>
+    parm: [regs.rdi, regs.rsi, regs.rdx, regs.rcx, regs.r8, regs.r9],
+    ret: regs.rsp,
+    fp: Some(regs.rbp),
+    rc: regs.rax,
+    sp: regs.rsp,
+    ip: regs.rip,
+}
+
+#[cfg(target_arch = "x86")]
+pt_regs_accessors! {
+    regs =>
+    parm: [regs.eax, regs.edx, regs.ecx],
+    ret: regs.esp,
+    fp: Some(regs.ebp),
+    rc: regs.eax,
+    sp: regs.esp,
+    ip: regs.eip,
+}
+
+#[cfg(target_arch = "aarch64")]
+pt_regs_accessors! {
+    regs =>
+    parm: [
+        regs.regs[0], regs.regs[1], regs.regs[2], regs.regs[3],
+        regs.regs[4], regs.regs[5], regs.regs[6], regs.regs[7],
+    ],
+    ret: regs.regs[30],
+    fp: Some(regs.regs[29]),
+    rc: regs.regs[0],
+    sp: regs.sp,
+    ip: regs.pc,
+}
+
+#[cfg(target_arch = "arm")]
+pt_regs_accessors! {
+    regs =>
+    parm: [regs.uregs[0], regs.uregs[1], regs.uregs[2], regs.uregs[3]],
+    ret: regs.uregs[14],
+    fp: Some(regs.uregs[11]),
+    rc: regs.uregs[0],
+    sp: regs.uregs[13],
+    ip: regs.uregs[12],
+}
+
+#[cfg(target_arch = "riscv64")]
+pt_regs_accessors! {
+    regs =>
+    parm: [
+        regs.a0, regs.a1, regs.a2, regs.a3, regs.a4, regs.a5, regs.a6, regs.a7,
+    ],
+    ret: regs.ra,
+    fp: Some(regs.s0),
+    rc: regs.a0,
+    sp: regs.sp,
+    ip: regs.pc,
+}
+
+#[cfg(target_arch = "s390x")]
+pt_regs_accessors! {
+    regs =>
+    parm: [regs.gprs[2], regs.gprs[3], regs.gprs[4], regs.gprs[5], regs.gprs[6]],
+    ret: regs.gprs[14],
+    fp: Some(regs.gprs[11]),
+    rc: regs.gprs[2],
+    sp: regs.gprs[15],
+    ip: regs.psw.addr,
+}
+
+#[cfg(target_arch = "powerpc64")]
+pt_regs_accessors! {
+    regs =>
+    parm: [
+        regs.gpr[3], regs.gpr[4], regs.gpr[5], regs.gpr[6],
+        regs.gpr[7], regs.gpr[8], regs.gpr[9], regs.gpr[10],
+    ],
+    ret: regs.link,
+    fp: None::<::core::ffi::c_ulong>,
+    rc: regs.gpr[3],
+    sp: regs.gpr[1],
+    ip: regs.nip,
+}
+
+#[cfg(target_arch = "loongarch64")]
+pt_regs_accessors! {
+    regs =>
+    parm: [
+        regs.regs[4], regs.regs[5], regs.regs[6], regs.regs[7],
+        regs.regs[8], regs.regs[9], regs.regs[10], regs.regs[11],
+    ],
+    ret: regs.regs[1],
+    fp: Some(regs.regs[22]),
+    rc: regs.regs[4],
+    sp: regs.regs[3],
+    ip: regs.csr_era,
+}
@@ -0,0 +1,103 @@
+// src/attr.rs
+
+//! Per-command accessors for the `bpf_attr` union.
+//!
+//! bindgen names most of the anonymous structs making up `bpf_attr`
+//! after their position in the union, which makes it easy to pick the
+//! wrong member for a given `bpf_cmd`. The type aliases and functions in
+//! here name them after the command(s) they belong to instead.
+
+use crate::bindings::*;
+
+macro_rules! attr_variants {
+    ($(
+        $(#[$docs:meta])*
+        ($alias:ident, $ty:ty, $field:ident, $new:ident, $get:ident, $get_mut:ident)
+    ),* $(,)?) => {
+        $(
+            $(#[$docs])*
+            pub type $alias = $ty;
+        )*
+
+        impl bpf_attr {
+            $(
+                #[doc = concat!(
+                    "Create a zero-initialized `bpf_attr` with its `",
+                    stringify!($field),
+                    "` member set to `attr`."
+                )]
+                #[inline]
+                pub fn $new(attr: $alias) -> Self {
+                    let mut this = Self::default();
+                    this.$field = attr;
+                    this
+                }
+
+                #[doc = concat!("Access the `", stringify!($field), "` member.")]
+                ///
+                /// # Safety
+                /// The caller has to ensure that the attribute was initialized
+                /// for a command using this member.
+                #[inline]
+                pub unsafe fn $get(&self) -> &$alias {
+                    unsafe { &self.$field }
+                }
+
+                #[doc = concat!("Mutably access the `", stringify!($field), "` member.")]
+                ///
+                /// # Safety
+                /// The caller has to ensure that the attribute was initialized
+                /// for a command using this member.
+                #[inline]
+                pub unsafe fn $get_mut(&mut self) -> &mut $alias {
+                    unsafe { &mut self.$field }
+                }
+            )*
+        }
+    };
+}
+
+attr_variants! {
+    /// Attributes for `BPF_MAP_CREATE`.
+    (bpf_attr_map_create, bpf_attr__bindgen_ty_1, __bindgen_anon_1, map_create, as_map_create, as_map_create_mut),
+    /// Attributes for `BPF_MAP_{LOOKUP,UPDATE,DELETE}_ELEM`,
+    /// `BPF_MAP_GET_NEXT_KEY`, `BPF_MAP_LOOKUP_AND_DELETE_ELEM`, and
+    /// `BPF_MAP_FREEZE`.
+    (bpf_attr_map_elem, bpf_attr__bindgen_ty_2, __bindgen_anon_2, map_elem, as_map_elem, as_map_elem_mut),
+    /// Attributes for the `BPF_MAP_*_BATCH` commands.
+    (bpf_attr_map_batch, bpf_attr__bindgen_ty_3, batch, map_batch, as_map_batch, as_map_batch_mut),
+    /// Attributes for `BPF_PROG_LOAD`.
+    (bpf_attr_prog_load, bpf_attr__bindgen_ty_4, __bindgen_anon_3, prog_load, as_prog_load, as_prog_load_mut),
+    /// Attributes for `BPF_OBJ_PIN` and `BPF_OBJ_GET`.
+    (bpf_attr_obj, bpf_attr__bindgen_ty_5, __bindgen_anon_4, obj, as_obj, as_obj_mut),
+    /// Attributes for `BPF_PROG_ATTACH` and `BPF_PROG_DETACH`.
+    (bpf_attr_prog_attach, bpf_attr__bindgen_ty_6, __bindgen_anon_5, prog_attach, as_prog_attach, as_prog_attach_mut),
+    /// Attributes for `BPF_PROG_TEST_RUN`.
+    (bpf_attr_prog_test_run, bpf_attr__bindgen_ty_7, test, prog_test_run, as_prog_test_run, as_prog_test_run_mut),
+    /// Attributes for the `BPF_*_GET_{NEXT_ID,FD_BY_ID}` commands.
+    (bpf_attr_get_id, bpf_attr__bindgen_ty_8, __bindgen_anon_6, get_id, as_get_id, as_get_id_mut),
+    /// Attributes for `BPF_OBJ_GET_INFO_BY_FD`.
+    (bpf_attr_obj_get_info_by_fd, bpf_attr__bindgen_ty_9, info, obj_get_info_by_fd, as_obj_get_info_by_fd, as_obj_get_info_by_fd_mut),
+    /// Attributes for `BPF_PROG_QUERY`.
+    (bpf_attr_prog_query, bpf_attr__bindgen_ty_10, query, prog_query, as_prog_query, as_prog_query_mut),
+    /// Attributes for `BPF_RAW_TRACEPOINT_OPEN`.
+    (bpf_attr_raw_tracepoint_open, bpf_attr__bindgen_ty_11, raw_tracepoint, raw_tracepoint_open, as_raw_tracepoint_open, as_raw_tracepoint_open_mut),
+    /// Attributes for `BPF_BTF_LOAD`.
+    (bpf_attr_btf_load, bpf_attr__bindgen_ty_12, __bindgen_anon_7, btf_load, as_btf_load, as_btf_load_mut),
+    /// Attributes for `BPF_TASK_FD_QUERY`.
+    (bpf_attr_task_fd_query, bpf_attr__bindgen_ty_13, task_fd_query, task_fd_query, as_task_fd_query, as_task_fd_query_mut),
+    /// Attributes for `BPF_LINK_CREATE`.
+    (bpf_attr_link_create, bpf_attr__bindgen_ty_14, link_create, link_create, as_link_create, as_link_create_mut),
+    /// Attributes for `BPF_LINK_UPDATE`.
+    (bpf_attr_link_update, bpf_attr__bindgen_ty_15, link_update, link_update, as_link_update, as_link_update_mut),
+    /// Attributes for `BPF_LINK_DETACH`.
+    (bpf_attr_link_detach, bpf_attr__bindgen_ty_16, link_detach, link_detach, as_link_detach, as_link_detach_mut),
+    /// Attributes for `BPF_ENABLE_STATS`.
+    (bpf_attr_enable_stats, bpf_attr__bindgen_ty_17, enable_stats, enable_stats, as_enable_stats, as_enable_stats_mut),
+    /// Attributes for `BPF_ITER_CREATE`.
+    (bpf_attr_iter_create, bpf_attr__bindgen_ty_18, iter_create, iter_create, as_iter_create, as_iter_create_mut),
+    /// Attributes for `BPF_PROG_BIND_MAP`.
+    (bpf_attr_prog_bind_map, bpf_attr__bindgen_ty_19, prog_bind_map, prog_bind_map, as_prog_bind_map, as_prog_bind_map_mut),
+    /// Attributes for `BPF_TOKEN_CREATE`.
+    (bpf_attr_token_create, bpf_attr__bindgen_ty_20, token_create, token_create, as_token_create, as_token_create_mut),
+}
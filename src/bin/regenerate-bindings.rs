@@ -0,0 +1,239 @@
+// src/bin/regenerate-bindings.rs
+
+//! Regenerate the shipped bindings in `src/bindings.rs`, e.g., after
+//! bumping the libbpf submodule, check that they still apply to all
+//! supported 64 bit architectures, and regenerate the ones of the
+//! supported 32 bit architectures in `src/bindings/<arch>.rs`.
+//!
+//! The bindings are regenerated with the `bindgen-source` feature.
+//! Afterwards, bindings are generated with the `bindgen` feature for
+//! each of the other 64 bit architectures and compared to them, item by
+//! item, as any difference means that the shipped bindings are wrong
+//! there. Those generated for 32 bit architectures are written to
+//! `src/bindings/<arch>.rs`. Generating bindings for another
+//! architecture requires the Rust target to be installed, e.g., with
+//! `rustup target add`, and a sysroot with its C headers, which is
+//! passed on to libclang:
+//!
+//! ```text
+//! $ cargo run --features regenerate-bindings --bin regenerate-bindings -- \
+//!     --target aarch64-unknown-linux-gnu=/usr/aarch64-linux-gnu \
+//!     --target armv7-unknown-linux-gnueabihf=/usr/arm-linux-gnueabihf
+//! ```
+//!
+//! Without any `--target`, all architectures are checked, using the
+//! system's headers. With `--check`, no bindings in `src` are written;
+//! they are generated into the build directory instead, and the tool
+//! fails if they differ from the ones in `src`.
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::process::Command;
+use std::process::Stdio;
+
+#[path = "../../tests/bindings/diff.rs"]
+mod diff;
+
+/// The targets of the supported 64 bit architectures, which the shipped
+/// bindings apply to.
+const TARGETS: &[&str] = &[
+    "x86_64-unknown-linux-gnu",
+    "aarch64-unknown-linux-gnu",
+    "riscv64gc-unknown-linux-gnu",
+    "s390x-unknown-linux-gnu",
+    "powerpc64le-unknown-linux-gnu",
+    "loongarch64-unknown-linux-gnu",
+];
+
+/// The targets of the supported 32 bit architectures, along with the
+/// architectures, in the spelling of `target_arch`, whose bindings in
+/// `src/bindings/<arch>.rs` get generated for them.
+const ARCH_TARGETS: &[(&str, &str)] = &[
+    ("armv7-unknown-linux-gnueabihf", "arm"),
+    ("i686-unknown-linux-gnu", "x86"),
+];
+
+/// The target the shipped bindings are generated for.
+const HOST_TARGET: &str = "x86_64-unknown-linux-gnu";
+
+/// Retrieve the value of `key` from a line of JSON, assuming it is a
+/// string without escaped quotes.
+fn json_str<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let start = line.find(&format!("\"{key}\":\""))? + key.len() + 4;
+    let end = line[start..].find('"')?;
+    Some(&line[start..start + end])
+}
+
+/// Run `cargo check` in the crate's directory with `args`, using a
+/// separate target directory, and return the `OUT_DIR` of the crate's
+/// build script. libbpf is neither built nor looked for, so that
+/// nothing but the bindings has to be available for the target.
+fn cargo_check(args: &[&str], envs: &[(String, OsString)]) -> Result<PathBuf, String> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = Command::new(cargo)
+        .current_dir(dir)
+        .arg("check")
+        .arg("--message-format=json")
+        .arg("--target-dir")
+        .arg(dir.join("target/regenerate-bindings"))
+        .args(args)
+        .env("LIBBPF_SYS_SKIP_NATIVE_BUILD", "1")
+        .envs(envs.iter().map(|(key, value)| (key, value)))
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| format!("failed to run cargo: {}", err))?;
+    if !output.status.success() {
+        return Err(format!("`cargo check {}` failed", args.join(" ")));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| json_str(line, "reason") == Some("build-script-executed"))
+        .filter(|line| json_str(line, "package_id").is_some_and(|id| id.contains("libbpf-sys")))
+        .find_map(|line| json_str(line, "out_dir"))
+        .map(PathBuf::from)
+        .ok_or_else(|| "no build script output found".to_string())
+}
+
+/// Generate bindings for `target`, with the C headers in `sysroot`, if
+/// any, and return them.
+fn generate(target: &str, sysroot: Option<&str>) -> Result<String, String> {
+    let mut envs = Vec::new();
+    if let Some(sysroot) = sysroot {
+        envs.push((
+            format!("BINDGEN_EXTRA_CLANG_ARGS_{}", target.replace('-', "_")),
+            format!("--sysroot={}", sysroot).into(),
+        ));
+    }
+    let out_dir = cargo_check(
+        &[
+            "--no-default-features",
+            "--features",
+            "bindgen",
+            "--target",
+            target,
+        ],
+        &envs,
+    )?;
+    fs::read_to_string(out_dir.join("bindings.rs"))
+        .map_err(|err| format!("failed to read bindings for {}: {}", target, err))
+}
+
+fn main() {
+    let mut check = false;
+    let mut targets = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check = true,
+            "--target" => match args.next() {
+                Some(target) => targets.push(target),
+                None => {
+                    eprintln!("--target requires an argument");
+                    process::exit(2);
+                },
+            },
+            _ => {
+                eprintln!(
+                    "usage: regenerate-bindings [--check] [--target <target>[=<sysroot>]]..."
+                );
+                process::exit(2);
+            },
+        }
+    }
+    if targets.is_empty() {
+        targets = TARGETS
+            .iter()
+            .chain(ARCH_TARGETS.iter().map(|(target, _)| target))
+            .map(|target| target.to_string())
+            .collect();
+    }
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/bindings.rs");
+    let shipped = fs::read_to_string(&path).expect("failed to read src/bindings.rs");
+
+    // The bindings generated with the `bindgen` feature for the host are
+    // the ones `bindgen-source` writes to `src/bindings.rs`.
+    let regenerated = if check {
+        eprintln!("generating bindings for {}", HOST_TARGET);
+        generate(HOST_TARGET, None)
+    } else {
+        eprintln!("regenerating src/bindings.rs");
+        cargo_check(&["--features", "bindgen-source"], &[])
+            .map(|_| fs::read_to_string(&path).expect("failed to read src/bindings.rs"))
+    };
+    let regenerated = match regenerated {
+        Ok(regenerated) => regenerated,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        },
+    };
+    let changed = diff::report(&shipped, &regenerated);
+    match &changed {
+        Some(report) => eprintln!("src/bindings.rs changed:\n{}", report),
+        None => eprintln!("src/bindings.rs is up to date"),
+    }
+
+    let mut failed = check && changed.is_some();
+    for target in &targets {
+        let (target, sysroot) = match target.split_once('=') {
+            Some((target, sysroot)) => (target, Some(sysroot)),
+            None => (target.as_str(), None),
+        };
+        if target == HOST_TARGET {
+            continue;
+        }
+        eprintln!("generating bindings for {}", target);
+        let bindings = match generate(target, sysroot) {
+            Ok(bindings) => bindings,
+            Err(err) => {
+                eprintln!("{}", err);
+                failed = true;
+                continue;
+            },
+        };
+
+        let arch = ARCH_TARGETS
+            .iter()
+            .find(|(arch_target, _)| *arch_target == target)
+            .map(|(_, arch)| arch);
+        let Some(arch) = arch else {
+            if let Some(report) = diff::report(&regenerated, &bindings) {
+                eprintln!(
+                    "bindings for {} differ from src/bindings.rs:\n{}",
+                    target, report
+                );
+                failed = true;
+            }
+            continue;
+        };
+
+        let name = format!("src/bindings/{}.rs", arch);
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(&name);
+        let shipped = fs::read_to_string(&path).unwrap_or_default();
+        match diff::report(&shipped, &bindings) {
+            Some(report) => eprintln!("{} changed:\n{}", name, report),
+            None => eprintln!("{} is up to date", name),
+        }
+        if check {
+            failed |= shipped != bindings;
+        } else {
+            let result = fs::create_dir_all(path.parent().unwrap())
+                .and_then(|()| fs::write(&path, &bindings));
+            if let Err(err) = result {
+                eprintln!("failed to write {}: {}", name, err);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
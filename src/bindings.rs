@@ -139,11 +139,11 @@ where
 }
 #[repr(C)]
 #[derive(Default)]
-pub struct __IncompleteArrayField<T>(::std::marker::PhantomData<T>, [T; 0]);
+pub struct __IncompleteArrayField<T>(::core::marker::PhantomData<T>, [T; 0]);
 impl<T> __IncompleteArrayField<T> {
     #[inline]
     pub const fn new() -> Self {
-        __IncompleteArrayField(::std::marker::PhantomData, [])
+        __IncompleteArrayField(::core::marker::PhantomData, [])
     }
     #[inline]
     pub fn as_ptr(&self) -> *const T {
@@ -155,15 +155,15 @@ impl<T> __IncompleteArrayField<T> {
     }
     #[inline]
     pub unsafe fn as_slice(&self, len: usize) -> &[T] {
-        ::std::slice::from_raw_parts(self.as_ptr(), len)
+        ::core::slice::from_raw_parts(self.as_ptr(), len)
     }
     #[inline]
     pub unsafe fn as_mut_slice(&mut self, len: usize) -> &mut [T] {
-        ::std::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
+        ::core::slice::from_raw_parts_mut(self.as_mut_ptr(), len)
     }
 }
-impl<T> ::std::fmt::Debug for __IncompleteArrayField<T> {
-    fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<T> ::core::fmt::Debug for __IncompleteArrayField<T> {
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         fmt.write_str("__IncompleteArrayField")
     }
 }
@@ -388,23 +388,125 @@ pub const BTF_INT_BOOL: u32 = 4;
 pub const BTF_ELF_SEC: &[u8; 5] = b".BTF\0";
 pub const BTF_EXT_ELF_SEC: &[u8; 9] = b".BTF.ext\0";
 pub const BTF_BASE_ELF_SEC: &[u8; 10] = b".BTF.base\0";
-pub type size_t = ::std::os::raw::c_ulong;
-pub type __pid_t = ::std::os::raw::c_int;
-pub type __u8 = ::std::os::raw::c_uchar;
-pub type __s16 = ::std::os::raw::c_short;
-pub type __u16 = ::std::os::raw::c_ushort;
-pub type __s32 = ::std::os::raw::c_int;
-pub type __u32 = ::std::os::raw::c_uint;
-pub type __s64 = ::std::os::raw::c_longlong;
-pub type __u64 = ::std::os::raw::c_ulonglong;
+pub const TC_H_MAJ_MASK: u32 = 4294901760;
+pub const TC_H_MIN_MASK: u32 = 65535;
+pub const TC_H_UNSPEC: u32 = 0;
+pub const TC_H_ROOT: u32 = 4294967295;
+pub const TC_H_INGRESS: u32 = 4294967281;
+pub const TC_H_CLSACT: u32 = 4294967281;
+pub const TC_H_MIN_PRIORITY: u32 = 65504;
+pub const TC_H_MIN_INGRESS: u32 = 65522;
+pub const TC_H_MIN_EGRESS: u32 = 65523;
+pub const TC_ACT_UNSPEC: i32 = -1;
+pub const TC_ACT_OK: u32 = 0;
+pub const TC_ACT_RECLASSIFY: u32 = 1;
+pub const TC_ACT_SHOT: u32 = 2;
+pub const TC_ACT_PIPE: u32 = 3;
+pub const TC_ACT_STOLEN: u32 = 4;
+pub const TC_ACT_QUEUED: u32 = 5;
+pub const TC_ACT_REPEAT: u32 = 6;
+pub const TC_ACT_REDIRECT: u32 = 7;
+pub const TC_ACT_TRAP: u32 = 8;
+pub const TC_ACT_VALUE_MAX: u32 = 8;
+pub const TC_ACT_EXT_SHIFT: u32 = 28;
+pub const TC_ACT_EXT_VAL_MASK: u32 = 268435455;
+pub const TCA_BPF_FLAG_ACT_DIRECT: u32 = 1;
+pub type size_t = ::core::ffi::c_ulong;
+pub type __pid_t = ::core::ffi::c_int;
+pub type __u8 = ::core::ffi::c_uchar;
+pub type __s16 = ::core::ffi::c_short;
+pub type __u16 = ::core::ffi::c_ushort;
+pub type __s32 = ::core::ffi::c_int;
+pub type __u32 = ::core::ffi::c_uint;
+pub type __s64 = ::core::ffi::c_longlong;
+pub type __u64 = ::core::ffi::c_ulonglong;
 pub type __be16 = __u16;
 pub type __be32 = __u32;
+pub const IFLA_UNSPEC: _bindgen_ty_2 = 0;
+pub const IFLA_ADDRESS: _bindgen_ty_2 = 1;
+pub const IFLA_BROADCAST: _bindgen_ty_2 = 2;
+pub const IFLA_IFNAME: _bindgen_ty_2 = 3;
+pub const IFLA_MTU: _bindgen_ty_2 = 4;
+pub const IFLA_LINK: _bindgen_ty_2 = 5;
+pub const IFLA_QDISC: _bindgen_ty_2 = 6;
+pub const IFLA_STATS: _bindgen_ty_2 = 7;
+pub const IFLA_COST: _bindgen_ty_2 = 8;
+pub const IFLA_PRIORITY: _bindgen_ty_2 = 9;
+pub const IFLA_MASTER: _bindgen_ty_2 = 10;
+pub const IFLA_WIRELESS: _bindgen_ty_2 = 11;
+pub const IFLA_PROTINFO: _bindgen_ty_2 = 12;
+pub const IFLA_TXQLEN: _bindgen_ty_2 = 13;
+pub const IFLA_MAP: _bindgen_ty_2 = 14;
+pub const IFLA_WEIGHT: _bindgen_ty_2 = 15;
+pub const IFLA_OPERSTATE: _bindgen_ty_2 = 16;
+pub const IFLA_LINKMODE: _bindgen_ty_2 = 17;
+pub const IFLA_LINKINFO: _bindgen_ty_2 = 18;
+pub const IFLA_NET_NS_PID: _bindgen_ty_2 = 19;
+pub const IFLA_IFALIAS: _bindgen_ty_2 = 20;
+pub const IFLA_NUM_VF: _bindgen_ty_2 = 21;
+pub const IFLA_VFINFO_LIST: _bindgen_ty_2 = 22;
+pub const IFLA_STATS64: _bindgen_ty_2 = 23;
+pub const IFLA_VF_PORTS: _bindgen_ty_2 = 24;
+pub const IFLA_PORT_SELF: _bindgen_ty_2 = 25;
+pub const IFLA_AF_SPEC: _bindgen_ty_2 = 26;
+pub const IFLA_GROUP: _bindgen_ty_2 = 27;
+pub const IFLA_NET_NS_FD: _bindgen_ty_2 = 28;
+pub const IFLA_EXT_MASK: _bindgen_ty_2 = 29;
+pub const IFLA_PROMISCUITY: _bindgen_ty_2 = 30;
+pub const IFLA_NUM_TX_QUEUES: _bindgen_ty_2 = 31;
+pub const IFLA_NUM_RX_QUEUES: _bindgen_ty_2 = 32;
+pub const IFLA_CARRIER: _bindgen_ty_2 = 33;
+pub const IFLA_PHYS_PORT_ID: _bindgen_ty_2 = 34;
+pub const IFLA_CARRIER_CHANGES: _bindgen_ty_2 = 35;
+pub const IFLA_PHYS_SWITCH_ID: _bindgen_ty_2 = 36;
+pub const IFLA_LINK_NETNSID: _bindgen_ty_2 = 37;
+pub const IFLA_PHYS_PORT_NAME: _bindgen_ty_2 = 38;
+pub const IFLA_PROTO_DOWN: _bindgen_ty_2 = 39;
+pub const IFLA_GSO_MAX_SEGS: _bindgen_ty_2 = 40;
+pub const IFLA_GSO_MAX_SIZE: _bindgen_ty_2 = 41;
+pub const IFLA_PAD: _bindgen_ty_2 = 42;
+pub const IFLA_XDP: _bindgen_ty_2 = 43;
+pub const IFLA_EVENT: _bindgen_ty_2 = 44;
+pub const IFLA_NEW_NETNSID: _bindgen_ty_2 = 45;
+pub const IFLA_IF_NETNSID: _bindgen_ty_2 = 46;
+pub const IFLA_CARRIER_UP_COUNT: _bindgen_ty_2 = 47;
+pub const IFLA_CARRIER_DOWN_COUNT: _bindgen_ty_2 = 48;
+pub const IFLA_NEW_IFINDEX: _bindgen_ty_2 = 49;
+pub const IFLA_MIN_MTU: _bindgen_ty_2 = 50;
+pub const IFLA_MAX_MTU: _bindgen_ty_2 = 51;
+pub const IFLA_PROP_LIST: _bindgen_ty_2 = 52;
+pub const IFLA_ALT_IFNAME: _bindgen_ty_2 = 53;
+pub const IFLA_PERM_ADDRESS: _bindgen_ty_2 = 54;
+pub const IFLA_PROTO_DOWN_REASON: _bindgen_ty_2 = 55;
+pub const IFLA_PARENT_DEV_NAME: _bindgen_ty_2 = 56;
+pub const IFLA_PARENT_DEV_BUS_NAME: _bindgen_ty_2 = 57;
+pub const IFLA_GRO_MAX_SIZE: _bindgen_ty_2 = 58;
+pub const IFLA_TSO_MAX_SIZE: _bindgen_ty_2 = 59;
+pub const IFLA_TSO_MAX_SEGS: _bindgen_ty_2 = 60;
+pub const IFLA_ALLMULTI: _bindgen_ty_2 = 61;
+pub const IFLA_DEVLINK_PORT: _bindgen_ty_2 = 62;
+pub const IFLA_GSO_IPV4_MAX_SIZE: _bindgen_ty_2 = 63;
+pub const IFLA_GRO_IPV4_MAX_SIZE: _bindgen_ty_2 = 64;
+pub const IFLA_DPLL_PIN: _bindgen_ty_2 = 65;
+pub const __IFLA_MAX: _bindgen_ty_2 = 66;
+pub type _bindgen_ty_2 = ::core::ffi::c_uint;
 pub const XDP_ATTACHED_NONE: _bindgen_ty_48 = 0;
 pub const XDP_ATTACHED_DRV: _bindgen_ty_48 = 1;
 pub const XDP_ATTACHED_SKB: _bindgen_ty_48 = 2;
 pub const XDP_ATTACHED_HW: _bindgen_ty_48 = 3;
 pub const XDP_ATTACHED_MULTI: _bindgen_ty_48 = 4;
-pub type _bindgen_ty_48 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_48 = ::core::ffi::c_uint;
+pub const IFLA_XDP_UNSPEC: _bindgen_ty_49 = 0;
+pub const IFLA_XDP_FD: _bindgen_ty_49 = 1;
+pub const IFLA_XDP_ATTACHED: _bindgen_ty_49 = 2;
+pub const IFLA_XDP_FLAGS: _bindgen_ty_49 = 3;
+pub const IFLA_XDP_PROG_ID: _bindgen_ty_49 = 4;
+pub const IFLA_XDP_DRV_PROG_ID: _bindgen_ty_49 = 5;
+pub const IFLA_XDP_SKB_PROG_ID: _bindgen_ty_49 = 6;
+pub const IFLA_XDP_HW_PROG_ID: _bindgen_ty_49 = 7;
+pub const IFLA_XDP_EXPECTED_FD: _bindgen_ty_49 = 8;
+pub const __IFLA_XDP_MAX: _bindgen_ty_49 = 9;
+pub type _bindgen_ty_49 = ::core::ffi::c_uint;
 pub const PERF_TYPE_HARDWARE: perf_type_id = 0;
 pub const PERF_TYPE_SOFTWARE: perf_type_id = 1;
 pub const PERF_TYPE_TRACEPOINT: perf_type_id = 2;
@@ -412,7 +514,7 @@ pub const PERF_TYPE_HW_CACHE: perf_type_id = 3;
 pub const PERF_TYPE_RAW: perf_type_id = 4;
 pub const PERF_TYPE_BREAKPOINT: perf_type_id = 5;
 pub const PERF_TYPE_MAX: perf_type_id = 6;
-pub type perf_type_id = ::std::os::raw::c_uint;
+pub type perf_type_id = ::core::ffi::c_uint;
 pub const PERF_COUNT_HW_CPU_CYCLES: perf_hw_id = 0;
 pub const PERF_COUNT_HW_INSTRUCTIONS: perf_hw_id = 1;
 pub const PERF_COUNT_HW_CACHE_REFERENCES: perf_hw_id = 2;
@@ -424,7 +526,7 @@ pub const PERF_COUNT_HW_STALLED_CYCLES_FRONTEND: perf_hw_id = 7;
 pub const PERF_COUNT_HW_STALLED_CYCLES_BACKEND: perf_hw_id = 8;
 pub const PERF_COUNT_HW_REF_CPU_CYCLES: perf_hw_id = 9;
 pub const PERF_COUNT_HW_MAX: perf_hw_id = 10;
-pub type perf_hw_id = ::std::os::raw::c_uint;
+pub type perf_hw_id = ::core::ffi::c_uint;
 pub const PERF_COUNT_HW_CACHE_L1D: perf_hw_cache_id = 0;
 pub const PERF_COUNT_HW_CACHE_L1I: perf_hw_cache_id = 1;
 pub const PERF_COUNT_HW_CACHE_LL: perf_hw_cache_id = 2;
@@ -433,16 +535,16 @@ pub const PERF_COUNT_HW_CACHE_ITLB: perf_hw_cache_id = 4;
 pub const PERF_COUNT_HW_CACHE_BPU: perf_hw_cache_id = 5;
 pub const PERF_COUNT_HW_CACHE_NODE: perf_hw_cache_id = 6;
 pub const PERF_COUNT_HW_CACHE_MAX: perf_hw_cache_id = 7;
-pub type perf_hw_cache_id = ::std::os::raw::c_uint;
+pub type perf_hw_cache_id = ::core::ffi::c_uint;
 pub const PERF_COUNT_HW_CACHE_OP_READ: perf_hw_cache_op_id = 0;
 pub const PERF_COUNT_HW_CACHE_OP_WRITE: perf_hw_cache_op_id = 1;
 pub const PERF_COUNT_HW_CACHE_OP_PREFETCH: perf_hw_cache_op_id = 2;
 pub const PERF_COUNT_HW_CACHE_OP_MAX: perf_hw_cache_op_id = 3;
-pub type perf_hw_cache_op_id = ::std::os::raw::c_uint;
+pub type perf_hw_cache_op_id = ::core::ffi::c_uint;
 pub const PERF_COUNT_HW_CACHE_RESULT_ACCESS: perf_hw_cache_op_result_id = 0;
 pub const PERF_COUNT_HW_CACHE_RESULT_MISS: perf_hw_cache_op_result_id = 1;
 pub const PERF_COUNT_HW_CACHE_RESULT_MAX: perf_hw_cache_op_result_id = 2;
-pub type perf_hw_cache_op_result_id = ::std::os::raw::c_uint;
+pub type perf_hw_cache_op_result_id = ::core::ffi::c_uint;
 pub const PERF_COUNT_SW_CPU_CLOCK: perf_sw_ids = 0;
 pub const PERF_COUNT_SW_TASK_CLOCK: perf_sw_ids = 1;
 pub const PERF_COUNT_SW_PAGE_FAULTS: perf_sw_ids = 2;
@@ -456,7 +558,7 @@ pub const PERF_COUNT_SW_DUMMY: perf_sw_ids = 9;
 pub const PERF_COUNT_SW_BPF_OUTPUT: perf_sw_ids = 10;
 pub const PERF_COUNT_SW_CGROUP_SWITCHES: perf_sw_ids = 11;
 pub const PERF_COUNT_SW_MAX: perf_sw_ids = 12;
-pub type perf_sw_ids = ::std::os::raw::c_uint;
+pub type perf_sw_ids = ::core::ffi::c_uint;
 pub const PERF_SAMPLE_IP: perf_event_sample_format = 1;
 pub const PERF_SAMPLE_TID: perf_event_sample_format = 2;
 pub const PERF_SAMPLE_TIME: perf_event_sample_format = 4;
@@ -483,7 +585,7 @@ pub const PERF_SAMPLE_DATA_PAGE_SIZE: perf_event_sample_format = 4194304;
 pub const PERF_SAMPLE_CODE_PAGE_SIZE: perf_event_sample_format = 8388608;
 pub const PERF_SAMPLE_WEIGHT_STRUCT: perf_event_sample_format = 16777216;
 pub const PERF_SAMPLE_MAX: perf_event_sample_format = 33554432;
-pub type perf_event_sample_format = ::std::os::raw::c_uint;
+pub type perf_event_sample_format = ::core::ffi::c_uint;
 pub const PERF_SAMPLE_BRANCH_USER_SHIFT: perf_branch_sample_type_shift = 0;
 pub const PERF_SAMPLE_BRANCH_KERNEL_SHIFT: perf_branch_sample_type_shift = 1;
 pub const PERF_SAMPLE_BRANCH_HV_SHIFT: perf_branch_sample_type_shift = 2;
@@ -505,7 +607,7 @@ pub const PERF_SAMPLE_BRANCH_HW_INDEX_SHIFT: perf_branch_sample_type_shift = 17;
 pub const PERF_SAMPLE_BRANCH_PRIV_SAVE_SHIFT: perf_branch_sample_type_shift = 18;
 pub const PERF_SAMPLE_BRANCH_COUNTERS_SHIFT: perf_branch_sample_type_shift = 19;
 pub const PERF_SAMPLE_BRANCH_MAX_SHIFT: perf_branch_sample_type_shift = 20;
-pub type perf_branch_sample_type_shift = ::std::os::raw::c_uint;
+pub type perf_branch_sample_type_shift = ::core::ffi::c_uint;
 pub const PERF_SAMPLE_BRANCH_USER: perf_branch_sample_type = 1;
 pub const PERF_SAMPLE_BRANCH_KERNEL: perf_branch_sample_type = 2;
 pub const PERF_SAMPLE_BRANCH_HV: perf_branch_sample_type = 4;
@@ -527,7 +629,7 @@ pub const PERF_SAMPLE_BRANCH_HW_INDEX: perf_branch_sample_type = 131072;
 pub const PERF_SAMPLE_BRANCH_PRIV_SAVE: perf_branch_sample_type = 262144;
 pub const PERF_SAMPLE_BRANCH_COUNTERS: perf_branch_sample_type = 524288;
 pub const PERF_SAMPLE_BRANCH_MAX: perf_branch_sample_type = 1048576;
-pub type perf_branch_sample_type = ::std::os::raw::c_uint;
+pub type perf_branch_sample_type = ::core::ffi::c_uint;
 pub const PERF_BR_UNKNOWN: _bindgen_ty_55 = 0;
 pub const PERF_BR_COND: _bindgen_ty_55 = 1;
 pub const PERF_BR_UNCOND: _bindgen_ty_55 = 2;
@@ -545,13 +647,13 @@ pub const PERF_BR_SERROR: _bindgen_ty_55 = 13;
 pub const PERF_BR_NO_TX: _bindgen_ty_55 = 14;
 pub const PERF_BR_EXTEND_ABI: _bindgen_ty_55 = 15;
 pub const PERF_BR_MAX: _bindgen_ty_55 = 16;
-pub type _bindgen_ty_55 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_55 = ::core::ffi::c_uint;
 pub const PERF_BR_SPEC_NA: _bindgen_ty_56 = 0;
 pub const PERF_BR_SPEC_WRONG_PATH: _bindgen_ty_56 = 1;
 pub const PERF_BR_NON_SPEC_CORRECT_PATH: _bindgen_ty_56 = 2;
 pub const PERF_BR_SPEC_CORRECT_PATH: _bindgen_ty_56 = 3;
 pub const PERF_BR_SPEC_MAX: _bindgen_ty_56 = 4;
-pub type _bindgen_ty_56 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_56 = ::core::ffi::c_uint;
 pub const PERF_BR_NEW_FAULT_ALGN: _bindgen_ty_57 = 0;
 pub const PERF_BR_NEW_FAULT_DATA: _bindgen_ty_57 = 1;
 pub const PERF_BR_NEW_FAULT_INST: _bindgen_ty_57 = 2;
@@ -561,16 +663,16 @@ pub const PERF_BR_NEW_ARCH_3: _bindgen_ty_57 = 5;
 pub const PERF_BR_NEW_ARCH_4: _bindgen_ty_57 = 6;
 pub const PERF_BR_NEW_ARCH_5: _bindgen_ty_57 = 7;
 pub const PERF_BR_NEW_MAX: _bindgen_ty_57 = 8;
-pub type _bindgen_ty_57 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_57 = ::core::ffi::c_uint;
 pub const PERF_BR_PRIV_UNKNOWN: _bindgen_ty_58 = 0;
 pub const PERF_BR_PRIV_USER: _bindgen_ty_58 = 1;
 pub const PERF_BR_PRIV_KERNEL: _bindgen_ty_58 = 2;
 pub const PERF_BR_PRIV_HV: _bindgen_ty_58 = 3;
-pub type _bindgen_ty_58 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_58 = ::core::ffi::c_uint;
 pub const PERF_SAMPLE_REGS_ABI_NONE: perf_sample_regs_abi = 0;
 pub const PERF_SAMPLE_REGS_ABI_32: perf_sample_regs_abi = 1;
 pub const PERF_SAMPLE_REGS_ABI_64: perf_sample_regs_abi = 2;
-pub type perf_sample_regs_abi = ::std::os::raw::c_uint;
+pub type perf_sample_regs_abi = ::core::ffi::c_uint;
 pub const PERF_TXN_ELISION: _bindgen_ty_59 = 1;
 pub const PERF_TXN_TRANSACTION: _bindgen_ty_59 = 2;
 pub const PERF_TXN_SYNC: _bindgen_ty_59 = 4;
@@ -582,14 +684,14 @@ pub const PERF_TXN_CAPACITY_READ: _bindgen_ty_59 = 128;
 pub const PERF_TXN_MAX: _bindgen_ty_59 = 256;
 pub const PERF_TXN_ABORT_MASK: _bindgen_ty_59 = 18446744069414584320;
 pub const PERF_TXN_ABORT_SHIFT: _bindgen_ty_59 = 32;
-pub type _bindgen_ty_59 = ::std::os::raw::c_ulong;
+pub type _bindgen_ty_59 = ::core::ffi::c_ulong;
 pub const PERF_FORMAT_TOTAL_TIME_ENABLED: perf_event_read_format = 1;
 pub const PERF_FORMAT_TOTAL_TIME_RUNNING: perf_event_read_format = 2;
 pub const PERF_FORMAT_ID: perf_event_read_format = 4;
 pub const PERF_FORMAT_GROUP: perf_event_read_format = 8;
 pub const PERF_FORMAT_LOST: perf_event_read_format = 16;
 pub const PERF_FORMAT_MAX: perf_event_read_format = 32;
-pub type perf_event_read_format = ::std::os::raw::c_uint;
+pub type perf_event_read_format = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct perf_event_attr {
@@ -626,9 +728,9 @@ pub union perf_event_attr__bindgen_ty_1 {
 }
 impl Default for perf_event_attr__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -641,9 +743,9 @@ pub union perf_event_attr__bindgen_ty_2 {
 }
 impl Default for perf_event_attr__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -658,9 +760,9 @@ pub union perf_event_attr__bindgen_ty_3 {
 }
 impl Default for perf_event_attr__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -675,18 +777,18 @@ pub union perf_event_attr__bindgen_ty_4 {
 }
 impl Default for perf_event_attr__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for perf_event_attr {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -694,20 +796,20 @@ impl Default for perf_event_attr {
 impl perf_event_attr {
     #[inline]
     pub fn disabled(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_disabled(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn disabled_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 0usize,
                 1u8,
             ) as u64)
@@ -716,9 +818,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_disabled_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 0usize,
                 1u8,
                 val as u64,
@@ -727,20 +829,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn inherit(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_inherit(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn inherit_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 1usize,
                 1u8,
             ) as u64)
@@ -749,9 +851,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_inherit_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 1usize,
                 1u8,
                 val as u64,
@@ -760,20 +862,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn pinned(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_pinned(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn pinned_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 2usize,
                 1u8,
             ) as u64)
@@ -782,9 +884,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_pinned_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 2usize,
                 1u8,
                 val as u64,
@@ -793,20 +895,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclusive(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclusive(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(3usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclusive_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 3usize,
                 1u8,
             ) as u64)
@@ -815,9 +917,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclusive_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 3usize,
                 1u8,
                 val as u64,
@@ -826,20 +928,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_user(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_user(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(4usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_user_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 4usize,
                 1u8,
             ) as u64)
@@ -848,9 +950,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_user_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 4usize,
                 1u8,
                 val as u64,
@@ -859,20 +961,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_kernel(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_kernel(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(5usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_kernel_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 5usize,
                 1u8,
             ) as u64)
@@ -881,9 +983,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_kernel_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 5usize,
                 1u8,
                 val as u64,
@@ -892,20 +994,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_hv(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(6usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(6usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_hv(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(6usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_hv_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 6usize,
                 1u8,
             ) as u64)
@@ -914,9 +1016,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_hv_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 6usize,
                 1u8,
                 val as u64,
@@ -925,20 +1027,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_idle(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(7usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_idle(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(7usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_idle_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 7usize,
                 1u8,
             ) as u64)
@@ -947,9 +1049,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_idle_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 7usize,
                 1u8,
                 val as u64,
@@ -958,20 +1060,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn mmap(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(8usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(8usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_mmap(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(8usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mmap_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 8usize,
                 1u8,
             ) as u64)
@@ -980,9 +1082,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_mmap_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 8usize,
                 1u8,
                 val as u64,
@@ -991,20 +1093,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn comm(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(9usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(9usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_comm(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(9usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn comm_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 9usize,
                 1u8,
             ) as u64)
@@ -1013,9 +1115,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_comm_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 9usize,
                 1u8,
                 val as u64,
@@ -1024,20 +1126,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn freq(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(10usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(10usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_freq(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(10usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn freq_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 10usize,
                 1u8,
             ) as u64)
@@ -1046,9 +1148,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_freq_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 10usize,
                 1u8,
                 val as u64,
@@ -1057,20 +1159,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn inherit_stat(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(11usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(11usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_inherit_stat(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(11usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn inherit_stat_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 11usize,
                 1u8,
             ) as u64)
@@ -1079,9 +1181,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_inherit_stat_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 11usize,
                 1u8,
                 val as u64,
@@ -1090,20 +1192,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn enable_on_exec(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(12usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(12usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_enable_on_exec(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(12usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn enable_on_exec_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 12usize,
                 1u8,
             ) as u64)
@@ -1112,9 +1214,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_enable_on_exec_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 12usize,
                 1u8,
                 val as u64,
@@ -1123,20 +1225,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn task(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(13usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(13usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_task(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(13usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn task_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 13usize,
                 1u8,
             ) as u64)
@@ -1145,9 +1247,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_task_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 13usize,
                 1u8,
                 val as u64,
@@ -1156,20 +1258,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn watermark(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(14usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(14usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_watermark(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(14usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn watermark_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 14usize,
                 1u8,
             ) as u64)
@@ -1178,9 +1280,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_watermark_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 14usize,
                 1u8,
                 val as u64,
@@ -1189,20 +1291,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn precise_ip(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(15usize, 2u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(15usize, 2u8) as u64) }
     }
     #[inline]
     pub fn set_precise_ip(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(15usize, 2u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn precise_ip_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 15usize,
                 2u8,
             ) as u64)
@@ -1211,9 +1313,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_precise_ip_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 15usize,
                 2u8,
                 val as u64,
@@ -1222,20 +1324,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn mmap_data(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(17usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(17usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_mmap_data(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(17usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mmap_data_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 17usize,
                 1u8,
             ) as u64)
@@ -1244,9 +1346,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_mmap_data_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 17usize,
                 1u8,
                 val as u64,
@@ -1255,20 +1357,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn sample_id_all(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(18usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(18usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_sample_id_all(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(18usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn sample_id_all_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 18usize,
                 1u8,
             ) as u64)
@@ -1277,9 +1379,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_sample_id_all_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 18usize,
                 1u8,
                 val as u64,
@@ -1288,20 +1390,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_host(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(19usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(19usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_host(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(19usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_host_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 19usize,
                 1u8,
             ) as u64)
@@ -1310,9 +1412,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_host_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 19usize,
                 1u8,
                 val as u64,
@@ -1321,20 +1423,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_guest(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(20usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(20usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_guest(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(20usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_guest_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 20usize,
                 1u8,
             ) as u64)
@@ -1343,9 +1445,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_guest_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 20usize,
                 1u8,
                 val as u64,
@@ -1354,20 +1456,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_callchain_kernel(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(21usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(21usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_callchain_kernel(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(21usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_callchain_kernel_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 21usize,
                 1u8,
             ) as u64)
@@ -1376,9 +1478,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_callchain_kernel_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 21usize,
                 1u8,
                 val as u64,
@@ -1387,20 +1489,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn exclude_callchain_user(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(22usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(22usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_exclude_callchain_user(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(22usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn exclude_callchain_user_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 22usize,
                 1u8,
             ) as u64)
@@ -1409,9 +1511,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_exclude_callchain_user_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 22usize,
                 1u8,
                 val as u64,
@@ -1420,20 +1522,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn mmap2(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(23usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(23usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_mmap2(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(23usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mmap2_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 23usize,
                 1u8,
             ) as u64)
@@ -1442,9 +1544,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_mmap2_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 23usize,
                 1u8,
                 val as u64,
@@ -1453,20 +1555,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn comm_exec(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(24usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(24usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_comm_exec(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(24usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn comm_exec_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 24usize,
                 1u8,
             ) as u64)
@@ -1475,9 +1577,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_comm_exec_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 24usize,
                 1u8,
                 val as u64,
@@ -1486,20 +1588,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn use_clockid(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(25usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(25usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_use_clockid(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(25usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn use_clockid_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 25usize,
                 1u8,
             ) as u64)
@@ -1508,9 +1610,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_use_clockid_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 25usize,
                 1u8,
                 val as u64,
@@ -1519,20 +1621,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn context_switch(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(26usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(26usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_context_switch(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(26usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn context_switch_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 26usize,
                 1u8,
             ) as u64)
@@ -1541,9 +1643,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_context_switch_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 26usize,
                 1u8,
                 val as u64,
@@ -1552,20 +1654,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn write_backward(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(27usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(27usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_write_backward(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(27usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn write_backward_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 27usize,
                 1u8,
             ) as u64)
@@ -1574,9 +1676,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_write_backward_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 27usize,
                 1u8,
                 val as u64,
@@ -1585,20 +1687,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn namespaces(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(28usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(28usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_namespaces(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(28usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn namespaces_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 28usize,
                 1u8,
             ) as u64)
@@ -1607,9 +1709,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_namespaces_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 28usize,
                 1u8,
                 val as u64,
@@ -1618,20 +1720,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn ksymbol(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(29usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(29usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_ksymbol(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(29usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn ksymbol_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 29usize,
                 1u8,
             ) as u64)
@@ -1640,9 +1742,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_ksymbol_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 29usize,
                 1u8,
                 val as u64,
@@ -1651,20 +1753,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn bpf_event(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(30usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(30usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_bpf_event(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(30usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn bpf_event_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 30usize,
                 1u8,
             ) as u64)
@@ -1673,9 +1775,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_bpf_event_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 30usize,
                 1u8,
                 val as u64,
@@ -1684,20 +1786,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn aux_output(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(31usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(31usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_aux_output(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(31usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn aux_output_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 31usize,
                 1u8,
             ) as u64)
@@ -1706,9 +1808,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_aux_output_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 31usize,
                 1u8,
                 val as u64,
@@ -1717,20 +1819,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn cgroup(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(32usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(32usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cgroup(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(32usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cgroup_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 32usize,
                 1u8,
             ) as u64)
@@ -1739,9 +1841,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_cgroup_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 32usize,
                 1u8,
                 val as u64,
@@ -1750,20 +1852,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn text_poke(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(33usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(33usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_text_poke(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(33usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn text_poke_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 33usize,
                 1u8,
             ) as u64)
@@ -1772,9 +1874,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_text_poke_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 33usize,
                 1u8,
                 val as u64,
@@ -1783,20 +1885,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn build_id(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(34usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(34usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_build_id(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(34usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn build_id_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 34usize,
                 1u8,
             ) as u64)
@@ -1805,9 +1907,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_build_id_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 34usize,
                 1u8,
                 val as u64,
@@ -1816,20 +1918,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn inherit_thread(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(35usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(35usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_inherit_thread(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(35usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn inherit_thread_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 35usize,
                 1u8,
             ) as u64)
@@ -1838,9 +1940,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_inherit_thread_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 35usize,
                 1u8,
                 val as u64,
@@ -1849,20 +1951,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn remove_on_exec(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(36usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(36usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_remove_on_exec(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(36usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn remove_on_exec_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 36usize,
                 1u8,
             ) as u64)
@@ -1871,9 +1973,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_remove_on_exec_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 36usize,
                 1u8,
                 val as u64,
@@ -1882,20 +1984,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn sigtrap(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(37usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(37usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_sigtrap(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(37usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn sigtrap_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 37usize,
                 1u8,
             ) as u64)
@@ -1904,9 +2006,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set_sigtrap_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 37usize,
                 1u8,
                 val as u64,
@@ -1915,20 +2017,20 @@ impl perf_event_attr {
     }
     #[inline]
     pub fn __reserved_1(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(38usize, 26u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(38usize, 26u8) as u64) }
     }
     #[inline]
     pub fn set___reserved_1(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(38usize, 26u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn __reserved_1_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 38usize,
                 26u8,
             ) as u64)
@@ -1937,9 +2039,9 @@ impl perf_event_attr {
     #[inline]
     pub unsafe fn set___reserved_1_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 38usize,
                 26u8,
                 val as u64,
@@ -1989,157 +2091,157 @@ impl perf_event_attr {
     ) -> __BindgenBitfieldUnit<[u8; 8usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 8usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let disabled: u64 = unsafe { ::std::mem::transmute(disabled) };
+            let disabled: u64 = unsafe { ::core::mem::transmute(disabled) };
             disabled as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
-            let inherit: u64 = unsafe { ::std::mem::transmute(inherit) };
+            let inherit: u64 = unsafe { ::core::mem::transmute(inherit) };
             inherit as u64
         });
         __bindgen_bitfield_unit.set(2usize, 1u8, {
-            let pinned: u64 = unsafe { ::std::mem::transmute(pinned) };
+            let pinned: u64 = unsafe { ::core::mem::transmute(pinned) };
             pinned as u64
         });
         __bindgen_bitfield_unit.set(3usize, 1u8, {
-            let exclusive: u64 = unsafe { ::std::mem::transmute(exclusive) };
+            let exclusive: u64 = unsafe { ::core::mem::transmute(exclusive) };
             exclusive as u64
         });
         __bindgen_bitfield_unit.set(4usize, 1u8, {
-            let exclude_user: u64 = unsafe { ::std::mem::transmute(exclude_user) };
+            let exclude_user: u64 = unsafe { ::core::mem::transmute(exclude_user) };
             exclude_user as u64
         });
         __bindgen_bitfield_unit.set(5usize, 1u8, {
-            let exclude_kernel: u64 = unsafe { ::std::mem::transmute(exclude_kernel) };
+            let exclude_kernel: u64 = unsafe { ::core::mem::transmute(exclude_kernel) };
             exclude_kernel as u64
         });
         __bindgen_bitfield_unit.set(6usize, 1u8, {
-            let exclude_hv: u64 = unsafe { ::std::mem::transmute(exclude_hv) };
+            let exclude_hv: u64 = unsafe { ::core::mem::transmute(exclude_hv) };
             exclude_hv as u64
         });
         __bindgen_bitfield_unit.set(7usize, 1u8, {
-            let exclude_idle: u64 = unsafe { ::std::mem::transmute(exclude_idle) };
+            let exclude_idle: u64 = unsafe { ::core::mem::transmute(exclude_idle) };
             exclude_idle as u64
         });
         __bindgen_bitfield_unit.set(8usize, 1u8, {
-            let mmap: u64 = unsafe { ::std::mem::transmute(mmap) };
+            let mmap: u64 = unsafe { ::core::mem::transmute(mmap) };
             mmap as u64
         });
         __bindgen_bitfield_unit.set(9usize, 1u8, {
-            let comm: u64 = unsafe { ::std::mem::transmute(comm) };
+            let comm: u64 = unsafe { ::core::mem::transmute(comm) };
             comm as u64
         });
         __bindgen_bitfield_unit.set(10usize, 1u8, {
-            let freq: u64 = unsafe { ::std::mem::transmute(freq) };
+            let freq: u64 = unsafe { ::core::mem::transmute(freq) };
             freq as u64
         });
         __bindgen_bitfield_unit.set(11usize, 1u8, {
-            let inherit_stat: u64 = unsafe { ::std::mem::transmute(inherit_stat) };
+            let inherit_stat: u64 = unsafe { ::core::mem::transmute(inherit_stat) };
             inherit_stat as u64
         });
         __bindgen_bitfield_unit.set(12usize, 1u8, {
-            let enable_on_exec: u64 = unsafe { ::std::mem::transmute(enable_on_exec) };
+            let enable_on_exec: u64 = unsafe { ::core::mem::transmute(enable_on_exec) };
             enable_on_exec as u64
         });
         __bindgen_bitfield_unit.set(13usize, 1u8, {
-            let task: u64 = unsafe { ::std::mem::transmute(task) };
+            let task: u64 = unsafe { ::core::mem::transmute(task) };
             task as u64
         });
         __bindgen_bitfield_unit.set(14usize, 1u8, {
-            let watermark: u64 = unsafe { ::std::mem::transmute(watermark) };
+            let watermark: u64 = unsafe { ::core::mem::transmute(watermark) };
             watermark as u64
         });
         __bindgen_bitfield_unit.set(15usize, 2u8, {
-            let precise_ip: u64 = unsafe { ::std::mem::transmute(precise_ip) };
+            let precise_ip: u64 = unsafe { ::core::mem::transmute(precise_ip) };
             precise_ip as u64
         });
         __bindgen_bitfield_unit.set(17usize, 1u8, {
-            let mmap_data: u64 = unsafe { ::std::mem::transmute(mmap_data) };
+            let mmap_data: u64 = unsafe { ::core::mem::transmute(mmap_data) };
             mmap_data as u64
         });
         __bindgen_bitfield_unit.set(18usize, 1u8, {
-            let sample_id_all: u64 = unsafe { ::std::mem::transmute(sample_id_all) };
+            let sample_id_all: u64 = unsafe { ::core::mem::transmute(sample_id_all) };
             sample_id_all as u64
         });
         __bindgen_bitfield_unit.set(19usize, 1u8, {
-            let exclude_host: u64 = unsafe { ::std::mem::transmute(exclude_host) };
+            let exclude_host: u64 = unsafe { ::core::mem::transmute(exclude_host) };
             exclude_host as u64
         });
         __bindgen_bitfield_unit.set(20usize, 1u8, {
-            let exclude_guest: u64 = unsafe { ::std::mem::transmute(exclude_guest) };
+            let exclude_guest: u64 = unsafe { ::core::mem::transmute(exclude_guest) };
             exclude_guest as u64
         });
         __bindgen_bitfield_unit.set(21usize, 1u8, {
             let exclude_callchain_kernel: u64 =
-                unsafe { ::std::mem::transmute(exclude_callchain_kernel) };
+                unsafe { ::core::mem::transmute(exclude_callchain_kernel) };
             exclude_callchain_kernel as u64
         });
         __bindgen_bitfield_unit.set(22usize, 1u8, {
             let exclude_callchain_user: u64 =
-                unsafe { ::std::mem::transmute(exclude_callchain_user) };
+                unsafe { ::core::mem::transmute(exclude_callchain_user) };
             exclude_callchain_user as u64
         });
         __bindgen_bitfield_unit.set(23usize, 1u8, {
-            let mmap2: u64 = unsafe { ::std::mem::transmute(mmap2) };
+            let mmap2: u64 = unsafe { ::core::mem::transmute(mmap2) };
             mmap2 as u64
         });
         __bindgen_bitfield_unit.set(24usize, 1u8, {
-            let comm_exec: u64 = unsafe { ::std::mem::transmute(comm_exec) };
+            let comm_exec: u64 = unsafe { ::core::mem::transmute(comm_exec) };
             comm_exec as u64
         });
         __bindgen_bitfield_unit.set(25usize, 1u8, {
-            let use_clockid: u64 = unsafe { ::std::mem::transmute(use_clockid) };
+            let use_clockid: u64 = unsafe { ::core::mem::transmute(use_clockid) };
             use_clockid as u64
         });
         __bindgen_bitfield_unit.set(26usize, 1u8, {
-            let context_switch: u64 = unsafe { ::std::mem::transmute(context_switch) };
+            let context_switch: u64 = unsafe { ::core::mem::transmute(context_switch) };
             context_switch as u64
         });
         __bindgen_bitfield_unit.set(27usize, 1u8, {
-            let write_backward: u64 = unsafe { ::std::mem::transmute(write_backward) };
+            let write_backward: u64 = unsafe { ::core::mem::transmute(write_backward) };
             write_backward as u64
         });
         __bindgen_bitfield_unit.set(28usize, 1u8, {
-            let namespaces: u64 = unsafe { ::std::mem::transmute(namespaces) };
+            let namespaces: u64 = unsafe { ::core::mem::transmute(namespaces) };
             namespaces as u64
         });
         __bindgen_bitfield_unit.set(29usize, 1u8, {
-            let ksymbol: u64 = unsafe { ::std::mem::transmute(ksymbol) };
+            let ksymbol: u64 = unsafe { ::core::mem::transmute(ksymbol) };
             ksymbol as u64
         });
         __bindgen_bitfield_unit.set(30usize, 1u8, {
-            let bpf_event: u64 = unsafe { ::std::mem::transmute(bpf_event) };
+            let bpf_event: u64 = unsafe { ::core::mem::transmute(bpf_event) };
             bpf_event as u64
         });
         __bindgen_bitfield_unit.set(31usize, 1u8, {
-            let aux_output: u64 = unsafe { ::std::mem::transmute(aux_output) };
+            let aux_output: u64 = unsafe { ::core::mem::transmute(aux_output) };
             aux_output as u64
         });
         __bindgen_bitfield_unit.set(32usize, 1u8, {
-            let cgroup: u64 = unsafe { ::std::mem::transmute(cgroup) };
+            let cgroup: u64 = unsafe { ::core::mem::transmute(cgroup) };
             cgroup as u64
         });
         __bindgen_bitfield_unit.set(33usize, 1u8, {
-            let text_poke: u64 = unsafe { ::std::mem::transmute(text_poke) };
+            let text_poke: u64 = unsafe { ::core::mem::transmute(text_poke) };
             text_poke as u64
         });
         __bindgen_bitfield_unit.set(34usize, 1u8, {
-            let build_id: u64 = unsafe { ::std::mem::transmute(build_id) };
+            let build_id: u64 = unsafe { ::core::mem::transmute(build_id) };
             build_id as u64
         });
         __bindgen_bitfield_unit.set(35usize, 1u8, {
-            let inherit_thread: u64 = unsafe { ::std::mem::transmute(inherit_thread) };
+            let inherit_thread: u64 = unsafe { ::core::mem::transmute(inherit_thread) };
             inherit_thread as u64
         });
         __bindgen_bitfield_unit.set(36usize, 1u8, {
-            let remove_on_exec: u64 = unsafe { ::std::mem::transmute(remove_on_exec) };
+            let remove_on_exec: u64 = unsafe { ::core::mem::transmute(remove_on_exec) };
             remove_on_exec as u64
         });
         __bindgen_bitfield_unit.set(37usize, 1u8, {
-            let sigtrap: u64 = unsafe { ::std::mem::transmute(sigtrap) };
+            let sigtrap: u64 = unsafe { ::core::mem::transmute(sigtrap) };
             sigtrap as u64
         });
         __bindgen_bitfield_unit.set(38usize, 26u8, {
-            let __reserved_1: u64 = unsafe { ::std::mem::transmute(__reserved_1) };
+            let __reserved_1: u64 = unsafe { ::core::mem::transmute(__reserved_1) };
             __reserved_1 as u64
         });
         __bindgen_bitfield_unit
@@ -2153,7 +2255,7 @@ pub struct perf_event_query_bpf {
     pub ids: __IncompleteArrayField<__u32>,
 }
 pub const PERF_IOC_FLAG_GROUP: perf_event_ioc_flags = 1;
-pub type perf_event_ioc_flags = ::std::os::raw::c_uint;
+pub type perf_event_ioc_flags = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct perf_event_mmap_page {
@@ -2199,20 +2301,20 @@ pub struct perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
 impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub fn cap_bit0(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cap_bit0(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_bit0_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 0usize,
                 1u8,
             ) as u64)
@@ -2221,9 +2323,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_bit0_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 0usize,
                 1u8,
                 val as u64,
@@ -2232,20 +2334,20 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     }
     #[inline]
     pub fn cap_bit0_is_deprecated(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cap_bit0_is_deprecated(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_bit0_is_deprecated_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 1usize,
                 1u8,
             ) as u64)
@@ -2254,9 +2356,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_bit0_is_deprecated_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 1usize,
                 1u8,
                 val as u64,
@@ -2265,20 +2367,20 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     }
     #[inline]
     pub fn cap_user_rdpmc(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cap_user_rdpmc(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_user_rdpmc_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 2usize,
                 1u8,
             ) as u64)
@@ -2287,9 +2389,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_user_rdpmc_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 2usize,
                 1u8,
                 val as u64,
@@ -2298,20 +2400,20 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     }
     #[inline]
     pub fn cap_user_time(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cap_user_time(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(3usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_user_time_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 3usize,
                 1u8,
             ) as u64)
@@ -2320,9 +2422,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_user_time_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 3usize,
                 1u8,
                 val as u64,
@@ -2331,20 +2433,20 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     }
     #[inline]
     pub fn cap_user_time_zero(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cap_user_time_zero(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(4usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_user_time_zero_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 4usize,
                 1u8,
             ) as u64)
@@ -2353,9 +2455,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_user_time_zero_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 4usize,
                 1u8,
                 val as u64,
@@ -2364,20 +2466,20 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     }
     #[inline]
     pub fn cap_user_time_short(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(5usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_cap_user_time_short(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(5usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_user_time_short_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 5usize,
                 1u8,
             ) as u64)
@@ -2386,9 +2488,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_user_time_short_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 5usize,
                 1u8,
                 val as u64,
@@ -2397,20 +2499,20 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     }
     #[inline]
     pub fn cap_____res(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(6usize, 58u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(6usize, 58u8) as u64) }
     }
     #[inline]
     pub fn set_cap_____res(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(6usize, 58u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cap_____res_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 6usize,
                 58u8,
             ) as u64)
@@ -2419,9 +2521,9 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_cap_____res_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 6usize,
                 58u8,
                 val as u64,
@@ -2440,32 +2542,32 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
     ) -> __BindgenBitfieldUnit<[u8; 8usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 8usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let cap_bit0: u64 = unsafe { ::std::mem::transmute(cap_bit0) };
+            let cap_bit0: u64 = unsafe { ::core::mem::transmute(cap_bit0) };
             cap_bit0 as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
             let cap_bit0_is_deprecated: u64 =
-                unsafe { ::std::mem::transmute(cap_bit0_is_deprecated) };
+                unsafe { ::core::mem::transmute(cap_bit0_is_deprecated) };
             cap_bit0_is_deprecated as u64
         });
         __bindgen_bitfield_unit.set(2usize, 1u8, {
-            let cap_user_rdpmc: u64 = unsafe { ::std::mem::transmute(cap_user_rdpmc) };
+            let cap_user_rdpmc: u64 = unsafe { ::core::mem::transmute(cap_user_rdpmc) };
             cap_user_rdpmc as u64
         });
         __bindgen_bitfield_unit.set(3usize, 1u8, {
-            let cap_user_time: u64 = unsafe { ::std::mem::transmute(cap_user_time) };
+            let cap_user_time: u64 = unsafe { ::core::mem::transmute(cap_user_time) };
             cap_user_time as u64
         });
         __bindgen_bitfield_unit.set(4usize, 1u8, {
-            let cap_user_time_zero: u64 = unsafe { ::std::mem::transmute(cap_user_time_zero) };
+            let cap_user_time_zero: u64 = unsafe { ::core::mem::transmute(cap_user_time_zero) };
             cap_user_time_zero as u64
         });
         __bindgen_bitfield_unit.set(5usize, 1u8, {
-            let cap_user_time_short: u64 = unsafe { ::std::mem::transmute(cap_user_time_short) };
+            let cap_user_time_short: u64 = unsafe { ::core::mem::transmute(cap_user_time_short) };
             cap_user_time_short as u64
         });
         __bindgen_bitfield_unit.set(6usize, 58u8, {
-            let cap_____res: u64 = unsafe { ::std::mem::transmute(cap_____res) };
+            let cap_____res: u64 = unsafe { ::core::mem::transmute(cap_____res) };
             cap_____res as u64
         });
         __bindgen_bitfield_unit
@@ -2473,24 +2575,25 @@ impl perf_event_mmap_page__bindgen_ty_1__bindgen_ty_1 {
 }
 impl Default for perf_event_mmap_page__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for perf_event_mmap_page {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct perf_event_header {
     pub type_: __u32,
     pub misc: __u16,
@@ -2524,17 +2627,17 @@ pub const PERF_RECORD_CGROUP: perf_event_type = 19;
 pub const PERF_RECORD_TEXT_POKE: perf_event_type = 20;
 pub const PERF_RECORD_AUX_OUTPUT_HW_ID: perf_event_type = 21;
 pub const PERF_RECORD_MAX: perf_event_type = 22;
-pub type perf_event_type = ::std::os::raw::c_uint;
+pub type perf_event_type = ::core::ffi::c_uint;
 pub const PERF_RECORD_KSYMBOL_TYPE_UNKNOWN: perf_record_ksymbol_type = 0;
 pub const PERF_RECORD_KSYMBOL_TYPE_BPF: perf_record_ksymbol_type = 1;
 pub const PERF_RECORD_KSYMBOL_TYPE_OOL: perf_record_ksymbol_type = 2;
 pub const PERF_RECORD_KSYMBOL_TYPE_MAX: perf_record_ksymbol_type = 3;
-pub type perf_record_ksymbol_type = ::std::os::raw::c_uint;
+pub type perf_record_ksymbol_type = ::core::ffi::c_uint;
 pub const PERF_BPF_EVENT_UNKNOWN: perf_bpf_event_type = 0;
 pub const PERF_BPF_EVENT_PROG_LOAD: perf_bpf_event_type = 1;
 pub const PERF_BPF_EVENT_PROG_UNLOAD: perf_bpf_event_type = 2;
 pub const PERF_BPF_EVENT_MAX: perf_bpf_event_type = 3;
-pub type perf_bpf_event_type = ::std::os::raw::c_uint;
+pub type perf_bpf_event_type = ::core::ffi::c_uint;
 pub const PERF_CONTEXT_HV: perf_callchain_context = 18446744073709551584;
 pub const PERF_CONTEXT_KERNEL: perf_callchain_context = 18446744073709551488;
 pub const PERF_CONTEXT_USER: perf_callchain_context = 18446744073709551104;
@@ -2542,7 +2645,7 @@ pub const PERF_CONTEXT_GUEST: perf_callchain_context = 18446744073709549568;
 pub const PERF_CONTEXT_GUEST_KERNEL: perf_callchain_context = 18446744073709549440;
 pub const PERF_CONTEXT_GUEST_USER: perf_callchain_context = 18446744073709549056;
 pub const PERF_CONTEXT_MAX: perf_callchain_context = 18446744073709547521;
-pub type perf_callchain_context = ::std::os::raw::c_ulong;
+pub type perf_callchain_context = ::core::ffi::c_ulong;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union perf_mem_data_src {
@@ -2559,20 +2662,20 @@ pub struct perf_mem_data_src__bindgen_ty_1 {
 impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub fn mem_op(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 5u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 5u8) as u64) }
     }
     #[inline]
     pub fn set_mem_op(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 5u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_op_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 0usize,
                 5u8,
             ) as u64)
@@ -2581,9 +2684,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_op_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 0usize,
                 5u8,
                 val as u64,
@@ -2592,20 +2695,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_lvl(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(5usize, 14u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(5usize, 14u8) as u64) }
     }
     #[inline]
     pub fn set_mem_lvl(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(5usize, 14u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_lvl_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 5usize,
                 14u8,
             ) as u64)
@@ -2614,9 +2717,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_lvl_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 5usize,
                 14u8,
                 val as u64,
@@ -2625,20 +2728,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_snoop(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(19usize, 5u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(19usize, 5u8) as u64) }
     }
     #[inline]
     pub fn set_mem_snoop(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(19usize, 5u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_snoop_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 19usize,
                 5u8,
             ) as u64)
@@ -2647,9 +2750,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_snoop_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 19usize,
                 5u8,
                 val as u64,
@@ -2658,20 +2761,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_lock(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(24usize, 2u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(24usize, 2u8) as u64) }
     }
     #[inline]
     pub fn set_mem_lock(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(24usize, 2u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_lock_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 24usize,
                 2u8,
             ) as u64)
@@ -2680,9 +2783,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_lock_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 24usize,
                 2u8,
                 val as u64,
@@ -2691,20 +2794,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_dtlb(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(26usize, 7u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(26usize, 7u8) as u64) }
     }
     #[inline]
     pub fn set_mem_dtlb(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(26usize, 7u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_dtlb_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 26usize,
                 7u8,
             ) as u64)
@@ -2713,9 +2816,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_dtlb_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 26usize,
                 7u8,
                 val as u64,
@@ -2724,20 +2827,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_lvl_num(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(33usize, 4u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(33usize, 4u8) as u64) }
     }
     #[inline]
     pub fn set_mem_lvl_num(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(33usize, 4u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_lvl_num_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 33usize,
                 4u8,
             ) as u64)
@@ -2746,9 +2849,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_lvl_num_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 33usize,
                 4u8,
                 val as u64,
@@ -2757,20 +2860,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_remote(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(37usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(37usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_mem_remote(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(37usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_remote_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 37usize,
                 1u8,
             ) as u64)
@@ -2779,9 +2882,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_remote_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 37usize,
                 1u8,
                 val as u64,
@@ -2790,20 +2893,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_snoopx(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(38usize, 2u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(38usize, 2u8) as u64) }
     }
     #[inline]
     pub fn set_mem_snoopx(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(38usize, 2u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_snoopx_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 38usize,
                 2u8,
             ) as u64)
@@ -2812,9 +2915,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_snoopx_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 38usize,
                 2u8,
                 val as u64,
@@ -2823,20 +2926,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_blk(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(40usize, 3u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(40usize, 3u8) as u64) }
     }
     #[inline]
     pub fn set_mem_blk(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(40usize, 3u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_blk_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 40usize,
                 3u8,
             ) as u64)
@@ -2845,9 +2948,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_blk_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 40usize,
                 3u8,
                 val as u64,
@@ -2856,20 +2959,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_hops(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(43usize, 3u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(43usize, 3u8) as u64) }
     }
     #[inline]
     pub fn set_mem_hops(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(43usize, 3u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_hops_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 43usize,
                 3u8,
             ) as u64)
@@ -2878,9 +2981,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_hops_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 43usize,
                 3u8,
                 val as u64,
@@ -2889,20 +2992,20 @@ impl perf_mem_data_src__bindgen_ty_1 {
     }
     #[inline]
     pub fn mem_rsvd(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(46usize, 18u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(46usize, 18u8) as u64) }
     }
     #[inline]
     pub fn set_mem_rsvd(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(46usize, 18u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mem_rsvd_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 46usize,
                 18u8,
             ) as u64)
@@ -2911,9 +3014,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
     #[inline]
     pub unsafe fn set_mem_rsvd_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 46usize,
                 18u8,
                 val as u64,
@@ -2936,47 +3039,47 @@ impl perf_mem_data_src__bindgen_ty_1 {
     ) -> __BindgenBitfieldUnit<[u8; 8usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 8usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 5u8, {
-            let mem_op: u64 = unsafe { ::std::mem::transmute(mem_op) };
+            let mem_op: u64 = unsafe { ::core::mem::transmute(mem_op) };
             mem_op as u64
         });
         __bindgen_bitfield_unit.set(5usize, 14u8, {
-            let mem_lvl: u64 = unsafe { ::std::mem::transmute(mem_lvl) };
+            let mem_lvl: u64 = unsafe { ::core::mem::transmute(mem_lvl) };
             mem_lvl as u64
         });
         __bindgen_bitfield_unit.set(19usize, 5u8, {
-            let mem_snoop: u64 = unsafe { ::std::mem::transmute(mem_snoop) };
+            let mem_snoop: u64 = unsafe { ::core::mem::transmute(mem_snoop) };
             mem_snoop as u64
         });
         __bindgen_bitfield_unit.set(24usize, 2u8, {
-            let mem_lock: u64 = unsafe { ::std::mem::transmute(mem_lock) };
+            let mem_lock: u64 = unsafe { ::core::mem::transmute(mem_lock) };
             mem_lock as u64
         });
         __bindgen_bitfield_unit.set(26usize, 7u8, {
-            let mem_dtlb: u64 = unsafe { ::std::mem::transmute(mem_dtlb) };
+            let mem_dtlb: u64 = unsafe { ::core::mem::transmute(mem_dtlb) };
             mem_dtlb as u64
         });
         __bindgen_bitfield_unit.set(33usize, 4u8, {
-            let mem_lvl_num: u64 = unsafe { ::std::mem::transmute(mem_lvl_num) };
+            let mem_lvl_num: u64 = unsafe { ::core::mem::transmute(mem_lvl_num) };
             mem_lvl_num as u64
         });
         __bindgen_bitfield_unit.set(37usize, 1u8, {
-            let mem_remote: u64 = unsafe { ::std::mem::transmute(mem_remote) };
+            let mem_remote: u64 = unsafe { ::core::mem::transmute(mem_remote) };
             mem_remote as u64
         });
         __bindgen_bitfield_unit.set(38usize, 2u8, {
-            let mem_snoopx: u64 = unsafe { ::std::mem::transmute(mem_snoopx) };
+            let mem_snoopx: u64 = unsafe { ::core::mem::transmute(mem_snoopx) };
             mem_snoopx as u64
         });
         __bindgen_bitfield_unit.set(40usize, 3u8, {
-            let mem_blk: u64 = unsafe { ::std::mem::transmute(mem_blk) };
+            let mem_blk: u64 = unsafe { ::core::mem::transmute(mem_blk) };
             mem_blk as u64
         });
         __bindgen_bitfield_unit.set(43usize, 3u8, {
-            let mem_hops: u64 = unsafe { ::std::mem::transmute(mem_hops) };
+            let mem_hops: u64 = unsafe { ::core::mem::transmute(mem_hops) };
             mem_hops as u64
         });
         __bindgen_bitfield_unit.set(46usize, 18u8, {
-            let mem_rsvd: u64 = unsafe { ::std::mem::transmute(mem_rsvd) };
+            let mem_rsvd: u64 = unsafe { ::core::mem::transmute(mem_rsvd) };
             mem_rsvd as u64
         });
         __bindgen_bitfield_unit
@@ -2984,9 +3087,9 @@ impl perf_mem_data_src__bindgen_ty_1 {
 }
 impl Default for perf_mem_data_src {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3002,20 +3105,20 @@ pub struct perf_branch_entry {
 impl perf_branch_entry {
     #[inline]
     pub fn mispred(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_mispred(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn mispred_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 0usize,
                 1u8,
             ) as u64)
@@ -3024,9 +3127,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_mispred_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 0usize,
                 1u8,
                 val as u64,
@@ -3035,20 +3138,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn predicted(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_predicted(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(1usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn predicted_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 1usize,
                 1u8,
             ) as u64)
@@ -3057,9 +3160,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_predicted_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 1usize,
                 1u8,
                 val as u64,
@@ -3068,20 +3171,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn in_tx(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_in_tx(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(2usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn in_tx_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 2usize,
                 1u8,
             ) as u64)
@@ -3090,9 +3193,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_in_tx_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 2usize,
                 1u8,
                 val as u64,
@@ -3101,20 +3204,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn abort(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u64) }
     }
     #[inline]
     pub fn set_abort(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(3usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn abort_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 3usize,
                 1u8,
             ) as u64)
@@ -3123,9 +3226,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_abort_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 3usize,
                 1u8,
                 val as u64,
@@ -3134,20 +3237,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn cycles(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 16u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 16u8) as u64) }
     }
     #[inline]
     pub fn set_cycles(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(4usize, 16u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn cycles_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 4usize,
                 16u8,
             ) as u64)
@@ -3156,9 +3259,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_cycles_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 4usize,
                 16u8,
                 val as u64,
@@ -3167,20 +3270,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn type_(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(20usize, 4u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(20usize, 4u8) as u64) }
     }
     #[inline]
     pub fn set_type(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(20usize, 4u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn type__raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 20usize,
                 4u8,
             ) as u64)
@@ -3189,9 +3292,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_type_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 20usize,
                 4u8,
                 val as u64,
@@ -3200,20 +3303,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn spec(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(24usize, 2u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(24usize, 2u8) as u64) }
     }
     #[inline]
     pub fn set_spec(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(24usize, 2u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn spec_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 24usize,
                 2u8,
             ) as u64)
@@ -3222,9 +3325,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_spec_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 24usize,
                 2u8,
                 val as u64,
@@ -3233,20 +3336,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn new_type(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(26usize, 4u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(26usize, 4u8) as u64) }
     }
     #[inline]
     pub fn set_new_type(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(26usize, 4u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn new_type_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 26usize,
                 4u8,
             ) as u64)
@@ -3255,9 +3358,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_new_type_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 26usize,
                 4u8,
                 val as u64,
@@ -3266,20 +3369,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn priv_(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(30usize, 3u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(30usize, 3u8) as u64) }
     }
     #[inline]
     pub fn set_priv(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(30usize, 3u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn priv__raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 30usize,
                 3u8,
             ) as u64)
@@ -3288,9 +3391,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_priv_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 30usize,
                 3u8,
                 val as u64,
@@ -3299,20 +3402,20 @@ impl perf_branch_entry {
     }
     #[inline]
     pub fn reserved(&self) -> __u64 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(33usize, 31u8) as u64) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(33usize, 31u8) as u64) }
     }
     #[inline]
     pub fn set_reserved(&mut self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             self._bitfield_1.set(33usize, 31u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn reserved_raw(this: *const Self) -> __u64 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 8usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 33usize,
                 31u8,
             ) as u64)
@@ -3321,9 +3424,9 @@ impl perf_branch_entry {
     #[inline]
     pub unsafe fn set_reserved_raw(this: *mut Self, val: __u64) {
         unsafe {
-            let val: u64 = ::std::mem::transmute(val);
+            let val: u64 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 8usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 33usize,
                 31u8,
                 val as u64,
@@ -3345,43 +3448,43 @@ impl perf_branch_entry {
     ) -> __BindgenBitfieldUnit<[u8; 8usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 8usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let mispred: u64 = unsafe { ::std::mem::transmute(mispred) };
+            let mispred: u64 = unsafe { ::core::mem::transmute(mispred) };
             mispred as u64
         });
         __bindgen_bitfield_unit.set(1usize, 1u8, {
-            let predicted: u64 = unsafe { ::std::mem::transmute(predicted) };
+            let predicted: u64 = unsafe { ::core::mem::transmute(predicted) };
             predicted as u64
         });
         __bindgen_bitfield_unit.set(2usize, 1u8, {
-            let in_tx: u64 = unsafe { ::std::mem::transmute(in_tx) };
+            let in_tx: u64 = unsafe { ::core::mem::transmute(in_tx) };
             in_tx as u64
         });
         __bindgen_bitfield_unit.set(3usize, 1u8, {
-            let abort: u64 = unsafe { ::std::mem::transmute(abort) };
+            let abort: u64 = unsafe { ::core::mem::transmute(abort) };
             abort as u64
         });
         __bindgen_bitfield_unit.set(4usize, 16u8, {
-            let cycles: u64 = unsafe { ::std::mem::transmute(cycles) };
+            let cycles: u64 = unsafe { ::core::mem::transmute(cycles) };
             cycles as u64
         });
         __bindgen_bitfield_unit.set(20usize, 4u8, {
-            let type_: u64 = unsafe { ::std::mem::transmute(type_) };
+            let type_: u64 = unsafe { ::core::mem::transmute(type_) };
             type_ as u64
         });
         __bindgen_bitfield_unit.set(24usize, 2u8, {
-            let spec: u64 = unsafe { ::std::mem::transmute(spec) };
+            let spec: u64 = unsafe { ::core::mem::transmute(spec) };
             spec as u64
         });
         __bindgen_bitfield_unit.set(26usize, 4u8, {
-            let new_type: u64 = unsafe { ::std::mem::transmute(new_type) };
+            let new_type: u64 = unsafe { ::core::mem::transmute(new_type) };
             new_type as u64
         });
         __bindgen_bitfield_unit.set(30usize, 3u8, {
-            let priv_: u64 = unsafe { ::std::mem::transmute(priv_) };
+            let priv_: u64 = unsafe { ::core::mem::transmute(priv_) };
             priv_ as u64
         });
         __bindgen_bitfield_unit.set(33usize, 31u8, {
-            let reserved: u64 = unsafe { ::std::mem::transmute(reserved) };
+            let reserved: u64 = unsafe { ::core::mem::transmute(reserved) };
             reserved as u64
         });
         __bindgen_bitfield_unit
@@ -3402,15 +3505,15 @@ pub struct perf_sample_weight__bindgen_ty_1 {
 }
 impl Default for perf_sample_weight {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 pub const BPF_MAY_GOTO: bpf_cond_pseudo_jmp = 0;
-pub type bpf_cond_pseudo_jmp = ::std::os::raw::c_uint;
+pub type bpf_cond_pseudo_jmp = ::core::ffi::c_uint;
 pub const BPF_REG_0: _bindgen_ty_61 = 0;
 pub const BPF_REG_1: _bindgen_ty_61 = 1;
 pub const BPF_REG_2: _bindgen_ty_61 = 2;
@@ -3423,9 +3526,10 @@ pub const BPF_REG_8: _bindgen_ty_61 = 8;
 pub const BPF_REG_9: _bindgen_ty_61 = 9;
 pub const BPF_REG_10: _bindgen_ty_61 = 10;
 pub const __MAX_BPF_REG: _bindgen_ty_61 = 11;
-pub type _bindgen_ty_61 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_61 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct bpf_insn {
     pub code: __u8,
     pub _bitfield_align_1: [u8; 0],
@@ -3436,20 +3540,20 @@ pub struct bpf_insn {
 impl bpf_insn {
     #[inline]
     pub fn dst_reg(&self) -> __u8 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
     }
     #[inline]
     pub fn set_dst_reg(&mut self, val: __u8) {
         unsafe {
-            let val: u8 = ::std::mem::transmute(val);
+            let val: u8 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 4u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn dst_reg_raw(this: *const Self) -> __u8 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 0usize,
                 4u8,
             ) as u8)
@@ -3458,9 +3562,9 @@ impl bpf_insn {
     #[inline]
     pub unsafe fn set_dst_reg_raw(this: *mut Self, val: __u8) {
         unsafe {
-            let val: u8 = ::std::mem::transmute(val);
+            let val: u8 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 0usize,
                 4u8,
                 val as u64,
@@ -3469,20 +3573,20 @@ impl bpf_insn {
     }
     #[inline]
     pub fn src_reg(&self) -> __u8 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
     }
     #[inline]
     pub fn set_src_reg(&mut self, val: __u8) {
         unsafe {
-            let val: u8 = ::std::mem::transmute(val);
+            let val: u8 = ::core::mem::transmute(val);
             self._bitfield_1.set(4usize, 4u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn src_reg_raw(this: *const Self) -> __u8 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 4usize,
                 4u8,
             ) as u8)
@@ -3491,9 +3595,9 @@ impl bpf_insn {
     #[inline]
     pub unsafe fn set_src_reg_raw(this: *mut Self, val: __u8) {
         unsafe {
-            let val: u8 = ::std::mem::transmute(val);
+            let val: u8 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 4usize,
                 4u8,
                 val as u64,
@@ -3504,11 +3608,11 @@ impl bpf_insn {
     pub fn new_bitfield_1(dst_reg: __u8, src_reg: __u8) -> __BindgenBitfieldUnit<[u8; 1usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 4u8, {
-            let dst_reg: u8 = unsafe { ::std::mem::transmute(dst_reg) };
+            let dst_reg: u8 = unsafe { ::core::mem::transmute(dst_reg) };
             dst_reg as u64
         });
         __bindgen_bitfield_unit.set(4usize, 4u8, {
-            let src_reg: u8 = unsafe { ::std::mem::transmute(src_reg) };
+            let src_reg: u8 = unsafe { ::core::mem::transmute(src_reg) };
             src_reg as u64
         });
         __bindgen_bitfield_unit
@@ -3538,18 +3642,18 @@ pub union bpf_lpm_trie_key_u8__bindgen_ty_1 {
 }
 impl Default for bpf_lpm_trie_key_u8__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_lpm_trie_key_u8 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3566,7 +3670,7 @@ pub const BPF_CGROUP_ITER_SELF_ONLY: bpf_cgroup_iter_order = 1;
 pub const BPF_CGROUP_ITER_DESCENDANTS_PRE: bpf_cgroup_iter_order = 2;
 pub const BPF_CGROUP_ITER_DESCENDANTS_POST: bpf_cgroup_iter_order = 3;
 pub const BPF_CGROUP_ITER_ANCESTORS_UP: bpf_cgroup_iter_order = 4;
-pub type bpf_cgroup_iter_order = ::std::os::raw::c_uint;
+pub type bpf_cgroup_iter_order = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_iter_link_info {
@@ -3588,9 +3692,9 @@ pub struct bpf_iter_link_info__bindgen_ty_2 {
 }
 impl Default for bpf_iter_link_info__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3604,9 +3708,9 @@ pub struct bpf_iter_link_info__bindgen_ty_3 {
 }
 impl Default for bpf_iter_link_info {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3650,7 +3754,7 @@ pub const BPF_LINK_DETACH: bpf_cmd = 34;
 pub const BPF_PROG_BIND_MAP: bpf_cmd = 35;
 pub const BPF_TOKEN_CREATE: bpf_cmd = 36;
 pub const __MAX_BPF_CMD: bpf_cmd = 37;
-pub type bpf_cmd = ::std::os::raw::c_uint;
+pub type bpf_cmd = ::core::ffi::c_uint;
 pub const BPF_MAP_TYPE_UNSPEC: bpf_map_type = 0;
 pub const BPF_MAP_TYPE_HASH: bpf_map_type = 1;
 pub const BPF_MAP_TYPE_ARRAY: bpf_map_type = 2;
@@ -3688,7 +3792,7 @@ pub const BPF_MAP_TYPE_USER_RINGBUF: bpf_map_type = 31;
 pub const BPF_MAP_TYPE_CGRP_STORAGE: bpf_map_type = 32;
 pub const BPF_MAP_TYPE_ARENA: bpf_map_type = 33;
 pub const __MAX_BPF_MAP_TYPE: bpf_map_type = 34;
-pub type bpf_map_type = ::std::os::raw::c_uint;
+pub type bpf_map_type = ::core::ffi::c_uint;
 pub const BPF_PROG_TYPE_UNSPEC: bpf_prog_type = 0;
 pub const BPF_PROG_TYPE_SOCKET_FILTER: bpf_prog_type = 1;
 pub const BPF_PROG_TYPE_KPROBE: bpf_prog_type = 2;
@@ -3723,7 +3827,7 @@ pub const BPF_PROG_TYPE_SK_LOOKUP: bpf_prog_type = 30;
 pub const BPF_PROG_TYPE_SYSCALL: bpf_prog_type = 31;
 pub const BPF_PROG_TYPE_NETFILTER: bpf_prog_type = 32;
 pub const __MAX_BPF_PROG_TYPE: bpf_prog_type = 33;
-pub type bpf_prog_type = ::std::os::raw::c_uint;
+pub type bpf_prog_type = ::core::ffi::c_uint;
 pub const BPF_CGROUP_INET_INGRESS: bpf_attach_type = 0;
 pub const BPF_CGROUP_INET_EGRESS: bpf_attach_type = 1;
 pub const BPF_CGROUP_INET_SOCK_CREATE: bpf_attach_type = 2;
@@ -3782,7 +3886,7 @@ pub const BPF_NETKIT_PRIMARY: bpf_attach_type = 54;
 pub const BPF_NETKIT_PEER: bpf_attach_type = 55;
 pub const BPF_TRACE_KPROBE_SESSION: bpf_attach_type = 56;
 pub const __MAX_BPF_ATTACH_TYPE: bpf_attach_type = 57;
-pub type bpf_attach_type = ::std::os::raw::c_uint;
+pub type bpf_attach_type = ::core::ffi::c_uint;
 pub const BPF_LINK_TYPE_UNSPEC: bpf_link_type = 0;
 pub const BPF_LINK_TYPE_RAW_TRACEPOINT: bpf_link_type = 1;
 pub const BPF_LINK_TYPE_TRACING: bpf_link_type = 2;
@@ -3799,7 +3903,7 @@ pub const BPF_LINK_TYPE_UPROBE_MULTI: bpf_link_type = 12;
 pub const BPF_LINK_TYPE_NETKIT: bpf_link_type = 13;
 pub const BPF_LINK_TYPE_SOCKMAP: bpf_link_type = 14;
 pub const __MAX_BPF_LINK_TYPE: bpf_link_type = 15;
-pub type bpf_link_type = ::std::os::raw::c_uint;
+pub type bpf_link_type = ::core::ffi::c_uint;
 pub const BPF_PERF_EVENT_UNSPEC: bpf_perf_event_type = 0;
 pub const BPF_PERF_EVENT_UPROBE: bpf_perf_event_type = 1;
 pub const BPF_PERF_EVENT_URETPROBE: bpf_perf_event_type = 2;
@@ -3807,18 +3911,18 @@ pub const BPF_PERF_EVENT_KPROBE: bpf_perf_event_type = 3;
 pub const BPF_PERF_EVENT_KRETPROBE: bpf_perf_event_type = 4;
 pub const BPF_PERF_EVENT_TRACEPOINT: bpf_perf_event_type = 5;
 pub const BPF_PERF_EVENT_EVENT: bpf_perf_event_type = 6;
-pub type bpf_perf_event_type = ::std::os::raw::c_uint;
+pub type bpf_perf_event_type = ::core::ffi::c_uint;
 pub const BPF_F_KPROBE_MULTI_RETURN: _bindgen_ty_62 = 1;
-pub type _bindgen_ty_62 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_62 = ::core::ffi::c_uint;
 pub const BPF_F_UPROBE_MULTI_RETURN: _bindgen_ty_63 = 1;
-pub type _bindgen_ty_63 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_63 = ::core::ffi::c_uint;
 pub const BPF_ADDR_SPACE_CAST: bpf_addr_space_cast = 1;
-pub type bpf_addr_space_cast = ::std::os::raw::c_uint;
+pub type bpf_addr_space_cast = ::core::ffi::c_uint;
 pub const BPF_ANY: _bindgen_ty_64 = 0;
 pub const BPF_NOEXIST: _bindgen_ty_64 = 1;
 pub const BPF_EXIST: _bindgen_ty_64 = 2;
 pub const BPF_F_LOCK: _bindgen_ty_64 = 4;
-pub type _bindgen_ty_64 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_64 = ::core::ffi::c_uint;
 pub const BPF_F_NO_PREALLOC: _bindgen_ty_65 = 1;
 pub const BPF_F_NO_COMMON_LRU: _bindgen_ty_65 = 2;
 pub const BPF_F_NUMA_NODE: _bindgen_ty_65 = 4;
@@ -3838,18 +3942,18 @@ pub const BPF_F_VTYPE_BTF_OBJ_FD: _bindgen_ty_65 = 32768;
 pub const BPF_F_TOKEN_FD: _bindgen_ty_65 = 65536;
 pub const BPF_F_SEGV_ON_FAULT: _bindgen_ty_65 = 131072;
 pub const BPF_F_NO_USER_CONV: _bindgen_ty_65 = 262144;
-pub type _bindgen_ty_65 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_65 = ::core::ffi::c_uint;
 pub const BPF_STATS_RUN_TIME: bpf_stats_type = 0;
-pub type bpf_stats_type = ::std::os::raw::c_uint;
+pub type bpf_stats_type = ::core::ffi::c_uint;
 pub const BPF_STACK_BUILD_ID_EMPTY: bpf_stack_build_id_status = 0;
 pub const BPF_STACK_BUILD_ID_VALID: bpf_stack_build_id_status = 1;
 pub const BPF_STACK_BUILD_ID_IP: bpf_stack_build_id_status = 2;
-pub type bpf_stack_build_id_status = ::std::os::raw::c_uint;
+pub type bpf_stack_build_id_status = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bpf_stack_build_id {
     pub status: __s32,
-    pub build_id: [::std::os::raw::c_uchar; 20usize],
+    pub build_id: [::core::ffi::c_uchar; 20usize],
     pub __bindgen_anon_1: bpf_stack_build_id__bindgen_ty_1,
 }
 #[repr(C)]
@@ -3860,24 +3964,25 @@ pub union bpf_stack_build_id__bindgen_ty_1 {
 }
 impl Default for bpf_stack_build_id__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_stack_build_id {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
+#[repr(align(8))]
 pub union bpf_attr {
     pub __bindgen_anon_1: bpf_attr__bindgen_ty_1,
     pub __bindgen_anon_2: bpf_attr__bindgen_ty_2,
@@ -3910,7 +4015,7 @@ pub struct bpf_attr__bindgen_ty_1 {
     pub map_flags: __u32,
     pub inner_map_fd: __u32,
     pub numa_node: __u32,
-    pub map_name: [::std::os::raw::c_char; 16usize],
+    pub map_name: [::core::ffi::c_char; 16usize],
     pub map_ifindex: __u32,
     pub btf_fd: __u32,
     pub btf_key_type_id: __u32,
@@ -3937,18 +4042,18 @@ pub union bpf_attr__bindgen_ty_2__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_2__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3977,7 +4082,7 @@ pub struct bpf_attr__bindgen_ty_4 {
     pub log_buf: __u64,
     pub kern_version: __u32,
     pub prog_flags: __u32,
-    pub prog_name: [::std::os::raw::c_char; 16usize],
+    pub prog_name: [::core::ffi::c_char; 16usize],
     pub prog_ifindex: __u32,
     pub expected_attach_type: __u32,
     pub prog_btf_fd: __u32,
@@ -4005,18 +4110,18 @@ pub union bpf_attr__bindgen_ty_4__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_4__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4049,9 +4154,9 @@ pub union bpf_attr__bindgen_ty_6__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_6__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4064,18 +4169,18 @@ pub union bpf_attr__bindgen_ty_6__bindgen_ty_2 {
 }
 impl Default for bpf_attr__bindgen_ty_6__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_6 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4118,18 +4223,18 @@ pub union bpf_attr__bindgen_ty_8__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_8__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_8 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4165,9 +4270,9 @@ pub union bpf_attr__bindgen_ty_10__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_10__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4180,18 +4285,18 @@ pub union bpf_attr__bindgen_ty_10__bindgen_ty_2 {
 }
 impl Default for bpf_attr__bindgen_ty_10__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_10 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4261,9 +4366,9 @@ pub union bpf_attr__bindgen_ty_14__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4276,9 +4381,9 @@ pub union bpf_attr__bindgen_ty_14__bindgen_ty_2 {
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4347,18 +4452,18 @@ pub union bpf_attr__bindgen_ty_14__bindgen_ty_3__bindgen_ty_6__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_3__bindgen_ty_6__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_3__bindgen_ty_6 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4390,36 +4495,36 @@ pub union bpf_attr__bindgen_ty_14__bindgen_ty_3__bindgen_ty_8__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_3__bindgen_ty_8__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_3__bindgen_ty_8 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_14__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_14 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4440,9 +4545,9 @@ pub union bpf_attr__bindgen_ty_15__bindgen_ty_1 {
 }
 impl Default for bpf_attr__bindgen_ty_15__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4455,18 +4560,18 @@ pub union bpf_attr__bindgen_ty_15__bindgen_ty_2 {
 }
 impl Default for bpf_attr__bindgen_ty_15__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_attr__bindgen_ty_15 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4502,9 +4607,9 @@ pub struct bpf_attr__bindgen_ty_20 {
 }
 impl Default for bpf_attr {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4722,42 +4827,42 @@ pub const BPF_FUNC_user_ringbuf_drain: bpf_func_id = 209;
 pub const BPF_FUNC_cgrp_storage_get: bpf_func_id = 210;
 pub const BPF_FUNC_cgrp_storage_delete: bpf_func_id = 211;
 pub const __BPF_FUNC_MAX_ID: bpf_func_id = 212;
-pub type bpf_func_id = ::std::os::raw::c_uint;
+pub type bpf_func_id = ::core::ffi::c_uint;
 pub const BPF_F_RECOMPUTE_CSUM: _bindgen_ty_66 = 1;
 pub const BPF_F_INVALIDATE_HASH: _bindgen_ty_66 = 2;
-pub type _bindgen_ty_66 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_66 = ::core::ffi::c_uint;
 pub const BPF_F_HDR_FIELD_MASK: _bindgen_ty_67 = 15;
-pub type _bindgen_ty_67 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_67 = ::core::ffi::c_uint;
 pub const BPF_F_PSEUDO_HDR: _bindgen_ty_68 = 16;
 pub const BPF_F_MARK_MANGLED_0: _bindgen_ty_68 = 32;
 pub const BPF_F_MARK_ENFORCE: _bindgen_ty_68 = 64;
-pub type _bindgen_ty_68 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_68 = ::core::ffi::c_uint;
 pub const BPF_F_TUNINFO_IPV6: _bindgen_ty_69 = 1;
-pub type _bindgen_ty_69 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_69 = ::core::ffi::c_uint;
 pub const BPF_F_SKIP_FIELD_MASK: _bindgen_ty_70 = 255;
 pub const BPF_F_USER_STACK: _bindgen_ty_70 = 256;
 pub const BPF_F_FAST_STACK_CMP: _bindgen_ty_70 = 512;
 pub const BPF_F_REUSE_STACKID: _bindgen_ty_70 = 1024;
 pub const BPF_F_USER_BUILD_ID: _bindgen_ty_70 = 2048;
-pub type _bindgen_ty_70 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_70 = ::core::ffi::c_uint;
 pub const BPF_F_ZERO_CSUM_TX: _bindgen_ty_71 = 2;
 pub const BPF_F_DONT_FRAGMENT: _bindgen_ty_71 = 4;
 pub const BPF_F_SEQ_NUMBER: _bindgen_ty_71 = 8;
 pub const BPF_F_NO_TUNNEL_KEY: _bindgen_ty_71 = 16;
-pub type _bindgen_ty_71 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_71 = ::core::ffi::c_uint;
 pub const BPF_F_TUNINFO_FLAGS: _bindgen_ty_72 = 16;
-pub type _bindgen_ty_72 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_72 = ::core::ffi::c_uint;
 pub const BPF_F_INDEX_MASK: _bindgen_ty_73 = 4294967295;
 pub const BPF_F_CURRENT_CPU: _bindgen_ty_73 = 4294967295;
 pub const BPF_F_CTXLEN_MASK: _bindgen_ty_73 = 4503595332403200;
-pub type _bindgen_ty_73 = ::std::os::raw::c_ulong;
+pub type _bindgen_ty_73 = ::core::ffi::c_ulong;
 pub const BPF_F_CURRENT_NETNS: _bindgen_ty_74 = -1;
-pub type _bindgen_ty_74 = ::std::os::raw::c_int;
+pub type _bindgen_ty_74 = ::core::ffi::c_int;
 pub const BPF_CSUM_LEVEL_QUERY: _bindgen_ty_75 = 0;
 pub const BPF_CSUM_LEVEL_INC: _bindgen_ty_75 = 1;
 pub const BPF_CSUM_LEVEL_DEC: _bindgen_ty_75 = 2;
 pub const BPF_CSUM_LEVEL_RESET: _bindgen_ty_75 = 3;
-pub type _bindgen_ty_75 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_75 = ::core::ffi::c_uint;
 pub const BPF_F_ADJ_ROOM_FIXED_GSO: _bindgen_ty_76 = 1;
 pub const BPF_F_ADJ_ROOM_ENCAP_L3_IPV4: _bindgen_ty_76 = 2;
 pub const BPF_F_ADJ_ROOM_ENCAP_L3_IPV6: _bindgen_ty_76 = 4;
@@ -4767,54 +4872,54 @@ pub const BPF_F_ADJ_ROOM_NO_CSUM_RESET: _bindgen_ty_76 = 32;
 pub const BPF_F_ADJ_ROOM_ENCAP_L2_ETH: _bindgen_ty_76 = 64;
 pub const BPF_F_ADJ_ROOM_DECAP_L3_IPV4: _bindgen_ty_76 = 128;
 pub const BPF_F_ADJ_ROOM_DECAP_L3_IPV6: _bindgen_ty_76 = 256;
-pub type _bindgen_ty_76 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_76 = ::core::ffi::c_uint;
 pub const BPF_ADJ_ROOM_ENCAP_L2_MASK: _bindgen_ty_77 = 255;
 pub const BPF_ADJ_ROOM_ENCAP_L2_SHIFT: _bindgen_ty_77 = 56;
-pub type _bindgen_ty_77 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_77 = ::core::ffi::c_uint;
 pub const BPF_F_SYSCTL_BASE_NAME: _bindgen_ty_78 = 1;
-pub type _bindgen_ty_78 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_78 = ::core::ffi::c_uint;
 pub const BPF_LOCAL_STORAGE_GET_F_CREATE: _bindgen_ty_79 = 1;
 pub const BPF_SK_STORAGE_GET_F_CREATE: _bindgen_ty_79 = 1;
-pub type _bindgen_ty_79 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_79 = ::core::ffi::c_uint;
 pub const BPF_F_GET_BRANCH_RECORDS_SIZE: _bindgen_ty_80 = 1;
-pub type _bindgen_ty_80 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_80 = ::core::ffi::c_uint;
 pub const BPF_RB_NO_WAKEUP: _bindgen_ty_81 = 1;
 pub const BPF_RB_FORCE_WAKEUP: _bindgen_ty_81 = 2;
-pub type _bindgen_ty_81 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_81 = ::core::ffi::c_uint;
 pub const BPF_RB_AVAIL_DATA: _bindgen_ty_82 = 0;
 pub const BPF_RB_RING_SIZE: _bindgen_ty_82 = 1;
 pub const BPF_RB_CONS_POS: _bindgen_ty_82 = 2;
 pub const BPF_RB_PROD_POS: _bindgen_ty_82 = 3;
-pub type _bindgen_ty_82 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_82 = ::core::ffi::c_uint;
 pub const BPF_RINGBUF_BUSY_BIT: _bindgen_ty_83 = 2147483648;
 pub const BPF_RINGBUF_DISCARD_BIT: _bindgen_ty_83 = 1073741824;
 pub const BPF_RINGBUF_HDR_SZ: _bindgen_ty_83 = 8;
-pub type _bindgen_ty_83 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_83 = ::core::ffi::c_uint;
 pub const BPF_SK_LOOKUP_F_REPLACE: _bindgen_ty_84 = 1;
 pub const BPF_SK_LOOKUP_F_NO_REUSEPORT: _bindgen_ty_84 = 2;
-pub type _bindgen_ty_84 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_84 = ::core::ffi::c_uint;
 pub const BPF_ADJ_ROOM_NET: bpf_adj_room_mode = 0;
 pub const BPF_ADJ_ROOM_MAC: bpf_adj_room_mode = 1;
-pub type bpf_adj_room_mode = ::std::os::raw::c_uint;
+pub type bpf_adj_room_mode = ::core::ffi::c_uint;
 pub const BPF_HDR_START_MAC: bpf_hdr_start_off = 0;
 pub const BPF_HDR_START_NET: bpf_hdr_start_off = 1;
-pub type bpf_hdr_start_off = ::std::os::raw::c_uint;
+pub type bpf_hdr_start_off = ::core::ffi::c_uint;
 pub const BPF_LWT_ENCAP_SEG6: bpf_lwt_encap_mode = 0;
 pub const BPF_LWT_ENCAP_SEG6_INLINE: bpf_lwt_encap_mode = 1;
 pub const BPF_LWT_ENCAP_IP: bpf_lwt_encap_mode = 2;
-pub type bpf_lwt_encap_mode = ::std::os::raw::c_uint;
+pub type bpf_lwt_encap_mode = ::core::ffi::c_uint;
 pub const BPF_F_BPRM_SECUREEXEC: _bindgen_ty_85 = 1;
-pub type _bindgen_ty_85 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_85 = ::core::ffi::c_uint;
 pub const BPF_F_INGRESS: _bindgen_ty_86 = 1;
 pub const BPF_F_BROADCAST: _bindgen_ty_86 = 8;
 pub const BPF_F_EXCLUDE_INGRESS: _bindgen_ty_86 = 16;
-pub type _bindgen_ty_86 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_86 = ::core::ffi::c_uint;
 pub const BPF_SKB_TSTAMP_UNSPEC: _bindgen_ty_87 = 0;
 pub const BPF_SKB_TSTAMP_DELIVERY_MONO: _bindgen_ty_87 = 1;
 pub const BPF_SKB_CLOCK_REALTIME: _bindgen_ty_87 = 0;
 pub const BPF_SKB_CLOCK_MONOTONIC: _bindgen_ty_87 = 1;
 pub const BPF_SKB_CLOCK_TAI: _bindgen_ty_87 = 2;
-pub type _bindgen_ty_87 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_87 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bpf_tunnel_key {
@@ -4834,9 +4939,9 @@ pub union bpf_tunnel_key__bindgen_ty_1 {
 }
 impl Default for bpf_tunnel_key__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4849,9 +4954,9 @@ pub union bpf_tunnel_key__bindgen_ty_2 {
 }
 impl Default for bpf_tunnel_key__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4864,18 +4969,18 @@ pub union bpf_tunnel_key__bindgen_ty_3 {
 }
 impl Default for bpf_tunnel_key__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_tunnel_key {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4897,18 +5002,18 @@ pub union bpf_xfrm_state__bindgen_ty_1 {
 }
 impl Default for bpf_xfrm_state__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_xfrm_state {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4918,7 +5023,7 @@ pub const BPF_DROP: bpf_ret_code = 2;
 pub const BPF_REDIRECT: bpf_ret_code = 7;
 pub const BPF_LWT_REROUTE: bpf_ret_code = 128;
 pub const BPF_FLOW_DISSECTOR_CONTINUE: bpf_ret_code = 129;
-pub type bpf_ret_code = ::std::os::raw::c_uint;
+pub type bpf_ret_code = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct bpf_sock {
@@ -5005,18 +5110,18 @@ pub struct bpf_sock_tuple__bindgen_ty_1__bindgen_ty_2 {
 }
 impl Default for bpf_sock_tuple__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_sock_tuple {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5031,7 +5136,7 @@ pub const XDP_DROP: xdp_action = 1;
 pub const XDP_PASS: xdp_action = 2;
 pub const XDP_TX: xdp_action = 3;
 pub const XDP_REDIRECT: xdp_action = 4;
-pub type xdp_action = ::std::os::raw::c_uint;
+pub type xdp_action = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct xdp_md {
@@ -5051,23 +5156,23 @@ pub struct bpf_devmap_val {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_devmap_val__bindgen_ty_1 {
-    pub fd: ::std::os::raw::c_int,
+    pub fd: ::core::ffi::c_int,
     pub id: __u32,
 }
 impl Default for bpf_devmap_val__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_devmap_val {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5081,29 +5186,31 @@ pub struct bpf_cpumap_val {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_cpumap_val__bindgen_ty_1 {
-    pub fd: ::std::os::raw::c_int,
+    pub fd: ::core::ffi::c_int,
     pub id: __u32,
 }
 impl Default for bpf_cpumap_val__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_cpumap_val {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(align(8))]
 pub struct bpf_prog_info {
     pub type_: __u32,
     pub id: __u32,
@@ -5116,7 +5223,7 @@ pub struct bpf_prog_info {
     pub created_by_uid: __u32,
     pub nr_map_ids: __u32,
     pub map_ids: __u64,
-    pub name: [::std::os::raw::c_char; 16usize],
+    pub name: [::core::ffi::c_char; 16usize],
     pub ifindex: __u32,
     pub _bitfield_align_1: [u8; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
@@ -5149,20 +5256,20 @@ pub struct bpf_prog_info {
 impl bpf_prog_info {
     #[inline]
     pub fn gpl_compatible(&self) -> __u32 {
-        unsafe { ::std::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
     }
     #[inline]
     pub fn set_gpl_compatible(&mut self, val: __u32) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             self._bitfield_1.set(0usize, 1u8, val as u64)
         }
     }
     #[inline]
     pub unsafe fn gpl_compatible_raw(this: *const Self) -> __u32 {
         unsafe {
-            ::std::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
-                ::std::ptr::addr_of!((*this)._bitfield_1),
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
                 0usize,
                 1u8,
             ) as u32)
@@ -5171,9 +5278,9 @@ impl bpf_prog_info {
     #[inline]
     pub unsafe fn set_gpl_compatible_raw(this: *mut Self, val: __u32) {
         unsafe {
-            let val: u32 = ::std::mem::transmute(val);
+            let val: u32 = ::core::mem::transmute(val);
             <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
-                ::std::ptr::addr_of_mut!((*this)._bitfield_1),
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
                 0usize,
                 1u8,
                 val as u64,
@@ -5184,7 +5291,7 @@ impl bpf_prog_info {
     pub fn new_bitfield_1(gpl_compatible: __u32) -> __BindgenBitfieldUnit<[u8; 4usize]> {
         let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
         __bindgen_bitfield_unit.set(0usize, 1u8, {
-            let gpl_compatible: u32 = unsafe { ::std::mem::transmute(gpl_compatible) };
+            let gpl_compatible: u32 = unsafe { ::core::mem::transmute(gpl_compatible) };
             gpl_compatible as u64
         });
         __bindgen_bitfield_unit
@@ -5192,6 +5299,9 @@ impl bpf_prog_info {
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(align(8))]
 pub struct bpf_map_info {
     pub type_: __u32,
     pub id: __u32,
@@ -5199,7 +5309,7 @@ pub struct bpf_map_info {
     pub value_size: __u32,
     pub max_entries: __u32,
     pub map_flags: __u32,
-    pub name: [::std::os::raw::c_char; 16usize],
+    pub name: [::core::ffi::c_char; 16usize],
     pub ifindex: __u32,
     pub btf_vmlinux_value_type_id: __u32,
     pub netns_dev: __u64,
@@ -5212,6 +5322,9 @@ pub struct bpf_map_info {
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[repr(align(8))]
 pub struct bpf_btf_info {
     pub btf: __u64,
     pub btf_size: __u32,
@@ -5222,6 +5335,7 @@ pub struct bpf_btf_info {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_link_info {
     pub type_: __u32,
     pub id: __u32,
@@ -5288,9 +5402,9 @@ pub struct bpf_link_info__bindgen_ty_1__bindgen_ty_4__bindgen_ty_1__bindgen_ty_1
 }
 impl Default for bpf_link_info__bindgen_ty_1__bindgen_ty_4__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5316,18 +5430,18 @@ pub struct bpf_link_info__bindgen_ty_1__bindgen_ty_4__bindgen_ty_2__bindgen_ty_2
 }
 impl Default for bpf_link_info__bindgen_ty_1__bindgen_ty_4__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_link_info__bindgen_ty_1__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5445,18 +5559,18 @@ impl bpf_link_info__bindgen_ty_1__bindgen_ty_11__bindgen_ty_1__bindgen_ty_4 {
 }
 impl Default for bpf_link_info__bindgen_ty_1__bindgen_ty_11__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_link_info__bindgen_ty_1__bindgen_ty_11 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5488,18 +5602,18 @@ pub struct bpf_link_info__bindgen_ty_1__bindgen_ty_14 {
 }
 impl Default for bpf_link_info__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_link_info {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5528,9 +5642,9 @@ pub union bpf_sock_addr__bindgen_ty_1 {
 }
 impl Default for bpf_sock_addr__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5544,9 +5658,9 @@ impl bpf_sock_addr__bindgen_ty_1 {
 }
 impl Default for bpf_sock_addr {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5605,9 +5719,9 @@ pub union bpf_sock_ops__bindgen_ty_1 {
 }
 impl Default for bpf_sock_ops__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5621,9 +5735,9 @@ pub union bpf_sock_ops__bindgen_ty_2 {
 }
 impl Default for bpf_sock_ops__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5638,15 +5752,15 @@ impl bpf_sock_ops__bindgen_ty_2 {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_sock_ops__bindgen_ty_3 {
-    pub skb_data: *mut ::std::os::raw::c_void,
+    pub skb_data: *mut ::core::ffi::c_void,
     pub _bitfield_align_1: [u8; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize]>,
 }
 impl Default for bpf_sock_ops__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5661,15 +5775,15 @@ impl bpf_sock_ops__bindgen_ty_3 {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_sock_ops__bindgen_ty_4 {
-    pub skb_data_end: *mut ::std::os::raw::c_void,
+    pub skb_data_end: *mut ::core::ffi::c_void,
     pub _bitfield_align_1: [u8; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize]>,
 }
 impl Default for bpf_sock_ops__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5683,9 +5797,9 @@ impl bpf_sock_ops__bindgen_ty_4 {
 }
 impl Default for bpf_sock_ops {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5698,7 +5812,7 @@ pub const BPF_SOCK_OPS_PARSE_ALL_HDR_OPT_CB_FLAG: _bindgen_ty_88 = 16;
 pub const BPF_SOCK_OPS_PARSE_UNKNOWN_HDR_OPT_CB_FLAG: _bindgen_ty_88 = 32;
 pub const BPF_SOCK_OPS_WRITE_HDR_OPT_CB_FLAG: _bindgen_ty_88 = 64;
 pub const BPF_SOCK_OPS_ALL_CB_FLAGS: _bindgen_ty_88 = 127;
-pub type _bindgen_ty_88 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_88 = ::core::ffi::c_uint;
 pub const BPF_SOCK_OPS_VOID: _bindgen_ty_89 = 0;
 pub const BPF_SOCK_OPS_TIMEOUT_INIT: _bindgen_ty_89 = 1;
 pub const BPF_SOCK_OPS_RWND_INIT: _bindgen_ty_89 = 2;
@@ -5715,7 +5829,7 @@ pub const BPF_SOCK_OPS_RTT_CB: _bindgen_ty_89 = 12;
 pub const BPF_SOCK_OPS_PARSE_HDR_OPT_CB: _bindgen_ty_89 = 13;
 pub const BPF_SOCK_OPS_HDR_OPT_LEN_CB: _bindgen_ty_89 = 14;
 pub const BPF_SOCK_OPS_WRITE_HDR_OPT_CB: _bindgen_ty_89 = 15;
-pub type _bindgen_ty_89 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_89 = ::core::ffi::c_uint;
 pub const BPF_TCP_ESTABLISHED: _bindgen_ty_90 = 1;
 pub const BPF_TCP_SYN_SENT: _bindgen_ty_90 = 2;
 pub const BPF_TCP_SYN_RECV: _bindgen_ty_90 = 3;
@@ -5730,12 +5844,12 @@ pub const BPF_TCP_CLOSING: _bindgen_ty_90 = 11;
 pub const BPF_TCP_NEW_SYN_RECV: _bindgen_ty_90 = 12;
 pub const BPF_TCP_BOUND_INACTIVE: _bindgen_ty_90 = 13;
 pub const BPF_TCP_MAX_STATES: _bindgen_ty_90 = 14;
-pub type _bindgen_ty_90 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_90 = ::core::ffi::c_uint;
 pub const BPF_LOAD_HDR_OPT_TCP_SYN: _bindgen_ty_92 = 1;
-pub type _bindgen_ty_92 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_92 = ::core::ffi::c_uint;
 pub const BPF_WRITE_HDR_TCP_CURRENT_MSS: _bindgen_ty_93 = 1;
 pub const BPF_WRITE_HDR_TCP_SYNACK_COOKIE: _bindgen_ty_93 = 2;
-pub type _bindgen_ty_93 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_93 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct bpf_perf_event_value {
@@ -5746,10 +5860,10 @@ pub struct bpf_perf_event_value {
 pub const BPF_DEVCG_ACC_MKNOD: _bindgen_ty_94 = 1;
 pub const BPF_DEVCG_ACC_READ: _bindgen_ty_94 = 2;
 pub const BPF_DEVCG_ACC_WRITE: _bindgen_ty_94 = 4;
-pub type _bindgen_ty_94 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_94 = ::core::ffi::c_uint;
 pub const BPF_DEVCG_DEV_BLOCK: _bindgen_ty_95 = 1;
 pub const BPF_DEVCG_DEV_CHAR: _bindgen_ty_95 = 2;
-pub type _bindgen_ty_95 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_95 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
 pub struct bpf_cgroup_dev_ctx {
@@ -5768,7 +5882,7 @@ pub const BPF_FIB_LOOKUP_SKIP_NEIGH: _bindgen_ty_96 = 4;
 pub const BPF_FIB_LOOKUP_TBID: _bindgen_ty_96 = 8;
 pub const BPF_FIB_LOOKUP_SRC: _bindgen_ty_96 = 16;
 pub const BPF_FIB_LOOKUP_MARK: _bindgen_ty_96 = 32;
-pub type _bindgen_ty_96 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_96 = ::core::ffi::c_uint;
 pub const BPF_FIB_LKUP_RET_SUCCESS: _bindgen_ty_97 = 0;
 pub const BPF_FIB_LKUP_RET_BLACKHOLE: _bindgen_ty_97 = 1;
 pub const BPF_FIB_LKUP_RET_UNREACHABLE: _bindgen_ty_97 = 2;
@@ -5779,7 +5893,7 @@ pub const BPF_FIB_LKUP_RET_UNSUPP_LWT: _bindgen_ty_97 = 6;
 pub const BPF_FIB_LKUP_RET_NO_NEIGH: _bindgen_ty_97 = 7;
 pub const BPF_FIB_LKUP_RET_FRAG_NEEDED: _bindgen_ty_97 = 8;
 pub const BPF_FIB_LKUP_RET_NO_SRC_ADDR: _bindgen_ty_97 = 9;
-pub type _bindgen_ty_97 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_97 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bpf_fib_lookup {
@@ -5803,9 +5917,9 @@ pub union bpf_fib_lookup__bindgen_ty_1 {
 }
 impl Default for bpf_fib_lookup__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5819,9 +5933,9 @@ pub union bpf_fib_lookup__bindgen_ty_2 {
 }
 impl Default for bpf_fib_lookup__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5834,9 +5948,9 @@ pub union bpf_fib_lookup__bindgen_ty_3 {
 }
 impl Default for bpf_fib_lookup__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5849,9 +5963,9 @@ pub union bpf_fib_lookup__bindgen_ty_4 {
 }
 impl Default for bpf_fib_lookup__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5870,9 +5984,9 @@ pub struct bpf_fib_lookup__bindgen_ty_5__bindgen_ty_1 {
 }
 impl Default for bpf_fib_lookup__bindgen_ty_5 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5896,18 +6010,18 @@ pub struct bpf_fib_lookup__bindgen_ty_6__bindgen_ty_2 {
 }
 impl Default for bpf_fib_lookup__bindgen_ty_6 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_fib_lookup {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -5926,39 +6040,39 @@ pub union bpf_redir_neigh__bindgen_ty_1 {
 }
 impl Default for bpf_redir_neigh__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_redir_neigh {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 pub const BPF_MTU_CHK_SEGS: bpf_check_mtu_flags = 1;
-pub type bpf_check_mtu_flags = ::std::os::raw::c_uint;
+pub type bpf_check_mtu_flags = ::core::ffi::c_uint;
 pub const BPF_MTU_CHK_RET_SUCCESS: bpf_check_mtu_ret = 0;
 pub const BPF_MTU_CHK_RET_FRAG_NEEDED: bpf_check_mtu_ret = 1;
 pub const BPF_MTU_CHK_RET_SEGS_TOOBIG: bpf_check_mtu_ret = 2;
-pub type bpf_check_mtu_ret = ::std::os::raw::c_uint;
+pub type bpf_check_mtu_ret = ::core::ffi::c_uint;
 pub const BPF_FD_TYPE_RAW_TRACEPOINT: bpf_task_fd_type = 0;
 pub const BPF_FD_TYPE_TRACEPOINT: bpf_task_fd_type = 1;
 pub const BPF_FD_TYPE_KPROBE: bpf_task_fd_type = 2;
 pub const BPF_FD_TYPE_KRETPROBE: bpf_task_fd_type = 3;
 pub const BPF_FD_TYPE_UPROBE: bpf_task_fd_type = 4;
 pub const BPF_FD_TYPE_URETPROBE: bpf_task_fd_type = 5;
-pub type bpf_task_fd_type = ::std::os::raw::c_uint;
+pub type bpf_task_fd_type = ::core::ffi::c_uint;
 pub const BPF_FLOW_DISSECTOR_F_PARSE_1ST_FRAG: _bindgen_ty_98 = 1;
 pub const BPF_FLOW_DISSECTOR_F_STOP_AT_FLOW_LABEL: _bindgen_ty_98 = 2;
 pub const BPF_FLOW_DISSECTOR_F_STOP_AT_ENCAP: _bindgen_ty_98 = 4;
-pub type _bindgen_ty_98 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_98 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bpf_flow_keys {
@@ -5996,30 +6110,32 @@ pub struct bpf_flow_keys__bindgen_ty_1__bindgen_ty_2 {
 }
 impl Default for bpf_flow_keys__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_flow_keys {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct bpf_func_info {
     pub insn_off: __u32,
     pub type_id: __u32,
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct bpf_line_info {
     pub insn_off: __u32,
     pub file_name_off: __u32,
@@ -6033,36 +6149,43 @@ pub struct bpf_spin_lock {
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_timer {
     pub __opaque: [__u64; 2usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_wq {
     pub __opaque: [__u64; 2usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_dynptr {
     pub __opaque: [__u64; 2usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_list_head {
     pub __opaque: [__u64; 2usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_list_node {
     pub __opaque: [__u64; 3usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_rb_root {
     pub __opaque: [__u64; 2usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_rb_node {
     pub __opaque: [__u64; 4usize],
 }
@@ -6097,9 +6220,9 @@ pub union bpf_sockopt__bindgen_ty_1 {
 }
 impl Default for bpf_sockopt__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6114,15 +6237,15 @@ impl bpf_sockopt__bindgen_ty_1 {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_sockopt__bindgen_ty_2 {
-    pub optval: *mut ::std::os::raw::c_void,
+    pub optval: *mut ::core::ffi::c_void,
     pub _bitfield_align_1: [u8; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize]>,
 }
 impl Default for bpf_sockopt__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6137,15 +6260,15 @@ impl bpf_sockopt__bindgen_ty_2 {
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_sockopt__bindgen_ty_3 {
-    pub optval_end: *mut ::std::os::raw::c_void,
+    pub optval_end: *mut ::core::ffi::c_void,
     pub _bitfield_align_1: [u8; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 8usize]>,
 }
 impl Default for bpf_sockopt__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6159,9 +6282,9 @@ impl bpf_sockopt__bindgen_ty_3 {
 }
 impl Default for bpf_sockopt {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6204,9 +6327,9 @@ pub union bpf_sk_lookup__bindgen_ty_1__bindgen_ty_1 {
 }
 impl Default for bpf_sk_lookup__bindgen_ty_1__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6220,18 +6343,18 @@ impl bpf_sk_lookup__bindgen_ty_1__bindgen_ty_1 {
 }
 impl Default for bpf_sk_lookup__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for bpf_sk_lookup {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6246,15 +6369,15 @@ impl bpf_sk_lookup {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct btf_ptr {
-    pub ptr: *mut ::std::os::raw::c_void,
+    pub ptr: *mut ::core::ffi::c_void,
     pub type_id: __u32,
     pub flags: __u32,
 }
 impl Default for btf_ptr {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6263,7 +6386,7 @@ pub const BTF_F_COMPACT: _bindgen_ty_99 = 1;
 pub const BTF_F_NONAME: _bindgen_ty_99 = 2;
 pub const BTF_F_PTR_RAW: _bindgen_ty_99 = 4;
 pub const BTF_F_ZERO: _bindgen_ty_99 = 8;
-pub type _bindgen_ty_99 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_99 = ::core::ffi::c_uint;
 pub const BPF_CORE_FIELD_BYTE_OFFSET: bpf_core_relo_kind = 0;
 pub const BPF_CORE_FIELD_BYTE_SIZE: bpf_core_relo_kind = 1;
 pub const BPF_CORE_FIELD_EXISTS: bpf_core_relo_kind = 2;
@@ -6277,9 +6400,10 @@ pub const BPF_CORE_TYPE_SIZE: bpf_core_relo_kind = 9;
 pub const BPF_CORE_ENUMVAL_EXISTS: bpf_core_relo_kind = 10;
 pub const BPF_CORE_ENUMVAL_VALUE: bpf_core_relo_kind = 11;
 pub const BPF_CORE_TYPE_MATCHES: bpf_core_relo_kind = 12;
-pub type bpf_core_relo_kind = ::std::os::raw::c_uint;
+pub type bpf_core_relo_kind = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct bpf_core_relo {
     pub insn_off: __u32,
     pub type_id: __u32,
@@ -6288,23 +6412,24 @@ pub struct bpf_core_relo {
 }
 impl Default for bpf_core_relo {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 pub const BPF_F_TIMER_ABS: _bindgen_ty_100 = 1;
 pub const BPF_F_TIMER_CPU_PIN: _bindgen_ty_100 = 2;
-pub type _bindgen_ty_100 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_100 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[repr(align(8))]
 pub struct bpf_iter_num {
     pub __opaque: [__u64; 1usize],
 }
 pub const BPF_F_PAD_ZEROS: bpf_kfunc_flags = 1;
-pub type bpf_kfunc_flags = ::std::os::raw::c_uint;
+pub type bpf_kfunc_flags = ::core::ffi::c_uint;
 pub const LIBBPF_STRICT_ALL: libbpf_strict_mode = 4294967295;
 pub const LIBBPF_STRICT_NONE: libbpf_strict_mode = 0;
 pub const LIBBPF_STRICT_CLEAN_PTRS: libbpf_strict_mode = 1;
@@ -6314,34 +6439,15 @@ pub const LIBBPF_STRICT_NO_OBJECT_LIST: libbpf_strict_mode = 8;
 pub const LIBBPF_STRICT_AUTO_RLIMIT_MEMLOCK: libbpf_strict_mode = 16;
 pub const LIBBPF_STRICT_MAP_DEFINITIONS: libbpf_strict_mode = 32;
 pub const __LIBBPF_STRICT_LAST: libbpf_strict_mode = 33;
-pub type libbpf_strict_mode = ::std::os::raw::c_uint;
+pub type libbpf_strict_mode = ::core::ffi::c_uint;
 unsafe extern "C" {
-    pub fn libbpf_set_strict_mode(mode: libbpf_strict_mode) -> ::std::os::raw::c_int;
+    pub fn libbpf_set_strict_mode(mode: libbpf_strict_mode) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn libbpf_get_error(ptr: *const ::std::os::raw::c_void) -> ::std::os::raw::c_long;
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct bpf_program {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct bpf_map {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct btf {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct btf_ext {
-    _unused: [u8; 0],
+    pub fn libbpf_get_error(ptr: *const ::core::ffi::c_void) -> ::core::ffi::c_long;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn libbpf_find_kernel_btf() -> *mut btf;
 }
 unsafe extern "C" {
@@ -6351,22 +6457,22 @@ unsafe extern "C" {
     pub fn bpf_program__get_expected_attach_type(prog: *const bpf_program) -> bpf_attach_type;
 }
 unsafe extern "C" {
-    pub fn bpf_map__get_pin_path(map: *const bpf_map) -> *const ::std::os::raw::c_char;
+    pub fn bpf_map__get_pin_path(map: *const bpf_map) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn btf__get_raw_data(btf: *const btf, size: *mut __u32) -> *const ::std::os::raw::c_void;
+    pub fn btf__get_raw_data(btf: *const btf, size: *mut __u32) -> *const ::core::ffi::c_void;
 }
 unsafe extern "C" {
     pub fn btf_ext__get_raw_data(
         btf_ext: *const btf_ext,
         size: *mut __u32,
-    ) -> *const ::std::os::raw::c_void;
+    ) -> *const ::core::ffi::c_void;
 }
 unsafe extern "C" {
-    pub fn libbpf_set_memlock_rlim(memlock_bytes: size_t) -> ::std::os::raw::c_int;
+    pub fn libbpf_set_memlock_rlim(memlock_bytes: size_t) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_map_create_opts {
     pub sz: size_t,
     pub btf_fd: __u32,
@@ -6382,20 +6488,21 @@ pub struct bpf_map_create_opts {
     pub token_fd: __u32,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_map_create(
         map_type: bpf_map_type,
-        map_name: *const ::std::os::raw::c_char,
+        map_name: *const ::core::ffi::c_char,
         key_size: __u32,
         value_size: __u32,
         max_entries: __u32,
         opts: *const bpf_map_create_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_prog_load_opts {
     pub sz: size_t,
-    pub attempts: ::std::os::raw::c_int,
+    pub attempts: ::core::ffi::c_int,
     pub expected_attach_type: bpf_attach_type,
     pub prog_btf_fd: __u32,
     pub prog_flags: __u32,
@@ -6405,43 +6512,35 @@ pub struct bpf_prog_load_opts {
     pub attach_prog_fd: __u32,
     pub attach_btf_obj_fd: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
-    pub fd_array: *const ::std::os::raw::c_int,
-    pub func_info: *const ::std::os::raw::c_void,
+    pub fd_array: *const ::core::ffi::c_int,
+    pub func_info: *const ::core::ffi::c_void,
     pub func_info_cnt: __u32,
     pub func_info_rec_size: __u32,
-    pub line_info: *const ::std::os::raw::c_void,
+    pub line_info: *const ::core::ffi::c_void,
     pub line_info_cnt: __u32,
     pub line_info_rec_size: __u32,
     pub log_level: __u32,
     pub log_size: __u32,
-    pub log_buf: *mut ::std::os::raw::c_char,
+    pub log_buf: *mut ::core::ffi::c_char,
     pub log_true_size: __u32,
     pub token_fd: __u32,
 }
-impl Default for bpf_prog_load_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_prog_load(
         prog_type: bpf_prog_type,
-        prog_name: *const ::std::os::raw::c_char,
-        license: *const ::std::os::raw::c_char,
+        prog_name: *const ::core::ffi::c_char,
+        license: *const ::core::ffi::c_char,
         insns: *const bpf_insn,
         insn_cnt: size_t,
         opts: *mut bpf_prog_load_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_btf_load_opts {
     pub sz: size_t,
-    pub log_buf: *mut ::std::os::raw::c_char,
+    pub log_buf: *mut ::core::ffi::c_char,
     pub log_level: __u32,
     pub log_size: __u32,
     pub log_true_size: __u32,
@@ -6449,85 +6548,77 @@ pub struct bpf_btf_load_opts {
     pub token_fd: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
-impl Default for bpf_btf_load_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_btf_load(
-        btf_data: *const ::std::os::raw::c_void,
+        btf_data: *const ::core::ffi::c_void,
         btf_size: size_t,
         opts: *mut bpf_btf_load_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_update_elem(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-        value: *const ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+        value: *const ::core::ffi::c_void,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_lookup_elem(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-        value: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+        value: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_lookup_elem_flags(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-        value: *mut ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+        value: *mut ::core::ffi::c_void,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_lookup_and_delete_elem(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-        value: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+        value: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_lookup_and_delete_elem_flags(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-        value: *mut ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+        value: *mut ::core::ffi::c_void,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_delete_elem(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_delete_elem_flags(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_get_next_key(
-        fd: ::std::os::raw::c_int,
-        key: *const ::std::os::raw::c_void,
-        next_key: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        fd: ::core::ffi::c_int,
+        key: *const ::core::ffi::c_void,
+        next_key: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_map_freeze(fd: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn bpf_map_freeze(fd: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_map_batch_opts {
     pub sz: size_t,
     pub elem_flags: __u64,
@@ -6535,99 +6626,101 @@ pub struct bpf_map_batch_opts {
 }
 unsafe extern "C" {
     pub fn bpf_map_delete_batch(
-        fd: ::std::os::raw::c_int,
-        keys: *const ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        keys: *const ::core::ffi::c_void,
         count: *mut __u32,
         opts: *const bpf_map_batch_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_lookup_batch(
-        fd: ::std::os::raw::c_int,
-        in_batch: *mut ::std::os::raw::c_void,
-        out_batch: *mut ::std::os::raw::c_void,
-        keys: *mut ::std::os::raw::c_void,
-        values: *mut ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        in_batch: *mut ::core::ffi::c_void,
+        out_batch: *mut ::core::ffi::c_void,
+        keys: *mut ::core::ffi::c_void,
+        values: *mut ::core::ffi::c_void,
         count: *mut __u32,
         opts: *const bpf_map_batch_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_lookup_and_delete_batch(
-        fd: ::std::os::raw::c_int,
-        in_batch: *mut ::std::os::raw::c_void,
-        out_batch: *mut ::std::os::raw::c_void,
-        keys: *mut ::std::os::raw::c_void,
-        values: *mut ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        in_batch: *mut ::core::ffi::c_void,
+        out_batch: *mut ::core::ffi::c_void,
+        keys: *mut ::core::ffi::c_void,
+        values: *mut ::core::ffi::c_void,
         count: *mut __u32,
         opts: *const bpf_map_batch_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_update_batch(
-        fd: ::std::os::raw::c_int,
-        keys: *const ::std::os::raw::c_void,
-        values: *const ::std::os::raw::c_void,
+        fd: ::core::ffi::c_int,
+        keys: *const ::core::ffi::c_void,
+        values: *const ::core::ffi::c_void,
         count: *mut __u32,
         opts: *const bpf_map_batch_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_obj_pin_opts {
     pub sz: size_t,
     pub file_flags: __u32,
-    pub path_fd: ::std::os::raw::c_int,
+    pub path_fd: ::core::ffi::c_int,
 }
 unsafe extern "C" {
     pub fn bpf_obj_pin(
-        fd: ::std::os::raw::c_int,
-        pathname: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        fd: ::core::ffi::c_int,
+        pathname: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_obj_pin_opts(
-        fd: ::std::os::raw::c_int,
-        pathname: *const ::std::os::raw::c_char,
+        fd: ::core::ffi::c_int,
+        pathname: *const ::core::ffi::c_char,
         opts: *const bpf_obj_pin_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_obj_get_opts {
     pub sz: size_t,
     pub file_flags: __u32,
-    pub path_fd: ::std::os::raw::c_int,
+    pub path_fd: ::core::ffi::c_int,
 }
 unsafe extern "C" {
-    pub fn bpf_obj_get(pathname: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_obj_get(pathname: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_obj_get_opts(
-        pathname: *const ::std::os::raw::c_char,
+        pathname: *const ::core::ffi::c_char,
         opts: *const bpf_obj_get_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_attach(
-        prog_fd: ::std::os::raw::c_int,
-        attachable_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
+        attachable_fd: ::core::ffi::c_int,
         type_: bpf_attach_type,
-        flags: ::std::os::raw::c_uint,
-    ) -> ::std::os::raw::c_int;
+        flags: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_detach(
-        attachable_fd: ::std::os::raw::c_int,
+        attachable_fd: ::core::ffi::c_int,
         type_: bpf_attach_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_detach2(
-        prog_fd: ::std::os::raw::c_int,
-        attachable_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
+        attachable_fd: ::core::ffi::c_int,
         type_: bpf_attach_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -6635,59 +6728,50 @@ pub struct bpf_prog_attach_opts {
     pub sz: size_t,
     pub flags: __u32,
     pub __bindgen_anon_1: bpf_prog_attach_opts__bindgen_ty_1,
-    pub relative_fd: ::std::os::raw::c_int,
+    pub relative_fd: ::core::ffi::c_int,
     pub relative_id: __u32,
     pub expected_revision: __u64,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union bpf_prog_attach_opts__bindgen_ty_1 {
-    pub replace_prog_fd: ::std::os::raw::c_int,
-    pub replace_fd: ::std::os::raw::c_int,
+    pub replace_prog_fd: ::core::ffi::c_int,
+    pub replace_fd: ::core::ffi::c_int,
 }
 impl Default for bpf_prog_attach_opts__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
-impl Default for bpf_prog_attach_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_prog_detach_opts {
     pub sz: size_t,
     pub flags: __u32,
-    pub relative_fd: ::std::os::raw::c_int,
+    pub relative_fd: ::core::ffi::c_int,
     pub relative_id: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
     pub expected_revision: __u64,
 }
 unsafe extern "C" {
     pub fn bpf_prog_attach_opts(
-        prog_fd: ::std::os::raw::c_int,
-        target: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
+        target: ::core::ffi::c_int,
         type_: bpf_attach_type,
         opts: *const bpf_prog_attach_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_detach_opts(
-        prog_fd: ::std::os::raw::c_int,
-        target: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
+        target: ::core::ffi::c_int,
         type_: bpf_attach_type,
         opts: *const bpf_prog_detach_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -6721,15 +6805,15 @@ pub struct bpf_link_create_opts__bindgen_ty_1__bindgen_ty_1 {
 pub struct bpf_link_create_opts__bindgen_ty_1__bindgen_ty_2 {
     pub flags: __u32,
     pub cnt: __u32,
-    pub syms: *mut *const ::std::os::raw::c_char,
-    pub addrs: *const ::std::os::raw::c_ulong,
+    pub syms: *mut *const ::core::ffi::c_char,
+    pub addrs: *const ::core::ffi::c_ulong,
     pub cookies: *const __u64,
 }
 impl Default for bpf_link_create_opts__bindgen_ty_1__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6739,18 +6823,18 @@ impl Default for bpf_link_create_opts__bindgen_ty_1__bindgen_ty_2 {
 pub struct bpf_link_create_opts__bindgen_ty_1__bindgen_ty_3 {
     pub flags: __u32,
     pub cnt: __u32,
-    pub path: *const ::std::os::raw::c_char,
-    pub offsets: *const ::std::os::raw::c_ulong,
-    pub ref_ctr_offsets: *const ::std::os::raw::c_ulong,
+    pub path: *const ::core::ffi::c_char,
+    pub offsets: *const ::core::ffi::c_ulong,
+    pub ref_ctr_offsets: *const ::core::ffi::c_ulong,
     pub cookies: *const __u64,
     pub pid: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
 impl Default for bpf_link_create_opts__bindgen_ty_1__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -6784,35 +6868,27 @@ pub struct bpf_link_create_opts__bindgen_ty_1__bindgen_ty_7 {
 }
 impl Default for bpf_link_create_opts__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
-impl Default for bpf_link_create_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_link_create(
-        prog_fd: ::std::os::raw::c_int,
-        target_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
+        target_fd: ::core::ffi::c_int,
         attach_type: bpf_attach_type,
         opts: *const bpf_link_create_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_link_detach(link_fd: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    pub fn bpf_link_detach(link_fd: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_link_update_opts {
     pub sz: size_t,
     pub flags: __u32,
@@ -6822,132 +6898,141 @@ pub struct bpf_link_update_opts {
 }
 unsafe extern "C" {
     pub fn bpf_link_update(
-        link_fd: ::std::os::raw::c_int,
-        new_prog_fd: ::std::os::raw::c_int,
+        link_fd: ::core::ffi::c_int,
+        new_prog_fd: ::core::ffi::c_int,
         opts: *const bpf_link_update_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_iter_create(link_fd: ::std::os::raw::c_int) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_iter_create(link_fd: ::core::ffi::c_int) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_prog_test_run_attr {
-    pub prog_fd: ::std::os::raw::c_int,
-    pub repeat: ::std::os::raw::c_int,
-    pub data_in: *const ::std::os::raw::c_void,
+    pub prog_fd: ::core::ffi::c_int,
+    pub repeat: ::core::ffi::c_int,
+    pub data_in: *const ::core::ffi::c_void,
     pub data_size_in: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
-    pub data_out: *mut ::std::os::raw::c_void,
+    pub data_out: *mut ::core::ffi::c_void,
     pub data_size_out: __u32,
     pub retval: __u32,
     pub duration: __u32,
     pub __bindgen_padding_1: [u8; 4usize],
-    pub ctx_in: *const ::std::os::raw::c_void,
+    pub ctx_in: *const ::core::ffi::c_void,
     pub ctx_size_in: __u32,
     pub __bindgen_padding_2: [u8; 4usize],
-    pub ctx_out: *mut ::std::os::raw::c_void,
+    pub ctx_out: *mut ::core::ffi::c_void,
     pub ctx_size_out: __u32,
     pub __bindgen_padding_3: [u8; 4usize],
 }
 impl Default for bpf_prog_test_run_attr {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 unsafe extern "C" {
-    pub fn bpf_prog_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_prog_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_map_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_map_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_btf_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_btf_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_link_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_link_get_next_id(start_id: __u32, next_id: *mut __u32) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_get_fd_by_id_opts {
     pub sz: size_t,
     pub open_flags: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
 unsafe extern "C" {
-    pub fn bpf_prog_get_fd_by_id(id: __u32) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_prog_get_fd_by_id(id: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_prog_get_fd_by_id_opts(
         id: __u32,
         opts: *const bpf_get_fd_by_id_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_map_get_fd_by_id(id: __u32) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_map_get_fd_by_id(id: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_map_get_fd_by_id_opts(
         id: __u32,
         opts: *const bpf_get_fd_by_id_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_btf_get_fd_by_id(id: __u32) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_btf_get_fd_by_id(id: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_btf_get_fd_by_id_opts(
         id: __u32,
         opts: *const bpf_get_fd_by_id_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_link_get_fd_by_id(id: __u32) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_link_get_fd_by_id(id: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_link_get_fd_by_id_opts(
         id: __u32,
         opts: *const bpf_get_fd_by_id_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_obj_get_info_by_fd(
-        bpf_fd: ::std::os::raw::c_int,
-        info: *mut ::std::os::raw::c_void,
+        bpf_fd: ::core::ffi::c_int,
+        info: *mut ::core::ffi::c_void,
         info_len: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_get_info_by_fd(
-        prog_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
         info: *mut bpf_prog_info,
         info_len: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map_get_info_by_fd(
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
         info: *mut bpf_map_info,
         info_len: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_btf_get_info_by_fd(
-        btf_fd: ::std::os::raw::c_int,
+        btf_fd: ::core::ffi::c_int,
         info: *mut bpf_btf_info,
         info_len: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_link_get_info_by_fd(
-        link_fd: ::std::os::raw::c_int,
+        link_fd: ::core::ffi::c_int,
         info: *mut bpf_link_info,
         info_len: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -6971,85 +7056,70 @@ pub union bpf_prog_query_opts__bindgen_ty_1 {
 }
 impl Default for bpf_prog_query_opts__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
-impl Default for bpf_prog_query_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 unsafe extern "C" {
     pub fn bpf_prog_query_opts(
-        target: ::std::os::raw::c_int,
+        target: ::core::ffi::c_int,
         type_: bpf_attach_type,
         opts: *mut bpf_prog_query_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_query(
-        target_fd: ::std::os::raw::c_int,
+        target_fd: ::core::ffi::c_int,
         type_: bpf_attach_type,
         query_flags: __u32,
         attach_flags: *mut __u32,
         prog_ids: *mut __u32,
         prog_cnt: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_raw_tp_opts {
     pub sz: size_t,
-    pub tp_name: *const ::std::os::raw::c_char,
+    pub tp_name: *const ::core::ffi::c_char,
     pub cookie: __u64,
 }
-impl Default for bpf_raw_tp_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_raw_tracepoint_open_opts(
-        prog_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
         opts: *mut bpf_raw_tp_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_raw_tracepoint_open(
-        name: *const ::std::os::raw::c_char,
-        prog_fd: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        name: *const ::core::ffi::c_char,
+        prog_fd: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_task_fd_query(
-        pid: ::std::os::raw::c_int,
-        fd: ::std::os::raw::c_int,
+        pid: ::core::ffi::c_int,
+        fd: ::core::ffi::c_int,
         flags: __u32,
-        buf: *mut ::std::os::raw::c_char,
+        buf: *mut ::core::ffi::c_char,
         buf_len: *mut __u32,
         prog_id: *mut __u32,
         fd_type: *mut __u32,
         probe_offset: *mut __u64,
         probe_addr: *mut __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_enable_stats(type_: bpf_stats_type) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_enable_stats(type_: bpf_stats_type) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_prog_bind_opts {
     pub sz: size_t,
     pub flags: __u32,
@@ -7057,62 +7127,55 @@ pub struct bpf_prog_bind_opts {
 }
 unsafe extern "C" {
     pub fn bpf_prog_bind_map(
-        prog_fd: ::std::os::raw::c_int,
-        map_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
+        map_fd: ::core::ffi::c_int,
         opts: *const bpf_prog_bind_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_test_run_opts {
     pub sz: size_t,
-    pub data_in: *const ::std::os::raw::c_void,
-    pub data_out: *mut ::std::os::raw::c_void,
+    pub data_in: *const ::core::ffi::c_void,
+    pub data_out: *mut ::core::ffi::c_void,
     pub data_size_in: __u32,
     pub data_size_out: __u32,
-    pub ctx_in: *const ::std::os::raw::c_void,
-    pub ctx_out: *mut ::std::os::raw::c_void,
+    pub ctx_in: *const ::core::ffi::c_void,
+    pub ctx_out: *mut ::core::ffi::c_void,
     pub ctx_size_in: __u32,
     pub ctx_size_out: __u32,
     pub retval: __u32,
-    pub repeat: ::std::os::raw::c_int,
+    pub repeat: ::core::ffi::c_int,
     pub duration: __u32,
     pub flags: __u32,
     pub cpu: __u32,
     pub batch_size: __u32,
 }
-impl Default for bpf_test_run_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
     pub fn bpf_prog_test_run_opts(
-        prog_fd: ::std::os::raw::c_int,
+        prog_fd: ::core::ffi::c_int,
         opts: *mut bpf_test_run_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_token_create_opts {
     pub sz: size_t,
     pub flags: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_token_create(
-        bpffs_fd: ::std::os::raw::c_int,
+        bpffs_fd: ::core::ffi::c_int,
         opts: *mut bpf_token_create_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub type __gnuc_va_list = __builtin_va_list;
 pub type va_list = __builtin_va_list;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_header {
     pub magic: __u16,
     pub version: __u8,
@@ -7125,6 +7188,7 @@ pub struct btf_header {
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_type {
     pub name_off: __u32,
     pub info: __u32,
@@ -7138,18 +7202,18 @@ pub union btf_type__bindgen_ty_1 {
 }
 impl Default for btf_type__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 impl Default for btf_type {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -7176,15 +7240,17 @@ pub const BTF_KIND_TYPE_TAG: _bindgen_ty_101 = 18;
 pub const BTF_KIND_ENUM64: _bindgen_ty_101 = 19;
 pub const NR_BTF_KINDS: _bindgen_ty_101 = 20;
 pub const BTF_KIND_MAX: _bindgen_ty_101 = 19;
-pub type _bindgen_ty_101 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_101 = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_enum {
     pub name_off: __u32,
     pub val: __s32,
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_array {
     pub type_: __u32,
     pub index_type: __u32,
@@ -7192,6 +7258,7 @@ pub struct btf_array {
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_member {
     pub name_off: __u32,
     pub type_: __u32,
@@ -7199,6 +7266,7 @@ pub struct btf_member {
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_param {
     pub name_off: __u32,
     pub type_: __u32,
@@ -7206,18 +7274,20 @@ pub struct btf_param {
 pub const BTF_VAR_STATIC: _bindgen_ty_102 = 0;
 pub const BTF_VAR_GLOBAL_ALLOCATED: _bindgen_ty_102 = 1;
 pub const BTF_VAR_GLOBAL_EXTERN: _bindgen_ty_102 = 2;
-pub type _bindgen_ty_102 = ::std::os::raw::c_uint;
+pub type _bindgen_ty_102 = ::core::ffi::c_uint;
 pub const BTF_FUNC_STATIC: btf_func_linkage = 0;
 pub const BTF_FUNC_GLOBAL: btf_func_linkage = 1;
 pub const BTF_FUNC_EXTERN: btf_func_linkage = 2;
-pub type btf_func_linkage = ::std::os::raw::c_uint;
+pub type btf_func_linkage = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_var {
     pub linkage: __u32,
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_var_secinfo {
     pub type_: __u32,
     pub offset: __u32,
@@ -7225,41 +7295,42 @@ pub struct btf_var_secinfo {
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_decl_tag {
     pub component_idx: __s32,
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct btf_enum64 {
     pub name_off: __u32,
     pub val_lo32: __u32,
     pub val_hi32: __u32,
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct bpf_object {
-    _unused: [u8; 0],
-}
 pub const BTF_LITTLE_ENDIAN: btf_endianness = 0;
 pub const BTF_BIG_ENDIAN: btf_endianness = 1;
-pub type btf_endianness = ::std::os::raw::c_uint;
+pub type btf_endianness = ::core::ffi::c_uint;
 unsafe extern "C" {
     pub fn btf__free(btf: *mut btf);
 }
 unsafe extern "C" {
-    pub fn btf__new(data: *const ::std::os::raw::c_void, size: __u32) -> *mut btf;
+    #[must_use]
+    pub fn btf__new(data: *const ::core::ffi::c_void, size: __u32) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__new_split(
-        data: *const ::std::os::raw::c_void,
+        data: *const ::core::ffi::c_void,
         size: __u32,
         base_btf: *mut btf,
     ) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__new_empty() -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__new_empty_split(base_btf: *mut btf) -> *mut btf;
 }
 unsafe extern "C" {
@@ -7267,60 +7338,70 @@ unsafe extern "C" {
         src_btf: *const btf,
         new_base_btf: *mut *mut btf,
         new_split_btf: *mut *mut btf,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__parse(path: *const ::std::os::raw::c_char, btf_ext: *mut *mut btf_ext) -> *mut btf;
+    #[must_use]
+    pub fn btf__parse(path: *const ::core::ffi::c_char, btf_ext: *mut *mut btf_ext) -> *mut btf;
 }
 unsafe extern "C" {
-    pub fn btf__parse_split(path: *const ::std::os::raw::c_char, base_btf: *mut btf) -> *mut btf;
+    #[must_use]
+    pub fn btf__parse_split(path: *const ::core::ffi::c_char, base_btf: *mut btf) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__parse_elf(
-        path: *const ::std::os::raw::c_char,
+        path: *const ::core::ffi::c_char,
         btf_ext: *mut *mut btf_ext,
     ) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__parse_elf_split(
-        path: *const ::std::os::raw::c_char,
+        path: *const ::core::ffi::c_char,
         base_btf: *mut btf,
     ) -> *mut btf;
 }
 unsafe extern "C" {
-    pub fn btf__parse_raw(path: *const ::std::os::raw::c_char) -> *mut btf;
+    #[must_use]
+    pub fn btf__parse_raw(path: *const ::core::ffi::c_char) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__parse_raw_split(
-        path: *const ::std::os::raw::c_char,
+        path: *const ::core::ffi::c_char,
         base_btf: *mut btf,
     ) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__load_vmlinux_btf() -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__load_module_btf(
-        module_name: *const ::std::os::raw::c_char,
+        module_name: *const ::core::ffi::c_char,
         vmlinux_btf: *mut btf,
     ) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__load_from_kernel_by_id(id: __u32) -> *mut btf;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf__load_from_kernel_by_id_split(id: __u32, base_btf: *mut btf) -> *mut btf;
 }
 unsafe extern "C" {
-    pub fn btf__load_into_kernel(btf: *mut btf) -> ::std::os::raw::c_int;
+    pub fn btf__load_into_kernel(btf: *mut btf) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__find_by_name(btf: *const btf, type_name: *const ::std::os::raw::c_char) -> __s32;
+    pub fn btf__find_by_name(btf: *const btf, type_name: *const ::core::ffi::c_char) -> __s32;
 }
 unsafe extern "C" {
     pub fn btf__find_by_name_kind(
         btf: *const btf,
-        type_name: *const ::std::os::raw::c_char,
+        type_name: *const ::core::ffi::c_char,
         kind: __u32,
     ) -> __s32;
 }
@@ -7337,39 +7418,40 @@ unsafe extern "C" {
     pub fn btf__pointer_size(btf: *const btf) -> size_t;
 }
 unsafe extern "C" {
-    pub fn btf__set_pointer_size(btf: *mut btf, ptr_sz: size_t) -> ::std::os::raw::c_int;
+    pub fn btf__set_pointer_size(btf: *mut btf, ptr_sz: size_t) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__endianness(btf: *const btf) -> btf_endianness;
 }
 unsafe extern "C" {
-    pub fn btf__set_endianness(btf: *mut btf, endian: btf_endianness) -> ::std::os::raw::c_int;
+    pub fn btf__set_endianness(btf: *mut btf, endian: btf_endianness) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__resolve_size(btf: *const btf, type_id: __u32) -> __s64;
 }
 unsafe extern "C" {
-    pub fn btf__resolve_type(btf: *const btf, type_id: __u32) -> ::std::os::raw::c_int;
+    pub fn btf__resolve_type(btf: *const btf, type_id: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__align_of(btf: *const btf, id: __u32) -> ::std::os::raw::c_int;
+    pub fn btf__align_of(btf: *const btf, id: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__fd(btf: *const btf) -> ::std::os::raw::c_int;
+    pub fn btf__fd(btf: *const btf) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__set_fd(btf: *mut btf, fd: ::std::os::raw::c_int);
+    pub fn btf__set_fd(btf: *mut btf, fd: ::core::ffi::c_int);
 }
 unsafe extern "C" {
-    pub fn btf__raw_data(btf: *const btf, size: *mut __u32) -> *const ::std::os::raw::c_void;
+    pub fn btf__raw_data(btf: *const btf, size: *mut __u32) -> *const ::core::ffi::c_void;
 }
 unsafe extern "C" {
-    pub fn btf__name_by_offset(btf: *const btf, offset: __u32) -> *const ::std::os::raw::c_char;
+    pub fn btf__name_by_offset(btf: *const btf, offset: __u32) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn btf__str_by_offset(btf: *const btf, offset: __u32) -> *const ::std::os::raw::c_char;
+    pub fn btf__str_by_offset(btf: *const btf, offset: __u32) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn btf_ext__new(data: *const __u8, size: __u32) -> *mut btf_ext;
 }
 unsafe extern "C" {
@@ -7379,7 +7461,7 @@ unsafe extern "C" {
     pub fn btf_ext__raw_data(
         btf_ext: *const btf_ext,
         size: *mut __u32,
-    ) -> *const ::std::os::raw::c_void;
+    ) -> *const ::core::ffi::c_void;
 }
 unsafe extern "C" {
     pub fn btf_ext__endianness(btf_ext: *const btf_ext) -> btf_endianness;
@@ -7388,197 +7470,197 @@ unsafe extern "C" {
     pub fn btf_ext__set_endianness(
         btf_ext: *mut btf_ext,
         endian: btf_endianness,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__find_str(btf: *mut btf, s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn btf__find_str(btf: *mut btf, s: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__add_str(btf: *mut btf, s: *const ::std::os::raw::c_char) -> ::std::os::raw::c_int;
+    pub fn btf__add_str(btf: *mut btf, s: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_type(
         btf: *mut btf,
         src_btf: *const btf,
         src_type: *const btf_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__add_btf(btf: *mut btf, src_btf: *const btf) -> ::std::os::raw::c_int;
+    pub fn btf__add_btf(btf: *mut btf, src_btf: *const btf) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_int(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         byte_sz: size_t,
-        encoding: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        encoding: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_float(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         byte_sz: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__add_ptr(btf: *mut btf, ref_type_id: ::std::os::raw::c_int)
-        -> ::std::os::raw::c_int;
+    pub fn btf__add_ptr(btf: *mut btf, ref_type_id: ::core::ffi::c_int)
+        -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_array(
         btf: *mut btf,
-        index_type_id: ::std::os::raw::c_int,
-        elem_type_id: ::std::os::raw::c_int,
+        index_type_id: ::core::ffi::c_int,
+        elem_type_id: ::core::ffi::c_int,
         nr_elems: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_struct(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         sz: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_union(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         sz: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_field(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
-        field_type_id: ::std::os::raw::c_int,
+        name: *const ::core::ffi::c_char,
+        field_type_id: ::core::ffi::c_int,
         bit_offset: __u32,
         bit_size: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_enum(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         bytes_sz: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_enum_value(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         value: __s64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_enum64(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         bytes_sz: __u32,
         is_signed: bool,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_enum64_value(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         value: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub const BTF_FWD_STRUCT: btf_fwd_kind = 0;
 pub const BTF_FWD_UNION: btf_fwd_kind = 1;
 pub const BTF_FWD_ENUM: btf_fwd_kind = 2;
-pub type btf_fwd_kind = ::std::os::raw::c_uint;
+pub type btf_fwd_kind = ::core::ffi::c_uint;
 unsafe extern "C" {
     pub fn btf__add_fwd(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         fwd_kind: btf_fwd_kind,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_typedef(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
-        ref_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        name: *const ::core::ffi::c_char,
+        ref_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_volatile(
         btf: *mut btf,
-        ref_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        ref_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_const(
         btf: *mut btf,
-        ref_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        ref_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_restrict(
         btf: *mut btf,
-        ref_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        ref_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_type_tag(
         btf: *mut btf,
-        value: *const ::std::os::raw::c_char,
-        ref_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        value: *const ::core::ffi::c_char,
+        ref_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_func(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         linkage: btf_func_linkage,
-        proto_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        proto_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_func_proto(
         btf: *mut btf,
-        ret_type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        ret_type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_func_param(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
-        type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        name: *const ::core::ffi::c_char,
+        type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_var(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
-        linkage: ::std::os::raw::c_int,
-        type_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        name: *const ::core::ffi::c_char,
+        linkage: ::core::ffi::c_int,
+        type_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_datasec(
         btf: *mut btf,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         byte_sz: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_datasec_var_info(
         btf: *mut btf,
-        var_type_id: ::std::os::raw::c_int,
+        var_type_id: ::core::ffi::c_int,
         offset: __u32,
         byte_sz: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn btf__add_decl_tag(
         btf: *mut btf,
-        value: *const ::std::os::raw::c_char,
-        ref_type_id: ::std::os::raw::c_int,
-        component_idx: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        value: *const ::core::ffi::c_char,
+        ref_type_id: ::core::ffi::c_int,
+        component_idx: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -7588,43 +7670,30 @@ pub struct btf_dedup_opts {
     pub force_collisions: bool,
     pub __bindgen_padding_0: [u8; 7usize],
 }
-impl Default for btf_dedup_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
-    pub fn btf__dedup(btf: *mut btf, opts: *const btf_dedup_opts) -> ::std::os::raw::c_int;
+    pub fn btf__dedup(btf: *mut btf, opts: *const btf_dedup_opts) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn btf__relocate(btf: *mut btf, base_btf: *const btf) -> ::std::os::raw::c_int;
+    pub fn btf__relocate(btf: *mut btf, base_btf: *const btf) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
-pub struct btf_dump {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
 pub struct btf_dump_opts {
     pub sz: size_t,
 }
-pub type btf_dump_printf_fn_t = ::std::option::Option<
+pub type btf_dump_printf_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut ::std::os::raw::c_void,
-        fmt: *const ::std::os::raw::c_char,
+        ctx: *mut ::core::ffi::c_void,
+        fmt: *const ::core::ffi::c_char,
         args: *mut __va_list_tag,
     ),
 >;
 unsafe extern "C" {
+    #[must_use]
     pub fn btf_dump__new(
         btf: *const btf,
         printf_fn: btf_dump_printf_fn_t,
-        ctx: *mut ::std::os::raw::c_void,
+        ctx: *mut ::core::ffi::c_void,
         opts: *const btf_dump_opts,
     ) -> *mut btf_dump;
 }
@@ -7632,68 +7701,50 @@ unsafe extern "C" {
     pub fn btf_dump__free(d: *mut btf_dump);
 }
 unsafe extern "C" {
-    pub fn btf_dump__dump_type(d: *mut btf_dump, id: __u32) -> ::std::os::raw::c_int;
+    pub fn btf_dump__dump_type(d: *mut btf_dump, id: __u32) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct btf_dump_emit_type_decl_opts {
     pub sz: size_t,
-    pub field_name: *const ::std::os::raw::c_char,
-    pub indent_level: ::std::os::raw::c_int,
+    pub field_name: *const ::core::ffi::c_char,
+    pub indent_level: ::core::ffi::c_int,
     pub strip_mods: bool,
     pub __bindgen_padding_0: [u8; 3usize],
 }
-impl Default for btf_dump_emit_type_decl_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
     pub fn btf_dump__emit_type_decl(
         d: *mut btf_dump,
         id: __u32,
         opts: *const btf_dump_emit_type_decl_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct btf_dump_type_data_opts {
     pub sz: size_t,
-    pub indent_str: *const ::std::os::raw::c_char,
-    pub indent_level: ::std::os::raw::c_int,
+    pub indent_str: *const ::core::ffi::c_char,
+    pub indent_level: ::core::ffi::c_int,
     pub compact: bool,
     pub skip_names: bool,
     pub emit_zeroes: bool,
     pub __bindgen_padding_0: u8,
 }
-impl Default for btf_dump_type_data_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
     pub fn btf_dump__dump_type_data(
         d: *mut btf_dump,
         id: __u32,
-        data: *const ::std::os::raw::c_void,
+        data: *const ::core::ffi::c_void,
         data_sz: size_t,
         opts: *const btf_dump_type_data_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn vdprintf(
-        __fd: ::std::os::raw::c_int,
-        __fmt: *const ::std::os::raw::c_char,
+        __fd: ::core::ffi::c_int,
+        __fmt: *const ::core::ffi::c_char,
         __arg: *mut __va_list_tag,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub type pid_t = __pid_t;
 unsafe extern "C" {
@@ -7703,37 +7754,37 @@ unsafe extern "C" {
     pub fn libbpf_minor_version() -> __u32;
 }
 unsafe extern "C" {
-    pub fn libbpf_version_string() -> *const ::std::os::raw::c_char;
+    pub fn libbpf_version_string() -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
     pub fn libbpf_strerror(
-        err: ::std::os::raw::c_int,
-        buf: *mut ::std::os::raw::c_char,
+        err: ::core::ffi::c_int,
+        buf: *mut ::core::ffi::c_char,
         size: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn libbpf_bpf_attach_type_str(t: bpf_attach_type) -> *const ::std::os::raw::c_char;
+    pub fn libbpf_bpf_attach_type_str(t: bpf_attach_type) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn libbpf_bpf_link_type_str(t: bpf_link_type) -> *const ::std::os::raw::c_char;
+    pub fn libbpf_bpf_link_type_str(t: bpf_link_type) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn libbpf_bpf_map_type_str(t: bpf_map_type) -> *const ::std::os::raw::c_char;
+    pub fn libbpf_bpf_map_type_str(t: bpf_map_type) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn libbpf_bpf_prog_type_str(t: bpf_prog_type) -> *const ::std::os::raw::c_char;
+    pub fn libbpf_bpf_prog_type_str(t: bpf_prog_type) -> *const ::core::ffi::c_char;
 }
 pub const LIBBPF_WARN: libbpf_print_level = 0;
 pub const LIBBPF_INFO: libbpf_print_level = 1;
 pub const LIBBPF_DEBUG: libbpf_print_level = 2;
-pub type libbpf_print_level = ::std::os::raw::c_uint;
-pub type libbpf_print_fn_t = ::std::option::Option<
+pub type libbpf_print_level = ::core::ffi::c_uint;
+pub type libbpf_print_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
         level: libbpf_print_level,
-        arg1: *const ::std::os::raw::c_char,
+        arg1: *const ::core::ffi::c_char,
         ap: *mut __va_list_tag,
-    ) -> ::std::os::raw::c_int,
+    ) -> ::core::ffi::c_int,
 >;
 unsafe extern "C" {
     pub fn libbpf_set_print(fn_: libbpf_print_fn_t) -> libbpf_print_fn_t;
@@ -7742,29 +7793,20 @@ unsafe extern "C" {
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_object_open_opts {
     pub sz: size_t,
-    pub object_name: *const ::std::os::raw::c_char,
+    pub object_name: *const ::core::ffi::c_char,
     pub relaxed_maps: bool,
     pub __bindgen_padding_0: [u8; 7usize],
-    pub pin_root_path: *const ::std::os::raw::c_char,
+    pub pin_root_path: *const ::core::ffi::c_char,
     pub _bitfield_align_1: [u8; 0],
     pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
     pub __bindgen_padding_1: [u8; 4usize],
-    pub kconfig: *const ::std::os::raw::c_char,
-    pub btf_custom_path: *const ::std::os::raw::c_char,
-    pub kernel_log_buf: *mut ::std::os::raw::c_char,
+    pub kconfig: *const ::core::ffi::c_char,
+    pub btf_custom_path: *const ::core::ffi::c_char,
+    pub kernel_log_buf: *mut ::core::ffi::c_char,
     pub kernel_log_size: size_t,
     pub kernel_log_level: __u32,
     pub __bindgen_padding_2: [u8; 4usize],
-    pub bpf_token_path: *const ::std::os::raw::c_char,
-}
-impl Default for bpf_object_open_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
+    pub bpf_token_path: *const ::core::ffi::c_char,
 }
 impl bpf_object_open_opts {
     #[inline]
@@ -7774,23 +7816,26 @@ impl bpf_object_open_opts {
     }
 }
 unsafe extern "C" {
-    pub fn bpf_object__open(path: *const ::std::os::raw::c_char) -> *mut bpf_object;
+    #[must_use]
+    pub fn bpf_object__open(path: *const ::core::ffi::c_char) -> *mut bpf_object;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_object__open_file(
-        path: *const ::std::os::raw::c_char,
+        path: *const ::core::ffi::c_char,
         opts: *const bpf_object_open_opts,
     ) -> *mut bpf_object;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_object__open_mem(
-        obj_buf: *const ::std::os::raw::c_void,
+        obj_buf: *const ::core::ffi::c_void,
         obj_buf_sz: size_t,
         opts: *const bpf_object_open_opts,
     ) -> *mut bpf_object;
 }
 unsafe extern "C" {
-    pub fn bpf_object__load(obj: *mut bpf_object) -> ::std::os::raw::c_int;
+    pub fn bpf_object__load(obj: *mut bpf_object) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__close(obj: *mut bpf_object);
@@ -7798,84 +7843,84 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn bpf_object__pin_maps(
         obj: *mut bpf_object,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__unpin_maps(
         obj: *mut bpf_object,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__pin_programs(
         obj: *mut bpf_object,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__unpin_programs(
         obj: *mut bpf_object,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__pin(
         object: *mut bpf_object,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__unpin(
         object: *mut bpf_object,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_object__name(obj: *const bpf_object) -> *const ::std::os::raw::c_char;
+    pub fn bpf_object__name(obj: *const bpf_object) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn bpf_object__kversion(obj: *const bpf_object) -> ::std::os::raw::c_uint;
+    pub fn bpf_object__kversion(obj: *const bpf_object) -> ::core::ffi::c_uint;
 }
 unsafe extern "C" {
     pub fn bpf_object__set_kversion(
         obj: *mut bpf_object,
         kern_version: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_object__token_fd(obj: *const bpf_object) -> ::std::os::raw::c_int;
+    pub fn bpf_object__token_fd(obj: *const bpf_object) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__btf(obj: *const bpf_object) -> *mut btf;
 }
 unsafe extern "C" {
-    pub fn bpf_object__btf_fd(obj: *const bpf_object) -> ::std::os::raw::c_int;
+    pub fn bpf_object__btf_fd(obj: *const bpf_object) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__find_program_by_name(
         obj: *const bpf_object,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> *mut bpf_program;
 }
 unsafe extern "C" {
     pub fn libbpf_prog_type_by_name(
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         prog_type: *mut bpf_prog_type,
         expected_attach_type: *mut bpf_attach_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn libbpf_attach_type_by_name(
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         attach_type: *mut bpf_attach_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn libbpf_find_vmlinux_btf_id(
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
         attach_type: bpf_attach_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__next_program(
@@ -7893,10 +7938,10 @@ unsafe extern "C" {
     pub fn bpf_program__set_ifindex(prog: *mut bpf_program, ifindex: __u32);
 }
 unsafe extern "C" {
-    pub fn bpf_program__name(prog: *const bpf_program) -> *const ::std::os::raw::c_char;
+    pub fn bpf_program__name(prog: *const bpf_program) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
-    pub fn bpf_program__section_name(prog: *const bpf_program) -> *const ::std::os::raw::c_char;
+    pub fn bpf_program__section_name(prog: *const bpf_program) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
     pub fn bpf_program__autoload(prog: *const bpf_program) -> bool;
@@ -7905,7 +7950,7 @@ unsafe extern "C" {
     pub fn bpf_program__set_autoload(
         prog: *mut bpf_program,
         autoload: bool,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__autoattach(prog: *const bpf_program) -> bool;
@@ -7921,72 +7966,69 @@ unsafe extern "C" {
         prog: *mut bpf_program,
         new_insns: *mut bpf_insn,
         new_insn_cnt: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__insn_cnt(prog: *const bpf_program) -> size_t;
 }
 unsafe extern "C" {
-    pub fn bpf_program__fd(prog: *const bpf_program) -> ::std::os::raw::c_int;
+    pub fn bpf_program__fd(prog: *const bpf_program) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__pin(
         prog: *mut bpf_program,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__unpin(
         prog: *mut bpf_program,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__unload(prog: *mut bpf_program);
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct bpf_link {
-    _unused: [u8; 0],
-}
 unsafe extern "C" {
-    pub fn bpf_link__open(path: *const ::std::os::raw::c_char) -> *mut bpf_link;
+    #[must_use]
+    pub fn bpf_link__open(path: *const ::core::ffi::c_char) -> *mut bpf_link;
 }
 unsafe extern "C" {
-    pub fn bpf_link__fd(link: *const bpf_link) -> ::std::os::raw::c_int;
+    pub fn bpf_link__fd(link: *const bpf_link) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_link__pin_path(link: *const bpf_link) -> *const ::std::os::raw::c_char;
+    pub fn bpf_link__pin_path(link: *const bpf_link) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
     pub fn bpf_link__pin(
         link: *mut bpf_link,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_link__unpin(link: *mut bpf_link) -> ::std::os::raw::c_int;
+    pub fn bpf_link__unpin(link: *mut bpf_link) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_link__update_program(
         link: *mut bpf_link,
         prog: *mut bpf_program,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_link__disconnect(link: *mut bpf_link);
 }
 unsafe extern "C" {
-    pub fn bpf_link__detach(link: *mut bpf_link) -> ::std::os::raw::c_int;
+    pub fn bpf_link__detach(link: *mut bpf_link) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_link__destroy(link: *mut bpf_link) -> ::std::os::raw::c_int;
+    pub fn bpf_link__destroy(link: *mut bpf_link) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach(prog: *const bpf_program) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_perf_event_opts {
     pub sz: size_t,
     pub bpf_cookie: __u64,
@@ -7994,15 +8036,17 @@ pub struct bpf_perf_event_opts {
     pub __bindgen_padding_0: [u8; 7usize],
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_perf_event(
         prog: *const bpf_program,
-        pfd: ::std::os::raw::c_int,
+        pfd: ::core::ffi::c_int,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_perf_event_opts(
         prog: *const bpf_program,
-        pfd: ::std::os::raw::c_int,
+        pfd: ::core::ffi::c_int,
         opts: *const bpf_perf_event_opts,
     ) -> *mut bpf_link;
 }
@@ -8010,7 +8054,7 @@ pub const PROBE_ATTACH_MODE_DEFAULT: probe_attach_mode = 0;
 pub const PROBE_ATTACH_MODE_LEGACY: probe_attach_mode = 1;
 pub const PROBE_ATTACH_MODE_PERF: probe_attach_mode = 2;
 pub const PROBE_ATTACH_MODE_LINK: probe_attach_mode = 3;
-pub type probe_attach_mode = ::std::os::raw::c_uint;
+pub type probe_attach_mode = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_kprobe_opts {
@@ -8021,26 +8065,19 @@ pub struct bpf_kprobe_opts {
     pub __bindgen_padding_0: [u8; 3usize],
     pub attach_mode: probe_attach_mode,
 }
-impl Default for bpf_kprobe_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_kprobe(
         prog: *const bpf_program,
         retprobe: bool,
-        func_name: *const ::std::os::raw::c_char,
+        func_name: *const ::core::ffi::c_char,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_kprobe_opts(
         prog: *const bpf_program,
-        func_name: *const ::std::os::raw::c_char,
+        func_name: *const ::core::ffi::c_char,
         opts: *const bpf_kprobe_opts,
     ) -> *mut bpf_link;
 }
@@ -8048,27 +8085,19 @@ unsafe extern "C" {
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_kprobe_multi_opts {
     pub sz: size_t,
-    pub syms: *mut *const ::std::os::raw::c_char,
-    pub addrs: *const ::std::os::raw::c_ulong,
+    pub syms: *mut *const ::core::ffi::c_char,
+    pub addrs: *const ::core::ffi::c_ulong,
     pub cookies: *const __u64,
     pub cnt: size_t,
     pub retprobe: bool,
     pub session: bool,
     pub __bindgen_padding_0: [u8; 6usize],
 }
-impl Default for bpf_kprobe_multi_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_kprobe_multi_opts(
         prog: *const bpf_program,
-        pattern: *const ::std::os::raw::c_char,
+        pattern: *const ::core::ffi::c_char,
         opts: *const bpf_kprobe_multi_opts,
     ) -> *mut bpf_link;
 }
@@ -8076,34 +8105,26 @@ unsafe extern "C" {
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_uprobe_multi_opts {
     pub sz: size_t,
-    pub syms: *mut *const ::std::os::raw::c_char,
-    pub offsets: *const ::std::os::raw::c_ulong,
-    pub ref_ctr_offsets: *const ::std::os::raw::c_ulong,
+    pub syms: *mut *const ::core::ffi::c_char,
+    pub offsets: *const ::core::ffi::c_ulong,
+    pub ref_ctr_offsets: *const ::core::ffi::c_ulong,
     pub cookies: *const __u64,
     pub cnt: size_t,
     pub retprobe: bool,
     pub __bindgen_padding_0: [u8; 7usize],
 }
-impl Default for bpf_uprobe_multi_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_uprobe_multi(
         prog: *const bpf_program,
         pid: pid_t,
-        binary_path: *const ::std::os::raw::c_char,
-        func_pattern: *const ::std::os::raw::c_char,
+        binary_path: *const ::core::ffi::c_char,
+        func_pattern: *const ::core::ffi::c_char,
         opts: *const bpf_uprobe_multi_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_ksyscall_opts {
     pub sz: size_t,
     pub bpf_cookie: __u64,
@@ -8111,9 +8132,10 @@ pub struct bpf_ksyscall_opts {
     pub __bindgen_padding_0: [u8; 7usize],
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_ksyscall(
         prog: *const bpf_program,
-        syscall_name: *const ::std::os::raw::c_char,
+        syscall_name: *const ::core::ffi::c_char,
         opts: *const bpf_ksyscall_opts,
     ) -> *mut bpf_link;
 }
@@ -8125,144 +8147,150 @@ pub struct bpf_uprobe_opts {
     pub bpf_cookie: __u64,
     pub retprobe: bool,
     pub __bindgen_padding_0: [u8; 7usize],
-    pub func_name: *const ::std::os::raw::c_char,
+    pub func_name: *const ::core::ffi::c_char,
     pub attach_mode: probe_attach_mode,
     pub __bindgen_padding_1: [u8; 4usize],
 }
-impl Default for bpf_uprobe_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_uprobe(
         prog: *const bpf_program,
         retprobe: bool,
         pid: pid_t,
-        binary_path: *const ::std::os::raw::c_char,
+        binary_path: *const ::core::ffi::c_char,
         func_offset: size_t,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_uprobe_opts(
         prog: *const bpf_program,
         pid: pid_t,
-        binary_path: *const ::std::os::raw::c_char,
+        binary_path: *const ::core::ffi::c_char,
         func_offset: size_t,
         opts: *const bpf_uprobe_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_usdt_opts {
     pub sz: size_t,
     pub usdt_cookie: __u64,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_usdt(
         prog: *const bpf_program,
         pid: pid_t,
-        binary_path: *const ::std::os::raw::c_char,
-        usdt_provider: *const ::std::os::raw::c_char,
-        usdt_name: *const ::std::os::raw::c_char,
+        binary_path: *const ::core::ffi::c_char,
+        usdt_provider: *const ::core::ffi::c_char,
+        usdt_name: *const ::core::ffi::c_char,
         opts: *const bpf_usdt_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_tracepoint_opts {
     pub sz: size_t,
     pub bpf_cookie: __u64,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_tracepoint(
         prog: *const bpf_program,
-        tp_category: *const ::std::os::raw::c_char,
-        tp_name: *const ::std::os::raw::c_char,
+        tp_category: *const ::core::ffi::c_char,
+        tp_name: *const ::core::ffi::c_char,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_tracepoint_opts(
         prog: *const bpf_program,
-        tp_category: *const ::std::os::raw::c_char,
-        tp_name: *const ::std::os::raw::c_char,
+        tp_category: *const ::core::ffi::c_char,
+        tp_name: *const ::core::ffi::c_char,
         opts: *const bpf_tracepoint_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_raw_tracepoint_opts {
     pub sz: size_t,
     pub cookie: __u64,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_raw_tracepoint(
         prog: *const bpf_program,
-        tp_name: *const ::std::os::raw::c_char,
+        tp_name: *const ::core::ffi::c_char,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_raw_tracepoint_opts(
         prog: *const bpf_program,
-        tp_name: *const ::std::os::raw::c_char,
+        tp_name: *const ::core::ffi::c_char,
         opts: *mut bpf_raw_tracepoint_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_trace_opts {
     pub sz: size_t,
     pub cookie: __u64,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_trace(prog: *const bpf_program) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_trace_opts(
         prog: *const bpf_program,
         opts: *const bpf_trace_opts,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_lsm(prog: *const bpf_program) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_cgroup(
         prog: *const bpf_program,
-        cgroup_fd: ::std::os::raw::c_int,
+        cgroup_fd: ::core::ffi::c_int,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_netns(
         prog: *const bpf_program,
-        netns_fd: ::std::os::raw::c_int,
+        netns_fd: ::core::ffi::c_int,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_sockmap(
         prog: *const bpf_program,
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_xdp(
         prog: *const bpf_program,
-        ifindex: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_freplace(
         prog: *const bpf_program,
-        target_fd: ::std::os::raw::c_int,
-        attach_func_name: *const ::std::os::raw::c_char,
+        target_fd: ::core::ffi::c_int,
+        attach_func_name: *const ::core::ffi::c_char,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_netfilter_opts {
     pub sz: size_t,
     pub pf: __u32,
@@ -8271,13 +8299,14 @@ pub struct bpf_netfilter_opts {
     pub flags: __u32,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_netfilter(
         prog: *const bpf_program,
         opts: *const bpf_netfilter_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_tcx_opts {
     pub sz: size_t,
     pub flags: __u32,
@@ -8287,14 +8316,15 @@ pub struct bpf_tcx_opts {
     pub expected_revision: __u64,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_tcx(
         prog: *const bpf_program,
-        ifindex: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
         opts: *const bpf_tcx_opts,
     ) -> *mut bpf_link;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_netkit_opts {
     pub sz: size_t,
     pub flags: __u32,
@@ -8304,17 +8334,19 @@ pub struct bpf_netkit_opts {
     pub expected_revision: __u64,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_netkit(
         prog: *const bpf_program,
-        ifindex: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
         opts: *const bpf_netkit_opts,
     ) -> *mut bpf_link;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_map__attach_struct_ops(map: *const bpf_map) -> *mut bpf_link;
 }
 unsafe extern "C" {
-    pub fn bpf_link__update_map(link: *mut bpf_link, map: *const bpf_map) -> ::std::os::raw::c_int;
+    pub fn bpf_link__update_map(link: *mut bpf_link, map: *const bpf_map) -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -8324,16 +8356,8 @@ pub struct bpf_iter_attach_opts {
     pub link_info_len: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
-impl Default for bpf_iter_attach_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_program__attach_iter(
         prog: *const bpf_program,
         opts: *const bpf_iter_attach_opts,
@@ -8346,7 +8370,7 @@ unsafe extern "C" {
     pub fn bpf_program__set_type(
         prog: *mut bpf_program,
         type_: bpf_prog_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__expected_attach_type(prog: *const bpf_program) -> bpf_attach_type;
@@ -8355,13 +8379,13 @@ unsafe extern "C" {
     pub fn bpf_program__set_expected_attach_type(
         prog: *mut bpf_program,
         type_: bpf_attach_type,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__flags(prog: *const bpf_program) -> __u32;
 }
 unsafe extern "C" {
-    pub fn bpf_program__set_flags(prog: *mut bpf_program, flags: __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_program__set_flags(prog: *mut bpf_program, flags: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__log_level(prog: *const bpf_program) -> __u32;
@@ -8370,39 +8394,39 @@ unsafe extern "C" {
     pub fn bpf_program__set_log_level(
         prog: *mut bpf_program,
         log_level: __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__log_buf(
         prog: *const bpf_program,
         log_size: *mut size_t,
-    ) -> *const ::std::os::raw::c_char;
+    ) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
     pub fn bpf_program__set_log_buf(
         prog: *mut bpf_program,
-        log_buf: *mut ::std::os::raw::c_char,
+        log_buf: *mut ::core::ffi::c_char,
         log_size: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_program__set_attach_target(
         prog: *mut bpf_program,
-        attach_prog_fd: ::std::os::raw::c_int,
-        attach_func_name: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        attach_prog_fd: ::core::ffi::c_int,
+        attach_func_name: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__find_map_by_name(
         obj: *const bpf_object,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> *mut bpf_map;
 }
 unsafe extern "C" {
     pub fn bpf_object__find_map_fd_by_name(
         obj: *const bpf_object,
-        name: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        name: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__next_map(obj: *const bpf_object, map: *const bpf_map) -> *mut bpf_map;
@@ -8411,63 +8435,63 @@ unsafe extern "C" {
     pub fn bpf_object__prev_map(obj: *const bpf_object, map: *const bpf_map) -> *mut bpf_map;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_autocreate(map: *mut bpf_map, autocreate: bool) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_autocreate(map: *mut bpf_map, autocreate: bool) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__autocreate(map: *const bpf_map) -> bool;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_autoattach(map: *mut bpf_map, autoattach: bool) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_autoattach(map: *mut bpf_map, autoattach: bool) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__autoattach(map: *const bpf_map) -> bool;
 }
 unsafe extern "C" {
-    pub fn bpf_map__fd(map: *const bpf_map) -> ::std::os::raw::c_int;
+    pub fn bpf_map__fd(map: *const bpf_map) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_map__reuse_fd(map: *mut bpf_map, fd: ::std::os::raw::c_int)
-        -> ::std::os::raw::c_int;
+    pub fn bpf_map__reuse_fd(map: *mut bpf_map, fd: ::core::ffi::c_int)
+        -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_map__name(map: *const bpf_map) -> *const ::std::os::raw::c_char;
+    pub fn bpf_map__name(map: *const bpf_map) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
     pub fn bpf_map__type(map: *const bpf_map) -> bpf_map_type;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_type(map: *mut bpf_map, type_: bpf_map_type) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_type(map: *mut bpf_map, type_: bpf_map_type) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__max_entries(map: *const bpf_map) -> __u32;
 }
 unsafe extern "C" {
     pub fn bpf_map__set_max_entries(map: *mut bpf_map, max_entries: __u32)
-        -> ::std::os::raw::c_int;
+        -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__map_flags(map: *const bpf_map) -> __u32;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_map_flags(map: *mut bpf_map, flags: __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_map_flags(map: *mut bpf_map, flags: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__numa_node(map: *const bpf_map) -> __u32;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_numa_node(map: *mut bpf_map, numa_node: __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_numa_node(map: *mut bpf_map, numa_node: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__key_size(map: *const bpf_map) -> __u32;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_key_size(map: *mut bpf_map, size: __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_key_size(map: *mut bpf_map, size: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__value_size(map: *const bpf_map) -> __u32;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_value_size(map: *mut bpf_map, size: __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_value_size(map: *mut bpf_map, size: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__btf_key_type_id(map: *const bpf_map) -> __u32;
@@ -8479,26 +8503,26 @@ unsafe extern "C" {
     pub fn bpf_map__ifindex(map: *const bpf_map) -> __u32;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_ifindex(map: *mut bpf_map, ifindex: __u32) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_ifindex(map: *mut bpf_map, ifindex: __u32) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__map_extra(map: *const bpf_map) -> __u64;
 }
 unsafe extern "C" {
-    pub fn bpf_map__set_map_extra(map: *mut bpf_map, map_extra: __u64) -> ::std::os::raw::c_int;
+    pub fn bpf_map__set_map_extra(map: *mut bpf_map, map_extra: __u64) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__set_initial_value(
         map: *mut bpf_map,
-        data: *const ::std::os::raw::c_void,
+        data: *const ::core::ffi::c_void,
         size: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__initial_value(
         map: *const bpf_map,
         psize: *mut size_t,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 unsafe extern "C" {
     pub fn bpf_map__is_internal(map: *const bpf_map) -> bool;
@@ -8506,11 +8530,11 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn bpf_map__set_pin_path(
         map: *mut bpf_map,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_map__pin_path(map: *const bpf_map) -> *const ::std::os::raw::c_char;
+    pub fn bpf_map__pin_path(map: *const bpf_map) -> *const ::core::ffi::c_char;
 }
 unsafe extern "C" {
     pub fn bpf_map__is_pinned(map: *const bpf_map) -> bool;
@@ -8518,20 +8542,20 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn bpf_map__pin(
         map: *mut bpf_map,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__unpin(
         map: *mut bpf_map,
-        path: *const ::std::os::raw::c_char,
-    ) -> ::std::os::raw::c_int;
+        path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__set_inner_map_fd(
         map: *mut bpf_map,
-        fd: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        fd: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__inner_map(map: *mut bpf_map) -> *mut bpf_map;
@@ -8539,65 +8563,65 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn bpf_map__lookup_elem(
         map: *const bpf_map,
-        key: *const ::std::os::raw::c_void,
+        key: *const ::core::ffi::c_void,
         key_sz: size_t,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
         value_sz: size_t,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__update_elem(
         map: *const bpf_map,
-        key: *const ::std::os::raw::c_void,
+        key: *const ::core::ffi::c_void,
         key_sz: size_t,
-        value: *const ::std::os::raw::c_void,
+        value: *const ::core::ffi::c_void,
         value_sz: size_t,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__delete_elem(
         map: *const bpf_map,
-        key: *const ::std::os::raw::c_void,
+        key: *const ::core::ffi::c_void,
         key_sz: size_t,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__lookup_and_delete_elem(
         map: *const bpf_map,
-        key: *const ::std::os::raw::c_void,
+        key: *const ::core::ffi::c_void,
         key_sz: size_t,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
         value_sz: size_t,
         flags: __u64,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_map__get_next_key(
         map: *const bpf_map,
-        cur_key: *const ::std::os::raw::c_void,
-        next_key: *mut ::std::os::raw::c_void,
+        cur_key: *const ::core::ffi::c_void,
+        next_key: *mut ::core::ffi::c_void,
         key_sz: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_xdp_set_link_opts {
     pub sz: size_t,
-    pub old_fd: ::std::os::raw::c_int,
+    pub old_fd: ::core::ffi::c_int,
     pub __bindgen_padding_0: [u8; 4usize],
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_xdp_attach_opts {
     pub sz: size_t,
-    pub old_prog_fd: ::std::os::raw::c_int,
+    pub old_prog_fd: ::core::ffi::c_int,
     pub __bindgen_padding_0: [u8; 4usize],
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_xdp_query_opts {
     pub sz: size_t,
     pub prog_id: __u32,
@@ -8612,62 +8636,53 @@ pub struct bpf_xdp_query_opts {
 }
 unsafe extern "C" {
     pub fn bpf_xdp_attach(
-        ifindex: ::std::os::raw::c_int,
-        prog_fd: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
+        prog_fd: ::core::ffi::c_int,
         flags: __u32,
         opts: *const bpf_xdp_attach_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_xdp_detach(
-        ifindex: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
         flags: __u32,
         opts: *const bpf_xdp_attach_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_xdp_query(
-        ifindex: ::std::os::raw::c_int,
-        flags: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
+        flags: ::core::ffi::c_int,
         opts: *mut bpf_xdp_query_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_xdp_query_id(
-        ifindex: ::std::os::raw::c_int,
-        flags: ::std::os::raw::c_int,
+        ifindex: ::core::ffi::c_int,
+        flags: ::core::ffi::c_int,
         prog_id: *mut __u32,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 pub const BPF_TC_INGRESS: bpf_tc_attach_point = 1;
 pub const BPF_TC_EGRESS: bpf_tc_attach_point = 2;
 pub const BPF_TC_CUSTOM: bpf_tc_attach_point = 4;
-pub type bpf_tc_attach_point = ::std::os::raw::c_uint;
+pub type bpf_tc_attach_point = ::core::ffi::c_uint;
 pub const BPF_TC_F_REPLACE: bpf_tc_flags = 1;
-pub type bpf_tc_flags = ::std::os::raw::c_uint;
+pub type bpf_tc_flags = ::core::ffi::c_uint;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_tc_hook {
     pub sz: size_t,
-    pub ifindex: ::std::os::raw::c_int,
+    pub ifindex: ::core::ffi::c_int,
     pub attach_point: bpf_tc_attach_point,
     pub parent: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
-impl Default for bpf_tc_hook {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_tc_opts {
     pub sz: size_t,
-    pub prog_fd: ::std::os::raw::c_int,
+    pub prog_fd: ::core::ffi::c_int,
     pub flags: __u32,
     pub prog_id: __u32,
     pub handle: __u32,
@@ -8675,56 +8690,42 @@ pub struct bpf_tc_opts {
     pub __bindgen_padding_0: [u8; 4usize],
 }
 unsafe extern "C" {
-    pub fn bpf_tc_hook_create(hook: *mut bpf_tc_hook) -> ::std::os::raw::c_int;
+    pub fn bpf_tc_hook_create(hook: *mut bpf_tc_hook) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_tc_hook_destroy(hook: *mut bpf_tc_hook) -> ::std::os::raw::c_int;
+    pub fn bpf_tc_hook_destroy(hook: *mut bpf_tc_hook) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_tc_attach(hook: *const bpf_tc_hook, opts: *mut bpf_tc_opts)
-        -> ::std::os::raw::c_int;
+        -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_tc_detach(
         hook: *const bpf_tc_hook,
         opts: *const bpf_tc_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_tc_query(hook: *const bpf_tc_hook, opts: *mut bpf_tc_opts) -> ::std::os::raw::c_int;
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ring_buffer {
-    _unused: [u8; 0],
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct ring {
-    _unused: [u8; 0],
+    pub fn bpf_tc_query(hook: *const bpf_tc_hook, opts: *mut bpf_tc_opts) -> ::core::ffi::c_int;
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct user_ring_buffer {
-    _unused: [u8; 0],
-}
-pub type ring_buffer_sample_fn = ::std::option::Option<
+pub type ring_buffer_sample_fn = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut ::std::os::raw::c_void,
-        data: *mut ::std::os::raw::c_void,
+        ctx: *mut ::core::ffi::c_void,
+        data: *mut ::core::ffi::c_void,
         size: size_t,
-    ) -> ::std::os::raw::c_int,
+    ) -> ::core::ffi::c_int,
 >;
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct ring_buffer_opts {
     pub sz: size_t,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn ring_buffer__new(
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
         sample_cb: ring_buffer_sample_fn,
-        ctx: *mut ::std::os::raw::c_void,
+        ctx: *mut ::core::ffi::c_void,
         opts: *const ring_buffer_opts,
     ) -> *mut ring_buffer;
 }
@@ -8734,106 +8735,126 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn ring_buffer__add(
         rb: *mut ring_buffer,
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
         sample_cb: ring_buffer_sample_fn,
-        ctx: *mut ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        ctx: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn ring_buffer__poll(
         rb: *mut ring_buffer,
-        timeout_ms: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        timeout_ms: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ring_buffer__consume(rb: *mut ring_buffer) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn ring_buffer__consume(rb: *mut ring_buffer) -> ::std::os::raw::c_int;
+    pub fn ring_buffer__consume_n(rb: *mut ring_buffer, n: size_t) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn ring_buffer__consume_n(rb: *mut ring_buffer, n: size_t) -> ::std::os::raw::c_int;
+    pub fn ring_buffer__epoll_fd(rb: *const ring_buffer) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn ring_buffer__epoll_fd(rb: *const ring_buffer) -> ::std::os::raw::c_int;
+    pub fn ring_buffer__ring(rb: *mut ring_buffer, idx: ::core::ffi::c_uint) -> *mut ring;
 }
 unsafe extern "C" {
-    pub fn ring_buffer__ring(rb: *mut ring_buffer, idx: ::std::os::raw::c_uint) -> *mut ring;
+    pub fn ring__consumer_pos(r: *const ring) -> ::core::ffi::c_ulong;
+}
+unsafe extern "C" {
+    pub fn ring__producer_pos(r: *const ring) -> ::core::ffi::c_ulong;
+}
+unsafe extern "C" {
+    pub fn ring__avail_data_size(r: *const ring) -> size_t;
+}
+unsafe extern "C" {
+    pub fn ring__size(r: *const ring) -> size_t;
+}
+unsafe extern "C" {
+    pub fn ring__map_fd(r: *const ring) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ring__consume(r: *mut ring) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn ring__consume_n(r: *mut ring, n: size_t) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct user_ring_buffer_opts {
     pub sz: size_t,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn user_ring_buffer__new(
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
         opts: *const user_ring_buffer_opts,
     ) -> *mut user_ring_buffer;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn user_ring_buffer__reserve(
         rb: *mut user_ring_buffer,
         size: __u32,
-    ) -> *mut ::std::os::raw::c_void;
+    ) -> *mut ::core::ffi::c_void;
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn user_ring_buffer__reserve_blocking(
         rb: *mut user_ring_buffer,
         size: __u32,
-        timeout_ms: ::std::os::raw::c_int,
-    ) -> *mut ::std::os::raw::c_void;
+        timeout_ms: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_void;
 }
 unsafe extern "C" {
-    pub fn user_ring_buffer__submit(rb: *mut user_ring_buffer, sample: *mut ::std::os::raw::c_void);
+    pub fn user_ring_buffer__submit(rb: *mut user_ring_buffer, sample: *mut ::core::ffi::c_void);
 }
 unsafe extern "C" {
     pub fn user_ring_buffer__discard(
         rb: *mut user_ring_buffer,
-        sample: *mut ::std::os::raw::c_void,
+        sample: *mut ::core::ffi::c_void,
     );
 }
 unsafe extern "C" {
     pub fn user_ring_buffer__free(rb: *mut user_ring_buffer);
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct perf_buffer {
-    _unused: [u8; 0],
-}
-pub type perf_buffer_sample_fn = ::std::option::Option<
+pub type perf_buffer_sample_fn = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut ::std::os::raw::c_void,
-        cpu: ::std::os::raw::c_int,
-        data: *mut ::std::os::raw::c_void,
+        ctx: *mut ::core::ffi::c_void,
+        cpu: ::core::ffi::c_int,
+        data: *mut ::core::ffi::c_void,
         size: __u32,
     ),
 >;
-pub type perf_buffer_lost_fn = ::std::option::Option<
-    unsafe extern "C" fn(ctx: *mut ::std::os::raw::c_void, cpu: ::std::os::raw::c_int, cnt: __u64),
+pub type perf_buffer_lost_fn = ::core::option::Option<
+    unsafe extern "C" fn(ctx: *mut ::core::ffi::c_void, cpu: ::core::ffi::c_int, cnt: __u64),
 >;
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct perf_buffer_opts {
     pub sz: size_t,
     pub sample_period: __u32,
     pub __bindgen_padding_0: [u8; 4usize],
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn perf_buffer__new(
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
         page_cnt: size_t,
         sample_cb: perf_buffer_sample_fn,
         lost_cb: perf_buffer_lost_fn,
-        ctx: *mut ::std::os::raw::c_void,
+        ctx: *mut ::core::ffi::c_void,
         opts: *const perf_buffer_opts,
     ) -> *mut perf_buffer;
 }
 pub const LIBBPF_PERF_EVENT_DONE: bpf_perf_event_ret = 0;
 pub const LIBBPF_PERF_EVENT_ERROR: bpf_perf_event_ret = -1;
 pub const LIBBPF_PERF_EVENT_CONT: bpf_perf_event_ret = -2;
-pub type bpf_perf_event_ret = ::std::os::raw::c_int;
-pub type perf_buffer_event_fn = ::std::option::Option<
+pub type bpf_perf_event_ret = ::core::ffi::c_int;
+pub type perf_buffer_event_fn = ::core::option::Option<
     unsafe extern "C" fn(
-        ctx: *mut ::std::os::raw::c_void,
-        cpu: ::std::os::raw::c_int,
+        ctx: *mut ::core::ffi::c_void,
+        cpu: ::core::ffi::c_int,
         event: *mut perf_event_header,
     ) -> bpf_perf_event_ret,
 >;
@@ -8841,27 +8862,19 @@ pub type perf_buffer_event_fn = ::std::option::Option<
 #[derive(Debug, Copy, Clone)]
 pub struct perf_buffer_raw_opts {
     pub sz: size_t,
-    pub cpu_cnt: ::std::os::raw::c_int,
+    pub cpu_cnt: ::core::ffi::c_int,
     pub __bindgen_padding_0: [u8; 4usize],
-    pub cpus: *mut ::std::os::raw::c_int,
-    pub map_keys: *mut ::std::os::raw::c_int,
-}
-impl Default for perf_buffer_raw_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
+    pub cpus: *mut ::core::ffi::c_int,
+    pub map_keys: *mut ::core::ffi::c_int,
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn perf_buffer__new_raw(
-        map_fd: ::std::os::raw::c_int,
+        map_fd: ::core::ffi::c_int,
         page_cnt: size_t,
         attr: *mut perf_event_attr,
         event_cb: perf_buffer_event_fn,
-        ctx: *mut ::std::os::raw::c_void,
+        ctx: *mut ::core::ffi::c_void,
         opts: *const perf_buffer_raw_opts,
     ) -> *mut perf_buffer;
 }
@@ -8869,47 +8882,43 @@ unsafe extern "C" {
     pub fn perf_buffer__free(pb: *mut perf_buffer);
 }
 unsafe extern "C" {
-    pub fn perf_buffer__epoll_fd(pb: *const perf_buffer) -> ::std::os::raw::c_int;
+    pub fn perf_buffer__epoll_fd(pb: *const perf_buffer) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn perf_buffer__poll(
         pb: *mut perf_buffer,
-        timeout_ms: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
+        timeout_ms: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn perf_buffer__consume(pb: *mut perf_buffer) -> ::std::os::raw::c_int;
+    pub fn perf_buffer__consume(pb: *mut perf_buffer) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn perf_buffer__consume_buffer(
         pb: *mut perf_buffer,
         buf_idx: size_t,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn perf_buffer__buffer_cnt(pb: *const perf_buffer) -> size_t;
 }
 unsafe extern "C" {
     pub fn perf_buffer__buffer_fd(pb: *const perf_buffer, buf_idx: size_t)
-        -> ::std::os::raw::c_int;
+        -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn perf_buffer__buffer(
         pb: *mut perf_buffer,
-        buf_idx: ::std::os::raw::c_int,
-        buf: *mut *mut ::std::os::raw::c_void,
+        buf_idx: ::core::ffi::c_int,
+        buf: *mut *mut ::core::ffi::c_void,
         buf_size: *mut size_t,
-    ) -> ::std::os::raw::c_int;
-}
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct bpf_prog_linfo {
-    _unused: [u8; 0],
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_prog_linfo__free(prog_linfo: *mut bpf_prog_linfo);
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_prog_linfo__new(info: *const bpf_prog_info) -> *mut bpf_prog_linfo;
 }
 unsafe extern "C" {
@@ -8930,38 +8939,38 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn libbpf_probe_bpf_prog_type(
         prog_type: bpf_prog_type,
-        opts: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        opts: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn libbpf_probe_bpf_map_type(
         map_type: bpf_map_type,
-        opts: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        opts: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn libbpf_probe_bpf_helper(
         prog_type: bpf_prog_type,
         helper_id: bpf_func_id,
-        opts: *const ::std::os::raw::c_void,
-    ) -> ::std::os::raw::c_int;
+        opts: *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn libbpf_num_possible_cpus() -> ::std::os::raw::c_int;
+    pub fn libbpf_num_possible_cpus() -> ::core::ffi::c_int;
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_map_skeleton {
-    pub name: *const ::std::os::raw::c_char,
+    pub name: *const ::core::ffi::c_char,
     pub map: *mut *mut bpf_map,
-    pub mmaped: *mut *mut ::std::os::raw::c_void,
+    pub mmaped: *mut *mut ::core::ffi::c_void,
     pub link: *mut *mut bpf_link,
 }
 impl Default for bpf_map_skeleton {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -8969,15 +8978,15 @@ impl Default for bpf_map_skeleton {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_prog_skeleton {
-    pub name: *const ::std::os::raw::c_char,
+    pub name: *const ::core::ffi::c_char,
     pub prog: *mut *mut bpf_program,
     pub link: *mut *mut bpf_link,
 }
 impl Default for bpf_prog_skeleton {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -8986,37 +8995,38 @@ impl Default for bpf_prog_skeleton {
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_object_skeleton {
     pub sz: size_t,
-    pub name: *const ::std::os::raw::c_char,
-    pub data: *const ::std::os::raw::c_void,
+    pub name: *const ::core::ffi::c_char,
+    pub data: *const ::core::ffi::c_void,
     pub data_sz: size_t,
     pub obj: *mut *mut bpf_object,
-    pub map_cnt: ::std::os::raw::c_int,
-    pub map_skel_sz: ::std::os::raw::c_int,
+    pub map_cnt: ::core::ffi::c_int,
+    pub map_skel_sz: ::core::ffi::c_int,
     pub maps: *mut bpf_map_skeleton,
-    pub prog_cnt: ::std::os::raw::c_int,
-    pub prog_skel_sz: ::std::os::raw::c_int,
+    pub prog_cnt: ::core::ffi::c_int,
+    pub prog_skel_sz: ::core::ffi::c_int,
     pub progs: *mut bpf_prog_skeleton,
 }
 impl Default for bpf_object_skeleton {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_object__open_skeleton(
         s: *mut bpf_object_skeleton,
         opts: *const bpf_object_open_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_object__load_skeleton(s: *mut bpf_object_skeleton) -> ::std::os::raw::c_int;
+    pub fn bpf_object__load_skeleton(s: *mut bpf_object_skeleton) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_object__attach_skeleton(s: *mut bpf_object_skeleton) -> ::std::os::raw::c_int;
+    pub fn bpf_object__attach_skeleton(s: *mut bpf_object_skeleton) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__detach_skeleton(s: *mut bpf_object_skeleton);
@@ -9027,15 +9037,15 @@ unsafe extern "C" {
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct bpf_var_skeleton {
-    pub name: *const ::std::os::raw::c_char,
+    pub name: *const ::core::ffi::c_char,
     pub map: *mut *mut bpf_map,
-    pub addr: *mut *mut ::std::os::raw::c_void,
+    pub addr: *mut *mut ::core::ffi::c_void,
 }
 impl Default for bpf_var_skeleton {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -9045,27 +9055,28 @@ impl Default for bpf_var_skeleton {
 pub struct bpf_object_subskeleton {
     pub sz: size_t,
     pub obj: *const bpf_object,
-    pub map_cnt: ::std::os::raw::c_int,
-    pub map_skel_sz: ::std::os::raw::c_int,
+    pub map_cnt: ::core::ffi::c_int,
+    pub map_skel_sz: ::core::ffi::c_int,
     pub maps: *mut bpf_map_skeleton,
-    pub prog_cnt: ::std::os::raw::c_int,
-    pub prog_skel_sz: ::std::os::raw::c_int,
+    pub prog_cnt: ::core::ffi::c_int,
+    pub prog_skel_sz: ::core::ffi::c_int,
     pub progs: *mut bpf_prog_skeleton,
-    pub var_cnt: ::std::os::raw::c_int,
-    pub var_skel_sz: ::std::os::raw::c_int,
+    pub var_cnt: ::core::ffi::c_int,
+    pub var_skel_sz: ::core::ffi::c_int,
     pub vars: *mut bpf_var_skeleton,
 }
 impl Default for bpf_object_subskeleton {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 unsafe extern "C" {
-    pub fn bpf_object__open_subskeleton(s: *mut bpf_object_subskeleton) -> ::std::os::raw::c_int;
+    #[must_use]
+    pub fn bpf_object__open_subskeleton(s: *mut bpf_object_subskeleton) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_object__destroy_subskeleton(s: *mut bpf_object_subskeleton);
@@ -9074,116 +9085,117 @@ unsafe extern "C" {
 #[derive(Debug, Copy, Clone)]
 pub struct gen_loader_opts {
     pub sz: size_t,
-    pub data: *const ::std::os::raw::c_char,
-    pub insns: *const ::std::os::raw::c_char,
+    pub data: *const ::core::ffi::c_char,
+    pub insns: *const ::core::ffi::c_char,
     pub data_sz: __u32,
     pub insns_sz: __u32,
 }
-impl Default for gen_loader_opts {
-    fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
-        unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
-            s.assume_init()
-        }
-    }
-}
 unsafe extern "C" {
     pub fn bpf_object__gen_loader(
         obj: *mut bpf_object,
         opts: *mut gen_loader_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_linker_opts {
     pub sz: size_t,
 }
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct bpf_linker_file_opts {
     pub sz: size_t,
 }
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub struct bpf_linker {
-    _unused: [u8; 0],
-}
 unsafe extern "C" {
+    #[must_use]
     pub fn bpf_linker__new(
-        filename: *const ::std::os::raw::c_char,
+        filename: *const ::core::ffi::c_char,
         opts: *mut bpf_linker_opts,
     ) -> *mut bpf_linker;
 }
 unsafe extern "C" {
     pub fn bpf_linker__add_file(
         linker: *mut bpf_linker,
-        filename: *const ::std::os::raw::c_char,
+        filename: *const ::core::ffi::c_char,
         opts: *const bpf_linker_file_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
-    pub fn bpf_linker__finalize(linker: *mut bpf_linker) -> ::std::os::raw::c_int;
+    pub fn bpf_linker__finalize(linker: *mut bpf_linker) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn bpf_linker__free(linker: *mut bpf_linker);
 }
-pub type libbpf_prog_setup_fn_t = ::std::option::Option<
+pub type libbpf_prog_setup_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
         prog: *mut bpf_program,
-        cookie: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int,
+        cookie: ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int,
 >;
-pub type libbpf_prog_prepare_load_fn_t = ::std::option::Option<
+pub type libbpf_prog_prepare_load_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
         prog: *mut bpf_program,
         opts: *mut bpf_prog_load_opts,
-        cookie: ::std::os::raw::c_long,
-    ) -> ::std::os::raw::c_int,
+        cookie: ::core::ffi::c_long,
+    ) -> ::core::ffi::c_int,
 >;
-pub type libbpf_prog_attach_fn_t = ::std::option::Option<
+pub type libbpf_prog_attach_fn_t = ::core::option::Option<
     unsafe extern "C" fn(
         prog: *const bpf_program,
-        cookie: ::std::os::raw::c_long,
+        cookie: ::core::ffi::c_long,
         link: *mut *mut bpf_link,
-    ) -> ::std::os::raw::c_int,
+    ) -> ::core::ffi::c_int,
 >;
 #[repr(C)]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub struct libbpf_prog_handler_opts {
     pub sz: size_t,
-    pub cookie: ::std::os::raw::c_long,
+    pub cookie: ::core::ffi::c_long,
     pub prog_setup_fn: libbpf_prog_setup_fn_t,
     pub prog_prepare_load_fn: libbpf_prog_prepare_load_fn_t,
     pub prog_attach_fn: libbpf_prog_attach_fn_t,
 }
 unsafe extern "C" {
     pub fn libbpf_register_prog_handler(
-        sec: *const ::std::os::raw::c_char,
+        sec: *const ::core::ffi::c_char,
         prog_type: bpf_prog_type,
         exp_attach_type: bpf_attach_type,
         opts: *const libbpf_prog_handler_opts,
-    ) -> ::std::os::raw::c_int;
+    ) -> ::core::ffi::c_int;
 }
 unsafe extern "C" {
     pub fn libbpf_unregister_prog_handler(
-        handler_id: ::std::os::raw::c_int,
-    ) -> ::std::os::raw::c_int;
-}
+        handler_id: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+}
+pub const TCA_BPF_UNSPEC: _bindgen_ty_146 = 0;
+pub const TCA_BPF_ACT: _bindgen_ty_146 = 1;
+pub const TCA_BPF_POLICE: _bindgen_ty_146 = 2;
+pub const TCA_BPF_CLASSID: _bindgen_ty_146 = 3;
+pub const TCA_BPF_OPS_LEN: _bindgen_ty_146 = 4;
+pub const TCA_BPF_OPS: _bindgen_ty_146 = 5;
+pub const TCA_BPF_FD: _bindgen_ty_146 = 6;
+pub const TCA_BPF_NAME: _bindgen_ty_146 = 7;
+pub const TCA_BPF_FLAGS: _bindgen_ty_146 = 8;
+pub const TCA_BPF_FLAGS_GEN: _bindgen_ty_146 = 9;
+pub const TCA_BPF_TAG: _bindgen_ty_146 = 10;
+pub const TCA_BPF_ID: _bindgen_ty_146 = 11;
+pub const __TCA_BPF_MAX: _bindgen_ty_146 = 12;
+pub type _bindgen_ty_146 = ::core::ffi::c_uint;
 pub type __builtin_va_list = [__va_list_tag; 1usize];
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct __va_list_tag {
-    pub gp_offset: ::std::os::raw::c_uint,
-    pub fp_offset: ::std::os::raw::c_uint,
-    pub overflow_arg_area: *mut ::std::os::raw::c_void,
-    pub reg_save_area: *mut ::std::os::raw::c_void,
+    pub gp_offset: ::core::ffi::c_uint,
+    pub fp_offset: ::core::ffi::c_uint,
+    pub overflow_arg_area: *mut ::core::ffi::c_void,
+    pub reg_save_area: *mut ::core::ffi::c_void,
 }
 impl Default for __va_list_tag {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
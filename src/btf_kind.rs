@@ -0,0 +1,64 @@
+// src/btf_kind.rs
+
+//! A typed representation of the `BTF_KIND_*` constants.
+//!
+//! The variants are generated from the bindings at build time, so they
+//! always match the kinds known to the bound libbpf.
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crate::bindings::*;
+
+/// The error returned when converting a value that is not a known BTF
+/// kind into a [`BtfKind`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UnknownBtfKind(pub u32);
+
+impl Display for UnknownBtfKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown BTF kind {}", self.0)
+    }
+}
+
+macro_rules! btf_kind_table {
+    ($($variant:ident = $value:ident,)*) => {
+        /// The kind of a BTF type, as stored in `btf_type::info`.
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[repr(u32)]
+        pub enum BtfKind {
+            $(
+                #[doc = concat!("`", stringify!($value), "`")]
+                $variant = $value,
+            )*
+        }
+
+        impl TryFrom<u32> for BtfKind {
+            type Error = UnknownBtfKind;
+
+            fn try_from(value: u32) -> Result<Self, Self::Error> {
+                match value {
+                    $($value => Ok(Self::$variant),)*
+                    _ => Err(UnknownBtfKind(value)),
+                }
+            }
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/btf_kind.rs"));
+
+impl From<BtfKind> for u32 {
+    fn from(kind: BtfKind) -> Self {
+        kind as u32
+    }
+}
+
+impl btf_type {
+    /// Extract the kind from `info` (`BTF_INFO_KIND`).
+    pub fn kind(&self) -> Result<BtfKind, UnknownBtfKind> {
+        BtfKind::try_from((self.info >> 24) & 0x1f)
+    }
+}
@@ -0,0 +1,112 @@
+// src/btf_wire.rs
+
+//! Reading BTF structures in either byte order.
+//!
+//! The bindings describe the layout of `.BTF` section contents in native
+//! byte order. Object files built for a target of the other byte order
+//! have all multi-byte fields swapped, which the `read` functions in
+//! here take care of, member by member, as the build script lists them
+//! in the bindings. The byte order of a `.BTF` section can be determined
+//! from its header, see [`btf_header::endianness`].
+
+use core::convert::TryInto as _;
+use core::mem::size_of;
+
+use crate::bindings::*;
+
+/// The byte order of the target being compiled for.
+pub const BTF_NATIVE_ENDIAN: btf_endianness = if cfg!(target_endian = "big") {
+    BTF_BIG_ENDIAN
+} else {
+    BTF_LITTLE_ENDIAN
+};
+
+/// An integer as found in BTF structures.
+trait WireInt: Sized {
+    const SIZE: usize;
+
+    fn read(data: &[u8], endianness: btf_endianness) -> Self;
+}
+
+macro_rules! impl_wire_int {
+    ($($ty:ty),*) => {
+        $(
+            impl WireInt for $ty {
+                const SIZE: usize = size_of::<Self>();
+
+                fn read(data: &[u8], endianness: btf_endianness) -> Self {
+                    let bytes = data[..Self::SIZE].try_into().unwrap();
+                    if endianness == BTF_BIG_ENDIAN {
+                        Self::from_be_bytes(bytes)
+                    } else {
+                        Self::from_le_bytes(bytes)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_wire_int!(u8, u16, u32, i32);
+
+macro_rules! impl_read {
+    ($($name:ident { $($field:ident: $ty:ty),* $(,)? })*) => {
+        $(
+            impl $name {
+                /// Read an instance from the beginning of `data`, which
+                /// is encoded in the byte order `endianness`.
+                ///
+                /// `None` is returned if `data` is too short.
+                pub fn read(data: &[u8], endianness: btf_endianness) -> Option<Self> {
+                    if data.len() < size_of::<Self>() {
+                        return None;
+                    }
+
+                    let mut offset = 0;
+                    $(
+                        let $field = <$ty as WireInt>::read(&data[offset..], endianness);
+                        offset += <$ty as WireInt>::SIZE;
+                    )*
+                    debug_assert_eq!(offset, size_of::<Self>());
+                    Some(Self { $($field),* })
+                }
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/btf_wire.rs"));
+
+impl btf_type {
+    /// Read an instance from the beginning of `data`, which is encoded
+    /// in the byte order `endianness`.
+    ///
+    /// `None` is returned if `data` is too short.
+    pub fn read(data: &[u8], endianness: btf_endianness) -> Option<Self> {
+        if data.len() < size_of::<Self>() {
+            return None;
+        }
+
+        Some(Self {
+            name_off: u32::read(data, endianness),
+            info: u32::read(&data[4..], endianness),
+            // `size` and `type` are both 32 bit wide.
+            __bindgen_anon_1: btf_type__bindgen_ty_1 {
+                size: u32::read(&data[8..], endianness),
+            },
+        })
+    }
+}
+
+impl btf_header {
+    /// Determine the byte order of the BTF data starting with the
+    /// header at the beginning of `data`, based on its magic.
+    pub fn endianness(data: &[u8]) -> Option<btf_endianness> {
+        let magic = BTF_MAGIC as u16;
+        match data.get(..2)? {
+            bytes if bytes == magic.to_le_bytes() => Some(BTF_LITTLE_ENDIAN),
+            bytes if bytes == magic.to_be_bytes() => Some(BTF_BIG_ENDIAN),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+// src/callback.rs
+
+//! Adapters turning Rust closures into libbpf callbacks.
+//!
+//! libbpf takes callbacks as a function pointer along with an opaque
+//! context pointer that it passes back to it. A [`Callback`] owns a
+//! boxed closure and provides both: a trampoline function, monomorphized
+//! for the closure type, that forwards to the closure, and the pointer
+//! to the closure as context. Where libbpf passes one context to several
+//! callbacks, the callback type is a tuple of them.
+//!
+//! A `Callback` has to outlive the libbpf object it is registered with,
+//! e.g., it must only be dropped after the `ring_buffer` was freed.
+//! Alternatively, [`Callback::into_raw`] hands over ownership along with
+//! a destructor to invoke once libbpf no longer uses the context.
+//!
+//! Closures must not panic: unwinding out of a trampoline aborts the
+//! process.
+
+use alloc::boxed::Box;
+use core::ffi::c_int;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::slice;
+
+use crate::bindings::*;
+
+/// A C callback of type `C`, along with the context to pass to it,
+/// owning the closure it forwards to.
+#[derive(Debug)]
+pub struct Callback<'f, C: Copy> {
+    callback: C,
+    ctx: *mut c_void,
+    destructor: unsafe fn(*mut c_void),
+    _closure: PhantomData<&'f mut ()>,
+}
+
+impl<'f, C: Copy> Callback<'f, C> {
+    fn new<F: 'f>(f: F, callback: C) -> Self {
+        unsafe fn destroy<F>(ctx: *mut c_void) {
+            drop(unsafe { Box::from_raw(ctx.cast::<F>()) })
+        }
+
+        Self {
+            callback,
+            ctx: Box::into_raw(Box::new(f)).cast(),
+            destructor: destroy::<F>,
+            _closure: PhantomData,
+        }
+    }
+
+    /// The callback function to pass to libbpf.
+    pub fn callback(&self) -> C {
+        self.callback
+    }
+
+    /// The context to pass to libbpf along with the callback.
+    pub fn ctx(&self) -> *mut c_void {
+        self.ctx
+    }
+
+    /// Give up ownership of the closure, returning the callback, the
+    /// context, and the destructor to invoke with the context to free
+    /// the closure.
+    pub fn into_raw(self) -> (C, *mut c_void, unsafe fn(*mut c_void)) {
+        let this = ManuallyDrop::new(self);
+        (this.callback, this.ctx, this.destructor)
+    }
+}
+
+impl<C: Copy> Drop for Callback<'_, C> {
+    fn drop(&mut self) {
+        unsafe { (self.destructor)(self.ctx) }
+    }
+}
+
+/// Interpret the `data` and `size` passed to a callback as a slice.
+///
+/// # Safety
+/// `data` has to be null or valid for reads of `size` bytes.
+unsafe fn as_slice<'a>(data: *const c_void, size: usize) -> &'a [u8] {
+    if data.is_null() || size == 0 {
+        &[]
+    } else {
+        unsafe { slice::from_raw_parts(data.cast(), size) }
+    }
+}
+
+/// Adapt `f` into a `ring_buffer_sample_fn`, for use with
+/// `ring_buffer__new` and `ring_buffer__add`.
+///
+/// `f` receives each sample and returns zero to continue consuming or a
+/// negative error code to stop.
+pub fn ring_buffer_callback<'f, F>(f: F) -> Callback<'f, ring_buffer_sample_fn>
+where
+    F: FnMut(&[u8]) -> c_int + 'f,
+{
+    unsafe extern "C" fn trampoline<F>(ctx: *mut c_void, data: *mut c_void, size: size_t) -> c_int
+    where
+        F: FnMut(&[u8]) -> c_int,
+    {
+        let f = unsafe { &mut *ctx.cast::<F>() };
+        // `size_t` is `usize` with the `libc` feature.
+        #[allow(clippy::unnecessary_cast)]
+        f(unsafe { as_slice(data, size as usize) })
+    }
+
+    Callback::new(f, Some(trampoline::<F> as _))
+}
+
+/// Adapt `sample` and `lost` into a `perf_buffer_sample_fn` and
+/// `perf_buffer_lost_fn` sharing a context, for use with
+/// `perf_buffer__new`.
+///
+/// `sample` receives the CPU and the data of each sample and `lost` the
+/// CPU and the number of samples lost.
+#[cfg(not(feature = "no-perf"))]
+pub fn perf_buffer_callbacks<'f, S, L>(
+    sample: S,
+    lost: L,
+) -> Callback<'f, (perf_buffer_sample_fn, perf_buffer_lost_fn)>
+where
+    S: FnMut(c_int, &[u8]) + 'f,
+    L: FnMut(c_int, u64) + 'f,
+{
+    unsafe extern "C" fn sample_trampoline<S, L>(
+        ctx: *mut c_void,
+        cpu: c_int,
+        data: *mut c_void,
+        size: __u32,
+    ) where
+        S: FnMut(c_int, &[u8]),
+    {
+        let (sample, _) = unsafe { &mut *ctx.cast::<(S, L)>() };
+        sample(cpu, unsafe { as_slice(data, size as usize) })
+    }
+
+    unsafe extern "C" fn lost_trampoline<S, L>(ctx: *mut c_void, cpu: c_int, cnt: __u64)
+    where
+        L: FnMut(c_int, u64),
+    {
+        let (_, lost) = unsafe { &mut *ctx.cast::<(S, L)>() };
+        lost(cpu, cnt)
+    }
+
+    Callback::new(
+        (sample, lost),
+        (
+            Some(sample_trampoline::<S, L> as _),
+            Some(lost_trampoline::<S, L> as _),
+        ),
+    )
+}
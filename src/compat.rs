@@ -0,0 +1,344 @@
+// src/compat.rs
+
+//! Pre-1.0 libbpf APIs, implemented on top of the current ones.
+//!
+//! libbpf 1.0 removed a number of deprecated functions. The functions
+//! in here have the signatures and semantics of the removed ones, to
+//! ease migrating code incrementally. They report errors the way libbpf
+//! 1.0 does, i.e., as negative error codes or null pointers with `errno`
+//! set.
+//!
+//! `bpf_object__unload` has no counterpart: as of libbpf 1.0, programs
+//! and maps of an object stay loaded until the object is closed with
+//! `bpf_object__close`.
+
+use core::ffi::c_char;
+use core::ffi::c_int;
+use core::ffi::CStr;
+use core::ptr;
+
+use crate::bindings::*;
+
+const ENOENT: c_int = 2;
+const EINVAL: c_int = 22;
+
+/// The attributes for [`bpf_prog_load_xattr`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bpf_prog_load_attr {
+    pub file: *const c_char,
+    pub prog_type: bpf_prog_type,
+    pub expected_attach_type: bpf_attach_type,
+    pub ifindex: c_int,
+    pub log_level: c_int,
+    pub prog_flags: c_int,
+}
+
+impl Default for bpf_prog_load_attr {
+    fn default() -> Self {
+        Self {
+            file: ptr::null(),
+            prog_type: BPF_PROG_TYPE_UNSPEC,
+            expected_attach_type: 0,
+            ifindex: 0,
+            log_level: 0,
+            prog_flags: 0,
+        }
+    }
+}
+
+/// Open and load the object file `attr.file`, storing the object in
+/// `pobj` and the file descriptor of its first program in `prog_fd`.
+///
+/// If `attr.prog_type` is set, it overrides the type of all programs
+/// along with their expected attach type.
+///
+/// # Safety
+/// `attr` has to point to valid attributes and `pobj` and `prog_fd`
+/// have to be valid for writes.
+pub unsafe fn bpf_prog_load_xattr(
+    attr: *const bpf_prog_load_attr,
+    pobj: *mut *mut bpf_object,
+    prog_fd: *mut c_int,
+) -> c_int {
+    let Some(attr) = (unsafe { attr.as_ref() }) else {
+        return -EINVAL;
+    };
+    if attr.file.is_null() {
+        return -EINVAL;
+    }
+
+    let obj = unsafe { bpf_object__open(attr.file) };
+    if obj.is_null() {
+        // libbpf reports the error through `errno`, which
+        // `libbpf_get_error` negates for null pointers.
+        return unsafe { libbpf_get_error(obj as *const _) } as c_int;
+    }
+
+    let mut first_prog: *mut bpf_program = ptr::null_mut();
+    let mut prog = unsafe { bpf_object__next_program(obj, ptr::null_mut()) };
+    while !prog.is_null() {
+        unsafe {
+            if attr.prog_type != BPF_PROG_TYPE_UNSPEC {
+                let _ = bpf_program__set_type(prog, attr.prog_type);
+                let _ = bpf_program__set_expected_attach_type(prog, attr.expected_attach_type);
+            }
+            if bpf_program__type(prog) == BPF_PROG_TYPE_UNSPEC {
+                bpf_object__close(obj);
+                return -EINVAL;
+            }
+            bpf_program__set_ifindex(prog, attr.ifindex as _);
+            let _ = bpf_program__set_log_level(prog, attr.log_level as _);
+            let _ = bpf_program__set_flags(prog, bpf_program__flags(prog) | attr.prog_flags as u32);
+        }
+
+        if first_prog.is_null() {
+            first_prog = prog;
+        }
+        prog = unsafe { bpf_object__next_program(obj, prog) };
+    }
+
+    let mut map = unsafe { bpf_object__next_map(obj, ptr::null()) };
+    while !map.is_null() {
+        unsafe {
+            if bpf_map__type(map) != BPF_MAP_TYPE_PERF_EVENT_ARRAY {
+                let _ = bpf_map__set_ifindex(map, attr.ifindex as _);
+            }
+            map = bpf_object__next_map(obj, map);
+        }
+    }
+
+    // An object without programs has no program to report the file
+    // descriptor of.
+    if first_prog.is_null() {
+        unsafe { bpf_object__close(obj) };
+        return -ENOENT;
+    }
+
+    let err = unsafe { bpf_object__load(obj) };
+    if err != 0 {
+        unsafe { bpf_object__close(obj) };
+        return err;
+    }
+
+    unsafe {
+        *pobj = obj;
+        *prog_fd = bpf_program__fd(first_prog);
+    }
+    0
+}
+
+/// The pre-1.0 `bpf_prog_load`: like [`bpf_prog_load_xattr`], with
+/// just the file and program type given.
+///
+/// # Safety
+/// `file` has to point to a NUL terminated string and `pobj` and
+/// `prog_fd` have to be valid for writes.
+pub unsafe fn bpf_prog_load_deprecated(
+    file: *const c_char,
+    type_: bpf_prog_type,
+    pobj: *mut *mut bpf_object,
+    prog_fd: *mut c_int,
+) -> c_int {
+    let attr = bpf_prog_load_attr {
+        file,
+        prog_type: type_,
+        ..Default::default()
+    };
+    unsafe { bpf_prog_load_xattr(&attr, pobj, prog_fd) }
+}
+
+/// Load a program, writing the verifier log to `log_buf` if it is not
+/// null.
+///
+/// # Safety
+/// The same requirements as for `bpf_prog_load` apply; `log_buf` has to
+/// be valid for `log_buf_sz` bytes of writes, if not null.
+pub unsafe fn bpf_load_program(
+    type_: bpf_prog_type,
+    insns: *const bpf_insn,
+    insns_cnt: size_t,
+    license: *const c_char,
+    kern_version: __u32,
+    log_buf: *mut c_char,
+    log_buf_sz: size_t,
+) -> c_int {
+    let mut opts = bpf_prog_load_opts {
+        kern_version,
+        log_buf,
+        log_size: log_buf_sz as _,
+        ..Default::default()
+    };
+    unsafe { bpf_prog_load(type_, ptr::null(), license, insns, insns_cnt, &mut opts) }
+}
+
+/// Create a map named `name`.
+///
+/// # Safety
+/// `name` has to be null or point to a NUL terminated string.
+pub unsafe fn bpf_create_map_name(
+    map_type: bpf_map_type,
+    name: *const c_char,
+    key_size: c_int,
+    value_size: c_int,
+    max_entries: c_int,
+    map_flags: __u32,
+) -> c_int {
+    let opts = bpf_map_create_opts {
+        map_flags,
+        ..Default::default()
+    };
+    unsafe {
+        bpf_map_create(
+            map_type,
+            name,
+            key_size as _,
+            value_size as _,
+            max_entries as _,
+            &opts,
+        )
+    }
+}
+
+/// Create an anonymous map.
+///
+/// # Safety
+/// This function is safe to call, but kept `unsafe` like its siblings.
+pub unsafe fn bpf_create_map(
+    map_type: bpf_map_type,
+    key_size: c_int,
+    value_size: c_int,
+    max_entries: c_int,
+    map_flags: __u32,
+) -> c_int {
+    unsafe {
+        bpf_create_map_name(
+            map_type,
+            ptr::null(),
+            key_size,
+            value_size,
+            max_entries,
+            map_flags,
+        )
+    }
+}
+
+/// Find the program of `obj` residing in the section `title`.
+///
+/// # Safety
+/// `obj` has to point to a valid object and `title` to a NUL terminated
+/// string.
+pub unsafe fn bpf_object__find_program_by_title(
+    obj: *const bpf_object,
+    title: *const c_char,
+) -> *mut bpf_program {
+    let title = unsafe { CStr::from_ptr(title) };
+    let mut prog = unsafe { bpf_object__next_program(obj, ptr::null_mut()) };
+    while !prog.is_null() {
+        let name = unsafe { CStr::from_ptr(bpf_program__section_name(prog)) };
+        if name == title {
+            return prog;
+        }
+        prog = unsafe { bpf_object__next_program(obj, prog) };
+    }
+    ptr::null_mut()
+}
+
+/// Retrieve the name of the section `prog` resides in. Copying the name
+/// is not supported anymore, so `needs_copy` has to be `false`.
+///
+/// # Safety
+/// `prog` has to point to a valid program.
+pub unsafe fn bpf_program__title(prog: *const bpf_program, needs_copy: bool) -> *const c_char {
+    if needs_copy {
+        return ptr::null();
+    }
+    unsafe { bpf_program__section_name(prog) }
+}
+
+/// Iterate over the programs of `obj`, starting after `prev`.
+///
+/// # Safety
+/// `obj` has to point to a valid object and `prev` has to be null or
+/// one of its programs.
+pub unsafe fn bpf_program__next(
+    prev: *mut bpf_program,
+    obj: *const bpf_object,
+) -> *mut bpf_program {
+    unsafe { bpf_object__next_program(obj, prev) }
+}
+
+/// Iterate over the programs of `obj` backwards, starting before `next`.
+///
+/// # Safety
+/// See [`bpf_program__next`].
+pub unsafe fn bpf_program__prev(
+    next: *mut bpf_program,
+    obj: *const bpf_object,
+) -> *mut bpf_program {
+    unsafe { bpf_object__prev_program(obj, next) }
+}
+
+/// Iterate over the maps of `obj`, starting after `prev`.
+///
+/// # Safety
+/// `obj` has to point to a valid object and `prev` has to be null or
+/// one of its maps.
+pub unsafe fn bpf_map__next(prev: *const bpf_map, obj: *const bpf_object) -> *mut bpf_map {
+    unsafe { bpf_object__next_map(obj, prev) }
+}
+
+/// Iterate over the maps of `obj` backwards, starting before `next`.
+///
+/// # Safety
+/// See [`bpf_map__next`].
+pub unsafe fn bpf_map__prev(next: *const bpf_map, obj: *const bpf_object) -> *mut bpf_map {
+    unsafe { bpf_object__prev_map(obj, next) }
+}
+
+/// Change the maximum number of entries of `map`, before it is loaded.
+///
+/// # Safety
+/// `map` has to point to a valid map.
+pub unsafe fn bpf_map__resize(map: *mut bpf_map, max_entries: __u32) -> c_int {
+    unsafe { bpf_map__set_max_entries(map, max_entries) }
+}
+
+/// Attach the XDP program `fd` to the interface `ifindex`, or detach
+/// the attached one if `fd` is -1.
+///
+/// # Safety
+/// This function is safe to call, but kept `unsafe` like its siblings.
+pub unsafe fn bpf_set_link_xdp_fd(ifindex: c_int, fd: c_int, flags: __u32) -> c_int {
+    unsafe { bpf_xdp_attach(ifindex, fd, flags, ptr::null()) }
+}
+
+/// Retrieve the ID of the XDP program attached to `ifindex`.
+///
+/// # Safety
+/// `prog_id` has to be valid for writes.
+pub unsafe fn bpf_get_link_xdp_id(ifindex: c_int, prog_id: *mut __u32, flags: __u32) -> c_int {
+    unsafe { bpf_xdp_query_id(ifindex, flags as _, prog_id) }
+}
+
+/// Load the BTF object with ID `id` from the kernel into `btf`.
+///
+/// # Safety
+/// `btf` has to be valid for writes.
+pub unsafe fn btf__get_from_id(id: __u32, btf: *mut *mut btf) -> c_int {
+    let res = unsafe { btf__load_from_kernel_by_id(id) };
+    let err = unsafe { libbpf_get_error(res as *const _) };
+    if err != 0 {
+        return err as c_int;
+    }
+    unsafe { *btf = res };
+    0
+}
+
+/// Retrieve the number of types in `btf`, not counting the `void` type.
+///
+/// # Safety
+/// `btf` has to point to a valid BTF object.
+pub unsafe fn btf__get_nr_types(btf: *const btf) -> __u32 {
+    unsafe { btf__type_cnt(btf) - 1 }
+}
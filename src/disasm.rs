@@ -0,0 +1,279 @@
+// src/disasm.rs
+
+//! A small disassembler for BPF instructions.
+//!
+//! The output loosely follows the format of the kernel's verifier log
+//! and `bpftool prog dump xlated`, e.g.:
+//! ```text
+//!    0: (b7) r0 = 0
+//!    1: (95) exit
+//! ```
+
+use alloc::string::String;
+use alloc::string::ToString as _;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crate::bindings::*;
+use crate::insn::BPF_CLASS;
+use crate::insn::BPF_MODE;
+use crate::insn::BPF_OP;
+use crate::insn::BPF_SIZE;
+use crate::insn::BPF_SRC;
+
+/// Disassemble `insns` into a multi-line string.
+pub fn disassemble(insns: &[bpf_insn]) -> String {
+    Disassembly(insns).to_string()
+}
+
+/// A wrapper around a sequence of instructions that renders them in
+/// human readable form when displayed.
+#[derive(Clone, Copy, Debug)]
+pub struct Disassembly<'insn>(pub &'insn [bpf_insn]);
+
+impl Display for Disassembly<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut idx = 0;
+        while let Some(insn) = self.0.get(idx) {
+            write!(f, "{idx:4}: ({:02x}) ", insn.code)?;
+
+            let code = u32::from(insn.code);
+            if code == BPF_LD | BPF_DW | BPF_IMM {
+                let next = self.0.get(idx + 1);
+                fmt_ld_imm64(f, insn, next)?;
+                idx += 1;
+            } else {
+                fmt_insn(f, insn)?;
+            }
+            writeln!(f)?;
+            idx += 1;
+        }
+        Ok(())
+    }
+}
+
+fn reg(class: u32, reg: u8) -> (char, u8) {
+    if class == BPF_ALU || class == BPF_JMP32 {
+        ('w', reg)
+    } else {
+        ('r', reg)
+    }
+}
+
+fn size_str(size: u32) -> &'static str {
+    match size {
+        BPF_B => "u8",
+        BPF_H => "u16",
+        BPF_W => "u32",
+        _ => "u64",
+    }
+}
+
+fn signed_size_str(size: u32) -> &'static str {
+    match size {
+        BPF_B => "s8",
+        BPF_H => "s16",
+        BPF_W => "s32",
+        _ => "s64",
+    }
+}
+
+fn alu_op_str(op: u32, off: i16) -> Option<&'static str> {
+    let s = match (op, off) {
+        (BPF_ADD, _) => "+=",
+        (BPF_SUB, _) => "-=",
+        (BPF_MUL, _) => "*=",
+        (BPF_DIV, 1) => "s/=",
+        (BPF_DIV, _) => "/=",
+        (BPF_OR, _) => "|=",
+        (BPF_AND, _) => "&=",
+        (BPF_LSH, _) => "<<=",
+        (BPF_RSH, _) => ">>=",
+        (BPF_MOD, 1) => "s%=",
+        (BPF_MOD, _) => "%=",
+        (BPF_XOR, _) => "^=",
+        (BPF_MOV, _) => "=",
+        (BPF_ARSH, _) => "s>>=",
+        _ => return None,
+    };
+    Some(s)
+}
+
+fn jmp_op_str(op: u32) -> Option<&'static str> {
+    let s = match op {
+        BPF_JEQ => "==",
+        BPF_JGT => ">",
+        BPF_JGE => ">=",
+        BPF_JSET => "&",
+        BPF_JNE => "!=",
+        BPF_JSGT => "s>",
+        BPF_JSGE => "s>=",
+        BPF_JLT => "<",
+        BPF_JLE => "<=",
+        BPF_JSLT => "s<",
+        BPF_JSLE => "s<=",
+        _ => return None,
+    };
+    Some(s)
+}
+
+fn fmt_ld_imm64(f: &mut Formatter<'_>, insn: &bpf_insn, next: Option<&bpf_insn>) -> fmt::Result {
+    let dst = insn.dst_reg();
+    let Some(next) = next else {
+        return write!(f, "r{dst} = <truncated ld_imm64>");
+    };
+
+    let lo = insn.imm as u32;
+    let hi = next.imm as u32;
+    match u32::from(insn.src_reg()) {
+        BPF_PSEUDO_MAP_FD => write!(f, "r{dst} = map[fd:{lo}]"),
+        BPF_PSEUDO_MAP_IDX => write!(f, "r{dst} = map[idx:{lo}]"),
+        BPF_PSEUDO_MAP_VALUE => write!(f, "r{dst} = map[fd:{lo}][0]+{hi}"),
+        BPF_PSEUDO_MAP_IDX_VALUE => write!(f, "r{dst} = map[idx:{lo}][0]+{hi}"),
+        BPF_PSEUDO_BTF_ID => write!(f, "r{dst} = btf_id[{lo}]"),
+        BPF_PSEUDO_FUNC => write!(f, "r{dst} = subprog[pc{:+}]", insn.imm),
+        _ => {
+            let imm = u64::from(hi) << 32 | u64::from(lo);
+            write!(f, "r{dst} = {imm:#x}")
+        },
+    }
+}
+
+fn fmt_insn(f: &mut Formatter<'_>, insn: &bpf_insn) -> fmt::Result {
+    let code = u32::from(insn.code);
+    let class = BPF_CLASS(code);
+    let (dst, src) = (insn.dst_reg(), insn.src_reg());
+    let (off, imm) = (insn.off, insn.imm);
+
+    match class {
+        BPF_ALU | BPF_ALU64 => {
+            let (r, dst) = reg(class, dst);
+            let op = BPF_OP(code);
+            match op {
+                BPF_NEG => write!(f, "{r}{dst} = -{r}{dst}"),
+                BPF_END => {
+                    let order = if class == BPF_ALU64 {
+                        "bswap"
+                    } else if BPF_SRC(code) == BPF_TO_BE {
+                        "be"
+                    } else {
+                        "le"
+                    };
+                    write!(f, "r{dst} = {order}{imm} r{dst}")
+                },
+                BPF_MOV if off != 0 && BPF_SRC(code) == BPF_X => {
+                    write!(f, "{r}{dst} = (s{off}){r}{src}")
+                },
+                _ => {
+                    let Some(op) = alu_op_str(op, off) else {
+                        return write!(f, "<invalid alu op>");
+                    };
+                    if BPF_SRC(code) == BPF_X {
+                        write!(f, "{r}{dst} {op} {r}{src}")
+                    } else {
+                        write!(f, "{r}{dst} {op} {imm}")
+                    }
+                },
+            }
+        },
+        BPF_LD => {
+            let size = size_str(BPF_SIZE(code));
+            match BPF_MODE(code) {
+                BPF_ABS => write!(f, "r0 = *({size} *)skb[{imm}]"),
+                BPF_IND => write!(f, "r0 = *({size} *)skb[r{src} + {imm}]"),
+                _ => write!(f, "<invalid ld mode>"),
+            }
+        },
+        BPF_LDX => {
+            let size = BPF_SIZE(code);
+            match BPF_MODE(code) {
+                BPF_MEM => write!(f, "r{dst} = *({} *)(r{src} {off:+})", size_str(size)),
+                BPF_MEMSX => write!(
+                    f,
+                    "r{dst} = *({} *)(r{src} {off:+})",
+                    signed_size_str(size)
+                ),
+                _ => write!(f, "<invalid ldx mode>"),
+            }
+        },
+        BPF_ST => {
+            let size = size_str(BPF_SIZE(code));
+            write!(f, "*({size} *)(r{dst} {off:+}) = {imm}")
+        },
+        BPF_STX => {
+            let size = BPF_SIZE(code);
+            match BPF_MODE(code) {
+                BPF_MEM => write!(f, "*({} *)(r{dst} {off:+}) = r{src}", size_str(size)),
+                BPF_ATOMIC => fmt_atomic(f, size, dst, src, off, imm as u32),
+                _ => write!(f, "<invalid stx mode>"),
+            }
+        },
+        BPF_JMP | BPF_JMP32 => {
+            let op = BPF_OP(code);
+            match op {
+                BPF_JA if class == BPF_JMP32 => write!(f, "gotol pc{imm:+}"),
+                BPF_JA => write!(f, "goto pc{off:+}"),
+                BPF_CALL => match u32::from(src) {
+                    BPF_PSEUDO_CALL => write!(f, "call pc{imm:+}"),
+                    BPF_PSEUDO_KFUNC_CALL => write!(f, "call kfunc#{imm}"),
+                    _ => write!(f, "call #{imm}"),
+                },
+                BPF_EXIT => write!(f, "exit"),
+                BPF_JCOND => write!(f, "may_goto pc{off:+}"),
+                _ => {
+                    let Some(op) = jmp_op_str(op) else {
+                        return write!(f, "<invalid jmp op>");
+                    };
+                    let (r, dst) = reg(class, dst);
+                    if BPF_SRC(code) == BPF_X {
+                        write!(f, "if {r}{dst} {op} {r}{src} goto pc{off:+}")
+                    } else {
+                        write!(f, "if {r}{dst} {op} {imm:#x} goto pc{off:+}")
+                    }
+                },
+            }
+        },
+        _ => write!(f, "<invalid class>"),
+    }
+}
+
+fn fmt_atomic(
+    f: &mut Formatter<'_>,
+    size: u32,
+    dst: u8,
+    src: u8,
+    off: i16,
+    op: u32,
+) -> fmt::Result {
+    let (r, width) = if size == BPF_DW { ('r', "64") } else { ('w', "") };
+    let size = size_str(size);
+    let fetch = op & BPF_FETCH != 0;
+    match op {
+        BPF_XCHG => write!(
+            f,
+            "{r}{src} = atomic{width}_xchg(({size} *)(r{dst} {off:+}), {r}{src})"
+        ),
+        BPF_CMPXCHG => write!(
+            f,
+            "{r}0 = atomic{width}_cmpxchg(({size} *)(r{dst} {off:+}), {r}0, {r}{src})"
+        ),
+        _ => {
+            let (name, op_str) = match op & !BPF_FETCH {
+                BPF_ADD => ("add", "+="),
+                BPF_AND => ("and", "&="),
+                BPF_OR => ("or", "|="),
+                BPF_XOR => ("xor", "^="),
+                _ => return write!(f, "<invalid atomic op>"),
+            };
+            if fetch {
+                write!(
+                    f,
+                    "{r}{src} = atomic{width}_fetch_{name}(({size} *)(r{dst} {off:+}), {r}{src})"
+                )
+            } else {
+                write!(f, "lock *({size} *)(r{dst} {off:+}) {op_str} {r}{src}")
+            }
+        },
+    }
+}
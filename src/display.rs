@@ -0,0 +1,104 @@
+// src/display.rs
+
+//! `Display` and `FromStr` for program, map, attach, and link types.
+//!
+//! The types wrap the respective constants and format them using the
+//! names libbpf uses, e.g., `BPF_PROG_TYPE_KPROBE` is `"kprobe"`. When
+//! linked against libbpf, its `libbpf_*_str` functions provide the
+//! names, with the tables of the [`names`](crate::names) module as
+//! fallback. Parsing always uses the latter.
+
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+use crate::bindings::*;
+use crate::names::*;
+
+/// The error returned when parsing an unknown type name.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UnknownTypeName {
+    /// The name of the type being parsed, e.g., `bpf_prog_type`.
+    pub ty: &'static str,
+}
+
+impl Display for UnknownTypeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} name", self.ty)
+    }
+}
+
+macro_rules! named_types {
+    ($(
+        $(#[$docs:meta])*
+        $name:ident($ty:ident, $libbpf_str:ident, $to_name:ident, $from_name:ident)
+    ),* $(,)?) => {
+        $(
+            $(#[$docs])*
+            #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+            #[repr(transparent)]
+            pub struct $name(pub $ty);
+
+            impl From<$ty> for $name {
+                fn from(value: $ty) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<$name> for $ty {
+                fn from(value: $name) -> Self {
+                    value.0
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    // Miri cannot call into libbpf, and `btf-only` does not
+                    // build these functions.
+                    #[cfg(not(any(
+                        feature = "dlopen",
+                        feature = "stubs",
+                        feature = "btf-only",
+                        miri
+                    )))]
+                    {
+                        let name = unsafe { $libbpf_str(self.0) };
+                        if !name.is_null() {
+                            let name = unsafe { core::ffi::CStr::from_ptr(name) };
+                            if let Ok(name) = name.to_str() {
+                                return f.write_str(name);
+                            }
+                        }
+                    }
+
+                    match $to_name(self.0) {
+                        Some(name) => f.write_str(name),
+                        None => write!(f, "unknown {} {}", stringify!($ty), self.0),
+                    }
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = UnknownTypeName;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    $from_name(s).map(Self).ok_or(UnknownTypeName {
+                        ty: stringify!($ty),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+named_types! {
+    /// A `bpf_prog_type`.
+    ProgType(bpf_prog_type, libbpf_bpf_prog_type_str, bpf_prog_type_name, bpf_prog_type_from_str),
+    /// A `bpf_map_type`.
+    MapType(bpf_map_type, libbpf_bpf_map_type_str, bpf_map_type_name, bpf_map_type_from_str),
+    /// A `bpf_attach_type`.
+    AttachType(bpf_attach_type, libbpf_bpf_attach_type_str, bpf_attach_type_name, bpf_attach_type_from_str),
+    /// A `bpf_link_type`.
+    LinkType(bpf_link_type, libbpf_bpf_link_type_str, bpf_link_type_name, bpf_link_type_from_str),
+}
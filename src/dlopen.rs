@@ -0,0 +1,79 @@
+// src/dlopen.rs
+
+//! Loading libbpf at runtime instead of linking against it.
+//!
+//! With the `dlopen` feature enabled, the crate does not link against
+//! libbpf. The functions declared at the crate root must not be used
+//! then; call them through a [`LibBpf`] instead, which provides a
+//! function pointer for each of them. Functions the loaded library
+//! lacks, for example because it is older than the bindings, are
+//! `None`.
+
+// libloading requires the standard library anyway.
+extern crate std;
+
+use core::fmt;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use std::ffi::OsStr;
+
+use libloading::Error;
+use libloading::Library;
+
+use crate::bindings::*;
+
+/// The file name of the shared libbpf, as found by the dynamic loader.
+pub const LIBBPF_SONAME: &str = "libbpf.so.1";
+
+macro_rules! dlopen_table {
+    ($(fn $name:ident($($param:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?;)*) => {
+        /// The bound functions, resolved from a shared libbpf.
+        pub struct LibBpf {
+            _lib: Library,
+            $(
+                pub $name: Option<unsafe extern "C" fn($($param: $ty),*) $(-> $ret)?>,
+            )*
+        }
+
+        impl LibBpf {
+            /// Load the library at `path` and resolve all functions.
+            ///
+            /// # Safety
+            /// Loading runs the library's initialization routines and
+            /// the resolved functions are assumed to have the declared
+            /// signatures, so `path` has to refer to a libbpf.
+            pub unsafe fn new<P>(path: P) -> Result<Self, Error>
+            where
+                P: AsRef<OsStr>,
+            {
+                let lib = unsafe { Library::new(path) }?;
+                Ok(Self {
+                    $(
+                        $name: unsafe { lib.get(concat!(stringify!($name), "\0").as_bytes()) }
+                            .ok()
+                            .map(|sym| *sym),
+                    )*
+                    _lib: lib,
+                })
+            }
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/dlopen.rs"));
+
+impl LibBpf {
+    /// Load the system's libbpf, [`LIBBPF_SONAME`].
+    ///
+    /// # Safety
+    /// See [`LibBpf::new`].
+    pub unsafe fn load() -> Result<Self, Error> {
+        unsafe { Self::new(LIBBPF_SONAME) }
+    }
+}
+
+impl Debug for LibBpf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LibBpf").finish_non_exhaustive()
+    }
+}
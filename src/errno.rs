@@ -0,0 +1,133 @@
+// src/errno.rs
+
+//! Turning the errors libbpf reports into [`io::Error`]s.
+//!
+//! Since libbpf 1.0, functions report errors in one of the two ways
+//! [`ErrorConvention`](crate::error::ErrorConvention) describes: as a
+//! negative return value, checked with [`check`], or as `NULL` with
+//! `errno` set, checked with [`check_ptr`]. Either way, the error is an
+//! `errno` value, which may also be one of libbpf's own, e.g.,
+//! `LIBBPF_ERRNO__FORMAT` for objects that aren't ELF files. The errors
+//! describe themselves using `libbpf_strerror`, which knows about those,
+//! and [`errno_of`] recovers the value:
+//!
+//! ```no_run
+//! use std::ptr;
+//!
+//! use libbpf_sys::errno;
+//!
+//! let data = b"not an ELF object";
+//! let obj = errno::check_ptr(unsafe {
+//!     libbpf_sys::bpf_object__open_mem(data.as_ptr().cast(), data.len() as _, ptr::null())
+//! });
+//! if let Err(err) = obj {
+//!     eprintln!("failed to open object: {} (errno {:?})", err, errno::errno_of(&err));
+//! }
+//! ```
+
+use std::ffi::CStr;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::io;
+use std::ptr::NonNull;
+
+use crate::bindings::*;
+
+/// The first of libbpf's own `errno` values, `LIBBPF_ERRNO__START`.
+pub const LIBBPF_ERRNO__START: i32 = 4000;
+
+/// Describe `errno`, which may be one of libbpf's own `errno` values,
+/// using `libbpf_strerror`.
+pub fn strerror(errno: i32) -> String {
+    let mut buf = [0u8; 128];
+    let ret = unsafe { libbpf_strerror(errno, buf.as_mut_ptr().cast(), buf.len() as _) };
+    match CStr::from_bytes_until_nul(&buf) {
+        Ok(msg) if ret == 0 => msg.to_string_lossy().into_owned(),
+        _ => format!("libbpf error {}", errno),
+    }
+}
+
+/// One of libbpf's own `errno` values, which the operating system
+/// doesn't know how to describe.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct LibbpfErrno(i32);
+
+impl Display for LibbpfErrno {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&strerror(self.0))
+    }
+}
+
+impl std::error::Error for LibbpfErrno {}
+
+/// Turn the positive `errno` value `errno` into an [`io::Error`].
+///
+/// Values of the operating system become plain OS errors, while
+/// libbpf's own ones carry their description.
+// `io::Error::other` requires Rust 1.74, past the MSRV.
+#[allow(clippy::io_other_error)]
+pub fn to_io_error(errno: i32) -> io::Error {
+    if errno >= LIBBPF_ERRNO__START {
+        io::Error::new(io::ErrorKind::Other, LibbpfErrno(errno))
+    } else {
+        io::Error::from_raw_os_error(errno)
+    }
+}
+
+/// Retrieve the `errno` value of `err`, if it has one, i.e., if it was
+/// created by [`to_io_error`] or is an OS error to begin with.
+pub fn errno_of(err: &io::Error) -> Option<i32> {
+    err.raw_os_error().or_else(|| {
+        err.get_ref()
+            .and_then(|inner| inner.downcast_ref::<LibbpfErrno>())
+            .map(|errno| errno.0)
+    })
+}
+
+/// Turn the current `errno` into an [`io::Error`], after a function
+/// following the `NullErrno` convention failed.
+pub fn last_error() -> io::Error {
+    let errno = io::Error::last_os_error().raw_os_error().unwrap_or(0);
+    to_io_error(errno)
+}
+
+/// The integer types functions following the `NegErrno` convention
+/// return.
+pub trait NegErrno: Copy {
+    /// The `errno` value `self` represents, if negative.
+    fn errno(self) -> Option<i32>;
+}
+
+macro_rules! impl_neg_errno {
+    ($($ty:ty),*) => {
+        $(
+            impl NegErrno for $ty {
+                fn errno(self) -> Option<i32> {
+                    // `errno` values fit into an `int`.
+                    (self < 0).then(|| -(self as i32))
+                }
+            }
+        )*
+    };
+}
+
+impl_neg_errno!(i32, i64, isize);
+
+/// Check the return value of a function following the `NegErrno`
+/// convention, passing it through unless it is an error.
+pub fn check<T>(ret: T) -> io::Result<T>
+where
+    T: NegErrno,
+{
+    match ret.errno() {
+        Some(errno) => Err(to_io_error(errno)),
+        None => Ok(ret),
+    }
+}
+
+/// Check the return value of a function following the `NullErrno`
+/// convention, retrieving the error from `errno` if it is `NULL`.
+pub fn check_ptr<T>(ptr: *mut T) -> io::Result<NonNull<T>> {
+    NonNull::new(ptr).ok_or_else(last_error)
+}
@@ -0,0 +1,34 @@
+// src/error.rs
+
+//! Machine-readable information on how functions report errors.
+//!
+//! The table is generated from the bindings at build time. Build
+//! scripts of dependent crates can find its source as
+//! `DEP_BPF_ERROR_CONVENTIONS`.
+
+/// The way a function reports errors.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ErrorConvention {
+    /// The return value does not indicate errors.
+    None,
+    /// A negative return value is a negated `errno` value.
+    NegErrno,
+    /// `NULL` is returned on error and `errno` is set. `libbpf_get_error`
+    /// can be used to retrieve the error.
+    NullErrno,
+    /// `NULL` is returned for an absent value, e.g., the pin path of an
+    /// unpinned map, and does not indicate an error.
+    Nullable,
+}
+
+/// The error conventions of all bound functions, sorted by name.
+pub static FUNCTIONS: &[(&str, ErrorConvention)] =
+    include!(concat!(env!("OUT_DIR"), "/error_conventions.rs"));
+
+/// Look up the error convention of the function `name`.
+pub fn error_convention(name: &str) -> Option<ErrorConvention> {
+    FUNCTIONS
+        .binary_search_by_key(&name, |(name, _)| name)
+        .ok()
+        .map(|idx| FUNCTIONS[idx].1)
+}
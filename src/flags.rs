@@ -0,0 +1,73 @@
+// src/flags.rs
+
+//! Typed wrappers for groups of related flag constants.
+//!
+//! Each type covers the flags accepted by one kind of call and is
+//! defined in terms of the raw constants, which remain available. Use
+//! `bits()` to pass a set of flags on to libbpf.
+
+use bitflags::bitflags;
+
+use crate::bindings::*;
+
+bitflags! {
+    /// Flags for map creation (`bpf_map_create_opts::map_flags`).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct MapCreateFlags: u32 {
+        const NO_PREALLOC = BPF_F_NO_PREALLOC;
+        const NO_COMMON_LRU = BPF_F_NO_COMMON_LRU;
+        const NUMA_NODE = BPF_F_NUMA_NODE;
+        const RDONLY = BPF_F_RDONLY;
+        const WRONLY = BPF_F_WRONLY;
+        const STACK_BUILD_ID = BPF_F_STACK_BUILD_ID;
+        const ZERO_SEED = BPF_F_ZERO_SEED;
+        const RDONLY_PROG = BPF_F_RDONLY_PROG;
+        const WRONLY_PROG = BPF_F_WRONLY_PROG;
+        const CLONE = BPF_F_CLONE;
+        const MMAPABLE = BPF_F_MMAPABLE;
+        const PRESERVE_ELEMS = BPF_F_PRESERVE_ELEMS;
+        const INNER_MAP = BPF_F_INNER_MAP;
+        const LINK = BPF_F_LINK;
+        const PATH_FD = BPF_F_PATH_FD;
+        const VTYPE_BTF_OBJ_FD = BPF_F_VTYPE_BTF_OBJ_FD;
+        const TOKEN_FD = BPF_F_TOKEN_FD;
+        const SEGV_ON_FAULT = BPF_F_SEGV_ON_FAULT;
+        const NO_USER_CONV = BPF_F_NO_USER_CONV;
+    }
+
+    /// Flags for program loading (`bpf_prog_load_opts::prog_flags`).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct ProgLoadFlags: u32 {
+        const STRICT_ALIGNMENT = BPF_F_STRICT_ALIGNMENT;
+        const ANY_ALIGNMENT = BPF_F_ANY_ALIGNMENT;
+        const TEST_RND_HI32 = BPF_F_TEST_RND_HI32;
+        const TEST_STATE_FREQ = BPF_F_TEST_STATE_FREQ;
+        const SLEEPABLE = BPF_F_SLEEPABLE;
+        const XDP_HAS_FRAGS = BPF_F_XDP_HAS_FRAGS;
+        const XDP_DEV_BOUND_ONLY = BPF_F_XDP_DEV_BOUND_ONLY;
+        const TEST_REG_INVARIANTS = BPF_F_TEST_REG_INVARIANTS;
+        const TOKEN_FD = BPF_F_TOKEN_FD;
+    }
+
+    /// Flags for attaching programs (`bpf_prog_attach_opts::flags`).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct AttachFlags: u32 {
+        const ALLOW_OVERRIDE = BPF_F_ALLOW_OVERRIDE;
+        const ALLOW_MULTI = BPF_F_ALLOW_MULTI;
+        const REPLACE = BPF_F_REPLACE;
+        const BEFORE = BPF_F_BEFORE;
+        const AFTER = BPF_F_AFTER;
+        const ID = BPF_F_ID;
+        const LINK = BPF_F_LINK;
+    }
+
+    /// Flags for attaching XDP programs (`bpf_xdp_attach`).
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct XdpFlags: u32 {
+        const UPDATE_IF_NOEXIST = XDP_FLAGS_UPDATE_IF_NOEXIST;
+        const SKB_MODE = XDP_FLAGS_SKB_MODE;
+        const DRV_MODE = XDP_FLAGS_DRV_MODE;
+        const HW_MODE = XDP_FLAGS_HW_MODE;
+        const REPLACE = XDP_FLAGS_REPLACE;
+    }
+}
@@ -0,0 +1,36 @@
+// src/helpers.rs
+
+//! Prototypes of the BPF helpers.
+//!
+//! The table is extracted from the vendored `bpf_helper_defs.h` at
+//! build time. Types are spelled as in C, e.g., `"const void *"`.
+
+/// The prototype of a BPF helper.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HelperProto {
+    /// The name, e.g., `"bpf_map_lookup_elem"`.
+    pub name: &'static str,
+    /// The ID, i.e., the corresponding `BPF_FUNC_*` value.
+    pub id: u32,
+    /// The return type.
+    pub ret: &'static str,
+    /// The argument types. Variadic helpers have `"..."` as the last
+    /// one.
+    pub args: &'static [&'static str],
+}
+
+/// The prototypes of all helpers, sorted by ID.
+pub const HELPER_PROTOS: &[HelperProto] = include!(concat!(env!("OUT_DIR"), "/helper_protos.rs"));
+
+/// Look up the prototype of the helper `name`.
+pub fn helper_proto(name: &str) -> Option<&'static HelperProto> {
+    HELPER_PROTOS.iter().find(|proto| proto.name == name)
+}
+
+/// Look up the prototype of the helper with ID `id`.
+pub fn helper_proto_by_id(id: u32) -> Option<&'static HelperProto> {
+    HELPER_PROTOS
+        .binary_search_by_key(&id, |proto| proto.id)
+        .ok()
+        .map(|idx| &HELPER_PROTOS[idx])
+}
@@ -0,0 +1,185 @@
+// src/insn.rs
+
+//! `const fn` counterparts of the kernel's instruction macros.
+//!
+//! Each function corresponds to the similarly named macro from the
+//! kernel's `filter.h`, e.g., [`alu64_imm`] is `BPF_ALU64_IMM`. Opcodes
+//! and sizes are the raw `BPF_*` constants and registers are `BPF_REG_*`
+//! values.
+
+use crate::bindings::*;
+
+/// Create an instruction from its raw components (`BPF_RAW_INSN`).
+pub const fn raw_insn(code: u32, dst: u32, src: u32, off: i16, imm: i32) -> bpf_insn {
+    let (dst, src) = (dst as u8 & 0xf, src as u8 & 0xf);
+    let regs = if cfg!(target_endian = "big") {
+        dst << 4 | src
+    } else {
+        src << 4 | dst
+    };
+
+    bpf_insn {
+        code: code as u8,
+        _bitfield_align_1: [],
+        _bitfield_1: __BindgenBitfieldUnit::new([regs]),
+        off,
+        imm,
+    }
+}
+
+/// `BPF_ALU64_REG`: `dst = dst OP src`
+pub const fn alu64_reg(op: u32, dst: u32, src: u32) -> bpf_insn {
+    raw_insn(BPF_ALU64 | BPF_OP(op) | BPF_X, dst, src, 0, 0)
+}
+
+/// `BPF_ALU32_REG`: `dst = (u32) dst OP (u32) src`
+pub const fn alu32_reg(op: u32, dst: u32, src: u32) -> bpf_insn {
+    raw_insn(BPF_ALU | BPF_OP(op) | BPF_X, dst, src, 0, 0)
+}
+
+/// `BPF_ALU64_IMM`: `dst = dst OP imm`
+pub const fn alu64_imm(op: u32, dst: u32, imm: i32) -> bpf_insn {
+    raw_insn(BPF_ALU64 | BPF_OP(op) | BPF_K, dst, 0, 0, imm)
+}
+
+/// `BPF_ALU32_IMM`: `dst = (u32) dst OP imm`
+pub const fn alu32_imm(op: u32, dst: u32, imm: i32) -> bpf_insn {
+    raw_insn(BPF_ALU | BPF_OP(op) | BPF_K, dst, 0, 0, imm)
+}
+
+/// `BPF_ENDIAN`: convert `dst` to the byte order given by `ty`
+/// (`BPF_TO_LE` or `BPF_TO_BE`), operating on `len` bits.
+pub const fn endian(ty: u32, dst: u32, len: i32) -> bpf_insn {
+    raw_insn(BPF_ALU | BPF_END | BPF_SRC(ty), dst, 0, 0, len)
+}
+
+/// `BPF_MOV64_REG`: `dst = src`
+pub const fn mov64_reg(dst: u32, src: u32) -> bpf_insn {
+    alu64_reg(BPF_MOV, dst, src)
+}
+
+/// `BPF_MOV32_REG`: `dst = (u32) src`
+pub const fn mov32_reg(dst: u32, src: u32) -> bpf_insn {
+    alu32_reg(BPF_MOV, dst, src)
+}
+
+/// `BPF_MOV64_IMM`: `dst = imm`
+pub const fn mov64_imm(dst: u32, imm: i32) -> bpf_insn {
+    alu64_imm(BPF_MOV, dst, imm)
+}
+
+/// `BPF_MOV32_IMM`: `dst = (u32) imm`
+pub const fn mov32_imm(dst: u32, imm: i32) -> bpf_insn {
+    alu32_imm(BPF_MOV, dst, imm)
+}
+
+/// `BPF_LD_IMM64_RAW`: the two instruction wide load of a 64 bit
+/// immediate, with `src` selecting the pseudo source.
+pub const fn ld_imm64_raw(dst: u32, src: u32, imm: u64) -> [bpf_insn; 2] {
+    [
+        raw_insn(BPF_LD | BPF_DW | BPF_IMM, dst, src, 0, imm as u32 as i32),
+        raw_insn(0, 0, 0, 0, (imm >> 32) as u32 as i32),
+    ]
+}
+
+/// `BPF_LD_IMM64`: `dst = imm`
+pub const fn ld_imm64(dst: u32, imm: u64) -> [bpf_insn; 2] {
+    ld_imm64_raw(dst, 0, imm)
+}
+
+/// `BPF_LD_MAP_FD`: `dst = map`, with the map referenced by file
+/// descriptor.
+pub const fn ld_map_fd(dst: u32, map_fd: i32) -> [bpf_insn; 2] {
+    ld_imm64_raw(dst, BPF_PSEUDO_MAP_FD, map_fd as u32 as u64)
+}
+
+/// `BPF_LD_ABS`: `r0 = *(size *) (skb->data + imm)`
+pub const fn ld_abs(size: u32, imm: i32) -> bpf_insn {
+    raw_insn(BPF_LD | BPF_SIZE(size) | BPF_ABS, 0, 0, 0, imm)
+}
+
+/// `BPF_LD_IND`: `r0 = *(size *) (skb->data + src + imm)`
+pub const fn ld_ind(size: u32, src: u32, imm: i32) -> bpf_insn {
+    raw_insn(BPF_LD | BPF_SIZE(size) | BPF_IND, 0, src, 0, imm)
+}
+
+/// `BPF_LDX_MEM`: `dst = *(size *) (src + off)`
+pub const fn ldx_mem(size: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    raw_insn(BPF_LDX | BPF_SIZE(size) | BPF_MEM, dst, src, off, 0)
+}
+
+/// `BPF_STX_MEM`: `*(size *) (dst + off) = src`
+pub const fn stx_mem(size: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    raw_insn(BPF_STX | BPF_SIZE(size) | BPF_MEM, dst, src, off, 0)
+}
+
+/// `BPF_ST_MEM`: `*(size *) (dst + off) = imm`
+pub const fn st_mem(size: u32, dst: u32, off: i16, imm: i32) -> bpf_insn {
+    raw_insn(BPF_ST | BPF_SIZE(size) | BPF_MEM, dst, 0, off, imm)
+}
+
+/// `BPF_ATOMIC_OP`: atomic operation `op` (e.g., `BPF_ADD`, possibly
+/// combined with `BPF_FETCH`) on `*(size *) (dst + off)` using `src`.
+pub const fn atomic_op(size: u32, op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    raw_insn(BPF_STX | BPF_SIZE(size) | BPF_ATOMIC, dst, src, off, op as i32)
+}
+
+/// `BPF_JMP_REG`: `if (dst OP src) goto pc + off`
+pub const fn jmp_reg(op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    raw_insn(BPF_JMP | BPF_OP(op) | BPF_X, dst, src, off, 0)
+}
+
+/// `BPF_JMP_IMM`: `if (dst OP imm) goto pc + off`
+pub const fn jmp_imm(op: u32, dst: u32, imm: i32, off: i16) -> bpf_insn {
+    raw_insn(BPF_JMP | BPF_OP(op) | BPF_K, dst, 0, off, imm)
+}
+
+/// `BPF_JMP32_REG`: `if ((u32) dst OP (u32) src) goto pc + off`
+pub const fn jmp32_reg(op: u32, dst: u32, src: u32, off: i16) -> bpf_insn {
+    raw_insn(BPF_JMP32 | BPF_OP(op) | BPF_X, dst, src, off, 0)
+}
+
+/// `BPF_JMP32_IMM`: `if ((u32) dst OP imm) goto pc + off`
+pub const fn jmp32_imm(op: u32, dst: u32, imm: i32, off: i16) -> bpf_insn {
+    raw_insn(BPF_JMP32 | BPF_OP(op) | BPF_K, dst, 0, off, imm)
+}
+
+/// `BPF_JMP_A`: `goto pc + off`
+pub const fn jmp_a(off: i16) -> bpf_insn {
+    raw_insn(BPF_JMP | BPF_JA, 0, 0, off, 0)
+}
+
+/// `BPF_EMIT_CALL`: call the helper `func`.
+pub const fn emit_call(func: bpf_func_id) -> bpf_insn {
+    raw_insn(BPF_JMP | BPF_CALL, 0, 0, 0, func as i32)
+}
+
+/// `BPF_EXIT_INSN`: `return r0`
+pub const fn exit_insn() -> bpf_insn {
+    raw_insn(BPF_JMP | BPF_EXIT, 0, 0, 0, 0)
+}
+
+/// `BPF_CLASS`: extract the instruction class from an opcode.
+pub const fn BPF_CLASS(code: u32) -> u32 {
+    code & 0x07
+}
+
+/// `BPF_SIZE`: extract the size from an opcode.
+pub const fn BPF_SIZE(code: u32) -> u32 {
+    code & 0x18
+}
+
+/// `BPF_MODE`: extract the mode from an opcode.
+pub const fn BPF_MODE(code: u32) -> u32 {
+    code & 0xe0
+}
+
+/// `BPF_OP`: extract the operation from an opcode.
+pub const fn BPF_OP(code: u32) -> u32 {
+    code & 0xf0
+}
+
+/// `BPF_SRC`: extract the source operand type from an opcode.
+pub const fn BPF_SRC(code: u32) -> u32 {
+    code & 0x08
+}
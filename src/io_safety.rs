@@ -0,0 +1,137 @@
+// src/io_safety.rs
+
+//! The bound functions dealing with file descriptors, using I/O safe
+//! types.
+//!
+//! For each function taking or returning file descriptors, this module
+//! provides a function of the same name that takes them as
+//! [`BorrowedFd`] or [`OwnedFd`] instead of a raw `c_int`, so that the
+//! type system keeps track of who closes them:
+//!
+//! - File descriptors the function merely uses, e.g., the `fd` of
+//!   `bpf_map_update_elem`, are passed as [`BorrowedFd`].
+//! - File descriptors the function takes ownership of, e.g., the `fd` of
+//!   `btf__set_fd`, are passed as [`OwnedFd`].
+//! - Newly created file descriptors, e.g., the one returned by
+//!   `bpf_map_create`, are returned as [`OwnedFd`], with negative
+//!   return values turned into errors.
+//! - File descriptors belonging to the object passed in, e.g., the one
+//!   returned by `bpf_map__fd`, are returned as [`BorrowedFd`], or
+//!   `None` if there is none, e.g., before loading. As the object is
+//!   passed by pointer, the caller picks the lifetime and has to make
+//!   sure that the object outlives it.
+//!
+//! ```no_run
+//! use std::os::fd::AsFd as _;
+//! use std::ptr;
+//!
+//! use libbpf_sys::io_safety;
+//!
+//! let map = unsafe {
+//!     io_safety::bpf_map_create(
+//!         libbpf_sys::BPF_MAP_TYPE_ARRAY,
+//!         ptr::null(),
+//!         4,
+//!         8,
+//!         1,
+//!         ptr::null(),
+//!     )
+//! }
+//! .unwrap();
+//! let (key, value) = (0u32, 42u64);
+//! let ret = unsafe {
+//!     io_safety::bpf_map_update_elem(
+//!         map.as_fd(),
+//!         (&key as *const u32).cast(),
+//!         (&value as *const u64).cast(),
+//!         0,
+//!     )
+//! };
+//! assert_eq!(ret, 0);
+//! ```
+//!
+//! Parameters that aren't always file descriptors, such as the
+//! `target_fd` of `bpf_link_create`, which is an interface index when
+//! attaching to XDP, stay raw. The functions at the crate root remain
+//! as they are.
+
+use std::io;
+use std::os::fd::AsRawFd as _;
+use std::os::fd::BorrowedFd;
+use std::os::fd::FromRawFd as _;
+use std::os::fd::IntoRawFd as _;
+use std::os::fd::OwnedFd;
+
+use crate::bindings::*;
+
+macro_rules! fd_param {
+    (borrowed, $ty:ty) => { BorrowedFd<'_> };
+    (owned, $ty:ty) => { OwnedFd };
+    (raw, $ty:ty) => { $ty };
+}
+
+macro_rules! fd_arg {
+    (borrowed, $param:ident) => {
+        $param.as_raw_fd()
+    };
+    (owned, $param:ident) => {
+        $param.into_raw_fd()
+    };
+    (raw, $param:ident) => {
+        $param
+    };
+}
+
+macro_rules! io_safety_fn {
+    ($name:ident($([$kind:ident] $param:ident: $ty:ty),*) -> [owned] $ret:ty) => {
+        #[doc = concat!("`", stringify!($name), "`, returning the new file descriptor.")]
+        ///
+        /// # Safety
+        /// The same requirements as for the raw function apply.
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name($($param: fd_param!($kind, $ty)),*) -> io::Result<OwnedFd> {
+            let fd = unsafe { crate::$name($(fd_arg!($kind, $param)),*) };
+            if fd < 0 {
+                return Err(io::Error::from_raw_os_error(-fd));
+            }
+            // SAFETY: The function returned a new file descriptor, which
+            //         nobody else owns.
+            Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+        }
+    };
+    ($name:ident($([$kind:ident] $param:ident: $ty:ty),*) -> [borrowed] $ret:ty) => {
+        #[doc = concat!("`", stringify!($name), "`, borrowing the object's file descriptor.")]
+        ///
+        /// # Safety
+        /// The same requirements as for the raw function apply, and the
+        /// object has to outlive `'fd` without closing its file
+        /// descriptor.
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name<'fd>($($param: fd_param!($kind, $ty)),*) -> Option<BorrowedFd<'fd>> {
+            let fd = unsafe { crate::$name($(fd_arg!($kind, $param)),*) };
+            // SAFETY: The caller guarantees that the file descriptor
+            //         stays open for `'fd`.
+            (fd >= 0).then(|| unsafe { BorrowedFd::borrow_raw(fd) })
+        }
+    };
+    ($name:ident($([$kind:ident] $param:ident: $ty:ty),*) -> [raw] $ret:ty) => {
+        #[doc = concat!("`", stringify!($name), "`, taking I/O safe file descriptors.")]
+        ///
+        /// # Safety
+        /// The same requirements as for the raw function apply.
+        #[allow(clippy::too_many_arguments)]
+        pub unsafe fn $name($($param: fd_param!($kind, $ty)),*) -> $ret {
+            unsafe { crate::$name($(fd_arg!($kind, $param)),*) }
+        }
+    };
+}
+
+macro_rules! io_safety_table {
+    ($(fn $name:ident($($params:tt)*) -> [$ret_kind:ident] $ret:ty;)*) => {
+        $(
+            io_safety_fn!($name($($params)*) -> [$ret_kind] $ret);
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/io_safety.rs"));
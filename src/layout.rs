@@ -0,0 +1,15 @@
+// src/layout.rs
+
+//! Compile-time checks of the layouts of heavily used types.
+//!
+//! A size or alignment deviating from the kernel's ABI, e.g., due to a
+//! toolchain or architecture specific quirk, fails the build instead of
+//! silently corrupting memory shared with the kernel or libbpf. The
+//! assertions are generated at build time from a table in `build.rs`.
+
+use core::mem::align_of;
+use core::mem::size_of;
+
+use crate::bindings::*;
+
+include!(concat!(env!("OUT_DIR"), "/layout_asserts.rs"));
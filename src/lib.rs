@@ -1,19 +1,137 @@
 // src/lib.rs
 
+#![cfg_attr(all(feature = "no-std", not(test)), no_std)]
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
+extern crate alloc;
+
 #[allow(clippy::all)]
 mod bindings {
-    #[cfg(all(feature = "bindgen", not(feature = "bindgen-source")))]
+    #[cfg(not(libbpf_sys_windows))]
+    pub use crate::arch::*;
+    pub use crate::opaque::*;
+
+    #[cfg(any(
+        all(feature = "bindgen", not(feature = "bindgen-source")),
+        feature = "no-perf",
+        feature = "no-btf-dump",
+        feature = "no-linker",
+        feature = "msrv-bindings",
+        feature = "no-explicit-padding",
+        feature = "btf-only",
+        feature = "libc",
+        libbpf_sys_arch_bindings,
+    ))]
     include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
-    #[cfg(any(not(feature = "bindgen"), feature = "bindgen-source"))]
+    #[cfg(not(any(
+        all(feature = "bindgen", not(feature = "bindgen-source")),
+        feature = "no-perf",
+        feature = "no-btf-dump",
+        feature = "no-linker",
+        feature = "msrv-bindings",
+        feature = "no-explicit-padding",
+        feature = "btf-only",
+        feature = "libc",
+        libbpf_sys_arch_bindings,
+    )))]
     include!("bindings.rs");
 }
 
 pub use bindings::*;
 
+#[cfg(not(libbpf_sys_windows))]
+mod arch;
+#[cfg(not(libbpf_sys_windows))]
+mod attr;
+#[cfg(not(libbpf_sys_windows))]
+mod layout;
+#[cfg(not(libbpf_sys_windows))]
+mod multi;
+mod opaque;
+#[cfg(not(libbpf_sys_windows))]
+mod opts;
+#[cfg(all(feature = "bytemuck", not(libbpf_sys_windows)))]
+mod pod;
+#[cfg(all(feature = "serde", not(libbpf_sys_windows)))]
+mod serialize;
+
+#[cfg(not(libbpf_sys_windows))]
+pub use attr::*;
+#[cfg(not(libbpf_sys_windows))]
+pub use multi::*;
+
+#[cfg(not(libbpf_sys_windows))]
+pub mod btf_kind;
+#[cfg(not(libbpf_sys_windows))]
+pub mod btf_wire;
+#[cfg(not(libbpf_sys_windows))]
+pub mod callback;
+#[cfg(all(
+    feature = "compat-0x",
+    not(any(feature = "dlopen", libbpf_sys_windows))
+))]
+pub mod compat;
+#[cfg(all(feature = "disasm", not(libbpf_sys_windows)))]
+pub mod disasm;
+#[cfg(all(feature = "display", not(libbpf_sys_windows)))]
+pub mod display;
+#[cfg(feature = "dlopen")]
+pub mod dlopen;
+#[cfg(all(
+    feature = "errno",
+    not(any(feature = "dlopen", feature = "no-std", libbpf_sys_windows))
+))]
+pub mod errno;
+pub mod error;
+#[cfg(all(feature = "bitflags", not(libbpf_sys_windows)))]
+pub mod flags;
+pub mod helpers;
+#[cfg(not(libbpf_sys_windows))]
+pub mod insn;
+#[cfg(all(
+    feature = "io-safety",
+    not(any(feature = "dlopen", feature = "no-std", libbpf_sys_windows))
+))]
+pub mod io_safety;
+#[cfg(not(any(
+    feature = "dlopen",
+    feature = "stubs",
+    feature = "btf-only",
+    libbpf_sys_windows
+)))]
+pub mod loaded;
+#[cfg(all(feature = "mmap", not(libbpf_sys_windows)))]
+pub mod mmap;
+#[cfg(not(libbpf_sys_windows))]
+pub mod names;
+#[cfg(not(libbpf_sys_windows))]
+pub mod opts_check;
+#[cfg(not(any(feature = "no-perf", libbpf_sys_windows)))]
+pub mod perf;
+#[cfg(all(feature = "printf", not(libbpf_sys_windows)))]
+pub mod printf;
+#[cfg(not(libbpf_sys_windows))]
+pub mod provenance;
+#[cfg(not(libbpf_sys_windows))]
+pub mod sections;
+#[cfg(not(libbpf_sys_windows))]
+pub mod skel;
+#[cfg(not(any(feature = "dlopen", libbpf_sys_windows)))]
+pub mod struct_ops;
+#[cfg(feature = "stubs")]
+pub mod stubs;
+pub mod subsys;
+#[cfg(not(libbpf_sys_windows))]
+pub mod tc;
+#[cfg(not(any(feature = "no-std", libbpf_sys_windows)))]
+pub mod testing;
+#[cfg(not(any(feature = "dlopen", feature = "btf-only", libbpf_sys_windows)))]
+pub mod verifier_log;
+#[cfg(not(any(feature = "no-std", libbpf_sys_windows)))]
+pub mod vmlinux_btf;
+
 #[cfg(feature = "vendored-libbpf")]
 macro_rules! header {
     ($file:literal) => {
@@ -0,0 +1,127 @@
+// src/loaded.rs
+
+//! Information on the libbpf actually in use by the process.
+//!
+//! Mostly useful for support tooling, to track down problems caused by
+//! the dynamic loader picking up a different libbpf than expected.
+
+use core::ffi::c_char;
+use core::ffi::c_int;
+use core::ffi::c_void;
+use core::ffi::CStr;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::ptr;
+
+use crate::bindings::*;
+
+#[repr(C)]
+struct Dl_info {
+    dli_fname: *const c_char,
+    dli_fbase: *mut c_void,
+    dli_sname: *const c_char,
+    dli_saddr: *mut c_void,
+}
+
+extern "C" {
+    fn dladdr(addr: *const c_void, info: *mut Dl_info) -> c_int;
+}
+
+/// Retrieve the path of the object containing `addr`.
+fn object_path(addr: *const c_void) -> Option<&'static CStr> {
+    let mut info = Dl_info {
+        dli_fname: ptr::null(),
+        dli_fbase: ptr::null_mut(),
+        dli_sname: ptr::null(),
+        dli_saddr: ptr::null_mut(),
+    };
+    // SAFETY: `info` is a valid `Dl_info` to fill in.
+    let rc = unsafe { dladdr(addr, &mut info) };
+    if rc == 0 || info.dli_fname.is_null() {
+        return None;
+    }
+    // SAFETY: `dli_fname` points to a string owned by the dynamic
+    //         loader, which stays valid for as long as the object is
+    //         loaded. libbpf and this crate are never unloaded.
+    Some(unsafe { CStr::from_ptr(info.dli_fname) })
+}
+
+/// How libbpf is linked into the process.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Linkage {
+    /// libbpf is part of the same object as this crate.
+    Static,
+    /// libbpf is a shared library loaded from `path`.
+    Shared {
+        /// The path the library was loaded from.
+        path: &'static CStr,
+        /// The file name of `path`, which is the library's soname when
+        /// it was loaded as a dependency.
+        soname: &'static CStr,
+    },
+    /// The object containing libbpf could not be determined.
+    Unknown,
+}
+
+/// Information about the libbpf in use.
+#[derive(Clone, Copy, Debug)]
+pub struct LoadedLibbpf {
+    /// How libbpf is linked.
+    pub linkage: Linkage,
+    /// libbpf's major version.
+    pub major_version: u32,
+    /// libbpf's minor version.
+    pub minor_version: u32,
+    /// libbpf's version string, e.g., `v1.5`.
+    pub version: &'static CStr,
+}
+
+impl Display for LoadedLibbpf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "libbpf {}.{}", self.major_version, self.minor_version)?;
+        match self.linkage {
+            Linkage::Static => write!(f, " (statically linked)"),
+            Linkage::Shared { path, .. } => write!(f, " ({})", path.to_string_lossy()),
+            Linkage::Unknown => Ok(()),
+        }
+    }
+}
+
+/// Report on the libbpf in use by the process.
+pub fn loaded_libbpf() -> LoadedLibbpf {
+    let libbpf = object_path(libbpf_major_version as *const c_void);
+    let this = object_path(loaded_libbpf as *const c_void);
+
+    let linkage = match libbpf {
+        Some(path) if Some(path) == this => Linkage::Static,
+        Some(path) => {
+            let bytes = path.to_bytes_with_nul();
+            let start = bytes
+                .iter()
+                .rposition(|&b| b == b'/')
+                .map_or(0, |idx| idx + 1);
+            // SAFETY: A suffix of a C string is a C string.
+            let soname = unsafe { CStr::from_bytes_with_nul_unchecked(&bytes[start..]) };
+            Linkage::Shared { path, soname }
+        },
+        None => Linkage::Unknown,
+    };
+
+    // SAFETY: The version functions are always safe to call and the
+    //         version string is static.
+    let (major_version, minor_version, version) = unsafe {
+        (
+            libbpf_major_version(),
+            libbpf_minor_version(),
+            CStr::from_ptr(libbpf_version_string()),
+        )
+    };
+
+    LoadedLibbpf {
+        linkage,
+        major_version,
+        minor_version,
+        version,
+    }
+}
@@ -0,0 +1,155 @@
+// src/mmap.rs
+
+//! Memory mapping array maps created with `BPF_F_MMAPABLE`.
+//!
+//! The kernel only maps such maps shared, at offsets that are multiples
+//! of the page size, and no further than the values extend, rounded up
+//! to a whole page. Within the mapping, each value takes up its size
+//! rounded up to a multiple of eight bytes. [`MmapLayout`] works out
+//! these numbers for a map, and [`bpf_map_mmap`] and [`bpf_map_munmap`]
+//! map and unmap it:
+//!
+//! ```no_run
+//! use libbpf_sys::mmap::*;
+//! # let map_fd = 0;
+//!
+//! let layout = MmapLayout::new(
+//!     libbpf_sys::BPF_MAP_TYPE_ARRAY,
+//!     8,
+//!     16,
+//!     libbpf_sys::BPF_F_MMAPABLE,
+//!     page_size(),
+//! )
+//! .unwrap();
+//! let addr = unsafe { bpf_map_mmap(map_fd, 0, layout.mmap_len(), true) };
+//! assert!(!addr.is_null());
+//! let value = unsafe { addr.cast::<u8>().add(layout.value_offset(3).unwrap()) };
+//! unsafe { value.cast::<u64>().write(42) };
+//! unsafe { bpf_map_munmap(addr, layout.mmap_len()) };
+//! ```
+
+use core::convert::TryFrom;
+use core::ffi::c_int;
+use core::ffi::c_void;
+use core::ptr;
+
+use crate::bindings::*;
+
+/// Round `x` up to a multiple of `to`.
+fn round_up(x: usize, to: usize) -> Option<usize> {
+    x.checked_add(to.checked_sub(1)?).map(|x| x / to * to)
+}
+
+/// Retrieve the page size, which mappings are aligned to.
+pub fn page_size() -> usize {
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+/// The layout of the mapping of an mmap-able array map.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct MmapLayout {
+    len: usize,
+    value_stride: usize,
+    max_entries: u32,
+}
+
+impl MmapLayout {
+    /// Determine the layout of a map of type `map_type` with
+    /// `max_entries` values of `value_size` bytes, created with
+    /// `map_flags`, for pages of `page_size` bytes.
+    ///
+    /// Returns `None` unless the map is an array map created with
+    /// `BPF_F_MMAPABLE`, or if the mapping wouldn't fit the address
+    /// space.
+    pub fn new(
+        map_type: bpf_map_type,
+        value_size: u32,
+        max_entries: u32,
+        map_flags: u32,
+        page_size: usize,
+    ) -> Option<Self> {
+        if map_type != BPF_MAP_TYPE_ARRAY || map_flags & BPF_F_MMAPABLE == 0 {
+            return None;
+        }
+        let value_stride = round_up(usize::try_from(value_size).ok()?, 8)?;
+        let len = value_stride.checked_mul(usize::try_from(max_entries).ok()?)?;
+        let len = round_up(len, page_size)?;
+        Some(Self {
+            len,
+            value_stride,
+            max_entries,
+        })
+    }
+
+    /// Determine the layout of the map described by `info`, as
+    /// retrieved with `bpf_map_get_info_by_fd`.
+    pub fn from_info(info: &bpf_map_info, page_size: usize) -> Option<Self> {
+        Self::new(
+            info.type_,
+            info.value_size,
+            info.max_entries,
+            info.map_flags,
+            page_size,
+        )
+    }
+
+    /// The length of a mapping of all values, in bytes. Mappings at an
+    /// offset have to be shorter by that much.
+    pub fn mmap_len(&self) -> usize {
+        self.len
+    }
+
+    /// The distance between consecutive values, in bytes.
+    pub fn value_stride(&self) -> usize {
+        self.value_stride
+    }
+
+    /// The offset of the value at `idx` from the start of a mapping at
+    /// offset zero, or `None` if there is no such value.
+    pub fn value_offset(&self, idx: u32) -> Option<usize> {
+        (idx < self.max_entries).then(|| self.value_stride * idx as usize)
+    }
+}
+
+/// Map `len` bytes of the mmap-able map `map_fd`, starting `offset`
+/// bytes into the values, read-only or, if `writable`, writable.
+///
+/// `offset` has to be a multiple of the page size, and `offset + len`
+/// must not exceed [`MmapLayout::mmap_len`]. Frozen maps can only be mapped
+/// read-only. Returns `NULL` and sets `errno` on error.
+///
+/// # Safety
+/// Writes through the mapping race with BPF programs accessing the
+/// map.
+pub unsafe fn bpf_map_mmap(
+    map_fd: c_int,
+    offset: usize,
+    len: usize,
+    writable: bool,
+) -> *mut c_void {
+    let prot = if writable {
+        libc::PROT_READ | libc::PROT_WRITE
+    } else {
+        libc::PROT_READ
+    };
+    // `off_t` is 32 bits wide on some 32 bit targets, and `libc` links
+    // the `mmap` matching it.
+    let Ok(offset) = libc::off_t::try_from(offset) else {
+        return ptr::null_mut();
+    };
+    let addr = unsafe { libc::mmap(ptr::null_mut(), len, prot, libc::MAP_SHARED, map_fd, offset) };
+    if addr == libc::MAP_FAILED {
+        ptr::null_mut()
+    } else {
+        addr
+    }
+}
+
+/// Unmap `len` bytes at `addr`, as mapped by [`bpf_map_mmap`]. Returns
+/// `-1` and sets `errno` on error.
+///
+/// # Safety
+/// The mapping must not be accessed afterwards.
+pub unsafe fn bpf_map_munmap(addr: *mut c_void, len: usize) -> c_int {
+    unsafe { libc::munmap(addr, len) }
+}
@@ -0,0 +1,133 @@
+// src/multi.rs
+
+//! Construction of the kprobe.multi and uprobe.multi opts from slices.
+//!
+//! These opts describe the probes to attach through parallel arrays,
+//! all of which have to have `cnt` elements. The constructors in here
+//! fill in the pointers and `cnt` from slices after checking that their
+//! lengths agree. An empty slice leaves the corresponding pointer null.
+//!
+//! The opts borrow the slices' memory without tracking the lifetime, so
+//! the slices have to outlive any use of the opts.
+
+use core::ffi::c_char;
+use core::ffi::c_ulong;
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::ptr;
+
+use crate::bindings::*;
+
+/// The error returned when the slices passed to a multi opts
+/// constructor differ in length.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ArrayLengthMismatch {
+    /// The name of the offending member.
+    pub name: &'static str,
+    /// The length of the slice provided for it.
+    pub len: usize,
+    /// The length of the slices preceding it.
+    pub expected: usize,
+}
+
+impl Display for ArrayLengthMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` has {} elements, but {} were expected",
+            self.name, self.len, self.expected
+        )
+    }
+}
+
+/// Determine the common length of the non-empty arrays.
+fn common_len(arrays: &[(&'static str, usize)]) -> Result<usize, ArrayLengthMismatch> {
+    let mut cnt = 0;
+    for &(name, len) in arrays {
+        if len == 0 {
+            continue;
+        }
+        if cnt != 0 && len != cnt {
+            return Err(ArrayLengthMismatch {
+                name,
+                len,
+                expected: cnt,
+            });
+        }
+        cnt = len;
+    }
+    Ok(cnt)
+}
+
+fn as_ptr_or_null<T>(slice: &[T]) -> *const T {
+    if slice.is_empty() {
+        ptr::null()
+    } else {
+        slice.as_ptr()
+    }
+}
+
+impl bpf_kprobe_multi_opts {
+    /// Create opts attaching to the functions named in `syms` or at the
+    /// addresses in `addrs`, with the BPF cookies in `cookies`.
+    ///
+    /// libbpf expects either `syms` or `addrs` to be provided, unless a
+    /// pattern is passed to `bpf_program__attach_kprobe_multi_opts`.
+    pub fn from_slices(
+        syms: &[*const c_char],
+        addrs: &[c_ulong],
+        cookies: &[u64],
+        retprobe: bool,
+    ) -> Result<Self, ArrayLengthMismatch> {
+        let cnt = common_len(&[
+            ("syms", syms.len()),
+            ("addrs", addrs.len()),
+            ("cookies", cookies.len()),
+        ])?;
+
+        Ok(Self {
+            // libbpf does not write through `syms`.
+            syms: as_ptr_or_null(syms).cast_mut(),
+            addrs: as_ptr_or_null(addrs),
+            cookies: as_ptr_or_null(cookies),
+            cnt: cnt as _,
+            retprobe,
+            ..Default::default()
+        })
+    }
+}
+
+impl bpf_uprobe_multi_opts {
+    /// Create opts attaching to the functions named in `syms` or at the
+    /// offsets in `offsets`, with the reference counter offsets in
+    /// `ref_ctr_offsets` and the BPF cookies in `cookies`.
+    ///
+    /// libbpf expects either `syms` or `offsets` to be provided, unless
+    /// a pattern is passed to `bpf_program__attach_uprobe_multi`.
+    pub fn from_slices(
+        syms: &[*const c_char],
+        offsets: &[c_ulong],
+        ref_ctr_offsets: &[c_ulong],
+        cookies: &[u64],
+        retprobe: bool,
+    ) -> Result<Self, ArrayLengthMismatch> {
+        let cnt = common_len(&[
+            ("syms", syms.len()),
+            ("offsets", offsets.len()),
+            ("ref_ctr_offsets", ref_ctr_offsets.len()),
+            ("cookies", cookies.len()),
+        ])?;
+
+        Ok(Self {
+            // libbpf does not write through `syms`.
+            syms: as_ptr_or_null(syms).cast_mut(),
+            offsets: as_ptr_or_null(offsets),
+            ref_ctr_offsets: as_ptr_or_null(ref_ctr_offsets),
+            cookies: as_ptr_or_null(cookies),
+            cnt: cnt as _,
+            retprobe,
+            ..Default::default()
+        })
+    }
+}
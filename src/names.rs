@@ -0,0 +1,45 @@
+// src/names.rs
+
+//! The reverse of libbpf's `libbpf_*_str` functions.
+//!
+//! libbpf names program, map, attach, and link types after their
+//! constants, lower-cased and without the common prefix, e.g.,
+//! `BPF_PROG_TYPE_KPROBE` is `"kprobe"`. The functions in here map such
+//! names back to the constants and, for when libbpf is not at hand,
+//! constants to names. The tables are generated from the bindings at
+//! build time.
+
+use crate::bindings::*;
+
+macro_rules! type_names {
+    ($(fn $fn:ident, $name_fn:ident -> $ty:ident {
+        $($name:literal => $value:ident,)*
+    })*) => {
+        $(
+            #[doc = concat!(
+                "Look up the `", stringify!($ty), "` named `name`, as reported by `libbpf_",
+                stringify!($ty), "_str`."
+            )]
+            pub fn $fn(name: &str) -> Option<$ty> {
+                match name {
+                    $($name => Some($value),)*
+                    _ => None,
+                }
+            }
+
+            #[doc = concat!(
+                "Look up the name of the `", stringify!($ty), "` `value`, as reported by `libbpf_",
+                stringify!($ty), "_str`."
+            )]
+            #[allow(unreachable_patterns)]
+            pub fn $name_fn(value: $ty) -> Option<&'static str> {
+                match value {
+                    $($value => Some($name),)*
+                    _ => None,
+                }
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/type_names.rs"));
@@ -0,0 +1,60 @@
+// src/opaque.rs
+
+//! Types whose definition libbpf keeps private.
+//!
+//! These are only ever handled through pointers. Rather than the empty
+//! but otherwise regular structs bindgen would emit, they follow the
+//! pattern recommended by the Rustonomicon for opaque types: they can't
+//! be constructed, copied, or moved out of a reference, and they are
+//! neither `Send`, `Sync`, nor `Unpin`.
+//!
+//! ```compile_fail,E0507
+//! fn copy(map: *const libbpf_sys::bpf_map) -> libbpf_sys::bpf_map {
+//!     unsafe { *map }
+//! }
+//! ```
+
+use core::fmt;
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::marker::PhantomPinned;
+
+macro_rules! opaque {
+    ($($(#[$attr:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$attr])*
+            #[repr(C)]
+            pub struct $name {
+                _data: [u8; 0],
+                _marker: PhantomData<(*mut u8, PhantomPinned)>,
+            }
+
+            $(#[$attr])*
+            impl Debug for $name {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.debug_struct(stringify!($name)).finish_non_exhaustive()
+                }
+            }
+        )*
+    };
+}
+
+opaque!(
+    bpf_link,
+    #[cfg(not(feature = "no-linker"))]
+    bpf_linker,
+    bpf_map,
+    bpf_object,
+    bpf_prog_linfo,
+    bpf_program,
+    btf,
+    #[cfg(not(feature = "no-btf-dump"))]
+    btf_dump,
+    btf_ext,
+    #[cfg(not(feature = "no-perf"))]
+    perf_buffer,
+    ring,
+    ring_buffer,
+    user_ring_buffer,
+);
@@ -0,0 +1,98 @@
+// src/opts.rs
+
+//! `Default` implementations for libbpf's "opts" structs.
+//!
+//! libbpf rejects any opts struct whose `sz` member does not contain the
+//! size of the struct, which is what the `LIBBPF_OPTS` C macro takes
+//! care of. The `Default` implementations provided here mirror that
+//! macro: the struct is zero-initialized and `sz` is set accordingly.
+//! bindgen is instructed not to derive `Default` for these types, and
+//! the build script lists all structs starting out with `sz` that lack
+//! it.
+//!
+//! The same structs implement [`Opts`], for use with
+//! [`check_opts`](crate::opts_check::check_opts).
+
+use core::cmp::min;
+use core::mem::size_of;
+use core::mem::MaybeUninit;
+use core::ptr::copy_nonoverlapping;
+use core::ptr::write_bytes;
+
+use crate::bindings::*;
+use crate::opts_check::Opts;
+
+macro_rules! impl_default_opts {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl Default for $ty {
+                fn default() -> Self {
+                    let mut s = MaybeUninit::<Self>::uninit();
+                    // SAFETY: All opts structs are plain old data for
+                    //         which all-zeroes is a valid bit pattern.
+                    let mut opts = unsafe {
+                        write_bytes(s.as_mut_ptr(), 0, 1);
+                        s.assume_init()
+                    };
+                    opts.sz = size_of::<Self>() as _;
+                    opts
+                }
+            }
+
+            impl Opts for $ty {
+                const NAME: &'static str = stringify!($ty);
+
+                fn sz(&self) -> usize {
+                    self.sz as usize
+                }
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/default_opts.rs"));
+
+/// Copy the opts struct `opts` points to, or create a default one if it
+/// is null.
+///
+/// Like libbpf, only the first `sz` bytes are read, as the struct may
+/// stem from code built against an older, smaller version of it. The
+/// remaining members keep their zero defaults, which is what libbpf
+/// assumes for missing ones, and the copy's `sz` covers all of them. An
+/// `sz` too small for libbpf to accept is passed on as is.
+///
+/// # Safety
+/// `opts` has to be null or point to an opts struct whose first `sz`
+/// bytes are valid.
+// Neither `verifier_log` nor `vmlinux_btf` is built with these.
+#[cfg_attr(
+    all(feature = "no-std", any(feature = "dlopen", feature = "btf-only")),
+    allow(dead_code)
+)]
+pub(crate) unsafe fn read_opts<T: Opts + Default>(opts: *const T) -> T {
+    let mut copy = T::default();
+    if opts.is_null() {
+        return copy;
+    }
+
+    // SAFETY: All opts structs start out with `sz`.
+    let sz = unsafe { opts.cast::<size_t>().read() };
+    let dst = (&mut copy as *mut T).cast::<u8>();
+    // `size_t` is `usize` with the `libc` feature.
+    #[allow(clippy::unnecessary_cast)]
+    let len = sz as usize;
+    if len < size_of::<size_t>() {
+        // SAFETY: All opts structs start out with `sz`.
+        unsafe { dst.cast::<size_t>().write(sz) };
+        return copy;
+    }
+
+    let len = min(len, size_of::<T>());
+    // SAFETY: The caller guarantees that `sz` bytes are valid and `copy`
+    //         is large enough for `len` of them.
+    unsafe {
+        copy_nonoverlapping(opts.cast::<u8>(), dst, len);
+        dst.cast::<size_t>().write(size_of::<T>() as _);
+    }
+    copy
+}
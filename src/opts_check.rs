@@ -0,0 +1,138 @@
+// src/opts_check.rs
+
+//! Validation of libbpf's "opts" structs.
+//!
+//! An opts struct whose `sz` member does not match its size is the most
+//! common reason for libbpf functions to fail with `-EINVAL`. This
+//! usually happens when the struct is created through `mem::zeroed`
+//! instead of its `Default` implementation, which leaves `sz` at zero.
+//! [`check_opts`] detects such mistakes and is meant to be used in
+//! debug assertions ahead of the actual call.
+//!
+//! The bindings may also describe a newer version of a struct than the
+//! libbpf in use knows, in which case libbpf rejects the struct with
+//! `-E2BIG` unless all members it doesn't know are zero.
+//! [`check_opts_for_size`] additionally checks for that, the way libbpf
+//! does.
+
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::mem::size_of;
+use core::slice;
+
+/// A libbpf opts struct, i.e., one carrying its own size in `sz`.
+pub trait Opts: Sized {
+    /// The name of the struct.
+    const NAME: &'static str;
+
+    /// Retrieve the value of the `sz` member.
+    fn sz(&self) -> usize;
+}
+
+/// The problem found with an opts struct.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum OptsIssue {
+    /// `sz` is zero, likely because the struct was zero-initialized
+    /// instead of being created through `Default`.
+    ZeroSize,
+    /// `sz` is set, but to a value other than the size of the struct.
+    SizeMismatch {
+        /// The size of the struct.
+        expected: usize,
+        /// The value of `sz`.
+        actual: usize,
+    },
+    /// The struct is larger than the one libbpf knows and has a non-zero
+    /// byte past the end of the latter.
+    NonZeroTail {
+        /// The offset of the first non-zero byte.
+        offset: usize,
+    },
+}
+
+/// The error returned by [`check_opts`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct OptsError {
+    /// The name of the offending struct.
+    pub name: &'static str,
+    /// The problem found.
+    pub issue: OptsIssue,
+}
+
+impl Display for OptsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.issue {
+            OptsIssue::ZeroSize => write!(
+                f,
+                "{}: sz is 0; the struct was likely zero-initialized instead of created through `Default`",
+                self.name
+            ),
+            OptsIssue::SizeMismatch { expected, actual } => write!(
+                f,
+                "{}: sz is {}, but the struct is {} bytes in size",
+                self.name, actual, expected
+            ),
+            OptsIssue::NonZeroTail { offset } => write!(
+                f,
+                "{}: byte {} is non-zero, but not known to libbpf",
+                self.name, offset
+            ),
+        }
+    }
+}
+
+/// Check the opts struct `opts` points to for an invalid `sz` member.
+///
+/// A null pointer is valid, as libbpf uses default options then.
+///
+/// # Safety
+/// `opts` has to be null or point to a valid instance of `T`.
+pub unsafe fn check_opts<T: Opts>(opts: *const T) -> Result<(), OptsError> {
+    let Some(opts) = (unsafe { opts.as_ref() }) else {
+        return Ok(());
+    };
+
+    let expected = size_of::<T>();
+    let issue = match opts.sz() {
+        sz if sz == expected => return Ok(()),
+        0 => OptsIssue::ZeroSize,
+        actual => OptsIssue::SizeMismatch { expected, actual },
+    };
+    Err(OptsError {
+        name: T::NAME,
+        issue,
+    })
+}
+
+/// Check the opts struct `opts` points to like [`check_opts`] and,
+/// mirroring libbpf's own validation, for non-zero bytes past the
+/// `libbpf_size` bytes the libbpf in use knows of the struct.
+///
+/// # Safety
+/// `opts` has to be null or point to a valid instance of `T`, all bytes
+/// of which have to be initialized. The bindings' explicit padding
+/// members ensure the latter, unless the `no-explicit-padding` feature
+/// is enabled.
+pub unsafe fn check_opts_for_size<T: Opts>(
+    opts: *const T,
+    libbpf_size: usize,
+) -> Result<(), OptsError> {
+    unsafe { check_opts(opts) }?;
+    if opts.is_null() || libbpf_size >= size_of::<T>() {
+        return Ok(());
+    }
+
+    // SAFETY: The caller guarantees that all bytes of `opts` are
+    //         initialized.
+    let bytes = unsafe { slice::from_raw_parts(opts.cast::<u8>(), size_of::<T>()) };
+    match bytes[libbpf_size..].iter().position(|byte| *byte != 0) {
+        None => Ok(()),
+        Some(idx) => Err(OptsError {
+            name: T::NAME,
+            issue: OptsIssue::NonZeroTail {
+                offset: libbpf_size + idx,
+            },
+        }),
+    }
+}
@@ -0,0 +1,219 @@
+// src/perf.rs
+
+//! Helpers for working with perf events.
+//!
+//! For consuming perf event ring buffers mapped via `mmap`: the kernel
+//! publishes new data by advancing `data_head` and expects consumers to
+//! acknowledge consumed data by advancing `data_tail`. The former has
+//! to be read with acquire semantics and the latter written with
+//! release semantics, or records may be observed half-written or
+//! overwritten while still being read.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use core::ptr::addr_of;
+use core::ptr::addr_of_mut;
+use core::slice;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::Ordering;
+
+use crate::bindings::*;
+
+impl perf_event_attr {
+    /// Create a zero-initialized attribute with `size` set to `size`,
+    /// which should be one of the `PERF_ATTR_SIZE_VER*` constants.
+    pub fn with_size(size: u32) -> Self {
+        Self {
+            size,
+            ..Default::default()
+        }
+    }
+
+    /// Determine the smallest `PERF_ATTR_SIZE_VER*` size covering all
+    /// fields that are set.
+    ///
+    /// Kernels reject attributes larger than what they know about if
+    /// any of the unknown trailing bytes is non-zero, but accept
+    /// smaller ones, so this is the most compatible size to use.
+    pub fn required_size(&self) -> u32 {
+        if self.config3 != 0 {
+            PERF_ATTR_SIZE_VER8
+        } else if self.sig_data != 0 {
+            PERF_ATTR_SIZE_VER7
+        } else if self.aux_sample_size != 0 || self.__reserved_3 != 0 {
+            PERF_ATTR_SIZE_VER6
+        } else if self.aux_watermark != 0 || self.sample_max_stack != 0 || self.__reserved_2 != 0 {
+            PERF_ATTR_SIZE_VER5
+        } else if self.sample_regs_intr != 0 {
+            PERF_ATTR_SIZE_VER4
+        } else if self.sample_regs_user != 0 || self.sample_stack_user != 0 || self.clockid != 0 {
+            PERF_ATTR_SIZE_VER3
+        } else if self.branch_sample_type != 0 {
+            PERF_ATTR_SIZE_VER2
+        } else if unsafe { self.__bindgen_anon_4.config2 } != 0 {
+            PERF_ATTR_SIZE_VER1
+        } else {
+            PERF_ATTR_SIZE_VER0
+        }
+    }
+
+    /// Set `size` to [`required_size`][Self::required_size].
+    pub fn fit_size(&mut self) {
+        self.size = self.required_size();
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+/// Read `data_head` of the ring buffer's control page with acquire
+/// ordering.
+///
+/// # Safety
+/// `page` has to point to a valid, mapped `perf_event_mmap_page`.
+pub unsafe fn perf_mmap_read_head(page: *const perf_event_mmap_page) -> u64 {
+    let head = unsafe { addr_of!((*page).data_head) } as *const AtomicU64;
+    unsafe { (*head).load(Ordering::Acquire) }
+}
+
+#[cfg(target_has_atomic = "64")]
+/// Write `data_tail` of the ring buffer's control page with release
+/// ordering, handing the memory up to `tail` back to the kernel.
+///
+/// # Safety
+/// `page` has to point to a valid, mapped `perf_event_mmap_page` that
+/// was mapped writable.
+pub unsafe fn perf_mmap_write_tail(page: *mut perf_event_mmap_page, tail: u64) {
+    let tail_ptr = unsafe { addr_of_mut!((*page).data_tail) } as *const AtomicU64;
+    unsafe { (*tail_ptr).store(tail, Ordering::Release) }
+}
+
+#[cfg(target_has_atomic = "64")]
+/// A reader for the records of a perf ring buffer.
+///
+/// The reader snapshots `data_head` when created and publishes the
+/// position up to which records have been consumed as `data_tail`
+/// when dropped.
+#[derive(Debug)]
+pub struct PerfRingReader {
+    page: *mut perf_event_mmap_page,
+    data: *const u8,
+    size: u64,
+    head: u64,
+    tail: u64,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl PerfRingReader {
+    /// Create a reader for the ring buffer whose mapping starts with
+    /// the control page at `page`.
+    ///
+    /// `None` is returned if the control page does not describe the
+    /// data area, which is the case for kernels before 4.1.
+    ///
+    /// # Safety
+    /// `page` has to point to the beginning of a valid, writable perf
+    /// ring buffer mapping, which has to stay mapped for as long as
+    /// the reader is alive.
+    pub unsafe fn new(page: *mut perf_event_mmap_page) -> Option<Self> {
+        let (offset, size, tail) = unsafe {
+            (
+                (*page).data_offset,
+                (*page).data_size,
+                (*page).data_tail,
+            )
+        };
+        if size == 0 || !size.is_power_of_two() {
+            return None;
+        }
+
+        let head = unsafe { perf_mmap_read_head(page) };
+        let data = unsafe { (page as *const u8).add(offset as usize) };
+
+        Some(Self {
+            page,
+            data,
+            size,
+            head,
+            tail,
+        })
+    }
+
+    /// Iterate over the available records.
+    ///
+    /// Each record includes its `perf_event_header`. Records wrapping
+    /// around the end of the ring are copied, all others are borrowed
+    /// from the mapping.
+    pub fn records(&mut self) -> Records<'_> {
+        Records { reader: self }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl Drop for PerfRingReader {
+    fn drop(&mut self) {
+        unsafe { perf_mmap_write_tail(self.page, self.tail) }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+/// An iterator over the records of a [`PerfRingReader`].
+#[derive(Debug)]
+pub struct Records<'reader> {
+    reader: &'reader mut PerfRingReader,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<'reader> Iterator for Records<'reader> {
+    type Item = Cow<'reader, [u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reader = &mut *self.reader;
+        let avail = reader.head.wrapping_sub(reader.tail);
+        if avail < size_of::<perf_event_header>() as u64 {
+            return None;
+        }
+
+        let mask = reader.size - 1;
+        let read = |offset: u64, len: u64| -> Cow<'reader, [u8]> {
+            let start = offset & mask;
+            let first = len.min(reader.size - start);
+            // SAFETY: The caller of `PerfRingReader::new` guarantees
+            //         that the mapping is valid and the kernel does not
+            //         touch data before `data_tail` is advanced, which
+            //         only happens once the reader (and with it all
+            //         borrows) is gone.
+            let first_part = unsafe {
+                slice::from_raw_parts(reader.data.add(start as usize), first as usize)
+            };
+            if first == len {
+                Cow::Borrowed(first_part)
+            } else {
+                let second_part =
+                    unsafe { slice::from_raw_parts(reader.data, (len - first) as usize) };
+                let mut buf = Vec::with_capacity(len as usize);
+                buf.extend_from_slice(first_part);
+                buf.extend_from_slice(second_part);
+                Cow::Owned(buf)
+            }
+        };
+
+        let header = read(reader.tail, size_of::<perf_event_header>() as u64);
+        // SAFETY: `header` has the size of `perf_event_header`, which
+        //         consists of integers only.
+        let header =
+            unsafe { (header.as_ptr() as *const perf_event_header).read_unaligned() };
+        let len = u64::from(header.size);
+        if len < size_of::<perf_event_header>() as u64 || len > avail {
+            // A corrupted record; there is no way to resynchronize, so
+            // just skip everything.
+            reader.tail = reader.head;
+            return None;
+        }
+
+        let record = read(reader.tail, len);
+        reader.tail = reader.tail.wrapping_add(len);
+        Some(record)
+    }
+}
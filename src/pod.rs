@@ -0,0 +1,20 @@
+// src/pod.rs
+
+//! `bytemuck` support for types the `Pod` derive cannot handle: the
+//! bitfield storage type emitted by bindgen and unions.
+
+use bytemuck::Pod;
+use bytemuck::Zeroable;
+
+use crate::bindings::*;
+
+// SAFETY: `__BindgenBitfieldUnit` is a `repr(C)` wrapper around its
+//         storage, which is a byte array here.
+unsafe impl<const N: usize> Zeroable for __BindgenBitfieldUnit<[u8; N]> {}
+// SAFETY: See above.
+unsafe impl<const N: usize> Pod for __BindgenBitfieldUnit<[u8; N]> {}
+
+// SAFETY: All members of the union are `__u32`.
+unsafe impl Zeroable for btf_type__bindgen_ty_1 {}
+// SAFETY: See above.
+unsafe impl Pod for btf_type__bindgen_ty_1 {}
@@ -0,0 +1,116 @@
+// src/printf.rs
+
+//! Closures as libbpf's printf-style callbacks.
+//!
+//! Some of libbpf's callbacks, e.g., `libbpf_print_fn_t` and
+//! `btf_dump_printf_fn_t`, receive a format string along with a
+//! `va_list`, which Rust can't consume on stable. For each such callback
+//! type in the bindings, this module provides a function of the same
+//! name turning a closure into a callback of that type. The closure gets
+//! the formatted message in place of the format string and its
+//! arguments, along with the remaining parameters, e.g., the context of
+//! `btf_dump_printf_fn_t`:
+//!
+//! ```no_run
+//! use libbpf_sys::printf;
+//!
+//! let print = printf::libbpf_print_fn_t(|level, msg| {
+//!     eprint!("[{}] {}", level, msg.to_string_lossy());
+//!     0
+//! });
+//! unsafe { libbpf_sys::libbpf_set_print(print) };
+//! ```
+//!
+//! The callback types lack a place to keep the closure, so it cannot
+//! capture anything, which is checked at compile time. Messages are
+//! formatted by `vsnprintf` in a small C shim, into a buffer on the stack
+//! unless they are longer.
+
+use alloc::vec;
+use core::convert::TryFrom;
+use core::ffi::c_char;
+use core::ffi::c_int;
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+use crate::bindings::*;
+
+extern "C" {
+    fn libbpf_sys_vformat(
+        buf: *mut c_char,
+        size: size_t,
+        fmt: *const c_char,
+        args: VaList,
+    ) -> c_int;
+}
+
+/// The length of messages formatted on the stack.
+const STACK_LEN: usize = 256;
+
+/// Format `fmt` with `args` and pass the message to `f`.
+///
+/// # Safety
+/// `fmt` and `args` have to be the ones libbpf passed to a callback.
+unsafe fn with_message<R>(fmt: *const c_char, args: VaList, f: impl FnOnce(&CStr) -> R) -> R {
+    let mut buf = [0; STACK_LEN];
+    let len = unsafe { libbpf_sys_vformat(buf.as_mut_ptr(), buf.len() as _, fmt, args) };
+    let len = usize::try_from(len).unwrap_or(0);
+    if len < buf.len() {
+        // SAFETY: `vsnprintf` terminated the message.
+        return f(unsafe { CStr::from_ptr(buf.as_ptr()) });
+    }
+
+    let mut buf = vec![0; len + 1];
+    unsafe { libbpf_sys_vformat(buf.as_mut_ptr(), buf.len() as _, fmt, args) };
+    f(unsafe { CStr::from_ptr(buf.as_ptr()) })
+}
+
+/// Assert that `F` takes up no space, i.e., captures nothing, so that it
+/// can be conjured up in a callback.
+struct AssertCapturesNothing<F>(PhantomData<F>);
+
+impl<F> AssertCapturesNothing<F> {
+    const OK: () = assert!(
+        size_of::<F>() == 0,
+        "closures passed as printf-style callbacks cannot capture anything"
+    );
+}
+
+macro_rules! printf_callbacks_table {
+    (va_list = $va_list:ty; $($name:ident($($param:ident: $ty:ty),*) -> $ret:ty;)*) => {
+        /// The type a `va_list` is passed as, which depends on the
+        /// architecture.
+        type VaList = $va_list;
+
+        $(
+            #[doc = concat!("Turn `f` into a `", stringify!($name), "`, passing it the formatted message.")]
+            #[allow(clippy::unused_unit)]
+            pub fn $name<F>(f: F) -> $name
+            where
+                F: Fn($($ty,)* &CStr) -> $ret + Copy + 'static,
+            {
+                unsafe extern "C" fn trampoline<F>(
+                    $($param: $ty,)*
+                    fmt: *const c_char,
+                    args: VaList,
+                ) -> $ret
+                where
+                    F: Fn($($ty,)* &CStr) -> $ret + Copy + 'static,
+                {
+                    // SAFETY: `F` takes up no space, as asserted below,
+                    //         so there are no bytes to initialize.
+                    let f = unsafe { core::mem::zeroed::<F>() };
+                    unsafe { with_message(fmt, args, |msg| f($($param,)* msg)) }
+                }
+
+                #[allow(clippy::let_unit_value)]
+                let () = AssertCapturesNothing::<F>::OK;
+                let _ = f;
+                Some(trampoline::<F>)
+            }
+        )*
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/printf_callbacks.rs"));
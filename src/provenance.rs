@@ -0,0 +1,159 @@
+// src/provenance.rs
+
+//! The releases introducing constants and functions.
+//!
+//! Program and map types are annotated with the kernel release adding
+//! them, and libbpf's functions with the libbpf release first exporting
+//! them. The latter are extracted from the version nodes of the vendored
+//! `libbpf.map` at build time. Both are meant for gating features at
+//! runtime and for error messages pointing out what is required:
+//!
+//! ```
+//! use libbpf_sys::provenance::provenance;
+//!
+//! let ringbuf = provenance("BPF_MAP_TYPE_RINGBUF").unwrap();
+//! assert_eq!(ringbuf.kernel, Some((5, 8)));
+//! assert_eq!(ringbuf.to_string(), "`BPF_MAP_TYPE_RINGBUF` requires kernel 5.8");
+//! ```
+
+use core::fmt;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+/// The releases introducing a constant or function, as `(major, minor)`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Provenance {
+    /// The name of the constant or function.
+    pub name: &'static str,
+    /// The kernel release introducing it, if it's a kernel constant.
+    pub kernel: Option<(u32, u32)>,
+    /// The libbpf release introducing it, if it's a libbpf function.
+    pub libbpf: Option<(u32, u32)>,
+}
+
+impl Display for Provenance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` requires", self.name)?;
+        match (self.kernel, self.libbpf) {
+            (Some((major, minor)), None) => write!(f, " kernel {}.{}", major, minor),
+            (None, Some((major, minor))) => write!(f, " libbpf {}.{}", major, minor),
+            (Some(kernel), Some(libbpf)) => write!(
+                f,
+                " kernel {}.{} and libbpf {}.{}",
+                kernel.0, kernel.1, libbpf.0, libbpf.1
+            ),
+            (None, None) => f.write_str(" nothing in particular"),
+        }
+    }
+}
+
+/// The kernel releases introducing program and map types, sorted by
+/// name.
+pub const KERNEL_VERSIONS: &[(&str, (u32, u32))] = &[
+    ("BPF_MAP_TYPE_ARENA", (6, 9)),
+    ("BPF_MAP_TYPE_ARRAY", (3, 19)),
+    ("BPF_MAP_TYPE_ARRAY_OF_MAPS", (4, 12)),
+    ("BPF_MAP_TYPE_BLOOM_FILTER", (5, 16)),
+    ("BPF_MAP_TYPE_CGROUP_ARRAY", (4, 8)),
+    ("BPF_MAP_TYPE_CGROUP_STORAGE", (4, 19)),
+    ("BPF_MAP_TYPE_CGROUP_STORAGE_DEPRECATED", (4, 19)),
+    ("BPF_MAP_TYPE_CGRP_STORAGE", (6, 2)),
+    ("BPF_MAP_TYPE_CPUMAP", (4, 15)),
+    ("BPF_MAP_TYPE_DEVMAP", (4, 14)),
+    ("BPF_MAP_TYPE_DEVMAP_HASH", (5, 4)),
+    ("BPF_MAP_TYPE_HASH", (3, 19)),
+    ("BPF_MAP_TYPE_HASH_OF_MAPS", (4, 12)),
+    ("BPF_MAP_TYPE_INODE_STORAGE", (5, 10)),
+    ("BPF_MAP_TYPE_LPM_TRIE", (4, 11)),
+    ("BPF_MAP_TYPE_LRU_HASH", (4, 10)),
+    ("BPF_MAP_TYPE_LRU_PERCPU_HASH", (4, 10)),
+    ("BPF_MAP_TYPE_PERCPU_ARRAY", (4, 6)),
+    ("BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE", (4, 20)),
+    ("BPF_MAP_TYPE_PERCPU_CGROUP_STORAGE_DEPRECATED", (4, 20)),
+    ("BPF_MAP_TYPE_PERCPU_HASH", (4, 6)),
+    ("BPF_MAP_TYPE_PERF_EVENT_ARRAY", (4, 3)),
+    ("BPF_MAP_TYPE_PROG_ARRAY", (4, 2)),
+    ("BPF_MAP_TYPE_QUEUE", (4, 20)),
+    ("BPF_MAP_TYPE_REUSEPORT_SOCKARRAY", (4, 19)),
+    ("BPF_MAP_TYPE_RINGBUF", (5, 8)),
+    ("BPF_MAP_TYPE_SK_STORAGE", (5, 2)),
+    ("BPF_MAP_TYPE_SOCKHASH", (4, 18)),
+    ("BPF_MAP_TYPE_SOCKMAP", (4, 14)),
+    ("BPF_MAP_TYPE_STACK", (4, 20)),
+    ("BPF_MAP_TYPE_STACK_TRACE", (4, 6)),
+    ("BPF_MAP_TYPE_STRUCT_OPS", (5, 6)),
+    ("BPF_MAP_TYPE_TASK_STORAGE", (5, 11)),
+    ("BPF_MAP_TYPE_USER_RINGBUF", (6, 1)),
+    ("BPF_MAP_TYPE_XSKMAP", (4, 18)),
+    ("BPF_PROG_TYPE_CGROUP_DEVICE", (4, 15)),
+    ("BPF_PROG_TYPE_CGROUP_SKB", (4, 10)),
+    ("BPF_PROG_TYPE_CGROUP_SOCK", (4, 10)),
+    ("BPF_PROG_TYPE_CGROUP_SOCKOPT", (5, 3)),
+    ("BPF_PROG_TYPE_CGROUP_SOCK_ADDR", (4, 17)),
+    ("BPF_PROG_TYPE_CGROUP_SYSCTL", (5, 2)),
+    ("BPF_PROG_TYPE_EXT", (5, 6)),
+    ("BPF_PROG_TYPE_FLOW_DISSECTOR", (4, 20)),
+    ("BPF_PROG_TYPE_KPROBE", (4, 1)),
+    ("BPF_PROG_TYPE_LIRC_MODE2", (4, 18)),
+    ("BPF_PROG_TYPE_LSM", (5, 7)),
+    ("BPF_PROG_TYPE_LWT_IN", (4, 10)),
+    ("BPF_PROG_TYPE_LWT_OUT", (4, 10)),
+    ("BPF_PROG_TYPE_LWT_SEG6LOCAL", (4, 18)),
+    ("BPF_PROG_TYPE_LWT_XMIT", (4, 10)),
+    ("BPF_PROG_TYPE_NETFILTER", (6, 4)),
+    ("BPF_PROG_TYPE_PERF_EVENT", (4, 9)),
+    ("BPF_PROG_TYPE_RAW_TRACEPOINT", (4, 17)),
+    ("BPF_PROG_TYPE_RAW_TRACEPOINT_WRITABLE", (5, 2)),
+    ("BPF_PROG_TYPE_SCHED_ACT", (4, 1)),
+    ("BPF_PROG_TYPE_SCHED_CLS", (4, 1)),
+    ("BPF_PROG_TYPE_SK_LOOKUP", (5, 9)),
+    ("BPF_PROG_TYPE_SK_MSG", (4, 17)),
+    ("BPF_PROG_TYPE_SK_REUSEPORT", (4, 19)),
+    ("BPF_PROG_TYPE_SK_SKB", (4, 14)),
+    ("BPF_PROG_TYPE_SOCKET_FILTER", (3, 19)),
+    ("BPF_PROG_TYPE_SOCK_OPS", (4, 13)),
+    ("BPF_PROG_TYPE_STRUCT_OPS", (5, 6)),
+    ("BPF_PROG_TYPE_SYSCALL", (5, 14)),
+    ("BPF_PROG_TYPE_TRACEPOINT", (4, 7)),
+    ("BPF_PROG_TYPE_TRACING", (5, 5)),
+    ("BPF_PROG_TYPE_XDP", (4, 8)),
+];
+
+/// The libbpf releases introducing the functions libbpf exports, sorted
+/// by name.
+pub const LIBBPF_VERSIONS: &[(&str, (u32, u32))] =
+    include!(concat!(env!("OUT_DIR"), "/libbpf_versions.rs"));
+
+fn lookup(
+    table: &'static [(&'static str, (u32, u32))],
+    name: &str,
+) -> Option<&'static (&'static str, (u32, u32))> {
+    table
+        .binary_search_by_key(&name, |&(name, _)| name)
+        .ok()
+        .map(|idx| &table[idx])
+}
+
+/// Look up the kernel release introducing the constant `name`, e.g.,
+/// `"BPF_PROG_TYPE_LSM"`.
+pub fn kernel_version(name: &str) -> Option<(u32, u32)> {
+    lookup(KERNEL_VERSIONS, name).map(|&(_, version)| version)
+}
+
+/// Look up the libbpf release introducing the function `name`, e.g.,
+/// `"bpf_map_create"`.
+pub fn libbpf_version(name: &str) -> Option<(u32, u32)> {
+    lookup(LIBBPF_VERSIONS, name).map(|&(_, version)| version)
+}
+
+/// Look up the releases introducing the constant or function `name`.
+pub fn provenance(name: &str) -> Option<Provenance> {
+    let kernel = lookup(KERNEL_VERSIONS, name);
+    let libbpf = lookup(LIBBPF_VERSIONS, name);
+    let &(name, _) = kernel.or(libbpf)?;
+    Some(Provenance {
+        name,
+        kernel: kernel.map(|&(_, version)| version),
+        libbpf: libbpf.map(|&(_, version)| version),
+    })
+}
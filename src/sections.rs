@@ -0,0 +1,52 @@
+// src/sections.rs
+
+//! libbpf's table of `SEC()` names.
+//!
+//! libbpf derives the program type and expected attach type of a
+//! program from the name of the ELF section it resides in. The table is
+//! extracted from the vendored libbpf sources at build time, so that the
+//! names can be validated without loading anything.
+
+use crate::bindings::*;
+
+/// A section name known to libbpf, along with what it implies.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SectionDef {
+    /// The name, as spelled by libbpf. A trailing `/` requires
+    /// additional components after the slash, e.g., `"fentry/"`, while
+    /// a trailing `+` makes them optional, e.g., `"kprobe+"`. Any other
+    /// name has to match exactly.
+    pub name: &'static str,
+    /// The program type.
+    pub prog_type: bpf_prog_type,
+    /// The expected attach type.
+    pub expected_attach_type: bpf_attach_type,
+    /// Whether the program attaches to a BTF-described target.
+    pub attach_btf: bool,
+    /// Whether the program is sleepable.
+    pub sleepable: bool,
+}
+
+impl SectionDef {
+    /// Check whether the section `name` is covered by this definition,
+    /// following libbpf's rules.
+    pub fn matches(&self, name: &str) -> bool {
+        if self.name.ends_with('/') {
+            name.starts_with(self.name)
+        } else if let Some(prefix) = self.name.strip_suffix('+') {
+            name.strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        } else {
+            name == self.name
+        }
+    }
+}
+
+/// The section definitions in the order libbpf checks them.
+pub const SECTION_DEFS: &[SectionDef] = include!(concat!(env!("OUT_DIR"), "/section_defs.rs"));
+
+/// Look up the definition covering the section `name`, like libbpf does
+/// when opening an object.
+pub fn find_section_def(name: &str) -> Option<&'static SectionDef> {
+    SECTION_DEFS.iter().find(|def| def.matches(name))
+}
@@ -0,0 +1,162 @@
+// src/serialize.rs
+
+//! `Serialize` implementations for info structs that cannot simply
+//! derive it.
+//!
+//! Bitfields are serialized through their accessors and union members
+//! based on the discriminating `type_`; bitfield storage and padding is
+//! omitted. Deserializing these types is not supported, as there is no
+//! use in handing them back to the kernel.
+
+use serde::ser::SerializeStruct as _;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::bindings::*;
+
+macro_rules! serialize_struct {
+    ($serializer:expr, $name:literal, { $($field:ident: $value:expr),* $(,)? }) => {{
+        let len = [$(stringify!($field)),*].len();
+        let mut state = $serializer.serialize_struct($name, len)?;
+        $(state.serialize_field(stringify!($field), &$value)?;)*
+        state.end()
+    }};
+}
+
+impl Serialize for bpf_prog_info {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_struct!(serializer, "bpf_prog_info", {
+            type_: self.type_,
+            id: self.id,
+            tag: self.tag,
+            jited_prog_len: self.jited_prog_len,
+            xlated_prog_len: self.xlated_prog_len,
+            jited_prog_insns: self.jited_prog_insns,
+            xlated_prog_insns: self.xlated_prog_insns,
+            load_time: self.load_time,
+            created_by_uid: self.created_by_uid,
+            nr_map_ids: self.nr_map_ids,
+            map_ids: self.map_ids,
+            name: self.name,
+            ifindex: self.ifindex,
+            gpl_compatible: self.gpl_compatible(),
+            netns_dev: self.netns_dev,
+            netns_ino: self.netns_ino,
+            nr_jited_ksyms: self.nr_jited_ksyms,
+            nr_jited_func_lens: self.nr_jited_func_lens,
+            jited_ksyms: self.jited_ksyms,
+            jited_func_lens: self.jited_func_lens,
+            btf_id: self.btf_id,
+            func_info_rec_size: self.func_info_rec_size,
+            func_info: self.func_info,
+            nr_func_info: self.nr_func_info,
+            nr_line_info: self.nr_line_info,
+            line_info: self.line_info,
+            jited_line_info: self.jited_line_info,
+            nr_jited_line_info: self.nr_jited_line_info,
+            line_info_rec_size: self.line_info_rec_size,
+            jited_line_info_rec_size: self.jited_line_info_rec_size,
+            nr_prog_tags: self.nr_prog_tags,
+            prog_tags: self.prog_tags,
+            run_time_ns: self.run_time_ns,
+            run_cnt: self.run_cnt,
+            recursion_misses: self.recursion_misses,
+            verified_insns: self.verified_insns,
+            attach_btf_obj_id: self.attach_btf_obj_id,
+            attach_btf_id: self.attach_btf_id,
+        })
+    }
+}
+
+impl Serialize for bpf_link_info {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // The members of the link type specific union are flattened
+        // into the struct, similar to what `bpftool link show` does.
+        // Members whose layout depends on more than the link type are
+        // left out.
+        macro_rules! link_info {
+            ($($variant:ident { $($field:ident),* })?) => {
+                serialize_struct!(serializer, "bpf_link_info", {
+                    type_: self.type_,
+                    id: self.id,
+                    prog_id: self.prog_id,
+                    // SAFETY: `type_` tells us which union member is
+                    //         valid.
+                    $($($field: unsafe { self.__bindgen_anon_1.$variant.$field },)*)?
+                })
+            };
+        }
+
+        match self.type_ {
+            BPF_LINK_TYPE_RAW_TRACEPOINT => link_info!(raw_tracepoint {
+                tp_name,
+                tp_name_len
+            }),
+            BPF_LINK_TYPE_TRACING => {
+                link_info!(tracing {
+                    attach_type,
+                    target_obj_id,
+                    target_btf_id
+                })
+            },
+            BPF_LINK_TYPE_CGROUP => link_info!(cgroup {
+                cgroup_id,
+                attach_type
+            }),
+            BPF_LINK_TYPE_ITER => link_info!(iter {
+                target_name,
+                target_name_len
+            }),
+            BPF_LINK_TYPE_NETNS => link_info!(netns {
+                netns_ino,
+                attach_type
+            }),
+            BPF_LINK_TYPE_XDP => link_info!(xdp { ifindex }),
+            BPF_LINK_TYPE_STRUCT_OPS => link_info!(struct_ops { map_id }),
+            BPF_LINK_TYPE_NETFILTER => link_info!(netfilter {
+                pf,
+                hooknum,
+                priority,
+                flags
+            }),
+            BPF_LINK_TYPE_KPROBE_MULTI => {
+                link_info!(kprobe_multi {
+                    addrs,
+                    count,
+                    flags,
+                    missed,
+                    cookies
+                })
+            },
+            BPF_LINK_TYPE_UPROBE_MULTI => link_info!(uprobe_multi {
+                path,
+                offsets,
+                ref_ctr_offsets,
+                cookies,
+                path_size,
+                count,
+                flags,
+                pid
+            }),
+            BPF_LINK_TYPE_TCX => link_info!(tcx {
+                ifindex,
+                attach_type
+            }),
+            BPF_LINK_TYPE_NETKIT => link_info!(netkit {
+                ifindex,
+                attach_type
+            }),
+            BPF_LINK_TYPE_SOCKMAP => link_info!(sockmap {
+                map_id,
+                attach_type
+            }),
+            _ => link_info!(),
+        }
+    }
+}
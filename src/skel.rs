@@ -0,0 +1,236 @@
+// src/skel.rs
+
+//! Helpers for assembling skeleton structs by hand.
+//!
+//! `bpf_object_skeleton` and `bpf_object_subskeleton` reference arrays
+//! of map, program, and variable skeletons, each of which in turn points
+//! to the locations libbpf fills in. The builders in here take care of
+//! allocating these arrays and of setting the various size members.
+//!
+//! Because the arrays are owned by Rust, the resulting skeletons must
+//! not be passed to `bpf_object__destroy_skeleton` or
+//! `bpf_object__destroy_subskeleton`, both of which `free` them. Use
+//! `bpf_object__detach_skeleton` and `bpf_object__close` instead.
+
+use alloc::vec::Vec;
+use core::ffi::c_char;
+use core::ffi::c_void;
+use core::mem::size_of;
+
+use crate::bindings::*;
+
+/// A builder for [`ObjectSkeleton`].
+#[derive(Debug)]
+pub struct ObjectSkeletonBuilder {
+    skel: bpf_object_skeleton,
+    maps: Vec<bpf_map_skeleton>,
+    progs: Vec<bpf_prog_skeleton>,
+}
+
+impl ObjectSkeletonBuilder {
+    /// Start building a skeleton for the object `name`, with the ELF
+    /// object file contents at `data`. libbpf stores the opened object
+    /// in `obj`.
+    pub fn new(
+        name: *const c_char,
+        data: *const c_void,
+        data_sz: usize,
+        obj: *mut *mut bpf_object,
+    ) -> Self {
+        let skel = bpf_object_skeleton {
+            sz: size_of::<bpf_object_skeleton>() as _,
+            name,
+            data,
+            data_sz: data_sz as _,
+            obj,
+            ..Default::default()
+        };
+
+        Self {
+            skel,
+            maps: Vec::new(),
+            progs: Vec::new(),
+        }
+    }
+
+    /// Add a map. `mmaped` may be null for maps that are not
+    /// memory mapped and `link` may be null for maps that are not
+    /// struct_ops maps.
+    pub fn map(
+        &mut self,
+        name: *const c_char,
+        map: *mut *mut bpf_map,
+        mmaped: *mut *mut c_void,
+        link: *mut *mut bpf_link,
+    ) -> &mut Self {
+        self.maps.push(bpf_map_skeleton {
+            name,
+            map,
+            mmaped,
+            link,
+        });
+        self
+    }
+
+    /// Add a program. `link` may be null if the program is not meant
+    /// to be auto-attached.
+    pub fn prog(
+        &mut self,
+        name: *const c_char,
+        prog: *mut *mut bpf_program,
+        link: *mut *mut bpf_link,
+    ) -> &mut Self {
+        self.progs.push(bpf_prog_skeleton { name, prog, link });
+        self
+    }
+
+    /// Finish building the skeleton.
+    pub fn build(self) -> ObjectSkeleton {
+        let Self {
+            mut skel,
+            mut maps,
+            mut progs,
+        } = self;
+
+        skel.map_cnt = maps.len() as _;
+        skel.map_skel_sz = size_of::<bpf_map_skeleton>() as _;
+        skel.maps = maps.as_mut_ptr();
+        skel.prog_cnt = progs.len() as _;
+        skel.prog_skel_sz = size_of::<bpf_prog_skeleton>() as _;
+        skel.progs = progs.as_mut_ptr();
+
+        ObjectSkeleton {
+            skel,
+            _maps: maps,
+            _progs: progs,
+        }
+    }
+}
+
+/// A `bpf_object_skeleton` along with the arrays it references.
+#[derive(Debug)]
+pub struct ObjectSkeleton {
+    skel: bpf_object_skeleton,
+    // The arrays are only accessed through `skel`. Moving a `Vec` does
+    // not move its heap allocation, so the pointers stay valid.
+    _maps: Vec<bpf_map_skeleton>,
+    _progs: Vec<bpf_prog_skeleton>,
+}
+
+impl ObjectSkeleton {
+    /// Retrieve a pointer to the skeleton, suitable for passing to
+    /// `bpf_object__open_skeleton` and friends.
+    pub fn as_mut_ptr(&mut self) -> *mut bpf_object_skeleton {
+        &mut self.skel
+    }
+}
+
+/// A builder for [`ObjectSubskeleton`].
+#[derive(Debug)]
+pub struct ObjectSubskeletonBuilder {
+    skel: bpf_object_subskeleton,
+    maps: Vec<bpf_map_skeleton>,
+    progs: Vec<bpf_prog_skeleton>,
+    vars: Vec<bpf_var_skeleton>,
+}
+
+impl ObjectSubskeletonBuilder {
+    /// Start building a subskeleton for the already opened object
+    /// `obj`.
+    pub fn new(obj: *const bpf_object) -> Self {
+        let skel = bpf_object_subskeleton {
+            sz: size_of::<bpf_object_subskeleton>() as _,
+            obj,
+            ..Default::default()
+        };
+
+        Self {
+            skel,
+            maps: Vec::new(),
+            progs: Vec::new(),
+            vars: Vec::new(),
+        }
+    }
+
+    /// Add a map. `mmaped` may be null for maps that are not memory
+    /// mapped.
+    pub fn map(
+        &mut self,
+        name: *const c_char,
+        map: *mut *mut bpf_map,
+        mmaped: *mut *mut c_void,
+    ) -> &mut Self {
+        self.maps.push(bpf_map_skeleton {
+            name,
+            map,
+            mmaped,
+            link: core::ptr::null_mut(),
+        });
+        self
+    }
+
+    /// Add a program.
+    pub fn prog(&mut self, name: *const c_char, prog: *mut *mut bpf_program) -> &mut Self {
+        self.progs.push(bpf_prog_skeleton {
+            name,
+            prog,
+            link: core::ptr::null_mut(),
+        });
+        self
+    }
+
+    /// Add a global variable residing in `map`.
+    pub fn var(
+        &mut self,
+        name: *const c_char,
+        map: *mut *mut bpf_map,
+        addr: *mut *mut c_void,
+    ) -> &mut Self {
+        self.vars.push(bpf_var_skeleton { name, map, addr });
+        self
+    }
+
+    /// Finish building the subskeleton.
+    pub fn build(self) -> ObjectSubskeleton {
+        let Self {
+            mut skel,
+            mut maps,
+            mut progs,
+            mut vars,
+        } = self;
+
+        skel.map_cnt = maps.len() as _;
+        skel.map_skel_sz = size_of::<bpf_map_skeleton>() as _;
+        skel.maps = maps.as_mut_ptr();
+        skel.prog_cnt = progs.len() as _;
+        skel.prog_skel_sz = size_of::<bpf_prog_skeleton>() as _;
+        skel.progs = progs.as_mut_ptr();
+        skel.var_cnt = vars.len() as _;
+        skel.var_skel_sz = size_of::<bpf_var_skeleton>() as _;
+        skel.vars = vars.as_mut_ptr();
+
+        ObjectSubskeleton {
+            skel,
+            _maps: maps,
+            _progs: progs,
+            _vars: vars,
+        }
+    }
+}
+
+/// A `bpf_object_subskeleton` along with the arrays it references.
+#[derive(Debug)]
+pub struct ObjectSubskeleton {
+    skel: bpf_object_subskeleton,
+    _maps: Vec<bpf_map_skeleton>,
+    _progs: Vec<bpf_prog_skeleton>,
+    _vars: Vec<bpf_var_skeleton>,
+}
+
+impl ObjectSubskeleton {
+    /// Retrieve a pointer to the subskeleton, suitable for passing to
+    /// `bpf_object__open_subskeleton`.
+    pub fn as_mut_ptr(&mut self) -> *mut bpf_object_subskeleton {
+        &mut self.skel
+    }
+}
@@ -0,0 +1,185 @@
+// src/struct_ops.rs
+
+//! Layout information for `struct_ops` map values.
+//!
+//! The value of a `BPF_MAP_TYPE_STRUCT_OPS` map, as seen by libbpf, is
+//! an instance of the kernel struct being implemented, such as
+//! `tcp_congestion_ops` or `sched_ext_ops`. The kernel itself wraps that
+//! struct in `bpf_struct_ops_<name>`, whose `data` member holds it. The
+//! functions in here resolve both from kernel BTF, as returned by
+//! `btf__load_vmlinux_btf`, to help filling in values before attaching
+//! them with `bpf_map__attach_struct_ops`.
+//!
+//! Errors are reported as negative `errno` values.
+
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+use core::ffi::c_char;
+use core::ffi::c_int;
+use core::ffi::CStr;
+
+use crate::bindings::*;
+use crate::btf_kind::BtfKind;
+
+const ENOENT: c_int = 2;
+const EINVAL: c_int = 22;
+
+/// The prefix of the kernel's wrapper type for a struct_ops type.
+pub const STRUCT_OPS_VALUE_PREFIX: &str = "bpf_struct_ops_";
+
+/// The layout of a struct_ops type and of its kernel wrapper.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StructOpsLayout {
+    /// The BTF type ID of the struct_ops type.
+    pub type_id: u32,
+    /// The size of the struct_ops type.
+    pub size: usize,
+    /// The BTF type ID of the `bpf_struct_ops_<name>` wrapper.
+    pub value_type_id: u32,
+    /// The size of the wrapper, i.e., of the map value in the kernel.
+    pub value_size: usize,
+    /// The offset of the struct_ops type within the wrapper.
+    pub data_offset: usize,
+}
+
+/// A member of a struct_ops type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StructOpsMember {
+    /// The name of the member, owned by the BTF object.
+    pub name: *const c_char,
+    /// The BTF type ID of the member.
+    pub type_id: u32,
+    /// The offset of the member, in bytes.
+    pub offset: usize,
+    /// The size of the member, in bytes.
+    pub size: usize,
+    /// Whether the member is a function pointer, i.e., to be
+    /// implemented by a BPF program.
+    pub is_func_ptr: bool,
+}
+
+/// Retrieve the type `id` refers to, failing for unknown IDs.
+unsafe fn type_by_id<'btf>(btf: *const btf, id: u32) -> Result<&'btf btf_type, c_int> {
+    unsafe { btf__type_by_id(btf, id).as_ref() }.ok_or(-ENOENT)
+}
+
+/// Retrieve the members of the struct `t`.
+///
+/// # Safety
+/// `t` has to be part of a BTF object, where the members follow it.
+unsafe fn members(t: &btf_type) -> &[btf_member] {
+    // `BTF_INFO_VLEN`
+    let vlen = (t.info & 0xffff) as usize;
+    let first = (t as *const btf_type).wrapping_add(1).cast::<btf_member>();
+    unsafe { core::slice::from_raw_parts(first, vlen) }
+}
+
+/// Calculate the byte offset of member `m` of the struct `t`.
+fn member_offset(t: &btf_type, m: &btf_member) -> usize {
+    // With `kind_flag` set, the upper eight bits hold the size of a
+    // bitfield member (`BTF_MEMBER_BIT_OFFSET`).
+    let bits = if t.info >> 31 != 0 {
+        m.offset & 0xffffff
+    } else {
+        m.offset
+    };
+    (bits / 8) as usize
+}
+
+/// Retrieve the size of the type `id`.
+unsafe fn resolve_size(btf: *const btf, id: u32) -> Result<usize, c_int> {
+    let size = unsafe { btf__resolve_size(btf, id) };
+    if size < 0 {
+        Err(size as c_int)
+    } else {
+        Ok(size as usize)
+    }
+}
+
+/// Look up the struct_ops type `name` (e.g., `tcp_congestion_ops`) and
+/// its kernel wrapper in `btf`.
+///
+/// # Safety
+/// `btf` has to point to a valid BTF object.
+pub unsafe fn struct_ops_layout(btf: *const btf, name: &CStr) -> Result<StructOpsLayout, c_int> {
+    let type_id = unsafe { btf__find_by_name_kind(btf, name.as_ptr(), BTF_KIND_STRUCT) };
+    if type_id < 0 {
+        return Err(type_id);
+    }
+    let type_id = type_id as u32;
+
+    let mut value_name = Vec::with_capacity(STRUCT_OPS_VALUE_PREFIX.len() + name.to_bytes().len());
+    value_name.extend_from_slice(STRUCT_OPS_VALUE_PREFIX.as_bytes());
+    value_name.extend_from_slice(name.to_bytes());
+    // `name` cannot contain a NUL byte.
+    let value_name = CString::new(value_name).unwrap();
+    let value_type_id =
+        unsafe { btf__find_by_name_kind(btf, value_name.as_ptr(), BTF_KIND_STRUCT) };
+    if value_type_id < 0 {
+        return Err(value_type_id);
+    }
+    let value_type_id = value_type_id as u32;
+
+    let value_type = unsafe { type_by_id(btf, value_type_id)? };
+    let data = unsafe { members(value_type) }
+        .iter()
+        .find(|m| {
+            let name = unsafe { btf__name_by_offset(btf, m.name_off) };
+            !name.is_null() && unsafe { CStr::from_ptr(name) }.to_bytes() == b"data"
+        })
+        .ok_or(-EINVAL)?;
+    if unsafe { btf__resolve_type(btf, data.type_) } != type_id as c_int {
+        return Err(-EINVAL);
+    }
+
+    Ok(StructOpsLayout {
+        type_id,
+        size: unsafe { resolve_size(btf, type_id)? },
+        value_type_id,
+        value_size: unsafe { resolve_size(btf, value_type_id)? },
+        data_offset: member_offset(value_type, data),
+    })
+}
+
+/// Retrieve the members of the struct_ops type `type_id`, in
+/// declaration order.
+///
+/// # Safety
+/// `btf` has to point to a valid BTF object.
+pub unsafe fn struct_ops_members(
+    btf: *const btf,
+    type_id: u32,
+) -> Result<Vec<StructOpsMember>, c_int> {
+    let t = unsafe { type_by_id(btf, type_id)? };
+    if t.kind() != Ok(BtfKind::Struct) {
+        return Err(-EINVAL);
+    }
+
+    unsafe { members(t) }
+        .iter()
+        .map(|m| {
+            let resolved = unsafe { btf__resolve_type(btf, m.type_) };
+            if resolved < 0 {
+                return Err(resolved);
+            }
+            let resolved = unsafe { type_by_id(btf, resolved as u32)? };
+            let is_func_ptr = resolved.kind() == Ok(BtfKind::Ptr) && {
+                // SAFETY: Pointers store the pointee in `type`.
+                let pointee = unsafe { resolved.__bindgen_anon_1.type_ };
+                let pointee = unsafe { btf__resolve_type(btf, pointee) };
+                pointee >= 0
+                    && unsafe { type_by_id(btf, pointee as u32) }
+                        .map(|p| p.kind() == Ok(BtfKind::FuncProto))
+                        .unwrap_or(false)
+            };
+
+            Ok(StructOpsMember {
+                name: unsafe { btf__name_by_offset(btf, m.name_off) },
+                type_id: m.type_,
+                offset: member_offset(t, m),
+                size: unsafe { resolve_size(btf, m.type_)? },
+                is_func_ptr,
+            })
+        })
+        .collect()
+}
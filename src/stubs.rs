@@ -0,0 +1,100 @@
+// src/stubs.rs
+
+//! Stub implementations of the libbpf API.
+//!
+//! With the `stubs` feature enabled, the crate does not link against
+//! libbpf but defines all bound functions itself, which makes it
+//! possible to unit test code built on top of the bindings without
+//! libbpf or the privileges to load anything into the kernel.
+//!
+//! The stubs record each call, see [`calls`], and return what libbpf
+//! would report for a failure with the errno configured through [`fail`]
+//! or [`fail_all`]: functions returning a signed integer return the
+//! negated errno, whereas functions returning a pointer return null and
+//! set `errno`. Without an error configured, all functions return zero,
+//! `false`, or null.
+//!
+//! The stubs are defined as weak symbols, so a test can replace any of
+//! them with its own `#[no_mangle]` definition.
+//!
+//! The recorded calls and configured errors are kept per thread, so tests
+//! running concurrently don't interfere with each other.
+
+// The recorded state is thread-local.
+extern crate std;
+
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::ffi::c_char;
+use core::ffi::c_int;
+use core::ffi::CStr;
+
+struct State {
+    calls: Vec<&'static str>,
+    errors: Vec<(&'static str, c_int)>,
+    default_error: c_int,
+}
+
+std::thread_local! {
+    static STATE: RefCell<State> = const {
+        RefCell::new(State {
+            calls: Vec::new(),
+            errors: Vec::new(),
+            default_error: 0,
+        })
+    };
+}
+
+fn with_state<F, R>(f: F) -> R
+where
+    F: FnOnce(&mut State) -> R,
+{
+    STATE.with(|state| f(&mut state.borrow_mut()))
+}
+
+/// Record a call to `function` and look up the errno it should fail
+/// with, or zero. Called by every stub.
+#[no_mangle]
+extern "C" fn libbpf_sys_stub_call(function: *const c_char) -> c_int {
+    // The stubs pass their name as a string literal.
+    let function = unsafe { CStr::from_ptr::<'static>(function) }
+        .to_str()
+        .expect("Stub name is not UTF-8");
+    with_state(|state| {
+        state.calls.push(function);
+        state
+            .errors
+            .iter()
+            .find(|(name, _)| *name == function)
+            .map(|(_, errno)| *errno)
+            .unwrap_or(state.default_error)
+    })
+}
+
+/// Retrieve the names of the functions called so far, in order.
+pub fn calls() -> Vec<&'static str> {
+    with_state(|state| state.calls.clone())
+}
+
+/// Make `function` fail with `errno`, or succeed if `errno` is zero.
+pub fn fail(function: &'static str, errno: c_int) {
+    with_state(|state| {
+        state.errors.retain(|(name, _)| *name != function);
+        state.errors.push((function, errno));
+    })
+}
+
+/// Make all functions not configured through [`fail`] fail with
+/// `errno`, or succeed if `errno` is zero.
+pub fn fail_all(errno: c_int) {
+    with_state(|state| state.default_error = errno)
+}
+
+/// Forget all recorded calls and configured errors.
+pub fn reset() {
+    with_state(|state| {
+        state.calls.clear();
+        state.errors.clear();
+        state.default_error = 0;
+    })
+}
@@ -0,0 +1,55 @@
+// src/subsys.rs
+
+//! The bindings grouped by subsystem.
+//!
+//! All items remain available at the crate root; the modules in here
+//! merely re-export them, grouped by the part of libbpf or the kernel
+//! interface they belong to. Grouping happens by name.
+
+/// Generic BPF system call, object, program, and map related items.
+pub mod bpf {
+    #[cfg(not(libbpf_sys_windows))]
+    pub use crate::attr::*;
+    pub use crate::opaque::bpf_link;
+    pub use crate::opaque::bpf_map;
+    pub use crate::opaque::bpf_object;
+    pub use crate::opaque::bpf_prog_linfo;
+    pub use crate::opaque::bpf_program;
+    include!(concat!(env!("OUT_DIR"), "/subsys_bpf.rs"));
+}
+
+/// BPF Type Format related items, including BTF dumping.
+pub mod btf {
+    pub use crate::opaque::btf;
+    #[cfg(not(feature = "no-btf-dump"))]
+    pub use crate::opaque::btf_dump;
+    pub use crate::opaque::btf_ext;
+    include!(concat!(env!("OUT_DIR"), "/subsys_btf.rs"));
+}
+
+/// Items for linking BPF object files.
+#[cfg(not(feature = "no-linker"))]
+pub mod linker {
+    pub use crate::opaque::bpf_linker;
+    include!(concat!(env!("OUT_DIR"), "/subsys_linker.rs"));
+}
+
+/// Perf event and perf buffer related items.
+#[cfg(not(feature = "no-perf"))]
+pub mod perf {
+    pub use crate::opaque::perf_buffer;
+    include!(concat!(env!("OUT_DIR"), "/subsys_perf.rs"));
+}
+
+/// BPF ring buffer and user ring buffer related items.
+pub mod ringbuf {
+    pub use crate::opaque::ring;
+    pub use crate::opaque::ring_buffer;
+    pub use crate::opaque::user_ring_buffer;
+    include!(concat!(env!("OUT_DIR"), "/subsys_ringbuf.rs"));
+}
+
+/// XDP related items.
+pub mod xdp {
+    include!(concat!(env!("OUT_DIR"), "/subsys_xdp.rs"));
+}
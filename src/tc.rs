@@ -0,0 +1,25 @@
+// src/tc.rs
+
+//! `const fn` counterparts of the TC handle macros.
+//!
+//! TC handles, such as `bpf_tc_hook::parent` and `bpf_tc_opts::handle`,
+//! consist of a major number in the upper and a minor number in the
+//! lower 16 bits.
+
+use crate::bindings::*;
+
+/// `TC_H_MAJ`: extract the major number of `handle`, in place.
+pub const fn TC_H_MAJ(handle: u32) -> u32 {
+    handle & TC_H_MAJ_MASK
+}
+
+/// `TC_H_MIN`: extract the minor number of `handle`.
+pub const fn TC_H_MIN(handle: u32) -> u32 {
+    handle & TC_H_MIN_MASK
+}
+
+/// `TC_H_MAKE`: combine the major number `maj`, in place, and the minor
+/// number `min` into a handle.
+pub const fn TC_H_MAKE(maj: u32, min: u32) -> u32 {
+    (maj & TC_H_MAJ_MASK) | (min & TC_H_MIN_MASK)
+}
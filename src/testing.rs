@@ -0,0 +1,212 @@
+// src/testing.rs
+
+//! Skipping tests the running system cannot support.
+//!
+//! Tests that load programs or create maps fail with `EPERM` when run
+//! without the necessary privileges, which is hard to tell apart from a
+//! genuine failure. The [`require_or_skip!`](crate::require_or_skip)
+//! macro checks a test's [`Requirement`]s against the [`Environment`]
+//! first and returns early, reporting why, if any of them is not met:
+//!
+//! ```no_run
+//! use libbpf_sys::require_or_skip;
+//! use libbpf_sys::testing::Requirement;
+//!
+//! #[test]
+//! fn xdp() {
+//!     require_or_skip!(Requirement::BpfPrivileges, Requirement::Kernel(5, 9));
+//!     // ...
+//! }
+//! ```
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+
+/// The capability to administer networking.
+pub const CAP_NET_ADMIN: u32 = 12;
+/// The catch-all administrative capability.
+pub const CAP_SYS_ADMIN: u32 = 21;
+/// The capability for perf events, since Linux 5.8.
+pub const CAP_PERFMON: u32 = 38;
+/// The capability for BPF operations, since Linux 5.8.
+pub const CAP_BPF: u32 = 39;
+
+/// Something a test needs from the running system.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Requirement {
+    /// The effective capability with the given number, e.g.,
+    /// [`CAP_NET_ADMIN`].
+    Capability(u32),
+    /// The privileges to load programs and create maps, i.e.,
+    /// `CAP_BPF` or `CAP_SYS_ADMIN`.
+    BpfPrivileges,
+    /// Unprivileged BPF being enabled, i.e., the
+    /// `kernel.unprivileged_bpf_disabled` sysctl being zero.
+    UnprivilegedBpf,
+    /// A kernel of at least the given major and minor version.
+    Kernel(u32, u32),
+}
+
+/// The properties of the running system relevant to [`Requirement`]s.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Environment {
+    /// The effective capabilities of the current process, as a bitmask.
+    pub capabilities: u64,
+    /// The value of the `kernel.unprivileged_bpf_disabled` sysctl, if
+    /// available.
+    pub unprivileged_bpf_disabled: Option<u32>,
+    /// The major, minor, and patch version of the running kernel, if
+    /// available.
+    pub kernel_version: Option<(u32, u32, u32)>,
+}
+
+impl Environment {
+    /// Inspect the running system.
+    pub fn detect() -> Self {
+        let capabilities = fs::read_to_string("/proc/self/status")
+            .ok()
+            .and_then(|status| parse_capabilities(&status))
+            .unwrap_or(0);
+        let unprivileged_bpf_disabled =
+            fs::read_to_string("/proc/sys/kernel/unprivileged_bpf_disabled")
+                .ok()
+                .and_then(|value| value.trim().parse().ok());
+        let kernel_version = fs::read_to_string("/proc/sys/kernel/osrelease")
+            .ok()
+            .and_then(|release| parse_kernel_version(&release));
+
+        Self {
+            capabilities,
+            unprivileged_bpf_disabled,
+            kernel_version,
+        }
+    }
+
+    /// Check whether the effective capability `cap` is present.
+    pub fn has_capability(&self, cap: u32) -> bool {
+        cap < 64 && self.capabilities & (1 << cap) != 0
+    }
+
+    /// Check whether `requirement` is met.
+    ///
+    /// Requirements that cannot be determined, e.g., because `/proc`
+    /// is not mounted, are considered not met.
+    pub fn check(&self, requirement: Requirement) -> Result<(), Skip> {
+        let met = match requirement {
+            Requirement::Capability(cap) => self.has_capability(cap),
+            Requirement::BpfPrivileges => {
+                self.has_capability(CAP_BPF) || self.has_capability(CAP_SYS_ADMIN)
+            }
+            Requirement::UnprivilegedBpf => self.unprivileged_bpf_disabled == Some(0),
+            Requirement::Kernel(major, minor) => match self.kernel_version {
+                Some((running_major, running_minor, _)) => {
+                    (running_major, running_minor) >= (major, minor)
+                }
+                None => false,
+            },
+        };
+
+        if met {
+            Ok(())
+        } else {
+            Err(Skip {
+                requirement,
+                env: *self,
+            })
+        }
+    }
+}
+
+/// The reason for skipping a test: a [`Requirement`] that is not met.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Skip {
+    /// The requirement not met.
+    pub requirement: Requirement,
+    /// The environment it was checked against.
+    pub env: Environment,
+}
+
+impl Display for Skip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.requirement {
+            Requirement::Capability(cap) => write!(f, "requires {}", capability_name(cap)),
+            Requirement::BpfPrivileges => f.write_str("requires CAP_BPF or CAP_SYS_ADMIN"),
+            Requirement::UnprivilegedBpf => match self.env.unprivileged_bpf_disabled {
+                Some(value) => write!(
+                    f,
+                    "requires unprivileged BPF (kernel.unprivileged_bpf_disabled is {})",
+                    value
+                ),
+                None => f.write_str(
+                    "requires unprivileged BPF (kernel.unprivileged_bpf_disabled unknown)",
+                ),
+            },
+            Requirement::Kernel(major, minor) => match self.env.kernel_version {
+                Some((running_major, running_minor, patch)) => write!(
+                    f,
+                    "requires Linux {}.{} (running {}.{}.{})",
+                    major, minor, running_major, running_minor, patch
+                ),
+                None => write!(
+                    f,
+                    "requires Linux {}.{} (running version unknown)",
+                    major, minor
+                ),
+            },
+        }
+    }
+}
+
+/// Check `requirements` against the running system, returning the
+/// first one not met.
+pub fn check(requirements: &[Requirement]) -> Result<(), Skip> {
+    let env = Environment::detect();
+    requirements
+        .iter()
+        .try_for_each(|requirement| env.check(*requirement))
+}
+
+/// Return from the enclosing test, reporting the reason, unless all of
+/// the given [`Requirement`](crate::testing::Requirement)s are met.
+#[macro_export]
+macro_rules! require_or_skip {
+    ($($requirement:expr),+ $(,)?) => {
+        if let Err(skip) = $crate::testing::check(&[$($requirement),+]) {
+            ::std::eprintln!("skipping {}: {}", ::std::module_path!(), skip);
+            return;
+        }
+    };
+}
+
+fn capability_name(cap: u32) -> String {
+    match cap {
+        CAP_NET_ADMIN => "CAP_NET_ADMIN".to_string(),
+        CAP_SYS_ADMIN => "CAP_SYS_ADMIN".to_string(),
+        CAP_PERFMON => "CAP_PERFMON".to_string(),
+        CAP_BPF => "CAP_BPF".to_string(),
+        _ => format!("capability {}", cap),
+    }
+}
+
+/// Extract the effective capabilities from the contents of
+/// `/proc/<pid>/status`.
+fn parse_capabilities(status: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("CapEff:"))
+        .and_then(|caps| u64::from_str_radix(caps.trim(), 16).ok())
+}
+
+/// Extract the version from a kernel release such as `6.1.0-13-amd64`.
+fn parse_kernel_version(release: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = release.trim().splitn(3, '.').map(|part| {
+        let digits = part.len() - part.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        part[..digits].parse::<u32>().ok()
+    });
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next().flatten().unwrap_or(0);
+    Some((major, minor, patch))
+}
@@ -0,0 +1,102 @@
+// src/verifier_log.rs
+
+//! Loading programs and BTF while capturing the verifier log.
+//!
+//! The kernel fails a load with `ENOSPC` if the log does not fit into
+//! the provided buffer. The functions in here take care of providing a
+//! buffer and of growing it until the log fits. The `log_buf` and
+//! `log_size` members of the passed in opts are ignored and the opts
+//! themselves are left untouched.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ffi::c_char;
+use core::ffi::c_int;
+use core::ffi::c_void;
+
+use crate::bindings::*;
+use crate::opts::read_opts;
+
+const ENOSPC: c_int = 28;
+
+/// The size of the log buffer for the first attempt.
+const INITIAL_LOG_SIZE: u32 = 64 * 1024;
+/// The largest log buffer the kernel accepts.
+const MAX_LOG_SIZE: u32 = u32::MAX >> 2;
+
+/// Run `load` with a growing log buffer until the log fits.
+///
+/// `load` returns the result of the load along with the log size the
+/// kernel reported as necessary, which kernels before 6.4 don't.
+fn load_with_log<F>(mut load: F) -> (c_int, Vec<u8>)
+where
+    F: FnMut(&mut [u8]) -> (c_int, u32),
+{
+    let mut log = vec![0; INITIAL_LOG_SIZE as usize];
+    loop {
+        let (ret, true_size) = load(&mut log);
+        let size = log.len() as u32;
+        if ret != -ENOSPC || size >= MAX_LOG_SIZE {
+            let len = log.iter().position(|b| *b == 0).unwrap_or(log.len());
+            log.truncate(len);
+            return (ret, log);
+        }
+
+        let size = if true_size > size {
+            true_size
+        } else {
+            size.saturating_mul(2)
+        };
+        log.resize(size.min(MAX_LOG_SIZE) as usize, 0);
+    }
+}
+
+/// Call `bpf_prog_load`, returning its result along with the verifier
+/// log.
+///
+/// With a `log_level` of zero in `opts`, which may be null, libbpf only
+/// retrieves the log if loading fails.
+///
+/// # Safety
+/// The same requirements as for `bpf_prog_load` apply.
+pub unsafe fn bpf_prog_load_with_log(
+    prog_type: bpf_prog_type,
+    prog_name: *const c_char,
+    license: *const c_char,
+    insns: *const bpf_insn,
+    insn_cnt: size_t,
+    opts: *const bpf_prog_load_opts,
+) -> (c_int, Vec<u8>) {
+    let mut opts = unsafe { read_opts(opts) };
+
+    load_with_log(|log| {
+        opts.log_buf = log.as_mut_ptr().cast();
+        opts.log_size = log.len() as u32;
+        let ret =
+            unsafe { bpf_prog_load(prog_type, prog_name, license, insns, insn_cnt, &mut opts) };
+        (ret, opts.log_true_size)
+    })
+}
+
+/// Call `bpf_btf_load`, returning its result along with the verifier
+/// log.
+///
+/// With a `log_level` of zero in `opts`, which may be null, libbpf only
+/// retrieves the log if loading fails.
+///
+/// # Safety
+/// The same requirements as for `bpf_btf_load` apply.
+pub unsafe fn bpf_btf_load_with_log(
+    btf_data: *const c_void,
+    btf_size: size_t,
+    opts: *const bpf_btf_load_opts,
+) -> (c_int, Vec<u8>) {
+    let mut opts = unsafe { read_opts(opts) };
+
+    load_with_log(|log| {
+        opts.log_buf = log.as_mut_ptr().cast();
+        opts.log_size = log.len() as u32;
+        let ret = unsafe { bpf_btf_load(btf_data, btf_size, &mut opts) };
+        (ret, opts.log_true_size)
+    })
+}
@@ -0,0 +1,174 @@
+// src/vmlinux_btf.rs
+
+//! Locating the BTF of the running kernel.
+//!
+//! CO-RE relocations are resolved against the kernel's BTF, which
+//! `btf__load_vmlinux_btf` and the object loading functions look up in
+//! the locations listed in [`VMLINUX_BTF_LOCATIONS`]. On kernels built
+//! without `CONFIG_DEBUG_INFO_BTF`, none of them exists, and loading
+//! fails deep inside libbpf. [`vmlinux_btf`] performs the same lookup
+//! upfront, to fail early with a clear message:
+//!
+//! ```no_run
+//! match libbpf_sys::vmlinux_btf::vmlinux_btf() {
+//!     Ok(path) => println!("using kernel BTF from {}", path.display()),
+//!     Err(err) => eprintln!("{err}"),
+//! }
+//! ```
+//!
+//! Tools running on such kernels can ship the BTF themselves, e.g.,
+//! from [BTFHub](https://github.com/aquasecurity/btfhub), and point
+//! libbpf to it with the `btf_custom_path` member of
+//! `bpf_object_open_opts`. [`btf_custom_path`] tells whether that is
+//! necessary, and [`with_btf_custom_path`] sets it for the duration of
+//! opening an object.
+
+use std::ffi::CStr;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs::File;
+use std::io::Read as _;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::bindings::*;
+use crate::opts::read_opts;
+
+/// The path the kernel exposes its BTF at, in raw form.
+pub const VMLINUX_BTF_PATH: &str = "/sys/kernel/btf/vmlinux";
+
+/// The locations libbpf searches for the kernel's BTF, in order, with
+/// `{release}` standing for the kernel release. All but
+/// [`VMLINUX_BTF_PATH`] are ELF images with a `.BTF` section.
+pub const VMLINUX_BTF_LOCATIONS: [&str; 8] = [
+    VMLINUX_BTF_PATH,
+    "/boot/vmlinux-{release}",
+    "/lib/modules/{release}/vmlinux-{release}",
+    "/lib/modules/{release}/build/vmlinux",
+    "/usr/lib/modules/{release}/kernel/vmlinux",
+    "/usr/lib/debug/boot/vmlinux-{release}",
+    "/usr/lib/debug/boot/vmlinux-{release}.debug",
+    "/usr/lib/debug/lib/modules/{release}/vmlinux",
+];
+
+/// The error returned when no BTF of the running kernel was found.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MissingVmlinuxBtf {
+    /// The release of the running kernel, if available.
+    pub release: Option<String>,
+    /// The locations searched.
+    pub searched: Vec<PathBuf>,
+}
+
+impl Display for MissingVmlinuxBtf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.release {
+            Some(release) => write!(f, "no BTF found for kernel {}", release)?,
+            None => f.write_str("no BTF found for the running kernel")?,
+        }
+        f.write_str(" (searched")?;
+        for (i, path) in self.searched.iter().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}", sep, path.display())?;
+        }
+        f.write_str("); CO-RE requires a kernel built with CONFIG_DEBUG_INFO_BTF")
+    }
+}
+
+impl std::error::Error for MissingVmlinuxBtf {}
+
+/// Check whether the file at `path` starts with `magic`.
+fn starts_with(path: &Path, magic: &[u8]) -> bool {
+    let mut buf = [0; 4];
+    let buf = &mut buf[..magic.len()];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(buf))
+        .is_ok()
+        && buf == magic
+}
+
+/// Locate the BTF of the running kernel, the way libbpf does.
+///
+/// The raw BTF in [`VMLINUX_BTF_PATH`] is checked for the BTF magic and
+/// the other locations for being ELF images; whether the latter contain
+/// a `.BTF` section is left to libbpf to find out.
+pub fn vmlinux_btf() -> Result<PathBuf, MissingVmlinuxBtf> {
+    let release = std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .ok()
+        .map(|release| release.trim().to_string());
+
+    let mut searched = Vec::new();
+    for location in VMLINUX_BTF_LOCATIONS {
+        let path = if location == VMLINUX_BTF_PATH {
+            PathBuf::from(location)
+        } else {
+            match &release {
+                Some(release) => PathBuf::from(location.replace("{release}", release)),
+                None => continue,
+            }
+        };
+        let found = if location == VMLINUX_BTF_PATH {
+            starts_with(&path, &(BTF_MAGIC as u16).to_ne_bytes())
+        } else {
+            starts_with(&path, b"\x7fELF")
+        };
+        if found {
+            return Ok(path);
+        }
+        searched.push(path);
+    }
+
+    Err(MissingVmlinuxBtf { release, searched })
+}
+
+/// Determine the BTF to point libbpf to with `btf_custom_path`: none if
+/// the running kernel provides its own, and `fallback` otherwise.
+pub fn btf_custom_path(fallback: &Path) -> Option<&Path> {
+    match vmlinux_btf() {
+        Ok(_) => None,
+        Err(_) => Some(fallback),
+    }
+}
+
+/// Call `open` with a copy of `opts`, which may be null, that has
+/// `btf_custom_path` set to `btf`, if any.
+///
+/// Like libbpf, only the first `sz` bytes of `opts` are read. The copy
+/// only lives for the duration of `open`, which is long enough, as
+/// libbpf duplicates the path while opening the object, e.g., with
+/// `bpf_object__open_file`, and reads the BTF from it when loading:
+///
+/// ```no_run
+/// use std::ffi::CStr;
+/// use std::ptr;
+///
+/// use libbpf_sys::vmlinux_btf::with_btf_custom_path;
+///
+/// let path = CStr::from_bytes_with_nul(b"prog.bpf.o\0").unwrap();
+/// let btf = CStr::from_bytes_with_nul(b"/usr/share/prog/vmlinux.btf\0").unwrap();
+/// let obj = unsafe {
+///     with_btf_custom_path(ptr::null(), Some(btf), |opts| {
+///         libbpf_sys::bpf_object__open_file(path.as_ptr(), opts)
+///     })
+/// };
+/// ```
+///
+/// # Safety
+/// `opts` has to be null or point to a `bpf_object_open_opts` whose
+/// first `sz` bytes are valid.
+pub unsafe fn with_btf_custom_path<F, R>(
+    opts: *const bpf_object_open_opts,
+    btf: Option<&CStr>,
+    open: F,
+) -> R
+where
+    F: FnOnce(*const bpf_object_open_opts) -> R,
+{
+    // SAFETY: The caller guarantees that non-null `opts` are valid.
+    let mut opts = unsafe { read_opts(opts) };
+    if let Some(btf) = btf {
+        opts.btf_custom_path = btf.as_ptr();
+    }
+    open(&opts)
+}
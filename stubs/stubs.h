@@ -0,0 +1,63 @@
+// stubs/stubs.h
+
+/*
+ * Building blocks of the stub implementations of the libbpf API, for the
+ * `stubs` feature. The build script instantiates them for all bound
+ * functions. The stubs are weak, so that a test can replace any of them
+ * with its own definition.
+ */
+
+#ifndef LIBBPF_SYS_STUBS_H
+#define LIBBPF_SYS_STUBS_H
+
+#include <errno.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <sys/types.h>
+
+#if defined(__GNUC__) || defined(__clang__)
+#define STUB __attribute__((weak))
+#else
+#define STUB
+#endif
+
+/*
+ * Record a call to the function `name` and return the errno it should
+ * fail with, or zero. Defined by `src/stubs.rs`.
+ */
+int libbpf_sys_stub_call(const char *name);
+
+/* Functions returning a signed integer return the negated errno. */
+#define STUB_SIGNED(type, name, params)                      \
+	STUB type name params                                \
+	{                                                    \
+		return (type)-libbpf_sys_stub_call(#name);   \
+	}
+
+/* Functions returning an unsigned integer or bool return zero. */
+#define STUB_UNSIGNED(type, name, params)                    \
+	STUB type name params                                \
+	{                                                    \
+		libbpf_sys_stub_call(#name);                 \
+		return (type)0;                              \
+	}
+
+/* Functions returning a pointer return NULL and set errno. */
+#define STUB_POINTER(name, params)                           \
+	STUB void *name params                               \
+	{                                                    \
+		int err = libbpf_sys_stub_call(#name);       \
+                                                             \
+		if (err)                                     \
+			errno = err;                         \
+		return NULL;                                 \
+	}
+
+#define STUB_VOID(name, params)                              \
+	STUB void name params                                \
+	{                                                    \
+		libbpf_sys_stub_call(#name);                 \
+	}
+
+#endif /* LIBBPF_SYS_STUBS_H */
@@ -0,0 +1,50 @@
+// tests/bindings.rs
+
+//! Compares the bindings generated with the `bindgen` feature to the
+//! ones shipped in `src/bindings.rs`.
+//!
+//! The shipped bindings get regenerated with `bindgen-source`, so any
+//! difference means that they are out of date, or that generating them
+//! depends on the environment, e.g., the version of libclang. Instead of
+//! a line diff, the report lists the items, i.e., structs, constants,
+//! functions, and so on, that were added, removed, or changed.
+
+use std::fs;
+
+#[path = "bindings/diff.rs"]
+mod diff;
+
+#[test]
+fn generated_bindings_match_shipped() {
+    // Only bindings generated from the vendored headers, without any
+    // trimming, and for the target the shipped ones are for, compare.
+    if !cfg!(feature = "bindgen")
+        || cfg!(any(
+            feature = "bindgen-source",
+            feature = "bindgen-system-headers",
+            feature = "bindgen-builtins",
+            feature = "no-perf",
+            feature = "no-btf-dump",
+            feature = "no-linker",
+            feature = "msrv-bindings",
+            feature = "no-explicit-padding",
+            feature = "libc",
+            feature = "btf-only",
+            feature = "ebpf-for-windows",
+            not(target_arch = "x86_64"),
+        ))
+    {
+        eprintln!("bindings not generated for comparison; skipping");
+        return;
+    }
+
+    let shipped = include_str!("../src/bindings.rs");
+    let generated = fs::read_to_string(concat!(env!("OUT_DIR"), "/bindings.rs")).unwrap();
+    if let Some(report) = diff::report(shipped, &generated) {
+        panic!(
+            "generated bindings differ from src/bindings.rs; regenerate them with \
+             `cargo check --features bindgen-source` if intended:\n{}",
+            report
+        );
+    }
+}
@@ -0,0 +1,107 @@
+// tests/bindings/diff.rs
+
+//! Comparing bindings item by item, i.e., by structs, constants,
+//! functions, and so on, instead of line by line. Shared by the
+//! `bindings` test and the `regenerate-bindings` tool.
+
+use std::collections::BTreeMap;
+
+/// The most differences to report in full.
+const MAX_REPORTED: usize = 50;
+
+/// Split `bindings` into their top level items, by a key made up of
+/// the kind of the item and its name, e.g., `pub struct bpf_map_info`.
+fn items(bindings: &str) -> BTreeMap<String, String> {
+    let mut items = BTreeMap::new();
+    let mut item = String::new();
+    let mut key = None;
+    let mut depth = 0usize;
+    for line in bindings.lines() {
+        if item.is_empty() && (line.is_empty() || line.starts_with("/*")) {
+            continue;
+        }
+        item.push_str(line);
+        item.push('\n');
+
+        let trimmed = line.trim();
+        // Functions and statics each come in their own extern block,
+        // which is named after them.
+        if key.is_none()
+            && !trimmed.starts_with("#[")
+            && !trimmed.starts_with("unsafe extern")
+            && !trimmed.starts_with("extern")
+        {
+            let end = ["(", ": ", " = ", " {", ";"]
+                .iter()
+                .filter_map(|delimiter| trimmed.find(delimiter))
+                .min()
+                .unwrap_or(trimmed.len());
+            key = Some(trimmed[..end].to_string());
+        }
+
+        depth += line.matches('{').count();
+        depth -= line.matches('}').count();
+        if depth == 0 && (trimmed.ends_with(';') || trimmed.ends_with('}')) {
+            let key = key.take().unwrap_or_default();
+            // Keep repeated keys, e.g., of multiple `impl` blocks, apart.
+            let mut unique = key.clone();
+            let mut count = 1;
+            while items.contains_key(&unique) {
+                count += 1;
+                unique = format!("{} #{}", key, count);
+            }
+            items.insert(unique, std::mem::take(&mut item));
+        }
+    }
+    items
+}
+
+/// Describe the differences of `generated` over `shipped`, item by
+/// item.
+fn differences(shipped: &str, generated: &str) -> Vec<String> {
+    let shipped = items(shipped);
+    let generated = items(generated);
+    let mut differences = Vec::new();
+    for (key, item) in &shipped {
+        match generated.get(key) {
+            Some(other) if other == item => (),
+            Some(other) => differences.push(format!(
+                "changed `{}`:\n--- shipped\n{}+++ generated\n{}",
+                key, item, other
+            )),
+            None => differences.push(format!("removed `{}`:\n{}", key, item)),
+        }
+    }
+    for (key, item) in &generated {
+        if !shipped.contains_key(key) {
+            differences.push(format!("added `{}`:\n{}", key, item));
+        }
+    }
+    differences
+}
+
+/// Report the differences of `generated` over `shipped`, item by item,
+/// or `None` if there are none.
+pub fn report(shipped: &str, generated: &str) -> Option<String> {
+    if generated == shipped {
+        return None;
+    }
+
+    let differences = differences(shipped, generated);
+    let mut report = differences
+        .iter()
+        .take(MAX_REPORTED)
+        .cloned()
+        .collect::<Vec<_>>();
+    if differences.len() > MAX_REPORTED {
+        report.push(format!(
+            "... and {} more differences",
+            differences.len() - MAX_REPORTED
+        ));
+    }
+    // Differing in formatting only leaves no differing items.
+    if report.is_empty() {
+        report.push("whitespace or order of items".to_string());
+    }
+    Some(report.join("\n"))
+}
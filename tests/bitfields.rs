@@ -0,0 +1,135 @@
+// tests/bitfields.rs
+
+//! Checks for the generated bitfield accessors.
+//!
+//! The layout of each bitfield is derived from its accessors: setting a
+//! field to its maximum value reveals the bits backing it in the raw
+//! storage. These have to be contiguous, and the fields have to follow
+//! each other in declaration order, from the least significant bit on,
+//! as with the little endian bitfield layout used by GCC and Clang.
+//! Setting and clearing a field must round-trip through its getter and
+//! leave all other fields alone.
+
+#![cfg(target_endian = "little")]
+
+use libbpf_sys::*;
+
+/// Retrieve the raw bytes backing a bitfield unit.
+fn storage<const N: usize>(unit: &__BindgenBitfieldUnit<[u8; N]>) -> [u8; N] {
+    // SAFETY: `__BindgenBitfieldUnit` is `repr(C)` and consists only of
+    //         its storage.
+    unsafe { *(unit as *const _ as *const [u8; N]) }
+}
+
+macro_rules! check_bitfields {
+    ($ty:ty, $storage:ty, [$(($get:ident, $set:ident)),* $(,)?]) => {{
+        let raw = |value: &$ty| <$storage>::from_ne_bytes(storage(&value._bitfield_1));
+        let mut all = <$ty>::default();
+        let mut next_bit = 0;
+        $(
+            let mut value = <$ty>::default();
+            value.$set(!0 as _);
+            let mask = raw(&value);
+            let name = stringify!($get);
+            assert_ne!(mask, 0, "`{}` has no storage", name);
+            let shift = mask.trailing_zeros();
+            let width = mask.count_ones();
+            assert_eq!(
+                <$storage>::BITS - mask.leading_zeros() - shift,
+                width,
+                "storage of `{}` is not contiguous",
+                name
+            );
+            assert_eq!(shift, next_bit, "`{}` is out of order", name);
+            next_bit = shift + width;
+            assert_eq!(value.$get() as u64, (mask >> shift) as u64);
+
+            value.$set(0);
+            assert_eq!(value.$get(), 0);
+            assert_eq!(raw(&value), 0);
+
+            all.$set(!0 as _);
+        )*
+
+        // The fields don't overlap, and clearing any one of them leaves
+        // the others set.
+        let set = raw(&all);
+        assert_eq!(set.count_ones(), next_bit, "bitfields overlap");
+        $(
+            let mut value = all;
+            value.$set(0);
+            let mut only = <$ty>::default();
+            only.$set(!0 as _);
+            assert_eq!(raw(&value), set & !raw(&only), "clearing `{}`", stringify!($get));
+        )*
+    }};
+}
+
+#[cfg(not(feature = "no-perf"))]
+#[test]
+fn perf_event_attr_bitfields() {
+    check_bitfields!(
+        perf_event_attr,
+        u64,
+        [
+            (disabled, set_disabled),
+            (inherit, set_inherit),
+            (pinned, set_pinned),
+            (exclusive, set_exclusive),
+            (exclude_user, set_exclude_user),
+            (exclude_kernel, set_exclude_kernel),
+            (exclude_hv, set_exclude_hv),
+            (exclude_idle, set_exclude_idle),
+            (mmap, set_mmap),
+            (comm, set_comm),
+            (freq, set_freq),
+            (inherit_stat, set_inherit_stat),
+            (enable_on_exec, set_enable_on_exec),
+            (task, set_task),
+            (watermark, set_watermark),
+            (precise_ip, set_precise_ip),
+            (mmap_data, set_mmap_data),
+            (sample_id_all, set_sample_id_all),
+            (exclude_host, set_exclude_host),
+            (exclude_guest, set_exclude_guest),
+            (exclude_callchain_kernel, set_exclude_callchain_kernel),
+            (exclude_callchain_user, set_exclude_callchain_user),
+            (mmap2, set_mmap2),
+            (comm_exec, set_comm_exec),
+            (use_clockid, set_use_clockid),
+            (context_switch, set_context_switch),
+            (write_backward, set_write_backward),
+            (namespaces, set_namespaces),
+            (ksymbol, set_ksymbol),
+            (bpf_event, set_bpf_event),
+            (aux_output, set_aux_output),
+            (cgroup, set_cgroup),
+            (text_poke, set_text_poke),
+            (build_id, set_build_id),
+            (inherit_thread, set_inherit_thread),
+            (remove_on_exec, set_remove_on_exec),
+            (sigtrap, set_sigtrap),
+        ]
+    );
+}
+
+#[test]
+fn bpf_prog_info_bitfields() {
+    check_bitfields!(bpf_prog_info, u32, [(gpl_compatible, set_gpl_compatible)]);
+}
+
+#[test]
+fn bpf_insn_bitfields() {
+    check_bitfields!(
+        bpf_insn,
+        u8,
+        [(dst_reg, set_dst_reg), (src_reg, set_src_reg)]
+    );
+
+    let insn = bpf_insn {
+        _bitfield_1: bpf_insn::new_bitfield_1(1, 10),
+        ..Default::default()
+    };
+    assert_eq!(insn.dst_reg(), 1);
+    assert_eq!(insn.src_reg(), 10);
+}
@@ -0,0 +1,66 @@
+// tests/btf_only.rs
+
+//! Exercises the BTF code the `btf-only` feature builds, which works
+//! without a kernel and, hence, on any operating system.
+
+use std::ffi::CStr;
+use std::io;
+use std::ptr;
+use std::slice;
+
+use libbpf_sys::*;
+
+/// `LIBBPF_ERRNO__LIBELF`, for failures of libelf.
+const LIBBPF_ERRNO__LIBELF: i32 = 4000;
+
+#[test]
+fn version() {
+    let version = unsafe { CStr::from_ptr(libbpf_version_string()) };
+    let expected = format!("v{}.{}", unsafe { libbpf_major_version() }, unsafe {
+        libbpf_minor_version()
+    });
+    assert_eq!(version.to_str().unwrap(), expected);
+}
+
+#[test]
+fn build_and_parse() {
+    let btf = unsafe { btf__new_empty() };
+    assert!(!btf.is_null());
+
+    let int = unsafe { btf__add_int(btf, b"int\0".as_ptr().cast(), 4, BTF_INT_SIGNED as _) };
+    assert_eq!(int, 1);
+    let point = unsafe { btf__add_struct(btf, b"point\0".as_ptr().cast(), 8) };
+    assert_eq!(point, 2);
+    assert_eq!(
+        unsafe { btf__add_field(btf, b"x\0".as_ptr().cast(), int, 0, 0) },
+        0
+    );
+    assert_eq!(
+        unsafe { btf__add_field(btf, b"y\0".as_ptr().cast(), int, 32, 0) },
+        0
+    );
+
+    let mut size = 0;
+    let data = unsafe { btf__raw_data(btf, &mut size) };
+    assert!(!data.is_null());
+    let raw = unsafe { slice::from_raw_parts(data.cast::<u8>(), size as usize) }.to_vec();
+    unsafe { btf__free(btf) };
+
+    let btf = unsafe { btf__new(raw.as_ptr().cast(), raw.len() as _) };
+    assert!(!btf.is_null());
+    assert_eq!(unsafe { btf__type_cnt(btf) }, 3);
+    let id = unsafe { btf__find_by_name_kind(btf, b"point\0".as_ptr().cast(), BTF_KIND_STRUCT) };
+    assert_eq!(id, point);
+    let size = unsafe { btf__resolve_size(btf, id as _) };
+    assert_eq!(size, 8);
+    unsafe { btf__free(btf) };
+}
+
+#[test]
+fn elf_unsupported() {
+    // Any path does, as libelf refuses to initialize in the first place.
+    let btf = unsafe { btf__parse_elf(b"/nonexistent\0".as_ptr().cast(), ptr::null_mut()) };
+    assert!(btf.is_null());
+    let errno = io::Error::last_os_error().raw_os_error().unwrap();
+    assert_eq!(errno, LIBBPF_ERRNO__LIBELF);
+}
@@ -0,0 +1,145 @@
+// tests/concurrent_build.rs
+
+//! Builds several crates depending on this one at the same time, all
+//! sharing this source checkout.
+//!
+//! The vendored zlib and elfutils get copied into each build's
+//! `OUT_DIR` and built there, as crates like `libbpf-rs` and
+//! `libbpf-cargo` commonly end up building `libbpf-sys` in parallel.
+//! Each crate gets its own target directory here, so that all builds
+//! read the shared sources at the same time. The resulting binaries then have to run and
+//! agree with each other, which catches archives clobbered by a
+//! concurrent build.
+//!
+//! The test takes several minutes and is hence ignored by default:
+//!
+//! ```text
+//! $ cargo test --test concurrent_build -- --ignored
+//! ```
+//!
+//! `LIBBPF_SYS_STRESS_BUILDS` sets the number of builds, four by
+//! default.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+
+/// The `main` of the crates built, printing the libbpf version after
+/// exercising libbpf and libelf.
+const MAIN: &str = r#"use std::env;
+use std::ffi::CStr;
+use std::ffi::CString;
+
+use libbpf_sys::*;
+
+fn main() {
+    // libbpf reads the executable using libelf and rejects it, as it is
+    // no BPF object.
+    let exe = env::current_exe().unwrap();
+    let path = CString::new(exe.to_str().unwrap()).unwrap();
+    let obj = unsafe { bpf_object__open(path.as_ptr()) };
+    assert!(obj.is_null());
+
+    let version = unsafe { CStr::from_ptr(libbpf_version_string()) };
+    println!("{}", version.to_str().unwrap());
+}
+"#;
+
+/// Create the crate with index `index` in `dir`, depending on the
+/// vendored libraries of this checkout.
+fn create_crate(dir: &Path, index: usize) -> PathBuf {
+    let name = format!("consumer-{}", index);
+    let dir = dir.join(&name);
+    fs::create_dir_all(dir.join("src")).unwrap();
+
+    let manifest = format!(
+        r#"[package]
+name = "{}"
+version = "0.0.0"
+edition = "2018"
+publish = false
+
+[dependencies]
+libbpf-sys = {{ path = {:?}, default-features = false, features = ["vendored"] }}
+
+[workspace]
+"#,
+        name,
+        env!("CARGO_MANIFEST_DIR")
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    fs::write(dir.join("src/main.rs"), MAIN).unwrap();
+    dir
+}
+
+#[test]
+#[ignore = "builds the vendored libraries several times"]
+fn concurrent_vendored_builds() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if !root.join("libbpf/src/libbpf.h").exists() {
+        eprintln!("libbpf sources not available; skipping");
+        return;
+    }
+
+    let builds = env::var("LIBBPF_SYS_STRESS_BUILDS")
+        .ok()
+        .map(|builds| builds.parse::<usize>().unwrap())
+        .unwrap_or(4);
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("concurrent_build");
+    // Start from scratch, so that nothing is left over from a previous
+    // run.
+    let _ = fs::remove_dir_all(&dir);
+
+    let crates = (0..builds)
+        .map(|index| create_crate(&dir, index))
+        .collect::<Vec<_>>();
+    let children = crates
+        .iter()
+        .map(|dir| {
+            let child = Command::new(env!("CARGO"))
+                .arg("build")
+                .arg("--quiet")
+                .current_dir(dir)
+                .env("CARGO_TARGET_DIR", dir.join("target"))
+                .stdout(Stdio::null())
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap();
+            (dir, child)
+        })
+        .collect::<Vec<_>>();
+
+    let mut versions = Vec::new();
+    for (dir, child) in children {
+        let output = child.wait_with_output().unwrap();
+        assert!(
+            output.status.success(),
+            "building {} failed:\n{}",
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let name = dir.file_name().unwrap();
+        let output = Command::new(dir.join("target/debug").join(name))
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "running {} failed:\n{}",
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        versions.push(String::from_utf8(output.stdout).unwrap());
+    }
+
+    versions.dedup();
+    assert_eq!(
+        versions.len(),
+        1,
+        "builds disagree on the libbpf version: {:?}",
+        versions
+    );
+}
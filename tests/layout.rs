@@ -0,0 +1,98 @@
+// tests/layout.rs
+
+//! Checks the layouts of the bound structs and unions, as well as the
+//! values of the bound constants, against the vendored C headers.
+//!
+//! The sizes, alignments, member offsets, and constant values according
+//! to the bindings are turned into `_Static_assert`s of a C file
+//! including the headers, which then gets compiled. As nothing needs to
+//! be run but the test itself, this works for cross compilation as long
+//! as `CC` (defaulting to `cc`) refers to a compiler for the target.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::mem::align_of;
+use std::mem::offset_of;
+use std::mem::size_of;
+use std::path::Path;
+use std::process::Command;
+
+use libbpf_sys::*;
+
+macro_rules! layout_table {
+    (
+        types { $($kind:ident $name:ident {$($field:ident => $c_field:literal),*})* }
+        consts { $($constant:ident,)* }
+    ) => {
+        /// Retrieve C expressions along with the values they evaluate
+        /// to according to the bindings.
+        fn checks() -> Vec<(String, u64)> {
+            let mut checks = Vec::new();
+            $(
+                let ty = concat!(stringify!($kind), " ", stringify!($name));
+                checks.push((format!("sizeof({})", ty), size_of::<$name>() as u64));
+                checks.push((format!("_Alignof({})", ty), align_of::<$name>() as u64));
+                $(
+                    checks.push((
+                        format!("offsetof({}, {})", ty, $c_field),
+                        offset_of!($name, $field) as u64,
+                    ));
+                )*
+            )*
+            $(
+                checks.push((
+                    format!("(unsigned long long)({})", stringify!($constant)),
+                    $constant as i128 as u64,
+                ));
+            )*
+            checks
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/layout_table.rs"));
+
+#[test]
+fn layout_matches_headers() {
+    // The bindings only match the vendored headers.
+    if cfg!(feature = "bindgen-system-headers") {
+        return;
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if !root.join("libbpf/src/libbpf.h").exists() {
+        eprintln!("libbpf sources not available; skipping");
+        return;
+    }
+
+    let mut source = String::from("#include <stddef.h>\n#include \"bindings.h\"\n\n");
+    for (expr, value) in checks() {
+        writeln!(
+            source,
+            "_Static_assert({expr} == {value}ULL, \"{expr} is {value} in the bindings\");"
+        )
+        .unwrap();
+    }
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("layout.c");
+    fs::write(&path, source).unwrap();
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let output = Command::new(cc)
+        .arg("-std=gnu11")
+        .arg("-fsyntax-only")
+        .arg("-I")
+        .arg(root)
+        .arg("-I")
+        .arg(root.join("libbpf/include"))
+        .arg("-I")
+        .arg(root.join("libbpf/include/uapi"))
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "bindings differ from the C headers:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,115 @@
+// tests/opts.rs
+
+//! Checks the sizes of the opts structs against the ones recorded for
+//! the libbpf version the bindings correspond to.
+//!
+//! libbpf tells apart releases of an opts struct by its `sz` member and
+//! treats any tail beyond the size it knows about as having to be zero.
+//! A struct growing with a libbpf update thus changes what callers
+//! zero-filling it request, which deserves a deliberate review. The
+//! sizes are recorded per libbpf version in `tests/opts_sizes/`, for
+//! targets with 64-bit pointers. Running the test with
+//! `LIBBPF_SYS_BLESS=1` records the sizes for the current version.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::mem::size_of;
+use std::path::Path;
+
+use libbpf_sys::*;
+
+macro_rules! opts_sizes {
+    ($version:literal { $($name:ident,)* }) => {
+        /// The libbpf version the bindings correspond to.
+        const VERSION: &str = $version;
+
+        /// Retrieve the names of the opts structs along with their
+        /// sizes according to the bindings.
+        fn sizes() -> BTreeMap<&'static str, usize> {
+            let mut sizes = BTreeMap::new();
+            $(
+                sizes.insert(stringify!($name), size_of::<$name>());
+            )*
+            sizes
+        }
+    };
+}
+
+include!(concat!(env!("OUT_DIR"), "/opts_sizes.rs"));
+
+/// Parse the recorded sizes, one `<struct> <size>` pair per line.
+fn parse(recorded: &str) -> BTreeMap<&str, usize> {
+    recorded
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (name, size) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("malformed line: {}", line));
+            (name, size.trim().parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(
+    not(target_pointer_width = "64"),
+    ignore = "sizes recorded for 64-bit targets"
+)]
+fn opts_sizes_match_recorded() {
+    if VERSION.is_empty() {
+        eprintln!("libbpf version of the bindings unknown; skipping");
+        return;
+    }
+
+    let sizes = sizes();
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/opts_sizes")
+        .join(format!("v{}.txt", VERSION));
+    if env::var_os("LIBBPF_SYS_BLESS").is_some_and(|bless| bless == "1") {
+        let mut recorded = format!("# opts struct sizes of libbpf {}\n", VERSION);
+        for (name, size) in &sizes {
+            writeln!(recorded, "{} {}", name, size).unwrap();
+        }
+        fs::write(&path, recorded).unwrap();
+        return;
+    }
+
+    let recorded = match fs::read_to_string(&path) {
+        Ok(recorded) => recorded,
+        // Bindings generated from the system headers may correspond to
+        // any version.
+        Err(_) if cfg!(feature = "bindgen-system-headers") => {
+            eprintln!("no sizes recorded for libbpf {}; skipping", VERSION);
+            return;
+        },
+        Err(err) => panic!(
+            "no sizes recorded for libbpf {} in {}: {}; review the opts structs \
+             and record them with LIBBPF_SYS_BLESS=1",
+            VERSION,
+            path.display(),
+            err
+        ),
+    };
+    let recorded = parse(&recorded);
+
+    // Structs may be missing from the bindings, e.g., with `no-perf`.
+    let mismatches = sizes
+        .iter()
+        .filter_map(|(name, size)| match recorded.get(name) {
+            Some(expected) if expected == size => None,
+            Some(expected) => Some(format!("{}: {} bytes, recorded {}", name, size, expected)),
+            None => Some(format!("{}: {} bytes, not recorded", name, size)),
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        mismatches.is_empty(),
+        "opts structs of libbpf {} differ from {}; review the changes and \
+         record them with LIBBPF_SYS_BLESS=1:\n{}",
+        VERSION,
+        path.display(),
+        mismatches.join("\n")
+    );
+}
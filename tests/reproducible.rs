@@ -0,0 +1,178 @@
+// tests/reproducible.rs
+
+//! Checks that building the vendored libraries is reproducible.
+//!
+//! The crate gets built twice, into separate target directories and
+//! hence separate `OUT_DIR`s, and the static libraries ending up in
+//! there are compared byte for byte. Archives that differ are compared
+//! member by member, to report what varies between the builds: member
+//! timestamps or owners, paths of the build embedded into the objects,
+//! or anything else, e.g., random seeds.
+//!
+//! The test builds the vendored libraries twice and is hence ignored by
+//! default:
+//!
+//! ```text
+//! $ cargo test --test reproducible -- --ignored
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// The static libraries the vendored build produces.
+const ARCHIVES: &[&str] = &["libbpf.a", "libelf.a", "libz.a"];
+
+/// A member of an `ar` archive.
+struct Member<'a> {
+    /// The modification time, owner, group, and mode fields of the
+    /// member's header.
+    meta: &'a [u8],
+    data: &'a [u8],
+}
+
+/// Parse the members of the `ar` archive `archive`, in the System V
+/// (GNU) variant, by name.
+fn members(archive: &[u8]) -> BTreeMap<String, Member<'_>> {
+    let mut rest = archive
+        .strip_prefix(b"!<arch>\n")
+        .expect("not an ar archive");
+    let mut names = &[][..];
+    let mut members = BTreeMap::new();
+    while rest.len() >= 60 {
+        let (header, tail) = rest.split_at(60);
+        let field = |range: std::ops::Range<usize>| {
+            String::from_utf8_lossy(&header[range]).trim().to_string()
+        };
+        let size = field(48..58).parse::<usize>().unwrap();
+        let data = &tail[..size];
+        // Members are aligned to two bytes.
+        rest = &tail[(size + size % 2).min(tail.len())..];
+
+        let name = field(0..16);
+        let name = match name.as_str() {
+            "/" | "/SYM64/" => "symbol table".to_string(),
+            // The table of names exceeding the header's field.
+            "//" => {
+                names = data;
+                continue;
+            },
+            _ => match name.strip_prefix('/') {
+                Some(offset) => {
+                    let long = &names[offset.parse::<usize>().unwrap()..];
+                    let end = long.iter().position(|&b| b == b'\n').unwrap();
+                    String::from_utf8_lossy(&long[..end])
+                        .trim_end_matches('/')
+                        .to_string()
+                },
+                None => name.trim_end_matches('/').to_string(),
+            },
+        };
+        members.insert(
+            name,
+            Member {
+                meta: &header[16..48],
+                data,
+            },
+        );
+    }
+    members
+}
+
+/// Check whether `data` contains `needle`.
+fn contains(data: &[u8], needle: &[u8]) -> bool {
+    data.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Describe how `second` differs from `first`, as built in the target
+/// directories `dirs`.
+fn differences(first: &[u8], second: &[u8], dirs: [&Path; 2]) -> Vec<String> {
+    let first = members(first);
+    let second = members(second);
+    let mut differences = Vec::new();
+    for name in first
+        .keys()
+        .chain(second.keys().filter(|name| !first.contains_key(*name)))
+    {
+        let (a, b) = match (first.get(name), second.get(name)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => {
+                differences.push(format!("{}: only present in one build", name));
+                continue;
+            },
+        };
+        if a.meta != b.meta {
+            differences.push(format!("{}: timestamp, owner, or mode in header", name));
+        }
+        if a.data != b.data {
+            let embedded = [(a, dirs[0]), (b, dirs[1])]
+                .iter()
+                .any(|(member, dir)| contains(member.data, dir.to_str().unwrap().as_bytes()));
+            if embedded {
+                differences.push(format!("{}: embeds the path of the build", name));
+            } else {
+                differences.push(format!("{}: contents", name));
+            }
+        }
+    }
+    differences
+}
+
+/// Build the crate with its vendored libraries in `target_dir`,
+/// returning the `OUT_DIR` of the build script.
+fn build(target_dir: &Path) -> PathBuf {
+    let status = Command::new(env!("CARGO"))
+        .arg("build")
+        .arg("--quiet")
+        .arg("--features")
+        .arg("vendored")
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .env("CARGO_TARGET_DIR", target_dir)
+        .status()
+        .unwrap();
+    assert!(
+        status.success(),
+        "building in {} failed",
+        target_dir.display()
+    );
+
+    fs::read_dir(target_dir.join("debug/build"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path().join("out"))
+        .find(|out_dir| out_dir.join("libbpf.a").exists())
+        .expect("OUT_DIR with libbpf.a not found")
+}
+
+#[test]
+#[ignore = "builds the vendored libraries twice"]
+fn vendored_build_reproducible() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if !root.join("libbpf/src/libbpf.h").exists() {
+        eprintln!("libbpf sources not available; skipping");
+        return;
+    }
+
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("reproducible");
+    let _ = fs::remove_dir_all(&dir);
+    let target_dirs = [dir.join("first"), dir.join("second")];
+    let out_dirs = [build(&target_dirs[0]), build(&target_dirs[1])];
+
+    let mut report = Vec::new();
+    for archive in ARCHIVES {
+        let first = fs::read(out_dirs[0].join(archive)).unwrap();
+        let second = fs::read(out_dirs[1].join(archive)).unwrap();
+        if first != second {
+            let dirs = [target_dirs[0].as_path(), target_dirs[1].as_path()];
+            let differences = differences(&first, &second, dirs);
+            if differences.is_empty() {
+                report.push(format!("{}: order of members", archive));
+            }
+            for difference in differences {
+                report.push(format!("{}: {}", archive, difference));
+            }
+        }
+    }
+    assert!(report.is_empty(), "builds differ:\n{}", report.join("\n"));
+}
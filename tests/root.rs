@@ -0,0 +1,207 @@
+// tests/root.rs
+
+//! Smoke tests exercising the raw bindings against the running kernel.
+//!
+//! These tests require the privileges to load programs, create maps,
+//! and attach XDP programs, so they are only built with the
+//! `run-root-tests` feature, and skipped if the privileges are missing.
+//! They use nothing but this crate's symbols, to catch packaging and
+//! linking regressions.
+//!
+//! With the `vmtest` feature, the tests are ignored on the host and run
+//! inside a virtual machine by `tests/vmtest.rs` instead.
+
+use std::mem::size_of;
+use std::os::fd::AsRawFd;
+use std::os::fd::FromRawFd;
+use std::os::fd::OwnedFd;
+use std::os::raw::c_void;
+use std::ptr;
+
+use libbpf_sys::testing::Requirement;
+use libbpf_sys::testing::CAP_NET_ADMIN;
+use libbpf_sys::*;
+
+/// The interface index of the loopback device.
+const LOOPBACK: i32 = 1;
+
+/// Take ownership of the file descriptor returned by a libbpf call,
+/// failing on errors.
+fn fd(ret: i32, what: &str) -> OwnedFd {
+    assert!(ret >= 0, "{} failed: {}", what, ret);
+    unsafe { OwnedFd::from_raw_fd(ret) }
+}
+
+/// Load a program of type `prog_type` returning `ret`.
+fn load_prog(prog_type: bpf_prog_type, ret: i32) -> OwnedFd {
+    let insns = [insn::mov64_imm(BPF_REG_0, ret), insn::exit_insn()];
+    let prog_fd = unsafe {
+        bpf_prog_load(
+            prog_type,
+            b"smoke\0".as_ptr().cast(),
+            b"GPL\0".as_ptr().cast(),
+            insns.as_ptr(),
+            insns.len() as _,
+            ptr::null_mut(),
+        )
+    };
+    fd(prog_fd, "bpf_prog_load")
+}
+
+#[test]
+#[cfg_attr(feature = "vmtest", ignore = "run inside the VM by tests/vmtest.rs")]
+fn socket_filter() {
+    require_or_skip!(Requirement::BpfPrivileges);
+
+    let prog = load_prog(BPF_PROG_TYPE_SOCKET_FILTER, 0);
+
+    let mut data = [0u8; 64];
+    let mut opts = bpf_test_run_opts {
+        data_in: data.as_mut_ptr().cast(),
+        data_size_in: data.len() as _,
+        repeat: 1,
+        ..Default::default()
+    };
+    let ret = unsafe { bpf_prog_test_run_opts(prog.as_raw_fd(), &mut opts) };
+    assert_eq!(ret, 0);
+    assert_eq!(opts.retval, 0);
+}
+
+#[test]
+#[cfg_attr(feature = "vmtest", ignore = "run inside the VM by tests/vmtest.rs")]
+fn array_map() {
+    require_or_skip!(Requirement::BpfPrivileges);
+
+    let map_fd = unsafe {
+        bpf_map_create(
+            BPF_MAP_TYPE_ARRAY,
+            b"smoke\0".as_ptr().cast(),
+            size_of::<u32>() as _,
+            size_of::<u64>() as _,
+            1,
+            ptr::null(),
+        )
+    };
+    let map = fd(map_fd, "bpf_map_create");
+    let map_fd = map.as_raw_fd();
+
+    let key = 0u32;
+    let value = 42u64;
+    let ret = unsafe {
+        bpf_map_update_elem(
+            map_fd,
+            (&key as *const u32).cast(),
+            (&value as *const u64).cast(),
+            BPF_ANY as _,
+        )
+    };
+    assert_eq!(ret, 0);
+
+    let mut read = 0u64;
+    let ret = unsafe {
+        bpf_map_lookup_elem(
+            map_fd,
+            (&key as *const u32).cast(),
+            (&mut read as *mut u64).cast::<c_void>(),
+        )
+    };
+    assert_eq!(ret, 0);
+    assert_eq!(read, value);
+
+    let mut info = bpf_map_info::default();
+    let mut len = size_of::<bpf_map_info>() as u32;
+    let ret = unsafe {
+        bpf_obj_get_info_by_fd(map_fd, (&mut info as *mut bpf_map_info).cast(), &mut len)
+    };
+    assert_eq!(ret, 0);
+    assert_eq!(info.type_, BPF_MAP_TYPE_ARRAY);
+    assert_eq!(info.max_entries, 1);
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+#[cfg_attr(feature = "vmtest", ignore = "run inside the VM by tests/vmtest.rs")]
+fn mmapable_array_map() {
+    use libbpf_sys::mmap::*;
+
+    require_or_skip!(Requirement::BpfPrivileges);
+
+    let opts = bpf_map_create_opts {
+        map_flags: BPF_F_MMAPABLE,
+        ..Default::default()
+    };
+    let map_fd = unsafe {
+        bpf_map_create(
+            BPF_MAP_TYPE_ARRAY,
+            b"smoke\0".as_ptr().cast(),
+            size_of::<u32>() as _,
+            12,
+            4,
+            &opts,
+        )
+    };
+    let map = fd(map_fd, "bpf_map_create");
+    let map_fd = map.as_raw_fd();
+
+    let mut info = bpf_map_info::default();
+    let mut len = size_of::<bpf_map_info>() as u32;
+    let ret = unsafe {
+        bpf_obj_get_info_by_fd(map_fd, (&mut info as *mut bpf_map_info).cast(), &mut len)
+    };
+    assert_eq!(ret, 0);
+    let layout = MmapLayout::from_info(&info, page_size()).unwrap();
+    assert_eq!(layout.value_stride(), 16);
+
+    let addr = unsafe { bpf_map_mmap(map_fd, 0, layout.mmap_len(), true) };
+    assert!(!addr.is_null());
+    let offset = layout.value_offset(3).unwrap();
+    unsafe { addr.cast::<u8>().add(offset).cast::<u32>().write(42) };
+    assert_eq!(unsafe { bpf_map_munmap(addr, layout.mmap_len()) }, 0);
+
+    let key = 3u32;
+    let mut value = [0u8; 12];
+    let ret = unsafe {
+        bpf_map_lookup_elem(
+            map_fd,
+            (&key as *const u32).cast(),
+            value.as_mut_ptr().cast::<c_void>(),
+        )
+    };
+    assert_eq!(ret, 0);
+    assert_eq!(value[..4], 42u32.to_ne_bytes());
+
+    // Mapping beyond the values fails.
+    let addr = unsafe { bpf_map_mmap(map_fd, 0, layout.mmap_len() + page_size(), false) };
+    assert!(addr.is_null());
+}
+
+#[test]
+#[cfg_attr(feature = "vmtest", ignore = "run inside the VM by tests/vmtest.rs")]
+fn xdp_link() {
+    // XDP links are available since Linux 5.9.
+    require_or_skip!(
+        Requirement::BpfPrivileges,
+        Requirement::Capability(CAP_NET_ADMIN),
+        Requirement::Kernel(5, 9),
+    );
+
+    let prog = load_prog(BPF_PROG_TYPE_XDP, XDP_PASS as _);
+    let prog_fd = prog.as_raw_fd();
+
+    let link_fd = unsafe { bpf_link_create(prog_fd, LOOPBACK, BPF_XDP, ptr::null()) };
+    let link = fd(link_fd, "bpf_link_create");
+    let link_fd = link.as_raw_fd();
+
+    let mut prog_id = 0;
+    let ret = unsafe { bpf_xdp_query_id(LOOPBACK, 0, &mut prog_id) };
+    assert_eq!(ret, 0);
+    assert_ne!(prog_id, 0);
+
+    let ret = unsafe { bpf_link_detach(link_fd) };
+    assert_eq!(ret, 0);
+    drop(link);
+
+    let ret = unsafe { bpf_xdp_query_id(LOOPBACK, 0, &mut prog_id) };
+    assert_eq!(ret, 0);
+    assert_eq!(prog_id, 0);
+}
@@ -0,0 +1,39 @@
+// tests/stubs.rs
+
+//! Checks that the stubs of the `stubs` feature can be replaced. This
+//! lives in its own test binary, as the replacement applies to all of
+//! it.
+
+use core::ffi::c_int;
+
+use libbpf_sys::*;
+
+/// Replaces the weak stub of `libbpf_minor_version`.
+#[export_name = "libbpf_minor_version"]
+extern "C" fn minor_version() -> u32 {
+    42
+}
+
+#[test]
+fn override_stub() {
+    stubs::reset();
+    assert_eq!(unsafe { libbpf_minor_version() }, 42);
+    assert_eq!(unsafe { libbpf_major_version() }, 0);
+    assert_eq!(stubs::calls(), ["libbpf_major_version"]);
+}
+
+#[test]
+fn per_thread_state() {
+    const EPERM: c_int = 1;
+
+    stubs::reset();
+    stubs::fail_all(EPERM);
+    std::thread::spawn(|| {
+        assert_eq!(unsafe { libbpf_num_possible_cpus() }, 0);
+        assert_eq!(stubs::calls(), ["libbpf_num_possible_cpus"]);
+    })
+    .join()
+    .unwrap();
+    assert_eq!(unsafe { libbpf_num_possible_cpus() }, -EPERM);
+    assert_eq!(stubs::calls(), ["libbpf_num_possible_cpus"]);
+}
@@ -0,0 +1,72 @@
+// tests/symbols.rs
+
+//! Checks that the bindings cover exactly the functions libbpf
+//! exports, as listed in its linker version script.
+
+use std::collections::BTreeSet;
+use std::fs::read_to_string;
+use std::path::Path;
+
+/// Functions that are bound on purpose despite not being part of
+/// libbpf.
+const FOREIGN: &[&str] = &["vdprintf"];
+
+/// Retrieve the symbols listed in libbpf's linker version script.
+fn exported_symbols(map: &str) -> BTreeSet<&str> {
+    map.lines()
+        .filter_map(|line| {
+            let symbol = line.trim().strip_suffix(';')?;
+            let valid = !symbol.is_empty()
+                && symbol
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            valid.then_some(symbol)
+        })
+        .collect()
+}
+
+/// Retrieve the names of all functions declared in `bindings`.
+fn bound_functions(bindings: &str) -> BTreeSet<&str> {
+    bindings
+        .split("extern \"C\" {\n")
+        .skip(1)
+        .flat_map(|block| {
+            let block = &block[..block.find("\n}\n").unwrap_or(block.len())];
+            block.split("pub fn ").skip(1).map(|decl| {
+                let end = decl.find('(').unwrap();
+                &decl[..end]
+            })
+        })
+        .filter(|name| !FOREIGN.contains(name))
+        .collect()
+}
+
+#[test]
+#[cfg_attr(
+    libbpf_sys_no_libbpf_map,
+    ignore = "libbpf/src/libbpf.map is missing; check out the libbpf submodule"
+)]
+fn symbol_coverage() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let map = read_to_string(root.join("libbpf/src/libbpf.map"))
+        .expect("failed to read libbpf/src/libbpf.map; check out the libbpf submodule");
+    let bindings = read_to_string(root.join("src/bindings.rs")).unwrap();
+
+    let exported = exported_symbols(&map);
+    let bound = bound_functions(&bindings);
+    let diff = exported
+        .difference(&bound)
+        .map(|symbol| format!("- {symbol}"))
+        .chain(
+            bound
+                .difference(&exported)
+                .map(|symbol| format!("+ {symbol}")),
+        )
+        .collect::<Vec<_>>();
+
+    assert!(
+        diff.is_empty(),
+        "bindings differ from libbpf.map (- missing, + unexpected):\n{}",
+        diff.join("\n")
+    );
+}
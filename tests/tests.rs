@@ -4,6 +4,7 @@
 mod tests {
     use libbpf_sys::*;
 
+    #[cfg(not(any(feature = "dlopen", feature = "stubs")))]
     unsafe extern "C" fn print_fn(
         _level: libbpf_print_level,
         _arg1: *const std::os::raw::c_char,
@@ -12,11 +13,1251 @@ mod tests {
         0
     }
 
+    #[cfg(not(any(feature = "dlopen", feature = "stubs")))]
     #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
     fn test() {
         unsafe {
             // just tests that we can call into the library
             assert!(libbpf_set_print(Some(print_fn as _)).is_some());
         }
     }
+
+    #[test]
+    fn token_api() {
+        // Only type check the token entry point, so that the test does not
+        // depend on the linked libbpf exporting it.
+        const _: unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *mut bpf_token_create_opts,
+        ) -> std::os::raw::c_int = bpf_token_create;
+        const _: unsafe extern "C" fn(*const bpf_object) -> std::os::raw::c_int =
+            bpf_object__token_fd;
+
+        let _ = bpf_token_create_opts {
+            flags: 0,
+            ..Default::default()
+        };
+        let _ = bpf_map_create_opts {
+            token_fd: 42,
+            ..Default::default()
+        };
+        let _ = bpf_prog_load_opts {
+            token_fd: 42,
+            ..Default::default()
+        };
+        let _ = bpf_btf_load_opts {
+            token_fd: 42,
+            ..Default::default()
+        };
+        let _ = bpf_object_open_opts {
+            bpf_token_path: b"/sys/fs/bpf\0".as_ptr() as *const _,
+            ..Default::default()
+        };
+
+        let mut attr = bpf_attr::default();
+        attr.token_create.bpffs_fd = 42;
+        assert_eq!(unsafe { attr.token_create.bpffs_fd }, 42);
+        attr.__bindgen_anon_1.map_token_fd = 42;
+        assert_eq!(unsafe { attr.__bindgen_anon_1.map_token_fd }, 42);
+    }
+
+    #[test]
+    fn func_ids() {
+        // The helper IDs are ABI and must never be renumbered.
+        let ids: [(bpf_func_id, u32); 5] = [
+            (BPF_FUNC_unspec, 0),
+            (BPF_FUNC_map_lookup_elem, 1),
+            (BPF_FUNC_trace_printk, 6),
+            (BPF_FUNC_ringbuf_output, 130),
+            (BPF_FUNC_cgrp_storage_delete, 211),
+        ];
+        for (id, expected) in ids {
+            assert_eq!(id, expected);
+            assert!(id < __BPF_FUNC_MAX_ID);
+        }
+    }
+
+    #[test]
+    fn attr_variants() {
+        let attr = bpf_attr::map_create(bpf_attr_map_create {
+            map_type: BPF_MAP_TYPE_ARRAY,
+            key_size: 4,
+            value_size: 8,
+            max_entries: 1,
+            ..Default::default()
+        });
+        let map_create = unsafe { attr.as_map_create() };
+        assert_eq!(map_create.map_type, BPF_MAP_TYPE_ARRAY);
+        assert_eq!(map_create.value_size, 8);
+
+        let mut attr = bpf_attr::default();
+        unsafe { attr.as_obj_get_info_by_fd_mut() }.bpf_fd = 42;
+        assert_eq!(unsafe { attr.info.bpf_fd }, 42);
+    }
+
+    #[test]
+    fn opts_default() {
+        let opts = bpf_object_open_opts::default();
+        assert_eq!(opts.sz, std::mem::size_of::<bpf_object_open_opts>() as _);
+        assert!(opts.object_name.is_null());
+
+        let opts = bpf_tc_hook {
+            ifindex: 1,
+            ..Default::default()
+        };
+        assert_eq!(opts.sz, std::mem::size_of::<bpf_tc_hook>() as _);
+        assert_eq!(opts.ifindex, 1);
+    }
+
+    #[test]
+    fn skeleton_builder() {
+        use libbpf_sys::skel::ObjectSkeletonBuilder;
+        use std::ptr;
+
+        let mut obj = ptr::null_mut();
+        let mut map = ptr::null_mut();
+        let mut prog = ptr::null_mut();
+        let mut link = ptr::null_mut();
+        let mut builder = ObjectSkeletonBuilder::new(
+            b"test\0".as_ptr() as *const _,
+            ptr::null(),
+            0,
+            &mut obj,
+        );
+        builder
+            .map(
+                b"map\0".as_ptr() as *const _,
+                &mut map,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+            .prog(b"prog\0".as_ptr() as *const _, &mut prog, &mut link);
+        let mut skel = builder.build();
+
+        let skel = unsafe { &*skel.as_mut_ptr() };
+        assert_eq!(skel.sz, std::mem::size_of::<bpf_object_skeleton>() as _);
+        assert_eq!(skel.map_cnt, 1);
+        assert_eq!(skel.prog_cnt, 1);
+        assert_eq!(unsafe { (*skel.maps).map }, &mut map as *mut _);
+        assert_eq!(unsafe { (*skel.progs).link }, &mut link as *mut _);
+    }
+
+    #[test]
+    fn insn_encoding() {
+        use libbpf_sys::insn::*;
+
+        fn raw(insn: &bpf_insn) -> u64 {
+            u64::from_le_bytes(unsafe { std::mem::transmute::<bpf_insn, [u8; 8]>(*insn) })
+        }
+
+        const PROG: [bpf_insn; 3] = [
+            mov64_imm(BPF_REG_0, 0),
+            jmp_imm(BPF_JEQ, BPF_REG_1, 42, 1),
+            exit_insn(),
+        ];
+        assert_eq!(PROG[0].code, 0xb7);
+        assert_eq!(PROG[1].code, 0x15);
+        assert_eq!(PROG[1].dst_reg(), 1);
+        assert_eq!(PROG[1].off, 1);
+        assert_eq!(PROG[1].imm, 42);
+        assert_eq!(PROG[2].code, 0x95);
+
+        let [lo, hi] = ld_map_fd(BPF_REG_1, 5);
+        assert_eq!(lo.code, 0x18);
+        assert_eq!(lo.dst_reg(), BPF_REG_1 as u8);
+        assert_eq!(lo.src_reg(), BPF_PSEUDO_MAP_FD as u8);
+        assert_eq!(lo.imm, 5);
+        assert_eq!(raw(&hi), 0);
+
+        let insn = stx_mem(BPF_DW, BPF_REG_10, BPF_REG_1, -8);
+        assert_eq!(insn.code, 0x7b);
+        assert_eq!(insn.dst_reg(), 10);
+        assert_eq!(insn.src_reg(), 1);
+        assert_eq!(insn.off, -8);
+    }
+
+    #[cfg(feature = "no-explicit-padding")]
+    #[test]
+    fn no_explicit_padding() {
+        // Without the trailing padding field, the literal lists all
+        // fields, with the size unchanged.
+        let key = bpf_cgroup_storage_key {
+            cgroup_inode_id: 1,
+            attach_type: BPF_CGROUP_INET_INGRESS,
+        };
+        assert_eq!(key.attach_type, BPF_CGROUP_INET_INGRESS);
+        assert_eq!(std::mem::size_of::<bpf_cgroup_storage_key>(), 16);
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_layout() {
+        use libbpf_sys::mmap::MmapLayout;
+
+        let layout = MmapLayout::new(BPF_MAP_TYPE_ARRAY, 12, 300, BPF_F_MMAPABLE, 4096).unwrap();
+        assert_eq!(layout.value_stride(), 16);
+        assert_eq!(layout.mmap_len(), 8192);
+        assert_eq!(layout.value_offset(299), Some(4784));
+        assert_eq!(layout.value_offset(300), None);
+
+        let layout = MmapLayout::new(BPF_MAP_TYPE_ARRAY, 4096, 2, BPF_F_MMAPABLE, 4096).unwrap();
+        assert_eq!(layout.mmap_len(), 8192);
+
+        assert_eq!(MmapLayout::new(BPF_MAP_TYPE_ARRAY, 8, 1, 0, 4096), None);
+        assert_eq!(
+            MmapLayout::new(BPF_MAP_TYPE_HASH, 8, 1, BPF_F_MMAPABLE, 4096),
+            None
+        );
+    }
+
+    #[cfg(feature = "libc")]
+    #[test]
+    fn libc_types() {
+        // The types are interchangeable, without casts.
+        let size: libc::size_t = 4096;
+        let size: size_t = size;
+        let pid: libc::pid_t = 1;
+        let pid: pid_t = pid;
+        let _: (libc::size_t, libc::pid_t) = (size, pid);
+    }
+
+    #[cfg(feature = "disasm")]
+    #[test]
+    fn disassembly() {
+        use libbpf_sys::disasm::disassemble;
+        use libbpf_sys::insn::*;
+
+        let [ld_lo, ld_hi] = ld_map_fd(BPF_REG_1, 5);
+        let prog = [
+            mov64_reg(BPF_REG_2, BPF_REG_10),
+            alu64_imm(BPF_ADD, BPF_REG_2, -8),
+            st_mem(BPF_DW, BPF_REG_10, -8, 0),
+            ld_lo,
+            ld_hi,
+            emit_call(BPF_FUNC_map_lookup_elem),
+            jmp_imm(BPF_JEQ, BPF_REG_0, 0, 1),
+            ldx_mem(BPF_W, BPF_REG_0, BPF_REG_0, 0),
+            exit_insn(),
+        ];
+        let expected = "   0: (bf) r2 = r10
+   1: (07) r2 += -8
+   2: (7a) *(u64 *)(r10 -8) = 0
+   3: (18) r1 = map[fd:5]
+   5: (85) call #1
+   6: (15) if r0 == 0x0 goto pc+1
+   7: (61) r0 = *(u32 *)(r0 +0)
+   8: (95) exit
+";
+        assert_eq!(disassemble(&prog), expected);
+    }
+
+    #[cfg(not(feature = "no-perf"))]
+    #[test]
+    fn perf_ring_records() {
+        use libbpf_sys::perf::PerfRingReader;
+        use std::mem::size_of;
+
+        const PAGE: usize = 4096;
+        const DATA: usize = 64;
+
+        // Back the fake mapping by `u64`s to get proper alignment.
+        let mut mapping = vec![0u64; (PAGE + DATA) / 8];
+        let base = mapping.as_mut_ptr() as *mut u8;
+        let page = base as *mut perf_event_mmap_page;
+
+        let write = |pos: usize, bytes: &[u8]| {
+            for (i, b) in bytes.iter().enumerate() {
+                unsafe { *base.add(PAGE + (pos + i) % DATA) = *b };
+            }
+        };
+        let record = |size: u16, fill: u8| {
+            let mut bytes = vec![fill; size as usize];
+            let hdr = perf_event_header {
+                type_: PERF_RECORD_SAMPLE,
+                misc: 0,
+                size,
+            };
+            let hdr = unsafe {
+                std::slice::from_raw_parts(
+                    &hdr as *const _ as *const u8,
+                    size_of::<perf_event_header>(),
+                )
+            };
+            bytes[..hdr.len()].copy_from_slice(hdr);
+            bytes
+        };
+
+        // Start close to the end, so that the second record wraps.
+        let start = 40;
+        let first = record(16, 0xaa);
+        let second = record(24, 0xbb);
+        write(start, &first);
+        write(start + first.len(), &second);
+
+        unsafe {
+            (*page).data_offset = PAGE as u64;
+            (*page).data_size = DATA as u64;
+            (*page).data_tail = start as u64;
+            (*page).data_head = (start + first.len() + second.len()) as u64;
+        }
+
+        {
+            let mut reader = unsafe { PerfRingReader::new(page) }.unwrap();
+            let records = reader.records().collect::<Vec<_>>();
+            assert_eq!(records.len(), 2);
+            assert_eq!(&*records[0], &first[..]);
+            assert_eq!(&*records[1], &second[..]);
+        }
+        assert_eq!(unsafe { (*page).data_tail }, unsafe { (*page).data_head });
+    }
+
+    #[cfg(not(feature = "no-perf"))]
+    #[test]
+    fn perf_event_attr_size() {
+        let mut attr = perf_event_attr::with_size(PERF_ATTR_SIZE_VER0);
+        assert_eq!(attr.size, PERF_ATTR_SIZE_VER0);
+        assert_eq!(attr.required_size(), PERF_ATTR_SIZE_VER0);
+
+        attr.sample_regs_user = 1;
+        attr.fit_size();
+        assert_eq!(attr.size, PERF_ATTR_SIZE_VER3);
+
+        attr.config3 = 1;
+        assert_eq!(attr.required_size(), PERF_ATTR_SIZE_VER8);
+        assert_eq!(
+            PERF_ATTR_SIZE_VER8 as usize,
+            std::mem::size_of::<perf_event_attr>()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_info() {
+        fn serialize<T: serde::Serialize>() {}
+        fn deserialize<T: for<'de> serde::Deserialize<'de>>() {}
+
+        serialize::<bpf_prog_info>();
+        serialize::<bpf_link_info>();
+        serialize::<bpf_map_info>();
+        serialize::<bpf_btf_info>();
+        deserialize::<bpf_map_info>();
+        deserialize::<bpf_btf_info>();
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn pod_btf() {
+        use libbpf_sys::insn::mov64_imm;
+        use std::mem::size_of;
+
+        let mut bytes = [0u8; size_of::<btf_header>() + size_of::<btf_type>()];
+        bytes[..2].copy_from_slice(&(BTF_MAGIC as u16).to_ne_bytes());
+        bytes[2] = BTF_VERSION as u8;
+
+        let (hdr, ty) = bytes.split_at(size_of::<btf_header>());
+        let hdr = bytemuck::pod_read_unaligned::<btf_header>(hdr);
+        assert_eq!(u32::from(hdr.magic), BTF_MAGIC);
+        assert_eq!(u32::from(hdr.version), BTF_VERSION);
+
+        let ty = bytemuck::pod_read_unaligned::<btf_type>(ty);
+        assert_eq!(ty.name_off, 0);
+
+        let insn = mov64_imm(BPF_REG_0, 1);
+        let insn = bytemuck::bytes_of(&insn);
+        assert_eq!(insn.len(), size_of::<bpf_insn>());
+    }
+
+    #[cfg(feature = "bitflags")]
+    #[test]
+    fn typed_flags() {
+        use libbpf_sys::flags::*;
+
+        let flags = MapCreateFlags::NO_PREALLOC | MapCreateFlags::RDONLY_PROG;
+        assert_eq!(flags.bits(), BPF_F_NO_PREALLOC | BPF_F_RDONLY_PROG);
+        assert_eq!(
+            XdpFlags::from_bits(XDP_FLAGS_MODES),
+            Some(XdpFlags::SKB_MODE | XdpFlags::DRV_MODE | XdpFlags::HW_MODE)
+        );
+        assert_eq!(XdpFlags::all().bits(), XDP_FLAGS_MASK);
+        assert_eq!(ProgLoadFlags::from_bits(1 << 31), None);
+        assert!(AttachFlags::default().is_empty());
+    }
+
+    #[test]
+    fn error_conventions() {
+        use libbpf_sys::error::*;
+
+        assert_eq!(
+            error_convention("bpf_prog_load"),
+            Some(ErrorConvention::NegErrno)
+        );
+        assert_eq!(
+            error_convention("bpf_object__open_file"),
+            Some(ErrorConvention::NullErrno)
+        );
+        assert_eq!(
+            error_convention("bpf_object__next_map"),
+            Some(ErrorConvention::None)
+        );
+        assert_eq!(
+            error_convention("bpf_map__key_size"),
+            Some(ErrorConvention::None)
+        );
+        // Getters returning `NULL` for absent values.
+        for name in [
+            "bpf_map__pin_path",
+            "bpf_map__initial_value",
+            "libbpf_bpf_prog_type_str",
+        ] {
+            assert_eq!(error_convention(name), Some(ErrorConvention::Nullable));
+        }
+        assert_eq!(error_convention("bpf_does_not_exist"), None);
+    }
+
+    #[test]
+    fn subsystem_modules() {
+        use libbpf_sys::subsys;
+
+        let _: unsafe extern "C" fn() -> *mut subsys::btf::btf = subsys::btf::btf__new_empty;
+        let _ = subsys::bpf::bpf_prog_load;
+        let _ = subsys::bpf::BPF_PROG_TYPE_XDP;
+        let _ = subsys::xdp::bpf_xdp_attach;
+        assert_eq!(subsys::xdp::IFLA_XDP, 43);
+        assert_eq!(subsys::xdp::IFLA_XDP_FD, 1);
+        #[cfg(not(feature = "no-perf"))]
+        let _ = subsys::perf::PERF_RECORD_SAMPLE;
+        let _ = subsys::ringbuf::ring_buffer__new;
+        #[cfg(not(feature = "no-linker"))]
+        let _ = subsys::linker::bpf_linker__new;
+    }
+
+    #[test]
+    fn btf_kind() {
+        use libbpf_sys::btf_kind::*;
+        use std::convert::TryFrom as _;
+
+        assert_eq!(
+            BtfKind::try_from(BTF_KIND_FUNC_PROTO),
+            Ok(BtfKind::FuncProto)
+        );
+        assert_eq!(u32::from(BtfKind::Enum64), BTF_KIND_ENUM64);
+        assert_eq!(
+            BtfKind::try_from(NR_BTF_KINDS),
+            Err(UnknownBtfKind(NR_BTF_KINDS))
+        );
+
+        let ty = btf_type {
+            info: BTF_KIND_DATASEC << 24 | 3,
+            ..Default::default()
+        };
+        assert_eq!(ty.kind(), Ok(BtfKind::Datasec));
+    }
+
+    #[test]
+    fn btf_wire() {
+        let le = [
+            0x9f, 0xeb, 1, 0, 24, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 12, 0, 0, 0, 5, 0, 0, 0,
+        ];
+        let be = [
+            0xeb, 0x9f, 1, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 12, 0, 0, 0, 5,
+        ];
+        for data in [&le, &be] {
+            let endianness = btf_header::endianness(data).unwrap();
+            let hdr = btf_header::read(data, endianness).unwrap();
+            assert_eq!(hdr.magic, BTF_MAGIC as u16);
+            assert_eq!(hdr.version, 1);
+            assert_eq!(hdr.hdr_len, 24);
+            assert_eq!(hdr.type_len, 12);
+            assert_eq!(hdr.str_len, 5);
+        }
+        assert_eq!(btf_header::endianness(&le), Some(BTF_LITTLE_ENDIAN));
+        assert_eq!(btf_header::endianness(&be), Some(BTF_BIG_ENDIAN));
+        assert!(btf_header::read(&le[..23], BTF_LITTLE_ENDIAN).is_none());
+
+        let ty = [0, 0, 0, 1, 0x01, 0, 0, 0, 0, 0, 0, 4];
+        let ty = btf_type::read(&ty, BTF_BIG_ENDIAN).unwrap();
+        assert_eq!(ty.name_off, 1);
+        assert_eq!(ty.info, BTF_KIND_INT << 24);
+        assert_eq!(unsafe { ty.__bindgen_anon_1.size }, 4);
+
+        let member = btf_member::read(&[1, 0, 0, 0, 2, 0, 0, 0, 64, 0, 0, 0], BTF_LITTLE_ENDIAN);
+        let member = member.unwrap();
+        assert_eq!((member.name_off, member.type_, member.offset), (1, 2, 64));
+    }
+
+    #[test]
+    fn tc_handles() {
+        use libbpf_sys::tc::*;
+
+        let parent = TC_H_MAKE(TC_H_CLSACT, TC_H_MIN_INGRESS);
+        assert_eq!(parent, 0xfffffff2);
+        assert_eq!(TC_H_MAJ(parent), 0xffff0000);
+        assert_eq!(TC_H_MIN(parent), TC_H_MIN_INGRESS);
+        assert_eq!(TCA_BPF_FD, 6);
+        assert_eq!(TC_ACT_SHOT, 2);
+    }
+
+    #[test]
+    fn opts_check() {
+        use libbpf_sys::opts_check::*;
+        use std::mem::size_of;
+
+        let opts = bpf_prog_load_opts::default();
+        assert_eq!(unsafe { check_opts(&opts) }, Ok(()));
+        assert_eq!(
+            unsafe { check_opts(std::ptr::null::<bpf_prog_load_opts>()) },
+            Ok(())
+        );
+
+        let opts: bpf_map_create_opts = unsafe { std::mem::zeroed() };
+        let err = unsafe { check_opts(&opts) }.unwrap_err();
+        assert_eq!(err.name, "bpf_map_create_opts");
+        assert_eq!(err.issue, OptsIssue::ZeroSize);
+
+        let opts = bpf_object_open_opts {
+            sz: 8,
+            ..Default::default()
+        };
+        let err = unsafe { check_opts(&opts) }.unwrap_err();
+        assert_eq!(
+            err.issue,
+            OptsIssue::SizeMismatch {
+                expected: size_of::<bpf_object_open_opts>(),
+                actual: 8,
+            }
+        );
+        assert!(err.to_string().starts_with("bpf_object_open_opts: sz is 8"));
+
+        // Pretend libbpf predates `log_true_size`.
+        let mut opts = bpf_prog_load_opts::default();
+        let libbpf_size = &opts.log_true_size as *const _ as usize - &opts as *const _ as usize;
+        assert_eq!(unsafe { check_opts_for_size(&opts, libbpf_size) }, Ok(()));
+        opts.log_true_size = 1;
+        let err = unsafe { check_opts_for_size(&opts, libbpf_size) }.unwrap_err();
+        assert_eq!(
+            err.issue,
+            OptsIssue::NonZeroTail {
+                offset: libbpf_size
+            }
+        );
+    }
+
+    #[test]
+    fn multi_opts() {
+        use std::mem::size_of;
+        use std::os::raw::c_char;
+
+        let syms = [
+            b"foo\0".as_ptr().cast::<c_char>(),
+            b"bar\0".as_ptr().cast::<c_char>(),
+        ];
+        let cookies = [1, 2];
+        let opts = bpf_kprobe_multi_opts::from_slices(&syms, &[], &cookies, true).unwrap();
+        assert_eq!(opts.cnt, 2);
+        assert_eq!(opts.syms.cast_const(), syms.as_ptr());
+        assert!(opts.addrs.is_null());
+        assert_eq!(opts.cookies, cookies.as_ptr());
+        assert!(opts.retprobe);
+        assert_eq!(opts.sz, size_of::<bpf_kprobe_multi_opts>() as _);
+
+        let err =
+            bpf_uprobe_multi_opts::from_slices(&[], &[0x10, 0x20], &[], &[1], false).unwrap_err();
+        assert_eq!(
+            err,
+            ArrayLengthMismatch {
+                name: "cookies",
+                len: 1,
+                expected: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn ring_buffer_callback() {
+        use libbpf_sys::callback::*;
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let total = Cell::new(0);
+        let cb = ring_buffer_callback(|data| {
+            total.set(total.get() + data.len());
+            data.len() as i32
+        });
+        let f = cb.callback().unwrap();
+        let mut data = [1u8, 2, 3];
+        assert_eq!(unsafe { f(cb.ctx(), data.as_mut_ptr().cast(), 3) }, 3);
+        assert_eq!(unsafe { f(cb.ctx(), std::ptr::null_mut(), 0) }, 0);
+        drop(cb);
+        assert_eq!(total.get(), 3);
+
+        let captured = Rc::new(());
+        let clone = Rc::clone(&captured);
+        let (_, ctx, destructor) = ring_buffer_callback(move |_| {
+            let _ = &clone;
+            0
+        })
+        .into_raw();
+        assert_eq!(Rc::strong_count(&captured), 2);
+        unsafe { destructor(ctx) };
+        assert_eq!(Rc::strong_count(&captured), 1);
+    }
+
+    #[cfg(not(feature = "no-perf"))]
+    #[test]
+    fn perf_buffer_callbacks() {
+        use libbpf_sys::callback::*;
+        use std::cell::RefCell;
+
+        let events = RefCell::new(Vec::new());
+        let cb = perf_buffer_callbacks(
+            |cpu, data| events.borrow_mut().push((cpu, data.len() as u64)),
+            |cpu, cnt| events.borrow_mut().push((cpu, cnt)),
+        );
+        let (sample, lost) = cb.callback();
+        let mut data = [0u8; 8];
+        unsafe {
+            sample.unwrap()(cb.ctx(), 1, data.as_mut_ptr().cast(), 8);
+            lost.unwrap()(cb.ctx(), 2, 42);
+        }
+        drop(cb);
+        assert_eq!(*events.borrow(), [(1, 8), (2, 42)]);
+    }
+
+    #[test]
+    fn type_names() {
+        use libbpf_sys::names::*;
+
+        assert_eq!(bpf_prog_type_from_str("kprobe"), Some(BPF_PROG_TYPE_KPROBE));
+        assert_eq!(bpf_prog_type_from_str("xdp"), Some(BPF_PROG_TYPE_XDP));
+        assert_eq!(bpf_map_type_from_str("hash"), Some(BPF_MAP_TYPE_HASH));
+        assert_eq!(
+            bpf_map_type_from_str("cgroup_storage"),
+            Some(BPF_MAP_TYPE_CGROUP_STORAGE)
+        );
+        assert_eq!(
+            bpf_attach_type_from_str("cgroup_inet_ingress"),
+            Some(BPF_CGROUP_INET_INGRESS)
+        );
+        assert_eq!(bpf_link_type_from_str("tcx"), Some(BPF_LINK_TYPE_TCX));
+        assert_eq!(bpf_prog_type_from_str("KPROBE"), None);
+        assert_eq!(bpf_map_type_from_str("__max_bpf_map_type"), None);
+
+        assert_eq!(bpf_prog_type_name(BPF_PROG_TYPE_KPROBE), Some("kprobe"));
+        assert_eq!(bpf_link_type_name(BPF_LINK_TYPE_TCX), Some("tcx"));
+        assert_eq!(bpf_map_type_name(__MAX_BPF_MAP_TYPE), None);
+    }
+
+    #[cfg(feature = "display")]
+    #[test]
+    fn type_display() {
+        use libbpf_sys::display::*;
+
+        assert_eq!(ProgType(BPF_PROG_TYPE_XDP).to_string(), "xdp");
+        assert_eq!(MapType(BPF_MAP_TYPE_RINGBUF).to_string(), "ringbuf");
+        assert_eq!(
+            AttachType(BPF_CGROUP_INET_INGRESS).to_string(),
+            "cgroup_inet_ingress"
+        );
+        assert_eq!(
+            "tracing".parse::<LinkType>(),
+            Ok(LinkType(BPF_LINK_TYPE_TRACING))
+        );
+        assert_eq!(
+            "foo".parse::<ProgType>(),
+            Err(UnknownTypeName {
+                ty: "bpf_prog_type"
+            })
+        );
+        assert_eq!(
+            ProgType(__MAX_BPF_PROG_TYPE).to_string(),
+            format!("unknown bpf_prog_type {}", __MAX_BPF_PROG_TYPE)
+        );
+    }
+
+    #[test]
+    fn section_defs() {
+        use libbpf_sys::sections::*;
+
+        let def = |name| SectionDef {
+            name,
+            prog_type: BPF_PROG_TYPE_KPROBE,
+            expected_attach_type: 0,
+            attach_btf: false,
+            sleepable: false,
+        };
+        assert!(def("kprobe+").matches("kprobe"));
+        assert!(def("kprobe+").matches("kprobe/do_exit"));
+        assert!(!def("kprobe+").matches("kprobex"));
+        assert!(def("fentry/").matches("fentry/do_exit"));
+        assert!(!def("fentry/").matches("fentry"));
+        assert!(def("xdp").matches("xdp"));
+        assert!(!def("xdp").matches("xdp/foo"));
+
+        // The table is only available with the libbpf sources.
+        if SECTION_DEFS.is_empty() {
+            return;
+        }
+        let def = find_section_def("tp_btf/sched_switch").unwrap();
+        assert_eq!(def.prog_type, BPF_PROG_TYPE_TRACING);
+        assert_eq!(def.expected_attach_type, BPF_TRACE_RAW_TP);
+        assert!(def.attach_btf);
+        let def = find_section_def("cgroup_skb/egress").unwrap();
+        assert_eq!(def.expected_attach_type, BPF_CGROUP_INET_EGRESS);
+        assert!(find_section_def("uretprobe.s/bin:func").unwrap().sleepable);
+        assert_eq!(find_section_def("does_not_exist"), None);
+    }
+
+    #[test]
+    fn helper_protos() {
+        use libbpf_sys::helpers::*;
+
+        // The table is only available with the libbpf sources.
+        if HELPER_PROTOS.is_empty() {
+            return;
+        }
+        let proto = helper_proto("bpf_map_lookup_elem").unwrap();
+        assert_eq!(proto.id, BPF_FUNC_map_lookup_elem);
+        assert_eq!(proto.ret, "void *");
+        assert_eq!(proto.args, ["void *", "const void *"]);
+        assert_eq!(
+            helper_proto_by_id(BPF_FUNC_trace_printk)
+                .unwrap()
+                .args
+                .last(),
+            Some(&"...")
+        );
+        assert_eq!(helper_proto("bpf_does_not_exist"), None);
+    }
+
+    #[test]
+    fn provenance() {
+        use libbpf_sys::names::*;
+        use libbpf_sys::provenance::*;
+
+        for table in [KERNEL_VERSIONS, LIBBPF_VERSIONS] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        // Every program and map type has to be annotated.
+        for prog_type in 1..__MAX_BPF_PROG_TYPE {
+            let name = bpf_prog_type_name(prog_type).unwrap().to_ascii_uppercase();
+            let name = format!("BPF_PROG_TYPE_{}", name);
+            assert!(kernel_version(&name).is_some(), "{} is not annotated", name);
+        }
+        for map_type in 1..__MAX_BPF_MAP_TYPE {
+            let name = bpf_map_type_name(map_type).unwrap().to_ascii_uppercase();
+            let name = format!("BPF_MAP_TYPE_{}", name);
+            assert!(kernel_version(&name).is_some(), "{} is not annotated", name);
+        }
+
+        let lsm = provenance("BPF_PROG_TYPE_LSM").unwrap();
+        assert_eq!(lsm.kernel, Some((5, 7)));
+        assert_eq!(lsm.libbpf, None);
+        assert_eq!(lsm.to_string(), "`BPF_PROG_TYPE_LSM` requires kernel 5.7");
+        assert_eq!(provenance("BPF_DOES_NOT_EXIST"), None);
+    }
+
+    #[test]
+    #[cfg_attr(
+        libbpf_sys_no_libbpf_map,
+        ignore = "libbpf/src/libbpf.map is missing; check out the libbpf submodule"
+    )]
+    fn provenance_libbpf() {
+        use libbpf_sys::provenance::*;
+
+        assert!(!LIBBPF_VERSIONS.is_empty());
+        assert_eq!(libbpf_version("bpf_object__open"), Some((0, 0)));
+        assert_eq!(libbpf_version("bpf_map_create"), Some((0, 7)));
+        assert_eq!(
+            provenance("bpf_map_create").unwrap().to_string(),
+            "`bpf_map_create` requires libbpf 0.7"
+        );
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn perf_event_data() {
+        use std::mem::offset_of;
+        use std::mem::size_of;
+
+        assert_eq!(size_of::<pt_regs>(), 168);
+        assert_eq!(offset_of!(pt_regs, rip), 128);
+        assert_eq!(offset_of!(bpf_perf_event_data, sample_period), 168);
+        assert_eq!(size_of::<bpf_perf_event_data>(), 184);
+
+        let regs = pt_regs {
+            rdi: 1,
+            r9: 6,
+            rax: 42,
+            rip: 0xdead,
+            ..Default::default()
+        };
+        assert_eq!(pt_regs::PARM_COUNT, 6);
+        assert_eq!(regs.parm(1), Some(1));
+        assert_eq!(regs.parm(6), Some(6));
+        assert_eq!(regs.parm(0), None);
+        assert_eq!(regs.parm(7), None);
+        assert_eq!(regs.rc(), 42);
+        assert_eq!(regs.ip(), 0xdead);
+    }
+
+    #[cfg(not(any(feature = "dlopen", feature = "stubs", feature = "no-std")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn verifier_log() {
+        use libbpf_sys::testing::Requirement;
+        use libbpf_sys::verifier_log::*;
+
+        const EACCES: i32 = 13;
+
+        require_or_skip!(Requirement::BpfPrivileges);
+
+        // Returning without setting r0 is rejected by the verifier.
+        let insns = [insn::exit_insn()];
+        let (ret, log) = unsafe {
+            bpf_prog_load_with_log(
+                BPF_PROG_TYPE_SOCKET_FILTER,
+                std::ptr::null(),
+                b"GPL\0".as_ptr().cast(),
+                insns.as_ptr(),
+                insns.len() as _,
+                std::ptr::null(),
+            )
+        };
+        assert_eq!(ret, -EACCES);
+        let log = String::from_utf8(log).unwrap();
+        assert!(log.contains("R0 !read_ok"), "{}", log);
+    }
+
+    #[cfg(not(any(feature = "dlopen", feature = "stubs", feature = "no-std")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn feature_probes() {
+        use libbpf_sys::testing::Requirement;
+        use std::ptr;
+
+        const EINVAL: i32 = 22;
+
+        require_or_skip!(Requirement::BpfPrivileges);
+
+        // Probes report support as one or zero, or fail with a negative
+        // error code.
+        let supported = |ret: i32| {
+            assert!(ret == 0 || ret == 1, "probe failed: {}", ret);
+            ret == 1
+        };
+
+        // Supported by any kernel libbpf works with.
+        let prog_type = |ty| supported(unsafe { libbpf_probe_bpf_prog_type(ty, ptr::null()) });
+        assert!(prog_type(BPF_PROG_TYPE_SOCKET_FILTER));
+        assert!(prog_type(BPF_PROG_TYPE_KPROBE));
+        prog_type(BPF_PROG_TYPE_XDP);
+        prog_type(BPF_PROG_TYPE_TRACING);
+
+        let map_type = |ty| supported(unsafe { libbpf_probe_bpf_map_type(ty, ptr::null()) });
+        assert!(map_type(BPF_MAP_TYPE_HASH));
+        assert!(map_type(BPF_MAP_TYPE_ARRAY));
+        map_type(BPF_MAP_TYPE_RINGBUF);
+        map_type(BPF_MAP_TYPE_USER_RINGBUF);
+
+        let helper = |ty, id| supported(unsafe { libbpf_probe_bpf_helper(ty, id, ptr::null()) });
+        assert!(helper(
+            BPF_PROG_TYPE_SOCKET_FILTER,
+            BPF_FUNC_map_lookup_elem
+        ));
+        helper(BPF_PROG_TYPE_XDP, BPF_FUNC_redirect_map);
+
+        // Options are reserved and have to be null.
+        let opts = 1u64;
+        let ret = unsafe {
+            libbpf_probe_bpf_prog_type(BPF_PROG_TYPE_SOCKET_FILTER, (&opts as *const u64).cast())
+        };
+        assert_eq!(ret, -EINVAL);
+        let ret = unsafe { libbpf_probe_bpf_prog_type(__MAX_BPF_PROG_TYPE, ptr::null()) };
+        assert!(ret < 0, "probing an unknown program type returned {}", ret);
+    }
+
+    #[cfg(all(
+        feature = "printf",
+        not(any(feature = "dlopen", feature = "stubs", feature = "no-btf-dump"))
+    ))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn printf_callbacks() {
+        use std::ptr;
+
+        let btf = unsafe { btf__new_empty() };
+        let int = unsafe { btf__add_int(btf, b"int\0".as_ptr().cast(), 4, BTF_INT_SIGNED as _) };
+        let point = unsafe { btf__add_struct(btf, b"point\0".as_ptr().cast(), 8) };
+        unsafe { btf__add_field(btf, b"x\0".as_ptr().cast(), int, 0, 0) };
+        unsafe { btf__add_field(btf, b"y\0".as_ptr().cast(), int, 32, 0) };
+
+        let printf = printf::btf_dump_printf_fn_t(|ctx, msg| {
+            let out = unsafe { &mut *ctx.cast::<String>() };
+            out.push_str(msg.to_str().unwrap());
+        });
+        let mut out = String::new();
+        let dump =
+            unsafe { btf_dump__new(btf, printf, (&mut out as *mut String).cast(), ptr::null()) };
+        assert!(!dump.is_null());
+        assert_eq!(unsafe { btf_dump__dump_type(dump, point as _) }, 0);
+        unsafe { btf_dump__free(dump) };
+        unsafe { btf__free(btf) };
+        assert!(out.contains("struct point {"), "{}", out);
+        assert!(out.contains("\tint x;\n"), "{}", out);
+    }
+
+    #[cfg(all(
+        feature = "io-safety",
+        not(any(feature = "dlopen", feature = "stubs", feature = "no-std"))
+    ))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn io_safety() {
+        use std::fs::File;
+        use std::os::fd::AsRawFd as _;
+        use std::os::fd::OwnedFd;
+
+        let btf = unsafe { btf__new_empty() };
+        assert!(unsafe { io_safety::btf__fd(btf) }.is_none());
+
+        let fd = OwnedFd::from(File::open("/dev/null").unwrap());
+        let raw = fd.as_raw_fd();
+        unsafe { io_safety::btf__set_fd(btf, fd) };
+        let borrowed = unsafe { io_safety::btf__fd(btf) }.unwrap();
+        assert_eq!(borrowed.as_raw_fd(), raw);
+        // `btf__free` closes the file descriptor it took ownership of.
+        unsafe { btf__free(btf) };
+    }
+
+    /// Render `err` using `libbpf_strerror`.
+    #[cfg(not(any(feature = "dlopen", feature = "stubs")))]
+    fn strerror(err: i32) -> String {
+        let mut buf = [0u8; 128];
+        let ret = unsafe { libbpf_strerror(err, buf.as_mut_ptr().cast(), buf.len() as _) };
+        assert_eq!(ret, 0, "no description for {}", err);
+        let buf = std::ffi::CStr::from_bytes_until_nul(&buf).unwrap();
+        buf.to_str().unwrap().to_string()
+    }
+
+    #[cfg(not(any(feature = "dlopen", feature = "stubs")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn invalid_object_error() {
+        use libbpf_sys::error::*;
+        use std::io;
+        use std::ptr;
+
+        /// `LIBBPF_ERRNO__FORMAT`, for objects that are not ELF files.
+        const LIBBPF_ERRNO__FORMAT: i32 = 4001;
+
+        assert_eq!(
+            error_convention("bpf_object__open_mem"),
+            Some(ErrorConvention::NullErrno)
+        );
+        let data = b"not an ELF object";
+        let obj =
+            unsafe { bpf_object__open_mem(data.as_ptr().cast(), data.len() as _, ptr::null()) };
+        assert!(obj.is_null());
+        let errno = io::Error::last_os_error().raw_os_error().unwrap();
+        assert_eq!(errno, LIBBPF_ERRNO__FORMAT);
+        assert_eq!(strerror(-errno), "BPF object format invalid");
+    }
+
+    #[cfg(all(
+        feature = "errno",
+        not(any(feature = "dlopen", feature = "stubs", feature = "no-std"))
+    ))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn errno() {
+        use std::io;
+        use std::ptr;
+
+        /// `LIBBPF_ERRNO__FORMAT`, for objects that are not ELF files.
+        const LIBBPF_ERRNO__FORMAT: i32 = 4001;
+        const ENOENT: i32 = 2;
+
+        let data = b"not an ELF object";
+        let err = errno::check_ptr(unsafe {
+            bpf_object__open_mem(data.as_ptr().cast(), data.len() as _, ptr::null())
+        })
+        .unwrap_err();
+        assert_eq!(errno::errno_of(&err), Some(LIBBPF_ERRNO__FORMAT));
+        assert_eq!(err.to_string(), "BPF object format invalid");
+
+        assert_eq!(errno::check(42).unwrap(), 42);
+        let err = errno::check(-ENOENT).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert_eq!(errno::errno_of(&err), Some(ENOENT));
+        assert_eq!(errno::strerror(ENOENT), "No such file or directory");
+    }
+
+    #[cfg(not(any(feature = "dlopen", feature = "stubs", feature = "no-std")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn kernel_errors() {
+        use libbpf_sys::error::*;
+        use libbpf_sys::testing::Requirement;
+        use std::os::fd::AsRawFd;
+        use std::os::fd::FromRawFd;
+        use std::os::fd::OwnedFd;
+        use std::ptr;
+
+        const EBADF: i32 = 9;
+        const E2BIG: i32 = 7;
+
+        require_or_skip!(Requirement::BpfPrivileges);
+
+        // Values of array maps are limited to what can be allocated at
+        // once.
+        assert_eq!(
+            error_convention("bpf_map_create"),
+            Some(ErrorConvention::NegErrno)
+        );
+        let fd =
+            unsafe { bpf_map_create(BPF_MAP_TYPE_ARRAY, ptr::null(), 4, u32::MAX, 1, ptr::null()) };
+        assert_eq!(fd, -E2BIG);
+        assert_eq!(strerror(fd), "Argument list too long");
+
+        // Attaching to a cgroup that is not open.
+        let insns = [insn::mov64_imm(BPF_REG_0, 1), insn::exit_insn()];
+        let prog_fd = unsafe {
+            bpf_prog_load(
+                BPF_PROG_TYPE_CGROUP_SKB,
+                ptr::null(),
+                b"GPL\0".as_ptr().cast(),
+                insns.as_ptr(),
+                insns.len() as _,
+                ptr::null_mut(),
+            )
+        };
+        assert!(prog_fd >= 0, "bpf_prog_load failed: {}", prog_fd);
+        let prog = unsafe { OwnedFd::from_raw_fd(prog_fd) };
+
+        assert_eq!(
+            error_convention("bpf_link_create"),
+            Some(ErrorConvention::NegErrno)
+        );
+        let ret = unsafe {
+            bpf_link_create(
+                prog.as_raw_fd(),
+                i32::MAX,
+                BPF_CGROUP_INET_INGRESS,
+                ptr::null(),
+            )
+        };
+        assert_eq!(ret, -EBADF);
+        assert_eq!(strerror(ret), "Bad file descriptor");
+    }
+
+    #[cfg(not(feature = "no-std"))]
+    #[test]
+    #[cfg_attr(miri, ignore = "inspects /proc")]
+    fn testing_requirements() {
+        use libbpf_sys::testing::*;
+
+        let env = Environment {
+            capabilities: 1 << CAP_BPF,
+            unprivileged_bpf_disabled: Some(2),
+            kernel_version: Some((5, 15, 3)),
+        };
+        assert_eq!(env.check(Requirement::BpfPrivileges), Ok(()));
+        assert_eq!(env.check(Requirement::Kernel(5, 15)), Ok(()));
+
+        let skip = env.check(Requirement::Kernel(6, 1)).unwrap_err();
+        assert_eq!(skip.to_string(), "requires Linux 6.1 (running 5.15.3)");
+        let skip = env.check(Requirement::UnprivilegedBpf).unwrap_err();
+        assert_eq!(
+            skip.to_string(),
+            "requires unprivileged BPF (kernel.unprivileged_bpf_disabled is 2)"
+        );
+        let skip = env
+            .check(Requirement::Capability(CAP_NET_ADMIN))
+            .unwrap_err();
+        assert_eq!(skip.to_string(), "requires CAP_NET_ADMIN");
+
+        let env = Environment {
+            capabilities: 1 << CAP_SYS_ADMIN,
+            unprivileged_bpf_disabled: None,
+            kernel_version: None,
+        };
+        assert_eq!(env.check(Requirement::BpfPrivileges), Ok(()));
+        assert!(env.check(Requirement::Kernel(4, 18)).is_err());
+
+        let env = Environment::detect();
+        assert!(env.kernel_version.is_some());
+    }
+
+    #[cfg(not(feature = "no-std"))]
+    #[test]
+    #[cfg_attr(miri, ignore = "inspects /sys")]
+    fn vmlinux_btf() {
+        use libbpf_sys::vmlinux_btf::*;
+        use std::path::PathBuf;
+
+        match vmlinux_btf() {
+            Ok(path) => assert!(VMLINUX_BTF_LOCATIONS
+                .iter()
+                .any(|location| path.starts_with(location.split("{release}").next().unwrap()))),
+            Err(err) => assert!(!err.searched.is_empty()),
+        }
+
+        let err = MissingVmlinuxBtf {
+            release: Some("4.19.0".to_string()),
+            searched: vec![
+                PathBuf::from(VMLINUX_BTF_PATH),
+                PathBuf::from("/boot/vmlinux-4.19.0"),
+            ],
+        };
+        assert_eq!(
+            err.to_string(),
+            "no BTF found for kernel 4.19.0 (searched /sys/kernel/btf/vmlinux, \
+             /boot/vmlinux-4.19.0); CO-RE requires a kernel built with CONFIG_DEBUG_INFO_BTF"
+        );
+
+        let fallback = std::path::Path::new("/usr/share/vmlinux.btf");
+        assert_eq!(btf_custom_path(fallback).is_some(), vmlinux_btf().is_err());
+
+        let btf = std::ffi::CStr::from_bytes_with_nul(b"/usr/share/vmlinux.btf\0").unwrap();
+        let name = std::ffi::CStr::from_bytes_with_nul(b"name\0").unwrap();
+        let opts = libbpf_sys::bpf_object_open_opts {
+            object_name: name.as_ptr(),
+            ..Default::default()
+        };
+        let (object_name, btf_custom_path) = unsafe {
+            with_btf_custom_path(&opts, Some(btf), |opts| {
+                ((*opts).object_name, (*opts).btf_custom_path)
+            })
+        };
+        assert_eq!(object_name, name.as_ptr());
+        assert_eq!(btf_custom_path, btf.as_ptr());
+    }
+
+    #[cfg(all(feature = "compat-0x", not(feature = "stubs")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn compat_prog_load() {
+        use libbpf_sys::compat::*;
+
+        const EINVAL: i32 = 22;
+        const ENOENT: i32 = 2;
+
+        let mut obj = std::ptr::null_mut();
+        let mut fd = -1;
+        let rc = unsafe { bpf_prog_load_xattr(std::ptr::null(), &mut obj, &mut fd) };
+        assert_eq!(rc, -EINVAL);
+
+        let attr = bpf_prog_load_attr {
+            file: b"does-not-exist.o\0".as_ptr().cast(),
+            ..Default::default()
+        };
+        let rc = unsafe { bpf_prog_load_xattr(&attr, &mut obj, &mut fd) };
+        assert_eq!(rc, -ENOENT);
+        assert!(obj.is_null());
+        assert_eq!(fd, -1);
+    }
+
+    #[cfg(not(any(feature = "dlopen", feature = "stubs")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn struct_ops_layout() {
+        use libbpf_sys::struct_ops::*;
+        use std::ffi::CStr;
+
+        let btf = unsafe { btf__load_vmlinux_btf() };
+        if btf.is_null() {
+            return;
+        }
+
+        let name = CStr::from_bytes_with_nul(b"tcp_congestion_ops\0").unwrap();
+        let layout = unsafe { struct_ops_layout(btf, name) }.unwrap();
+        assert!(layout.data_offset > 0);
+        assert!(layout.value_size >= layout.data_offset + layout.size);
+
+        let members = unsafe { struct_ops_members(btf, layout.type_id) }.unwrap();
+        let member = |name: &[u8]| {
+            members
+                .iter()
+                .find(|m| unsafe { CStr::from_ptr(m.name) }.to_bytes() == name)
+                .unwrap()
+        };
+        assert!(member(b"ssthresh").is_func_ptr);
+        assert!(!member(b"name").is_func_ptr);
+        assert!(members.windows(2).all(|m| m[0].offset <= m[1].offset));
+
+        let name = CStr::from_bytes_with_nul(b"task_struct\0").unwrap();
+        assert!(unsafe { struct_ops_layout(btf, name) }.is_err());
+        unsafe { btf__free(btf) };
+    }
+
+    #[cfg(feature = "dlopen")]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn dlopen() {
+        use libbpf_sys::dlopen::LibBpf;
+
+        // libbpf not being installed is not an error.
+        let Ok(lib) = (unsafe { LibBpf::load() }) else {
+            return;
+        };
+        let major_version = lib.libbpf_major_version.unwrap();
+        assert_eq!(unsafe { major_version() }, 1);
+    }
+
+    #[cfg(feature = "stubs")]
+    #[test]
+    fn stubs() {
+        use std::io;
+
+        const EPERM: i32 = 1;
+        const ENOENT: i32 = 2;
+
+        stubs::reset();
+        assert_eq!(unsafe { libbpf_major_version() }, 0);
+
+        stubs::fail("bpf_map_create", EPERM);
+        let fd = unsafe {
+            bpf_map_create(
+                BPF_MAP_TYPE_ARRAY,
+                std::ptr::null(),
+                4,
+                4,
+                1,
+                std::ptr::null(),
+            )
+        };
+        assert_eq!(fd, -EPERM);
+
+        stubs::fail_all(ENOENT);
+        let obj = unsafe { bpf_object__open(b"foo.o\0".as_ptr().cast()) };
+        assert!(obj.is_null());
+        assert_eq!(io::Error::last_os_error().raw_os_error(), Some(ENOENT));
+
+        assert_eq!(
+            stubs::calls(),
+            ["libbpf_major_version", "bpf_map_create", "bpf_object__open"]
+        );
+        stubs::reset();
+        assert!(stubs::calls().is_empty());
+    }
+
+    #[cfg(not(any(feature = "dlopen", feature = "stubs")))]
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into libbpf")]
+    fn loaded_libbpf() {
+        use libbpf_sys::loaded::*;
+
+        let loaded = loaded_libbpf();
+        assert_eq!(loaded.major_version, unsafe { libbpf_major_version() });
+        assert!(loaded.version.to_bytes().starts_with(b"v"));
+        match loaded.linkage {
+            Linkage::Shared { path, soname } => {
+                assert!(path.to_bytes().ends_with(soname.to_bytes()));
+                assert!(soname.to_bytes().starts_with(b"libbpf.so"));
+            },
+            Linkage::Static | Linkage::Unknown => (),
+        }
+        assert!(loaded.to_string().starts_with("libbpf "));
+    }
 }
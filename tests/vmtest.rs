@@ -0,0 +1,79 @@
+// tests/vmtest.rs
+
+//! Runs the smoke tests of `tests/root.rs` inside a virtual machine.
+//!
+//! The virtual machine boots the kernel image that
+//! `LIBBPF_SYS_VMTEST_KERNEL` points to, using
+//! [vmtest](https://github.com/danobi/vmtest) on top of qemu. vmtest
+//! shares the host's file system with the guest, so the test binary
+//! built by cargo can be run inside as is. `LIBBPF_SYS_VMTEST` may name
+//! the `vmtest` binary to use. Without a kernel image, the test is
+//! skipped.
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Build the `root` test with the features this test was built with and
+/// retrieve the path to its binary, as cargo reports it.
+fn root_tests() -> PathBuf {
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "test",
+            "--no-run",
+            "--message-format=json",
+            "--test",
+            "root",
+        ])
+        .args([
+            "--no-default-features",
+            "--features",
+            env!("LIBBPF_SYS_FEATURES"),
+        ])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("failed to run cargo");
+    assert!(
+        output.status.success(),
+        "failed to build the root tests: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Each line is a JSON message; the one about the test binary is the
+    // only one naming the `root` test target and an executable.
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .filter(|line| line.contains(r#""name":"root""#) && line.contains(r#""kind":["test"]"#))
+        .find_map(|line| {
+            let (_, rest) = line.split_once(r#""executable":""#)?;
+            let (path, _) = rest.split_once('"')?;
+            Some(PathBuf::from(path))
+        })
+        .expect("binary of the root tests not found")
+}
+
+#[test]
+fn root_tests_in_vm() {
+    let kernel = match env::var_os("LIBBPF_SYS_VMTEST_KERNEL") {
+        Some(kernel) => kernel,
+        None => {
+            eprintln!("LIBBPF_SYS_VMTEST_KERNEL not set; skipping");
+            return;
+        },
+    };
+    let vmtest = env::var_os("LIBBPF_SYS_VMTEST").unwrap_or_else(|| "vmtest".into());
+
+    // The tests are ignored outside of the VM, see `tests/root.rs`.
+    let command = format!(
+        "{} --include-ignored --test-threads=1",
+        root_tests().display()
+    );
+    let status = Command::new(&vmtest)
+        .arg("--kernel")
+        .arg(&kernel)
+        .arg(command)
+        .status()
+        .unwrap_or_else(|err| panic!("failed to run {:?}: {}", vmtest, err));
+    assert!(status.success(), "root tests failed in the VM: {}", status);
+}
@@ -0,0 +1,10 @@
+/* SPDX-License-Identifier: GPL-2.0 WITH Linux-syscall-note */
+#ifndef __ASM_BITSPERLONG_H
+#define __ASM_BITSPERLONG_H
+
+/* Derived from the compiler's data model, for any architecture. */
+#define __BITS_PER_LONG (__CHAR_BIT__ * __SIZEOF_LONG__)
+
+#include <asm-generic/bitsperlong.h>
+
+#endif /* __ASM_BITSPERLONG_H */
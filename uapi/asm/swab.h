@@ -0,0 +1,3 @@
+/* SPDX-License-Identifier: GPL-2.0 WITH Linux-syscall-note */
+/* The architecture specific byte swapping helpers are optional. */
+#include <asm-generic/swab.h>